@@ -0,0 +1,59 @@
+//! Compares classifying every day of a multi-year window with
+//! `classify_days`, which looks up holidays of the whole window at once,
+//! against checking each day with `is_holiday` and the workweek.
+//!
+//! Run with `cargo bench --bench classify`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use holidays::{Country, CountrySet, Date, DayKind, WindowClip, Workweek};
+
+const ITERATIONS: u32 = 20;
+
+fn measure(name: &str, mut f: impl FnMut() -> Vec<DayKind>) {
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(f());
+        }
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{name:<6} {:>10.1} µs/window",
+        best.as_secs_f64() * 1e6 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let countries: CountrySet = [Country::US, Country::GB, Country::DE]
+        .into_iter()
+        .collect();
+    let (start, end) = (Date::from_ymd(2020, 1, 1), Date::from_ymd(2030, 1, 1));
+    let workweek = Workweek::MON_FRI;
+
+    measure("bulk", || {
+        holidays::classify_days(
+            black_box(countries),
+            start..end,
+            workweek,
+            WindowClip::Exact,
+        )
+    });
+    measure("naive", || {
+        let mut kinds = Vec::new();
+        let mut date = start;
+        while date < end {
+            kinds.push(if holidays::is_holiday(black_box(countries), date) {
+                DayKind::Holiday
+            } else if workweek.contains(date.weekday()) {
+                DayKind::Workday
+            } else {
+                DayKind::Weekend
+            });
+            date = date.saturating_add_days(1);
+        }
+        kinds
+    });
+}
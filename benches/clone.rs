@@ -0,0 +1,39 @@
+//! Measures cloning a partially consumed all-countries query, on its own and
+//! followed by peeking at a few of the next results.
+//!
+//! Run with `cargo bench --bench clone`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use holidays::Any;
+use holidays::Date;
+
+const ITERATIONS: u32 = 100_000;
+
+fn measure(name: &str, mut f: impl FnMut()) {
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            f();
+        }
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{name:<8} {:>8.1} ns/clone",
+        best.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let mut iter = holidays::get_holidays::<_, Date, _>(Any, Any);
+    iter.nth(1000);
+
+    measure("clone", || {
+        black_box(black_box(&iter).clone());
+    });
+    measure("peek", || {
+        black_box(black_box(&iter).clone().take(3).last());
+    });
+}
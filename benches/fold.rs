@@ -0,0 +1,53 @@
+//! Compares histograms of holidays by country built with `Iterator::fold`
+//! against ones built by calling `next` in a loop, for the full dataset and
+//! for all records of a few countries, which are merged from their tables.
+//!
+//! Run with `cargo bench --bench fold`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use holidays::{Any, Country, Date, Holiday};
+
+const ITERATIONS: u32 = 100;
+
+fn add(mut histogram: Vec<usize>, holiday: &Holiday) -> Vec<usize> {
+    histogram[holiday.code as usize] += 1;
+    histogram
+}
+
+fn measure(name: &str, mut f: impl FnMut() -> Vec<usize>) {
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(f());
+        }
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{name:<12} {:>8.1} µs/histogram",
+        best.as_secs_f64() * 1e6 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let all = holidays::PreparedQuery::new::<_, Date, _>(Any, Any);
+    let few =
+        holidays::PreparedQuery::new::<_, Date, _>([Country::US, Country::DE, Country::JP], Any);
+    let empty = || vec![0; holidays::enabled_countries().len()];
+
+    for (name, query) in [("all", &all), ("merged", &few)] {
+        measure(&format!("{name} fold"), || {
+            black_box(query.iter()).fold(empty(), add)
+        });
+        measure(&format!("{name} next"), || {
+            let mut histogram = empty();
+            // `for` loops call `next` for every record
+            for holiday in black_box(query.iter()) {
+                histogram = add(histogram, holiday);
+            }
+            histogram
+        });
+    }
+}
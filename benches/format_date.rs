@@ -0,0 +1,75 @@
+//! Compares zero-allocation ISO formatting of holiday dates, with and
+//! without `std::fmt`, against converting them to `chrono` and formatting
+//! through it.
+//!
+//! Run with `cargo bench --features chrono --bench format_date`.
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use holidays::Any;
+
+const ROUNDS: usize = 20;
+
+fn measure(name: &str, mut f: impl FnMut(&mut String) -> usize) {
+    let mut buf = String::with_capacity(1 << 20);
+    let mut best = Duration::MAX;
+    let mut records = 0;
+    for _ in 0..ROUNDS {
+        buf.clear();
+        let start = Instant::now();
+        records = black_box(f(&mut buf));
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{name:<16} {:>10.2?} total, {:>6.1} ns/record",
+        best,
+        best.as_nanos() as f64 / records as f64
+    );
+}
+
+fn main() {
+    let holidays: Vec<_> = holidays::get_holidays(Any, 2000..2036).collect();
+
+    measure("format_date", |buf| {
+        for holiday in &holidays {
+            holiday.format_date(buf).unwrap();
+            buf.push('\n');
+        }
+        holidays.len()
+    });
+
+    measure("display_date", |buf| {
+        for holiday in &holidays {
+            writeln!(buf, "{}", holiday.display_date()).unwrap();
+        }
+        holidays.len()
+    });
+
+    measure("iso_date_buf", |buf| {
+        for holiday in &holidays {
+            buf.push_str(holiday.iso_date_buf().as_str());
+            buf.push('\n');
+        }
+        holidays.len()
+    });
+
+    measure("iso_dates", |buf| {
+        let mut records = 0;
+        for date in holidays::get_holidays(Any, 2000..2036).iso_dates() {
+            buf.push_str(date.as_str());
+            buf.push('\n');
+            records += 1;
+        }
+        records
+    });
+
+    measure("chrono", |buf| {
+        for holiday in &holidays {
+            let date: chrono::NaiveDate = holiday.date().unwrap();
+            writeln!(buf, "{}", date.format("%Y-%m-%d")).unwrap();
+        }
+        holidays.len()
+    });
+}
@@ -0,0 +1,35 @@
+//! Compares `Iterator::last` on query results against walking the whole
+//! result, for the last holiday of a 35-year single-country query.
+//!
+//! Run with `cargo bench --bench last`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use holidays::Country;
+
+const ITERATIONS: u32 = 10_000;
+
+fn measure(name: &str, mut f: impl FnMut() -> Option<&'static holidays::Holiday>) {
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(f());
+        }
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{name:<8} {:>8.1} ns/query",
+        best.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let query = holidays::PreparedQuery::new(Country::US, 2000..2035);
+
+    measure("last", || black_box(query.iter()).last());
+    measure("walk", || {
+        black_box(query.iter()).fold(None, |_, it| Some(it))
+    });
+}
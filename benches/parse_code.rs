@@ -0,0 +1,39 @@
+//! Compares parsing two-letter country codes from bytes through `FromStr`
+//! against `Country::from_code_bytes`, for a mix of enabled, lowercase and
+//! unknown codes.
+//!
+//! Run with `cargo bench --bench parse_code`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use holidays::Country;
+
+const ITERATIONS: u32 = 100_000;
+const CODES: [[u8; 2]; 8] = [
+    *b"US", *b"de", *b"JP", *b"XX", *b"gb", *b"BR", *b"q1", *b"FR",
+];
+
+fn measure(name: &str, mut f: impl FnMut([u8; 2]) -> Option<Country>) {
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            for code in CODES {
+                black_box(f(black_box(code)));
+            }
+        }
+        best = best.min(start.elapsed());
+    }
+    let per_code = best.as_nanos() as f64 / (ITERATIONS as usize * CODES.len()) as f64;
+    println!("{name:<8} {per_code:>8.1} ns/code");
+}
+
+fn main() {
+    measure("parse", |code| {
+        std::str::from_utf8(&code)
+            .ok()
+            .and_then(|it| it.parse().ok())
+    });
+    measure("bytes", Country::from_code_bytes);
+}
@@ -1,90 +1,95 @@
-use csv::StringRecord;
+use dataset::{Attribution, Country, Coverage, Date, Holiday};
 use std::{
     collections::{BTreeMap, HashMap},
-    fmt::Display,
     fs::File,
     hash::Hash,
     io::{BufReader, BufWriter, Write},
     path::PathBuf,
-    str::FromStr,
 };
 
+#[path = "build/dataset.rs"]
+mod dataset;
+#[allow(dead_code)]
+#[path = "src/name.rs"]
+mod name;
+#[path = "build/shared.rs"]
+mod shared;
+
 // Make sure to also update ./gen.py years range
 // These numbers should be more conservative to reduce compile time
 const DEFAULT_MIN_YEAR: i64 = 2000;
 const DEFAULT_MAX_YEAR: i64 = 2035;
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
-struct Country {
-    index: u16,
-    code: String,
-    name: String,
-}
+// Years listed in generated country docs; fixed rather than derived from the
+// build date so docs are reproducible, bump along with data updates
+const DOC_YEARS: (i64, i64) = (2025, 2027);
+const DOC_MAX_ROWS: usize = 60;
 
 pub fn is_country_enabled(code: &str) -> bool {
     let feature = format!("CARGO_FEATURE_{code}");
     std::env::var(&feature).is_ok()
 }
 
-impl Display for Country {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Country::{}", self.code)
-    }
+pub fn is_names_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_NO_NAMES").is_err()
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-struct Date {
-    year: i64,
-    month: u8,
-    day: u8,
-    day_index: i64,
+pub fn is_name_index_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_NAME_INDEX").is_ok()
 }
 
-pub const fn ymd_as_isize(mut y: i64, m: i64, d: i64) -> i64 {
-    // Source: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
-    if m <= 2 {
-        y -= 1
-    }
+pub fn is_country_docs_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_COUNTRY_DOCS").is_ok()
+}
 
-    let era = y.div_euclid(400);
-    let year_of_era = (y - era * 400) as u32;
-    let day_of_year = ((153 * ((m + 9) % 12) + 2) / 5 + d - 1) as u32;
-    let day_of_era =
-        year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+/// Optional features other than countries that are listed in the generated
+/// build configuration.
+///
+/// Only these variables are read, so the configuration can't pick up
+/// unrelated contents of the build environment.
+const REPORTED_FEATURES: &[&str] = &[
+    "cache",
+    "chrono",
+    "chrono-tz",
+    "country-docs",
+    "name-index",
+    "no-names",
+    "raw-tables",
+    "serde",
+    "test-support",
+    "time",
+];
+
+pub fn is_feature_enabled(feature: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    std::env::var_os(var).is_some()
+}
 
-    era * 146097 + (day_of_era as i64) - 719468
+/// Path of a CSV file in `holidays.csv` format to merge into the data.
+pub fn extra_csv_path() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=HOLIDAYS_EXTRA_CSV");
+    let path = std::env::var_os("HOLIDAYS_EXTRA_CSV").map(PathBuf::from)?;
+    println!("cargo:rerun-if-changed={}", path.display());
+    Some(path)
 }
 
-impl Date {}
-impl FromStr for Date {
-    type Err = ();
-
-    fn from_str(date: &str) -> Result<Self, Self::Err> {
-        let mut date = date.split("-");
-        let year = date.next().ok_or(())?.parse().map_err(|_| ())?;
-        let month = date.next().ok_or(())?.parse().map_err(|_| ())?;
-        let day = date.next().ok_or(())?.parse().map_err(|_| ())?;
-        Ok(Date {
-            year,
-            month,
-            day,
-            day_index: ymd_as_isize(year, month as i64, day as i64),
-        })
-    }
+/// Unknown `holidays.csv` columns are rejected unless this variable is set,
+/// e.g. to build with a CSV produced by a newer `gen.py`.
+pub fn is_extra_columns_allowed() -> bool {
+    std::env::var("HOLIDAYS_ALLOW_EXTRA_COLUMNS").is_ok()
 }
 
 #[derive(PartialEq, Eq)]
 struct FullSpec<'a>(&'a Country, Date);
 impl<'a> Hash for FullSpec<'a> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0.index.hash(state);
-        self.1.day_index.hash(state);
+        shared::hash_point(self.0.index, self.1.day_index, state);
     }
 }
 impl<'a> phf_shared::PhfHash for FullSpec<'a> {
     fn phf_hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0.index.hash(state);
-        self.1.day_index.hash(state);
+        // must match `Point` of `src/data.rs`
+        shared::hash_point(self.0.index, self.1.day_index, state);
     }
 }
 impl<'a> phf_shared::FmtConst for FullSpec<'a> {
@@ -93,59 +98,47 @@ impl<'a> phf_shared::FmtConst for FullSpec<'a> {
     }
 }
 
-#[derive(PartialEq, Eq)]
-struct Holiday<'a> {
-    country: &'a Country,
-    date: Date,
-    name: String,
-}
-
-fn parse_holiday_row<'a>(
-    row: StringRecord,
-    countries: &'a HashMap<String, Country>,
-) -> Option<Holiday<'a>> {
-    let mut it = row.iter().map(String::from);
-
-    let code = it.next().expect("invalid row in holidays.csv");
-    let country = countries.get(&code)?;
-
-    Some(Holiday {
-        country,
-        date: {
-            let date = it.next().expect("invalid row in holidays.csv");
-            date.parse().expect("invalid date format in holidays.csv")
-        },
-        name: it.next().expect("invalid row in holidays.csv"),
-    })
-}
-
-impl<'a> PartialOrd for Holiday<'a> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl<'a> Ord for Holiday<'a> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.date
-            .cmp(&other.date)
-            .then(self.country.cmp(other.country))
-            .then(self.name.cmp(&other.name))
-    }
-}
-
 fn gen_country_enum_decl<'a, W: Write, C: Iterator<Item = &'a Country>>(
     out: &mut W,
     countries: C,
+    known_codes: &[String],
+    coverage: &HashMap<u16, Coverage>,
+    year_range: (i64, i64),
 ) -> std::io::Result<()> {
     let mut reverse_lookup = phf_codegen::Map::<&str>::new();
+    // index + 1 of countries by both letters of their codes, 0 if not enabled
+    let mut code_bytes = [0u8; 26 * 26];
 
     out.write_all(b"declare_countries![\n")?;
     for c in countries {
-        writeln!(out, "{0}: \"{0}\" \"{1}\" {2},", c.code, c.name, c.index)?;
+        let cov = Coverage::get(coverage, c, year_range.0, year_range.1);
+        writeln!(
+            out,
+            "{0}: \"{0}\" \"{1}\" \"Embedded data covers years {3}-{4} with {5} records.\" \"{6}\" \"{7}\" {2},",
+            c.code,
+            c.name,
+            c.index,
+            cov.first_year,
+            cov.last_year,
+            cov.record_count,
+            c.time_zone,
+            c.name_language
+        )?;
         reverse_lookup.entry(&c.code, format!("Country::{}", c.code));
+        let [first, second] = c.code.as_bytes() else {
+            panic!("country code {} isn't two letters long", c.code);
+        };
+        let slot = (first - b'A') as usize * 26 + (second - b'A') as usize;
+        code_bytes[slot] =
+            u8::try_from(c.index + 1).expect("too many countries for code byte table");
     }
     out.write_all(b"];\n")?;
 
+    writeln!(
+        out,
+        "pub(crate) static CODE_BYTES_TABLE: [u8; 676] = {code_bytes:?};"
+    )?;
+
     write!(
         out,
         "pub(crate) static CODE_TO_COUNTRY: phf::Map<&'static str, Country> = {}",
@@ -154,27 +147,165 @@ fn gen_country_enum_decl<'a, W: Write, C: Iterator<Item = &'a Country>>(
     .unwrap();
     writeln!(out, ";").unwrap();
 
+    let mut known = phf_codegen::Set::new();
+    for code in known_codes {
+        known.entry(code.as_str());
+    }
+    writeln!(
+        out,
+        "pub(crate) static KNOWN_CODES: phf::Set<&'static str> = {};",
+        known.build()
+    )?;
+
+    Ok(())
+}
+
+fn gen_coverage<'a, W: Write, C: Iterator<Item = &'a Country>>(
+    out: &mut W,
+    countries: C,
+    coverage: &HashMap<u16, Coverage>,
+    year_range: (i64, i64),
+) -> std::io::Result<()> {
+    let mut table = Vec::new();
+    for c in countries {
+        let cov = Coverage::get(coverage, c, year_range.0, year_range.1);
+        writeln!(out, "/// Embedded data coverage of {}.", c.name)?;
+        writeln!(
+            out,
+            "pub const {}: CountryCoverage = CountryCoverage {{ first_year: {}, last_year: {}, record_count: {} }};",
+            c.code, cov.first_year, cov.last_year, cov.record_count
+        )?;
+        table.push(c.code.as_str());
+    }
+    writeln!(
+        out,
+        "pub(crate) const TABLE: &[CountryCoverage] = &[{}];",
+        table.join(", ")
+    )?;
+    Ok(())
+}
+
+fn gen_attribution<W: Write>(out: &mut W, attribution: &Attribution) -> std::io::Result<()> {
+    writeln!(
+        out,
+        "pub(crate) const ATTRIBUTION: Attribution = Attribution {{ source: {:?}, license: {:?}, url: {:?} }};",
+        attribution.source, attribution.license, attribution.url
+    )
+}
+
+fn gen_build_config<'a, W: Write, C: Iterator<Item = &'a Country>>(
+    out: &mut W,
+    countries: C,
+    year_range: (i64, i64),
+    data_years: (i64, i64),
+) -> std::io::Result<()> {
+    let codes: Vec<&str> = countries.map(|it| it.code.as_str()).collect();
+    let features: Vec<&str> = REPORTED_FEATURES
+        .iter()
+        .copied()
+        .filter(|it| is_feature_enabled(it))
+        .collect();
+    let config = format!(
+        "countries={}\nfeatures={}\nyears={}-{}\ndata_years={}-{}\n",
+        codes.join(","),
+        features.join(","),
+        year_range.0,
+        year_range.1,
+        data_years.0,
+        data_years.1
+    );
+    writeln!(out, "pub(crate) const BUILD_CONFIG: &str = {config:?};")
+}
+
+fn gen_country_docs<'a, W: Write, C: Iterator<Item = &'a Country>>(
+    out: &mut W,
+    countries: C,
+    holidays: &[Holiday],
+) -> std::io::Result<()> {
+    for c in countries {
+        let doc = dataset::country_doc(c, holidays, DOC_YEARS, DOC_MAX_ROWS);
+        writeln!(out, "#[doc = {doc:?}]")?;
+        writeln!(out, "pub mod {} {{}}", c.code)?;
+    }
     Ok(())
 }
 
-fn gen_data_tables<W: Write>(out: &mut W, holidays: &[Holiday]) -> std::io::Result<()> {
+fn gen_data_tables<W: Write>(
+    out: &mut W,
+    holidays: &[Holiday],
+    countries: &[&Country],
+    known_codes: &[String],
+) -> std::io::Result<()> {
+    let country_count = countries.len();
     let mut year_lookup = BTreeMap::new();
     let mut country_lookup = BTreeMap::new();
     let mut exact_lookup = phf_codegen::Map::<FullSpec>::new();
+    let mut name_lookup = BTreeMap::new();
+    let names = is_names_enabled();
 
     out.write_all(b"pub(crate) static DATA: &[Holiday] = &[\n")?;
     for (i, h) in holidays.iter().enumerate() {
+        let name = if names { h.name.as_str() } else { "" };
         writeln!(
             out,
-            "crate::Holiday {{ code: {}, date: Date({}), name: \"{}\" }},",
-            h.country, h.date.day_index, h.name
+            "crate::Holiday {{ code: {}, date: Date({}), name: \"{}\", flags: crate::HolidayFlags({}) }},",
+            h.country, h.date.day_index, name, h.flags
         )?;
         year_lookup.entry(h.date.year).or_insert(i);
         country_lookup
             .entry(&h.country.index)
             .or_insert(Vec::new())
             .push(i);
-        exact_lookup.entry(FullSpec(h.country, h.date), i.to_string());
+        // same-day records of a country are sorted by name, so the map points
+        // to the lexicographically smallest one
+        let first_of_day = i
+            .checked_sub(1)
+            .map(|it| &holidays[it])
+            .is_none_or(|prev| prev.date != h.date || prev.country.index != h.country.index);
+        if first_of_day {
+            exact_lookup.entry(FullSpec(h.country, h.date), i.to_string());
+        }
+        if names {
+            name_lookup
+                .entry(name::normalize_name(&h.name).into_owned())
+                .or_insert(Vec::new())
+                .push(i);
+        }
+    }
+    out.write_all(b"];\n")?;
+
+    // position of each record among records on its date, in result order
+    out.write_all(b"pub(crate) static DAY_SEQUENCES: &[u16] = &[")?;
+    let mut sequence = 0;
+    for (i, h) in holidays.iter().enumerate() {
+        sequence = match i.checked_sub(1).map(|it| &holidays[it]) {
+            Some(prev) if prev.date == h.date => sequence + 1,
+            _ => 0,
+        };
+        write!(out, "{sequence},")?;
+    }
+    out.write_all(b"];\n")?;
+
+    // ids follow alphabetical order of all known codes, so they don't depend
+    // on which countries are enabled
+    let mut known_codes: Vec<&String> = known_codes.iter().collect();
+    known_codes.sort();
+    out.write_all(b"pub(crate) static STABLE_COUNTRY_IDS: &[u16] = &[")?;
+    for c in countries {
+        let id = known_codes
+            .binary_search(&&c.code)
+            .expect("enabled country must be known");
+        write!(out, "{id},")?;
+    }
+    out.write_all(b"];\n")?;
+
+    // most records are in the default language of their country, so only the
+    // remaining ones are listed, ordered by index into `DATA`
+    out.write_all(b"pub(crate) static NAME_LANGUAGE_OVERRIDES: &[(usize, &str)] = &[")?;
+    for (i, h) in holidays.iter().enumerate() {
+        if h.name_language() != h.country.name_language {
+            write!(out, "({i}, {:?}),", h.name_language())?;
+        }
     }
     out.write_all(b"];\n")?;
 
@@ -183,18 +314,19 @@ fn gen_data_tables<W: Write>(out: &mut W, holidays: &[Holiday]) -> std::io::Resu
     writeln!(out, "pub(crate) const DATA_MIN_YEAR: i64 = {min_year};")?;
     writeln!(out, "pub(crate) const DATA_MAX_YEAR: i64 = {max_year};")?;
 
+    // each entry is the index of first record in or after the year, so years
+    // without any records are empty ranges
     out.write_all(b"pub(crate) static YEAR_JUMP_TABLE: &[usize] = &[")?;
-    let mut index = 0;
-    for y in min_year..max_year {
-        index = *year_lookup.get(&y).unwrap_or(&index);
+    for y in min_year..=max_year {
+        let index = holidays.partition_point(|it| it.date.year < y);
         write!(out, "{index},")?;
     }
     out.write_all(b"];\n")?;
 
-    let min_country = **country_lookup.first_entry().unwrap().key();
-    let max_country = **country_lookup.last_entry().unwrap().key();
+    // table is indexed by country discriminant, so countries without any
+    // records need an (empty) entry as well
     out.write_all(b"pub(crate) static COUNTRY_JUMP_TABLE: &[&[usize]] = &[")?;
-    for ci in min_country..max_country {
+    for ci in 0..country_count as u16 {
         let indices = country_lookup
             .get(&ci)
             .map(|it| it.as_slice())
@@ -216,46 +348,77 @@ fn gen_data_tables<W: Write>(out: &mut W, holidays: &[Holiday]) -> std::io::Resu
     .unwrap();
     writeln!(out, ";").unwrap();
 
+    if is_name_index_enabled() {
+        let mut name_index = phf_codegen::Map::<&str>::new();
+        for (name, indices) in &name_lookup {
+            let indices = indices
+                .iter()
+                .map(|it| it.to_string())
+                .fold("".to_string(), |acc, it| acc + it.as_str() + ",");
+            name_index.entry(name.as_str(), format!("&[{indices}]"));
+        }
+        write!(
+            out,
+            "pub(crate) static NAME_INDEX: phf::Map<&'static str, &'static [u32]> = {}",
+            name_index.build()
+        )
+        .unwrap();
+        writeln!(out, ";").unwrap();
+    }
+
     Ok(())
 }
 
 fn main() {
     let root = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    // Listing any input replaces cargo's default of rerunning on every
+    // package change, so every file read here has to be listed.
+    for input in [
+        "build.rs",
+        "build",
+        "src/name.rs",
+        "countries.csv",
+        "holidays.csv",
+        "ATTRIBUTION.toml",
+    ] {
+        println!("cargo:rerun-if-changed={input}");
+    }
+    for var in [
+        "HOLIDAYS_MIN_YEAR",
+        "HOLIDAYS_MAX_YEAR",
+        "HOLIDAYS_ALLOW_EXTRA_COLUMNS",
+    ] {
+        println!("cargo:rerun-if-env-changed={var}");
+    }
     let countries_path = root.join("countries.csv");
-    let mut countries: Vec<Country> = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(BufReader::new(match File::open(&countries_path) {
+    let all_countries =
+        dataset::read_countries(BufReader::new(match File::open(&countries_path) {
             Ok(it) => it,
             Err(_) => {
                 panic!("missing {}", countries_path.display())
             }
-        }))
-        .records()
-        .filter_map(Result::ok)
-        .map(|it| {
-            let mut it = it.iter().map(String::from);
-            (
-                it.next().expect("invalid row countries.csv"),
-                it.next().expect("invalid row countries.csv"),
-            )
-        })
-        .filter(|(code, _)| is_country_enabled(code))
-        .map(|(code, name)| Country {
-            index: 0,
-            code,
-            name,
-        })
+        }));
+    // all codes are retained so parsing can tell unknown codes apart from
+    // ones that weren't enabled
+    let known_codes: Vec<String> = all_countries.iter().map(|it| it.code.clone()).collect();
+    let mut countries: Vec<Country> = all_countries
+        .into_iter()
+        .filter(|it| is_country_enabled(&it.code))
         .collect();
     countries.sort_by(|a, b| a.code.cmp(&b.code));
     countries.iter_mut().enumerate().for_each(|(i, it)| {
         it.index = i as u16;
     });
+    // CountrySet iteration order (and results ordering that depends on it) is
+    // guaranteed to be alphabetical by code
+    assert!(
+        countries
+            .windows(2)
+            .all(|w| w[0].code < w[1].code && w[0].index < w[1].index),
+        "country indices must follow alphabetical order of codes"
+    );
 
     let out_dir = PathBuf::from(&std::env::var("OUT_DIR").unwrap());
-    let countries_out = out_dir.join("decl_countries.rs");
-    let mut countries_out =
-        BufWriter::new(File::create(countries_out).expect("unable to create decl_countries.rs"));
-    gen_country_enum_decl(&mut countries_out, countries.iter()).unwrap();
 
     let countries: HashMap<String, Country> = countries
         .into_iter()
@@ -270,23 +433,134 @@ fn main() {
         .unwrap_or(DEFAULT_MAX_YEAR) as i64;
 
     let holidays_path = root.join("holidays.csv");
-    let holidays: Vec<Holiday> = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(BufReader::new(match File::open(&holidays_path) {
+    let year_range = (min_req_year, max_req_year);
+    let holidays = dataset::read_holidays(
+        BufReader::new(match File::open(&holidays_path) {
             Ok(it) => it,
             Err(_) => {
                 panic!("missing {}", holidays_path.display())
             }
-        }))
-        .records()
-        .filter_map(Result::ok)
-        .filter_map(|row| parse_holiday_row(row, &countries))
-        .skip_while(|it| it.date.year < min_req_year)
-        .take_while(|it| it.date.year <= max_req_year)
-        .collect();
+        }),
+        &countries,
+        year_range,
+        is_extra_columns_allowed(),
+    )
+    .unwrap_or_else(|err| panic!("invalid {}: {err}", holidays_path.display()));
+    let holidays = match extra_csv_path() {
+        Some(extra_path) => {
+            let extra = dataset::read_holidays(
+                BufReader::new(match File::open(&extra_path) {
+                    Ok(it) => it,
+                    Err(_) => {
+                        panic!("missing {}", extra_path.display())
+                    }
+                }),
+                &countries,
+                year_range,
+                is_extra_columns_allowed(),
+            )
+            .unwrap_or_else(|err| panic!("invalid {}: {err}", extra_path.display()));
+            dataset::merge_extra(holidays, extra)
+        }
+        None => holidays,
+    };
+    let holidays = if is_names_enabled() {
+        holidays
+    } else {
+        dataset::strip_names(holidays)
+    };
+
+    // attribution must be kept along with the data it describes, so it's
+    // never optional
+    let attribution_path = root.join("ATTRIBUTION.toml");
+    let attribution = dataset::read_attribution(match File::open(&attribution_path) {
+        Ok(it) => it,
+        Err(_) => {
+            panic!("missing {}", attribution_path.display())
+        }
+    })
+    .unwrap_or_else(|err| panic!("invalid {}: {err}", attribution_path.display()));
+
+    let coverage = Coverage::collect(&holidays);
+    let mut ordered: Vec<&Country> = countries.values().collect();
+    ordered.sort_by_key(|it| it.index);
+
+    // years without any holidays are essentially nonexistent, so these are
+    // most likely rows missing from holidays.csv
+    for gap in dataset::coverage_gaps(&holidays, ordered.iter().copied()) {
+        if gap.first_year == gap.last_year {
+            println!(
+                "cargo:warning=no {} holidays in {}",
+                gap.code, gap.first_year
+            );
+        } else {
+            println!(
+                "cargo:warning=no {} holidays in {}-{}",
+                gap.code, gap.first_year, gap.last_year
+            );
+        }
+    }
+
+    let countries_out = out_dir.join("decl_countries.rs");
+    let mut countries_out =
+        BufWriter::new(File::create(countries_out).expect("unable to create decl_countries.rs"));
+    gen_country_enum_decl(
+        &mut countries_out,
+        ordered.iter().copied(),
+        &known_codes,
+        &coverage,
+        year_range,
+    )
+    .unwrap();
+
+    let coverage_out = out_dir.join("coverage.rs");
+    let mut coverage_out =
+        BufWriter::new(File::create(coverage_out).expect("unable to create coverage.rs"));
+    gen_coverage(
+        &mut coverage_out,
+        ordered.iter().copied(),
+        &coverage,
+        year_range,
+    )
+    .unwrap();
 
     let holidays_out = out_dir.join("holiday_data.rs");
     let mut holidays_out =
         BufWriter::new(File::create(holidays_out).expect("unable to create holiday_data.rs"));
-    gen_data_tables(&mut holidays_out, &holidays).unwrap();
+    gen_data_tables(&mut holidays_out, &holidays, &ordered, &known_codes).unwrap();
+
+    let attribution_out = out_dir.join("attribution.rs");
+    let mut attribution_out =
+        BufWriter::new(File::create(attribution_out).expect("unable to create attribution.rs"));
+    gen_attribution(&mut attribution_out, &attribution).unwrap();
+
+    let data_years = (
+        holidays
+            .iter()
+            .map(|it| it.date.year)
+            .min()
+            .unwrap_or(year_range.0),
+        holidays
+            .iter()
+            .map(|it| it.date.year)
+            .max()
+            .unwrap_or(year_range.1),
+    );
+    let build_config_out = out_dir.join("build_config.rs");
+    let mut build_config_out =
+        BufWriter::new(File::create(build_config_out).expect("unable to create build_config.rs"));
+    gen_build_config(
+        &mut build_config_out,
+        ordered.iter().copied(),
+        year_range,
+        data_years,
+    )
+    .unwrap();
+
+    if is_country_docs_enabled() {
+        let docs_out = out_dir.join("countries_doc.rs");
+        let mut docs_out =
+            BufWriter::new(File::create(docs_out).expect("unable to create countries_doc.rs"));
+        gen_country_docs(&mut docs_out, ordered.iter().copied(), &holidays).unwrap();
+    }
 }
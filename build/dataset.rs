@@ -0,0 +1,589 @@
+//! Parsing and analysis of the CSV holiday dataset.
+//!
+//! This module is shared between `build.rs` and generation tests, so it must
+//! only depend on `std` and `csv`.
+
+use csv::StringRecord;
+use std::{collections::HashMap, fmt::Display, io::Read, str::FromStr};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Country {
+    pub index: u16,
+    pub code: String,
+    pub name: String,
+    /// IANA name of the time zone of the capital, empty if unknown.
+    pub time_zone: String,
+    /// BCP 47 tag of the language holiday names are in, unless a record
+    /// overrides it.
+    pub name_language: String,
+}
+
+impl Display for Country {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Country::{}", self.code)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+    pub day_index: i64,
+}
+
+pub const fn ymd_as_isize(mut y: i64, m: i64, d: i64) -> i64 {
+    // Source: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    if m <= 2 {
+        y -= 1
+    }
+
+    let era = y.div_euclid(400);
+    let year_of_era = (y - era * 400) as u32;
+    let day_of_year = ((153 * ((m + 9) % 12) + 2) / 5 + d - 1) as u32;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + (day_of_era as i64) - 719468
+}
+
+impl FromStr for Date {
+    type Err = ();
+
+    fn from_str(date: &str) -> Result<Self, Self::Err> {
+        let mut date = date.split("-");
+        let year = date.next().ok_or(())?.parse().map_err(|_| ())?;
+        let month = date.next().ok_or(())?.parse().map_err(|_| ())?;
+        let day = date.next().ok_or(())?.parse().map_err(|_| ())?;
+        Ok(Date {
+            year,
+            month,
+            day,
+            day_index: ymd_as_isize(year, month as i64, day as i64),
+        })
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct Holiday<'a> {
+    pub country: &'a Country,
+    pub date: Date,
+    pub name: String,
+    /// Packed `HolidayFlags` bits.
+    pub flags: u16,
+    /// BCP 47 tag of the language of `name`, empty if it's in the default
+    /// language of the country.
+    pub name_language: String,
+}
+
+impl<'a> Holiday<'a> {
+    /// Returns the BCP 47 tag of the language of `name`.
+    pub fn name_language(&self) -> &str {
+        if self.name_language.is_empty() {
+            &self.country.name_language
+        } else {
+            &self.name_language
+        }
+    }
+}
+
+/// Columns of `holidays.csv`, in the order they were introduced.
+///
+/// Only the first [`REQUIRED_COLUMNS`] must be present, so CSV files
+/// generated before later columns were added still build.
+pub const HOLIDAY_COLUMNS: &[&str] = &[
+    "country_code",
+    "date",
+    "holiday_name",
+    "flags",
+    "name_lang",
+    "portion",
+];
+pub const REQUIRED_COLUMNS: usize = 3;
+
+/// Position of the portion of the day in packed `HolidayFlags` bits.
+pub const PORTION_SHIFT: u16 = 3;
+pub const PORTION_MASK: u16 = 0b11 << PORTION_SHIFT;
+
+/// Returns packed `HolidayFlags` bits of a `portion` column value.
+fn parse_portion(portion: &str) -> u16 {
+    let bits = match portion {
+        "" | "full" => 0,
+        "half_am" => 1,
+        "half_pm" => 2,
+        other => panic!("invalid portion '{other}' in holidays.csv"),
+    };
+    bits << PORTION_SHIFT
+}
+
+/// Position of the source of a record in packed `HolidayFlags` bits.
+pub const SOURCE_SHIFT: u16 = 5;
+/// `HolidayFlags` bits of records from an extra CSV file.
+pub const SOURCE_EXTRA: u16 = 1 << SOURCE_SHIFT;
+
+/// Positions of known columns in a `holidays.csv` file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Schema {
+    columns: Vec<Option<usize>>,
+}
+
+impl Schema {
+    /// Validates the `header` of a `holidays.csv` file.
+    ///
+    /// Unknown columns are rejected unless `allow_extra_columns` is set, as
+    /// they most likely come from a newer schema whose data would be
+    /// silently dropped.
+    pub fn from_header(header: &StringRecord, allow_extra_columns: bool) -> Result<Self, String> {
+        let mut columns = vec![None; HOLIDAY_COLUMNS.len()];
+        for (position, name) in header.iter().enumerate() {
+            match HOLIDAY_COLUMNS.iter().position(|it| *it == name) {
+                Some(known) if columns[known].is_some() => {
+                    return Err(format!("duplicate column '{name}'"));
+                }
+                Some(known) => columns[known] = Some(position),
+                None if allow_extra_columns => {}
+                None => return Err(format!("unknown column '{name}'")),
+            }
+        }
+        if let Some(missing) = columns[..REQUIRED_COLUMNS].iter().position(Option::is_none) {
+            return Err(format!("missing column '{}'", HOLIDAY_COLUMNS[missing]));
+        }
+        Ok(Schema { columns })
+    }
+
+    fn get<'r>(&self, row: &'r StringRecord, column: usize) -> Option<&'r str> {
+        self.columns[column].and_then(|it| row.get(it))
+    }
+}
+
+fn parse_holiday_row<'a>(
+    row: StringRecord,
+    schema: &Schema,
+    countries: &'a HashMap<String, Country>,
+) -> Option<Holiday<'a>> {
+    let code = schema.get(&row, 0).expect("invalid row in holidays.csv");
+    let country = countries.get(code)?;
+
+    Some(Holiday {
+        country,
+        date: {
+            let date = schema.get(&row, 1).expect("invalid row in holidays.csv");
+            date.parse().expect("invalid date format in holidays.csv")
+        },
+        name: schema
+            .get(&row, 2)
+            .expect("invalid row in holidays.csv")
+            .to_string(),
+        flags: match schema.get(&row, 3) {
+            None | Some("") => 0,
+            Some(flags) => flags.parse::<u16>().expect("invalid flags in holidays.csv"),
+        } | parse_portion(schema.get(&row, 5).unwrap_or_default()),
+        name_language: schema.get(&row, 4).unwrap_or_default().to_string(),
+    })
+}
+
+impl<'a> PartialOrd for Holiday<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'a> Ord for Holiday<'a> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.date
+            .cmp(&other.date)
+            .then(self.country.cmp(other.country))
+            .then(self.name.cmp(&other.name))
+    }
+}
+
+/// Attribution of the dataset, read from `ATTRIBUTION.toml`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Attribution {
+    /// Name of the upstream source of the data.
+    pub source: String,
+    /// SPDX identifier of the license of the data.
+    pub license: String,
+    /// Location of the upstream source.
+    pub url: String,
+}
+
+/// Keys of the `[source]` table of `ATTRIBUTION.toml`, all of which are
+/// required.
+pub const ATTRIBUTION_KEYS: &[&str] = &["name", "license", "url"];
+
+/// Reads attribution in `ATTRIBUTION.toml` format.
+///
+/// Only the subset of TOML the file needs is supported: comments, a single
+/// `[source]` table and basic strings, whose only escapes are `\"` and
+/// `\\`.
+///
+/// # Errors
+///
+/// Returns a description of the problem if the file isn't in that format, or
+/// if any of [`ATTRIBUTION_KEYS`] is missing or empty.
+pub fn read_attribution<R: Read>(mut source: R) -> Result<Attribution, String> {
+    let mut content = String::new();
+    source
+        .read_to_string(&mut content)
+        .map_err(|err| err.to_string())?;
+
+    let mut in_source = false;
+    let mut values: Vec<Option<String>> = vec![None; ATTRIBUTION_KEYS.len()];
+    for (number, line) in content
+        .lines()
+        .enumerate()
+        .map(|(i, it)| (i + 1, it.trim()))
+    {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') {
+            if line != "[source]" || in_source {
+                return Err(format!("line {number}: unexpected table {line}"));
+            }
+            in_source = true;
+            continue;
+        }
+        if !in_source {
+            return Err(format!("line {number}: key outside of [source] table"));
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {number}: expected key = \"value\""))?;
+        let (key, value) = (key.trim(), parse_toml_string(value.trim()));
+        let value = value.ok_or_else(|| format!("line {number}: invalid string value"))?;
+        match ATTRIBUTION_KEYS.iter().position(|it| *it == key) {
+            Some(known) if values[known].is_some() => {
+                return Err(format!("line {number}: duplicate key '{key}'"));
+            }
+            Some(known) => values[known] = Some(value),
+            None => return Err(format!("line {number}: unknown key '{key}'")),
+        }
+    }
+
+    let mut values = values
+        .into_iter()
+        .zip(ATTRIBUTION_KEYS)
+        .map(|(value, key)| match value {
+            Some(it) if !it.trim().is_empty() => Ok(it),
+            Some(_) => Err(format!("empty key '{key}'")),
+            None => Err(format!("missing key '{key}'")),
+        });
+    Ok(Attribution {
+        source: values.next().unwrap()?,
+        license: values.next().unwrap()?,
+        url: values.next().unwrap()?,
+    })
+}
+
+/// Parses a basic TOML string, optionally followed by a comment.
+fn parse_toml_string(value: &str) -> Option<String> {
+    let mut chars = value.strip_prefix('"')?.chars();
+    let mut result = String::new();
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => result.push(match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                // values are displayed on a single line
+                _ => return None,
+            }),
+            c if c.is_control() => return None,
+            c => result.push(c),
+        }
+    }
+    let rest = chars.as_str().trim_start();
+    (rest.is_empty() || rest.starts_with('#')).then_some(result)
+}
+
+/// Reads all countries in `countries.csv` format, with zero indices.
+///
+/// The time zone and name language columns are optional; names are assumed
+/// to be in English if the latter is missing or empty.
+pub fn read_countries<R: Read>(source: R) -> Vec<Country> {
+    csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(source)
+        .records()
+        .filter_map(Result::ok)
+        .map(|it| {
+            let mut it = it.iter().map(String::from);
+            Country {
+                index: 0,
+                code: it.next().expect("invalid row countries.csv"),
+                name: it.next().expect("invalid row countries.csv"),
+                time_zone: it.next().unwrap_or_default(),
+                name_language: it
+                    .next()
+                    .filter(|it| !it.is_empty())
+                    .unwrap_or_else(|| "en".to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Reads holidays of the specified `countries` in `holidays.csv` format,
+/// limited to the inclusive `year_range`.
+///
+/// Rows must be sorted by date.
+///
+/// # Errors
+///
+/// Returns a description of the problem if the header doesn't match
+/// [`HOLIDAY_COLUMNS`], see [`Schema::from_header`].
+pub fn read_holidays<'a, R: Read>(
+    source: R,
+    countries: &'a HashMap<String, Country>,
+    year_range: (i64, i64),
+    allow_extra_columns: bool,
+) -> Result<Vec<Holiday<'a>>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(source);
+    let header = reader.headers().map_err(|err| err.to_string())?;
+    let schema = Schema::from_header(header, allow_extra_columns)?;
+    let holidays = reader
+        .records()
+        .filter_map(Result::ok)
+        .filter_map(|row| parse_holiday_row(row, &schema, countries))
+        .skip_while(|it| it.date.year < year_range.0)
+        .take_while(|it| it.date.year <= year_range.1)
+        .collect();
+    Ok(separate_same_day(holidays))
+}
+
+/// Merges `extra` records into `upstream` ones, labeling them with
+/// [`SOURCE_EXTRA`].
+///
+/// Extra records of a holiday that's already in upstream data replace it
+/// like duplicate rows of a single file, and keep the extra label.
+pub fn merge_extra<'a>(upstream: Vec<Holiday<'a>>, extra: Vec<Holiday<'a>>) -> Vec<Holiday<'a>> {
+    let mut holidays = upstream;
+    holidays.extend(extra.into_iter().map(|mut it| {
+        it.flags |= SOURCE_EXTRA;
+        it
+    }));
+    separate_same_day(holidays)
+}
+
+/// Separator upstream data joins names of holidays a country observes on the
+/// same date with.
+pub const NAME_SEPARATOR: &str = "; ";
+
+/// Splits names joined with [`NAME_SEPARATOR`] into separate records, and
+/// sorts records by date, country and name.
+///
+/// A country can observe multiple holidays on the same date, which are kept
+/// as separate records, so the order of names doesn't depend on the order of
+/// source rows. Duplicate rows of the same holiday are merged into one.
+fn separate_same_day(holidays: Vec<Holiday>) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> = holidays
+        .into_iter()
+        .flat_map(|h| {
+            let names: Vec<String> = h.name.split(NAME_SEPARATOR).map(str::to_string).collect();
+            names.into_iter().map(move |name| Holiday {
+                country: h.country,
+                date: h.date,
+                name,
+                flags: h.flags,
+                name_language: h.name_language.clone(),
+            })
+        })
+        .collect();
+    holidays.sort_by(|a, b| {
+        a.date
+            .cmp(&b.date)
+            .then(a.country.index.cmp(&b.country.index))
+            .then(a.name.cmp(&b.name))
+    });
+
+    let mut result: Vec<Holiday> = Vec::with_capacity(holidays.len());
+    for h in holidays {
+        match result.last_mut() {
+            Some(last)
+                if last.date == h.date
+                    && last.country.index == h.country.index
+                    && last.name == h.name =>
+            {
+                // a duplicate in the other half of the day (or all of it)
+                // makes the holiday last the whole day
+                let portion = if last.flags & PORTION_MASK == h.flags & PORTION_MASK {
+                    last.flags & PORTION_MASK
+                } else {
+                    0
+                };
+                last.flags = ((last.flags | h.flags) & !PORTION_MASK) | portion;
+            }
+            _ => result.push(h),
+        }
+    }
+    result
+}
+
+/// Clears names of all records, for builds without names.
+///
+/// Same-day records of a country can't be told apart without names, so
+/// they're merged into one like duplicate rows of a single holiday.
+pub fn strip_names(holidays: Vec<Holiday>) -> Vec<Holiday> {
+    separate_same_day(
+        holidays
+            .into_iter()
+            .map(|it| Holiday {
+                name: String::new(),
+                ..it
+            })
+            .collect(),
+    )
+}
+
+/// Embedded data statistics of a single country.
+pub struct Coverage {
+    pub first_year: i64,
+    pub last_year: i64,
+    pub record_count: usize,
+}
+
+impl Coverage {
+    pub fn collect(holidays: &[Holiday]) -> HashMap<u16, Coverage> {
+        let mut result: HashMap<u16, Coverage> = HashMap::new();
+        for h in holidays {
+            let it = result.entry(h.country.index).or_insert(Coverage {
+                first_year: h.date.year,
+                last_year: h.date.year,
+                record_count: 0,
+            });
+            it.first_year = it.first_year.min(h.date.year);
+            it.last_year = it.last_year.max(h.date.year);
+            it.record_count += 1;
+        }
+        result
+    }
+
+    /// Countries without any records report requested year bounds.
+    pub fn get(
+        coverage: &HashMap<u16, Coverage>,
+        country: &Country,
+        min_year: i64,
+        max_year: i64,
+    ) -> Coverage {
+        coverage.get(&country.index).map_or(
+            Coverage {
+                first_year: min_year,
+                last_year: max_year,
+                record_count: 0,
+            },
+            |it| Coverage { ..*it },
+        )
+    }
+}
+
+/// Inclusive range of years without any records of a country.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Gap {
+    pub code: String,
+    pub first_year: i64,
+    pub last_year: i64,
+}
+
+/// Returns years without any records of `countries` within the years covered
+/// by all `holidays`, ordered by country index and year.
+pub fn coverage_gaps<'a, C: Iterator<Item = &'a Country>>(
+    holidays: &[Holiday],
+    countries: C,
+) -> Vec<Gap> {
+    let (Some(first), Some(last)) = (holidays.first(), holidays.last()) else {
+        return Vec::new();
+    };
+
+    let mut years: HashMap<u16, Vec<i64>> = HashMap::new();
+    for h in holidays {
+        let it = years.entry(h.country.index).or_default();
+        if it.last() != Some(&h.date.year) {
+            it.push(h.date.year);
+        }
+    }
+
+    let mut result = Vec::new();
+    for c in countries {
+        let years = years.get(&c.index).map(Vec::as_slice).unwrap_or(&[]);
+        // sentinels just outside of the window report leading and trailing
+        // gaps too
+        let bounds: Vec<i64> = std::iter::once(first.date.year - 1)
+            .chain(years.iter().copied())
+            .chain(std::iter::once(last.date.year + 1))
+            .collect();
+        for pair in bounds.windows(2) {
+            if pair[1] - pair[0] > 1 {
+                result.push(Gap {
+                    code: c.code.clone(),
+                    first_year: pair[0] + 1,
+                    last_year: pair[1] - 1,
+                });
+            }
+        }
+    }
+    result
+}
+
+/// Escapes `text` so it's rendered literally within a Markdown table cell.
+pub fn escape_markdown(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#' | '~'
+        ) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Renders Markdown documentation of `country` with a table of its
+/// `holidays` within the inclusive `years`, listing at most `max_rows`
+/// records.
+///
+/// `holidays` must be sorted by date.
+pub fn country_doc(
+    country: &Country,
+    holidays: &[Holiday],
+    years: (i64, i64),
+    max_rows: usize,
+) -> String {
+    let records: Vec<&Holiday> = holidays
+        .iter()
+        .filter(|it| it.country.index == country.index)
+        .filter(|it| years.0 <= it.date.year && it.date.year <= years.1)
+        .collect();
+
+    let mut result = format!(
+        "Holidays of {} ({}) in {}-{}.\n\n",
+        escape_markdown(&country.name),
+        country.code,
+        years.0,
+        years.1
+    );
+    if records.is_empty() {
+        result.push_str("No holidays are embedded for these years.\n");
+        return result;
+    }
+
+    result.push_str("| Date | Holiday |\n|------|---------|\n");
+    for h in records.iter().take(max_rows) {
+        result.push_str(&format!(
+            "| {:04}-{:02}-{:02} | {} |\n",
+            h.date.year,
+            h.date.month,
+            h.date.day,
+            escape_markdown(&h.name)
+        ));
+    }
+    if records.len() > max_rows {
+        result.push_str(&format!(
+            "\nOnly the first {max_rows} of {} holidays are listed.\n",
+            records.len()
+        ));
+    }
+    result
+}
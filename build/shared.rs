@@ -0,0 +1,19 @@
+//! Definitions used both by `build.rs` and by the library.
+//!
+//! This module is compiled into both of them, so it must only depend on
+//! `std`.
+
+use std::hash::{Hash, Hasher};
+
+/// Feeds the key of a `DATA_MAP` entry to `state`: the index of the
+/// `country` followed by the date as days since the epoch.
+///
+/// `build.rs` generates the perfect hash map from these hashes and the
+/// library looks entries up with them. If the two ever hashed different
+/// inputs, lookups would miss without any error and holidays would be
+/// reported as missing, so both sides call this function and argument types
+/// have to match in both.
+pub(crate) fn hash_point<H: Hasher>(country: u16, day_index: i64, state: &mut H) {
+    country.hash(state);
+    day_index.hash(state);
+}
@@ -0,0 +1,163 @@
+//! Small command line tool for querying holidays.
+//!
+//! ```sh
+//! cargo run --example cli -- --country US,GB --from 2025-01-01 --to 2025-12-31 --name christmas --format json
+//! ```
+//!
+//! Exits with `0` if any holidays were found, `1` if none were, and `2` if
+//! arguments are invalid.
+
+use std::ops::Bound;
+use std::process::ExitCode;
+
+use holidays::{Country, CountrySet, Holiday};
+
+const USAGE: &str = "usage: cli [--country CODE[,CODE...]] [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--name TEXT] [--format csv|json]";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Json,
+}
+
+struct Args {
+    countries: CountrySet,
+    from: Option<String>,
+    to: Option<String>,
+    name: Option<String>,
+    format: Format,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut result = Args {
+        countries: CountrySet::all(),
+        from: None,
+        to: None,
+        name: None,
+        format: Format::Csv,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--country" => {
+                result.countries = value()?
+                    .split(',')
+                    .map(|code| {
+                        code.trim()
+                            .parse::<Country>()
+                            .map_err(|err| format!("invalid country '{code}': {err}"))
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "--from" => result.from = Some(value()?),
+            "--to" => result.to = Some(value()?),
+            "--name" => result.name = Some(value()?.to_lowercase()),
+            "--format" => {
+                result.format = match value()?.as_str() {
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format '{other}'")),
+                }
+            }
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+
+    Ok(result)
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn print(holidays: &[&Holiday], format: Format) {
+    match format {
+        Format::Csv => {
+            println!("date,country,name");
+            for holiday in holidays {
+                println!(
+                    "{},{},{}",
+                    holiday.display_date(),
+                    holiday.code,
+                    csv_field(holiday.name)
+                );
+            }
+        }
+        Format::Json => {
+            println!("[");
+            for (i, holiday) in holidays.iter().enumerate() {
+                let separator = if i + 1 < holidays.len() { "," } else { "" };
+                println!(
+                    "  {{\"date\": \"{}\", \"country\": \"{}\", \"name\": {}}}{separator}",
+                    holiday.display_date(),
+                    holiday.code,
+                    json_string(holiday.name)
+                );
+            }
+            println!("]");
+        }
+    }
+}
+
+fn bound(value: &Option<String>) -> Bound<&str> {
+    match value {
+        Some(it) => Bound::Included(it.as_str()),
+        None => Bound::Unbounded,
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(it) => it,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let bounds = (bound(&args.from), bound(&args.to));
+    let dates = match holidays::parse_date_range::<&str, _>(&bounds) {
+        Ok(it) => it,
+        Err(err) => {
+            eprintln!("invalid date: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let holidays: Vec<&Holiday> = holidays::get_holidays(&args.countries, dates)
+        .filter(|it| match &args.name {
+            Some(name) => it.name.to_lowercase().contains(name),
+            None => true,
+        })
+        .collect();
+
+    if holidays.is_empty() {
+        return ExitCode::from(1);
+    }
+    print(&holidays, args.format);
+    ExitCode::SUCCESS
+}
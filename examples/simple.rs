@@ -1,5 +1,5 @@
 use holidays::Country;
-use holidays::internal::Date; // use chrono or time types instead
+use holidays::Date; // or chrono and time types, with their features enabled
 
 fn main() {
     let d = Date::from_ymd(2022, 1, 1);
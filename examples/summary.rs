@@ -0,0 +1,36 @@
+use holidays::Country;
+use holidays::Date; // or chrono and time types, with their features enabled
+
+fn format(date: Option<Date>) -> String {
+    match date {
+        Some(date) => date.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn main() {
+    let countries = [
+        Country::US,
+        Country::GB,
+        Country::DE,
+        Country::JP,
+        Country::BR,
+    ];
+
+    println!(
+        "{:<8} {:>5} {:>12} {:>12} {:>6}",
+        "Country", "Count", "First", "Last", "Names"
+    );
+    for summary in holidays::summary(countries, 2025..2026) {
+        let first = summary.first::<Date>().and_then(Result::ok);
+        let last = summary.last::<Date>().and_then(Result::ok);
+        println!(
+            "{:<8} {:>5} {:>12} {:>12} {:>6}",
+            summary.country.to_string(),
+            summary.count,
+            format(first),
+            format(last),
+            summary.unique_names
+        );
+    }
+}
@@ -0,0 +1,17 @@
+#![no_main]
+
+use holidays::Date;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(date) = input.parse::<Date>() else {
+        return;
+    };
+
+    // every parsed date must be valid and survive a round trip
+    let (y, m, d) = date.ymd();
+    assert_eq!(Date::from_ymd_opt(y, m, d), Some(date));
+});
@@ -0,0 +1,379 @@
+use std::ops::Bound;
+
+use crate::country::CountrySet;
+use crate::date::Date;
+use crate::query::observing_countries;
+use crate::workweek::Workweek;
+
+/// Determines when a business day calendar of multiple countries is closed.
+///
+/// Used by [`is_business_day`], [`business_days_between`] and
+/// [`add_business_days`].
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{ClosureRule, Country, Workweek};
+///
+/// // Boxing Day is only observed in GB
+/// let boxing_day = Date::from_ymd(2025, 12, 26);
+/// let countries = [Country::US, Country::GB];
+/// assert!(!holidays::is_business_day(countries, ClosureRule::AnyClosed, Workweek::MON_FRI, boxing_day));
+/// assert!(holidays::is_business_day(countries, ClosureRule::AllClosed, Workweek::MON_FRI, boxing_day));
+/// ```
+///
+/// [`is_business_day`]: crate::is_business_day
+/// [`business_days_between`]: crate::business_days_between
+/// [`add_business_days`]: crate::add_business_days
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ClosureRule {
+    /// Closed if any of the countries observes a holiday, so business days
+    /// are the days on which all countries are open.
+    ///
+    /// This is the usual rule for cross-border settlement.
+    #[default]
+    AnyClosed,
+    /// Closed only if all of the countries observe a holiday, so business
+    /// days are the days on which at least one country is open.
+    AllClosed,
+}
+
+/// Business day calendar of a set of countries.
+pub(crate) struct Calendar {
+    countries: CountrySet,
+    rule: ClosureRule,
+    workweek: Workweek,
+}
+
+impl Calendar {
+    pub(crate) const fn new(countries: CountrySet, rule: ClosureRule, workweek: Workweek) -> Self {
+        Calendar {
+            countries,
+            rule,
+            workweek,
+        }
+    }
+
+    pub(crate) fn is_business_day(&self, date: Date) -> bool {
+        // the workweek is shared by all countries
+        if !self.workweek.contains(date.weekday()) {
+            return false;
+        }
+        // an empty selection never observes any holidays
+        if self.countries.is_empty() {
+            return true;
+        }
+
+        let closed = observing_countries(date) & self.countries;
+        match self.rule {
+            ClosureRule::AnyClosed => closed.is_empty(),
+            ClosureRule::AllClosed => closed != self.countries,
+        }
+    }
+
+    /// Returns the number of business halves of `date`, `0` to `2`.
+    ///
+    /// Unlike [`Calendar::is_business_day`], half-day holidays only close
+    /// the half of the day they're observed in.
+    pub(crate) fn open_halves(&self, date: Date) -> u32 {
+        if !self.workweek.contains(date.weekday()) {
+            return 0;
+        }
+        // records of a country on a date are adjacent, and together close
+        // every half any of them is observed in
+        let records = &crate::data::DATA[crate::data::date_to_indices(date)];
+        self.open_halves_with(
+            records
+                .chunk_by(|a, b| a.code == b.code)
+                .filter(|it| self.countries.contains(it[0].code))
+                .map(|it| {
+                    it.iter()
+                        .map(|holiday| holiday.portion().halves())
+                        .fold((false, false), |(am, pm), it| (am || it.0, pm || it.1))
+                }),
+        )
+    }
+
+    /// Returns the number of business halves of a workday on which selected
+    /// countries observe holidays closing `halves` of the day, one per
+    /// observing country.
+    fn open_halves_with(&self, halves: impl Iterator<Item = (bool, bool)>) -> u32 {
+        let (mut count, mut morning, mut afternoon) = (0, false, false);
+        for (am, pm) in halves {
+            count += 1;
+            match self.rule {
+                ClosureRule::AnyClosed => (morning, afternoon) = (morning || am, afternoon || pm),
+                ClosureRule::AllClosed if count == 1 => (morning, afternoon) = (am, pm),
+                ClosureRule::AllClosed => (morning, afternoon) = (morning && am, afternoon && pm),
+            }
+        }
+        // with `AllClosed`, a country without a holiday keeps the day open
+        if self.rule == ClosureRule::AllClosed && count < self.countries.len() {
+            return 2;
+        }
+        2 - morning as u32 - afternoon as u32
+    }
+
+    /// Counts business days in `start..end` like [`Calendar::days_between`],
+    /// with half days counting as `0.5`.
+    pub(crate) fn fractional_days_between(&self, start: Date, end: Date) -> f64 {
+        let (from, to, sign) = if start <= end {
+            (start.0, end.0, 1.0)
+        } else {
+            (end.0, start.0, -1.0)
+        };
+        let halves: u64 = (from..to)
+            .map(|day| self.open_halves(Date(day)) as u64)
+            .sum();
+        sign * halves as f64 / 2.0
+    }
+
+    /// Counts business days in `start..end`, negated if `end` is before
+    /// `start`.
+    pub(crate) fn days_between(&self, start: Date, end: Date) -> i64 {
+        let (from, to, sign) = if start <= end {
+            (start.0, end.0, 1)
+        } else {
+            (end.0, start.0, -1)
+        };
+        let count = (from..to)
+            .filter(|&day| self.is_business_day(Date(day)))
+            .count();
+        sign * count as i64
+    }
+
+    /// Moves `days` business days away from `date`, saturating at date
+    /// limits.
+    pub(crate) fn add_days(&self, date: Date, days: i64) -> Date {
+        let step = days.signum();
+        let mut remaining = days.unsigned_abs();
+        let mut current = date;
+        while remaining > 0 {
+            let Some(next) = current.checked_add_days(step) else {
+                break;
+            };
+            current = next;
+            if self.is_business_day(current) {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+}
+
+/// Kind of a day, as returned by [`classify_days`].
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, DayKind, WindowClip, Workweek};
+///
+/// // Saturday, Sunday and Monday
+/// let dates = Date::from_ymd(2025, 5, 24)..=Date::from_ymd(2025, 5, 26);
+/// let kinds = holidays::classify_days(Country::US, dates, Workweek::MON_FRI, WindowClip::Exact);
+/// assert_eq!(kinds, [DayKind::Weekend, DayKind::Weekend, DayKind::Holiday]);
+/// ```
+///
+/// [`classify_days`]: crate::classify_days
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayKind {
+    /// Any of the countries observes a holiday, even if it's on the weekend.
+    Holiday,
+    /// Day outside of the workweek without any holidays.
+    Weekend,
+    /// Working day without any holidays.
+    Workday,
+}
+
+/// Determines which days [`classify_days`] returns for bounds of a date
+/// range.
+///
+/// Unbounded sides of ranges always end at the coverage of selected
+/// countries, see [`Country::coverage`].
+///
+/// ```
+/// use holidays::{Country, WindowClip, Workweek};
+///
+/// let coverage = Country::US.coverage();
+/// let years = coverage.first_year - 1..coverage.last_year + 2;
+/// let days = |clip| holidays::classify_days(Country::US, years.clone(), Workweek::MON_FRI, clip).len();
+/// assert!(days(WindowClip::ToCoverage) < days(WindowClip::Exact));
+/// ```
+///
+/// [`classify_days`]: crate::classify_days
+/// [`Country::coverage`]: crate::Country::coverage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WindowClip {
+    /// Days of the range are returned as they are, including the ones
+    /// without any data, which can't be holidays.
+    #[default]
+    Exact,
+    /// Days of the range are limited to years covered by any of the selected
+    /// countries.
+    ToCoverage,
+}
+
+/// Iterator over days of a date range along with their [`DayKind`],
+/// returned by [`iter_day_kinds`].
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, DayKind, WindowClip, Workweek};
+///
+/// let july = Date::from_ymd(2025, 7, 1)..Date::from_ymd(2025, 8, 1);
+/// let mut days = holidays::iter_day_kinds(Country::US, july, Workweek::MON_FRI, WindowClip::Exact);
+/// assert_eq!(days.len(), 31);
+/// let (date, _) = days.find(|(_, kind)| *kind == DayKind::Holiday).unwrap();
+/// assert_eq!(date, Date::from_ymd(2025, 7, 4));
+/// ```
+///
+/// [`iter_day_kinds`]: crate::iter_day_kinds
+#[derive(Debug, Clone)]
+pub struct DayKinds {
+    /// Bit `i` is set if the `i`-th day of the range is a holiday.
+    holidays: Vec<u64>,
+    start: Date,
+    next: usize,
+    len: usize,
+    /// Zero-based ISO 8601 weekday of `start`, i.e. `0` for Monday.
+    first_weekday: usize,
+    workweek: Workweek,
+}
+
+impl DayKinds {
+    /// Resolves holidays of `countries` within bounds in one pass.
+    pub(crate) fn new(
+        countries: CountrySet,
+        start: Bound<Date>,
+        end: Bound<Date>,
+        workweek: Workweek,
+        clip: WindowClip,
+    ) -> Self {
+        let (first, last) = coverage_years(countries);
+        let covered = (
+            Date::saturating_from_year(first),
+            Date::saturating_from_year(last + 1),
+        );
+        let mut start = match start {
+            Bound::Included(date) => date,
+            Bound::Excluded(date) => date.saturating_add_days(1),
+            Bound::Unbounded => covered.0,
+        };
+        let mut end = match end {
+            Bound::Included(date) => date.saturating_add_days(1),
+            Bound::Excluded(date) => date,
+            Bound::Unbounded => covered.1,
+        };
+        if clip == WindowClip::ToCoverage {
+            (start, end) = (start.max(covered.0), end.min(covered.1));
+        }
+        let len = end.0.saturating_sub(start.0).max(0) as usize;
+
+        let mut holidays = vec![0u64; len.div_ceil(64)];
+        if len > 0 && !countries.is_empty() {
+            for holiday in crate::get_holidays(countries, start..end) {
+                let day = (holiday.date.0 - start.0) as usize;
+                holidays[day / 64] |= 1 << (day % 64);
+            }
+        }
+
+        DayKinds {
+            holidays,
+            start,
+            next: 0,
+            len,
+            first_weekday: start.weekday().number_from_monday() as usize - 1,
+            workweek,
+        }
+    }
+
+    fn kind(&self, day: usize) -> DayKind {
+        let weekday = (self.first_weekday + day % 7) % 7 + 1;
+        if (self.holidays[day / 64] >> (day % 64)) & 1 == 1 {
+            DayKind::Holiday
+        } else if (self.workweek.bits() >> weekday) & 1 == 1 {
+            DayKind::Workday
+        } else {
+            DayKind::Weekend
+        }
+    }
+}
+
+impl Iterator for DayKinds {
+    type Item = (Date, DayKind);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.len {
+            return None;
+        }
+        let day = self.next;
+        self.next += 1;
+        Some((Date(self.start.0 + day as i64), self.kind(day)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for DayKinds {}
+
+impl std::iter::FusedIterator for DayKinds {}
+
+/// Returns the first and the last year covered by any of the `countries`, or
+/// by the embedded data if there are none.
+fn coverage_years(countries: CountrySet) -> (i64, i64) {
+    if countries.is_empty() {
+        return (crate::data::DATA_MIN_YEAR, crate::data::DATA_MAX_YEAR);
+    }
+    countries
+        .iter()
+        .map(|it| it.coverage())
+        .fold((i64::MAX, i64::MIN), |(first, last), it| {
+            (first.min(it.first_year), last.max(it.last_year))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Country;
+
+    #[test]
+    fn half_days_close_their_half() {
+        use crate::DayPortion::{self, *};
+
+        let countries: CountrySet = [Country::US, Country::GB].into_iter().collect();
+        let any = Calendar::new(countries, ClosureRule::AnyClosed, Workweek::default());
+        let all = Calendar::new(countries, ClosureRule::AllClosed, Workweek::default());
+        let open = |calendar: &Calendar, portions: &[DayPortion]| {
+            calendar.open_halves_with(portions.iter().map(|it| it.halves()))
+        };
+
+        assert_eq!(open(&any, &[]), 2);
+        assert_eq!(open(&any, &[HalfPm]), 1);
+        assert_eq!(open(&any, &[HalfAm, HalfAm]), 1);
+        assert_eq!(open(&any, &[HalfAm, HalfPm]), 0);
+        assert_eq!(open(&any, &[Full, HalfPm]), 0);
+
+        assert_eq!(open(&all, &[HalfPm]), 2);
+        assert_eq!(open(&all, &[Full]), 2);
+        assert_eq!(open(&all, &[HalfAm, HalfPm]), 2);
+        assert_eq!(open(&all, &[HalfPm, HalfPm]), 1);
+        assert_eq!(open(&all, &[Full, HalfAm]), 1);
+        assert_eq!(open(&all, &[Full, Full]), 0);
+    }
+
+    #[test]
+    fn fractional_counts_match_full_days() {
+        // embedded records are all full days
+        let calendar = Calendar::new(
+            [Country::US, Country::GB].into_iter().collect(),
+            ClosureRule::AnyClosed,
+            Workweek::default(),
+        );
+        let (start, end) = (Date::from_ymd(2025, 1, 1), Date::from_ymd(2026, 1, 1));
+        let days = calendar.days_between(start, end);
+        assert_eq!(calendar.fractional_days_between(start, end), days as f64);
+        assert_eq!(calendar.fractional_days_between(end, start), -days as f64);
+    }
+}
@@ -0,0 +1,443 @@
+//! Memoization of query results.
+//!
+//! Requires the `cache` feature.
+//!
+//! Queries are fast, but services that answer the same handful of queries
+//! over and over still pay for collecting their results each time.
+//! [`QueryCache`] keeps results of recently run queries, so repeated ones
+//! only cost a lookup and a reference count increment:
+//!
+//! ```
+//! use holidays::cache::QueryCache;
+//! use holidays::Country;
+//!
+//! let cache = QueryCache::new(64);
+//! let first = cache.get_or_run([Country::US, Country::GB], 2025..2026);
+//! let again = cache.get_or_run([Country::GB, Country::US], 2025..2026);
+//!
+//! assert_eq!(first.len(), holidays::get_holidays([Country::US, Country::GB], 2025..2026).count());
+//! assert!(std::sync::Arc::ptr_eq(&first, &again));
+//! assert_eq!((cache.hits(), cache.misses()), (1, 1));
+//! ```
+//!
+//! # Keys
+//!
+//! Entries are keyed by the set of selected countries and the resolved
+//! half-open bounds of selected dates, so different spellings of the same
+//! query share an entry: country order and duplicates don't matter, an exact
+//! date is the same as the range of that day, and years `2025..2026` are the
+//! same as dates `2025-01-01..=2025-12-31`. Queries that can't match anything
+//! share a single entry.
+//!
+//! # Invalidation
+//!
+//! Queries only read data embedded at build time, which never changes while
+//! the program runs, so entries never go stale and are only evicted when the
+//! cache is full. Records added at runtime ([`Source::Overlay`]) aren't
+//! returned by queries, so they can't end up in cached results either.
+//!
+//! [`Source::Overlay`]: crate::Source::Overlay
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::query::selection::{CountrySelection, DateSelection};
+use crate::{Country, CountrySet, Date, Holiday};
+
+/// Normalized query, see [keys](self#keys).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    countries: CountrySet,
+    start: Option<Date>,
+    end: Option<Date>,
+}
+
+struct Entry {
+    holidays: Arc<[&'static Holiday]>,
+    /// Value of [`Entries::clock`] when the entry was last used.
+    last_used: u64,
+}
+
+struct Entries {
+    map: HashMap<Key, Entry>,
+    /// Incremented on each use of an entry, to order entries by recency.
+    clock: u64,
+}
+
+/// Least recently used cache of query results, safe to share between
+/// threads.
+///
+/// Holds results of up to [`capacity`](QueryCache::capacity) queries and
+/// evicts the least recently used one to make room for a new one. Queries are
+/// run without holding the lock, so a slow miss doesn't block hits of other
+/// threads; threads that miss the same query at once may each run it, and
+/// all of them get the result that was cached first.
+///
+/// ```
+/// use holidays::cache::QueryCache;
+/// use holidays::Country;
+///
+/// let cache = QueryCache::new(2);
+/// for year in [2025, 2026, 2025, 2027] {
+///     cache.get_or_run(Country::JP, year..year + 1);
+/// }
+/// assert_eq!((cache.hits(), cache.misses(), cache.len()), (1, 3, 2));
+/// ```
+pub struct QueryCache {
+    entries: Mutex<Entries>,
+    capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl QueryCache {
+    /// Constructs an empty cache that holds results of up to `capacity`
+    /// queries.
+    ///
+    /// With zero capacity nothing is cached, and every query is a miss.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let disabled = QueryCache::new(0);
+    /// disabled.get_or_run(Country::FR, 2025..2026);
+    /// disabled.get_or_run(Country::FR, 2025..2026);
+    /// assert_eq!((disabled.hits(), disabled.misses(), disabled.len()), (0, 2, 0));
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        QueryCache {
+            entries: Mutex::new(Entries {
+                map: HashMap::with_capacity(capacity),
+                clock: 0,
+            }),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns holidays selected by `countries` and `date`, running the query
+    /// if its results aren't cached.
+    ///
+    /// Accepts the same inputs as [`get_holidays`](crate::get_holidays), and
+    /// returns the same holidays in the same order.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let cache = QueryCache::new(8);
+    /// let christmas = cache.get_or_run(Country::DE, Date::from_ymd(2025, 12, 25));
+    /// let same_day = cache.get_or_run(Country::DE, Date::from_ymd(2025, 12, 25)..Date::from_ymd(2025, 12, 26));
+    ///
+    /// assert_eq!(christmas.len(), 1);
+    /// assert!(std::sync::Arc::ptr_eq(&christmas, &same_day));
+    /// ```
+    pub fn get_or_run<CountryIter, DateLike, DateRange>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+        date: impl Into<DateSelection<DateLike, DateRange>>,
+    ) -> Arc<[&'static Holiday]>
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<Country>,
+        DateLike: Into<Date> + Clone,
+        DateRange: std::ops::RangeBounds<DateLike>,
+    {
+        let query = countries.into().into_query().and(date.into().into_query());
+        let (countries, start, end) = query.normalized();
+        let key = Key {
+            countries,
+            start,
+            end,
+        };
+
+        if let Some(holidays) = self.lock().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return holidays;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let holidays: Arc<[&'static Holiday]> =
+            query.iter_with(crate::QueryOptions::DEFAULT).collect();
+        if self.capacity == 0 {
+            return holidays;
+        }
+        self.lock().insert(key, holidays, self.capacity)
+    }
+
+    /// Returns the number of queries whose results were cached.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let cache = QueryCache::new(8);
+    /// cache.get_or_run(Country::US, 2025..2026);
+    /// cache.get_or_run(Country::US, 2025..2026);
+    /// assert_eq!(cache.hits(), 1);
+    /// ```
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of queries that had to be run.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let cache = QueryCache::new(8);
+    /// cache.get_or_run(Country::US, 2025..2026);
+    /// cache.get_or_run(Country::CA, 2025..2026);
+    /// assert_eq!(cache.misses(), 2);
+    /// ```
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of cached query results.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let cache = QueryCache::new(1);
+    /// cache.get_or_run(Country::US, 2025..2026);
+    /// cache.get_or_run(Country::CA, 2025..2026);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().map.len()
+    }
+
+    /// Returns `true` if no query results are cached.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let cache = QueryCache::new(8);
+    /// assert!(cache.is_empty());
+    /// cache.get_or_run(Country::US, 2025..2026);
+    /// assert!(!cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of cached query results.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    ///
+    /// assert_eq!(QueryCache::new(64).capacity(), 64);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all cached results; hit and miss counters are kept.
+    ///
+    /// ```
+    /// use holidays::cache::QueryCache;
+    /// use holidays::Country;
+    ///
+    /// let cache = QueryCache::new(8);
+    /// cache.get_or_run(Country::US, 2025..2026);
+    /// cache.clear();
+    /// assert!(cache.is_empty());
+    /// assert_eq!(cache.misses(), 1);
+    /// ```
+    pub fn clear(&self) {
+        self.lock().map.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        // entries are consistent between statements, so a panic of another
+        // thread can't leave them in an invalid state
+        self.entries.lock().unwrap_or_else(|it| it.into_inner())
+    }
+}
+
+impl std::fmt::Debug for QueryCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryCache")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity)
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+impl Entries {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn get(&mut self, key: &Key) -> Option<Arc<[&'static Holiday]>> {
+        let now = self.tick();
+        let entry = self.map.get_mut(key)?;
+        entry.last_used = now;
+        Some(entry.holidays.clone())
+    }
+
+    /// Caches `holidays` of `key`, unless another thread did so first, and
+    /// returns the cached results.
+    fn insert(
+        &mut self,
+        key: Key,
+        holidays: Arc<[&'static Holiday]>,
+        capacity: usize,
+    ) -> Arc<[&'static Holiday]> {
+        if let Some(cached) = self.get(&key) {
+            return cached;
+        }
+        if self.map.len() >= capacity {
+            // a linear scan is cheap next to the query that missed
+            let oldest = self.map.iter().min_by_key(|(_, it)| it.last_used);
+            if let Some(oldest) = oldest.map(|(key, _)| *key) {
+                self.map.remove(&oldest);
+            }
+        }
+        let last_used = self.tick();
+        self.map.insert(
+            key,
+            Entry {
+                holidays: holidays.clone(),
+                last_used,
+            },
+        );
+        holidays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Any;
+
+    #[test]
+    fn equivalent_queries_share_entries() {
+        let cache = QueryCache::new(16);
+        let christmas = Date::from_ymd(2025, 12, 25);
+
+        let first = cache.get_or_run([Country::US, Country::GB], christmas);
+        for holidays in [
+            cache.get_or_run([Country::GB, Country::US, Country::GB], christmas),
+            cache.get_or_run([Country::US, Country::GB], christmas..=christmas),
+            cache.get_or_run(
+                [Country::US, Country::GB],
+                christmas..Date::from_ymd(2025, 12, 26),
+            ),
+        ] {
+            assert!(Arc::ptr_eq(&holidays, &first));
+        }
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (3, 1, 1));
+
+        let year = cache.get_or_run(Country::DE, 2025..2026);
+        let dates = Date::from_ymd(2025, 1, 1)..=Date::from_ymd(2025, 12, 31);
+        assert!(Arc::ptr_eq(&cache.get_or_run(Country::DE, dates), &year));
+        let dates = Date::from_ymd(2025, 1, 1)..Date::from_ymd(2026, 1, 1);
+        assert!(Arc::ptr_eq(&cache.get_or_run(Country::DE, dates), &year));
+        assert!(Arc::ptr_eq(
+            &cache.get_or_run(crate::enabled_countries(), 2025..2026),
+            &cache.get_or_run(Any, 2025..2026)
+        ));
+
+        // nothing to match
+        let empty = cache.get_or_run(Country::DE, 2025..2025);
+        assert!(empty.is_empty());
+        assert!(Arc::ptr_eq(
+            &cache.get_or_run(CountrySet::new(), 2025..2026),
+            &empty
+        ));
+        assert_eq!(cache.misses(), 4);
+    }
+
+    #[test]
+    fn results_match_queries() {
+        let cache = QueryCache::new(4);
+        let countries: CountrySet = [Country::US, Country::DE, Country::JP]
+            .into_iter()
+            .collect();
+        let new_year = Date::from_ymd(2025, 1, 1);
+        for _ in 0..2 {
+            let cached = cache.get_or_run(countries, 2025..2027);
+            assert!(cached
+                .iter()
+                .copied()
+                .eq(crate::get_holidays(countries, 2025..2027)));
+            let cached = cache.get_or_run(Any, new_year);
+            assert!(cached
+                .iter()
+                .copied()
+                .eq(crate::get_holidays(Any, new_year)));
+        }
+        assert_eq!((cache.hits(), cache.misses()), (2, 2));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = QueryCache::new(2);
+        let us = cache.get_or_run(Country::US, 2025..2026);
+        let gb = cache.get_or_run(Country::GB, 2025..2026);
+        // US is now more recent than GB
+        cache.get_or_run(Country::US, 2025..2026);
+        cache.get_or_run(Country::DE, 2025..2026);
+        assert_eq!(cache.len(), 2);
+
+        assert!(Arc::ptr_eq(&cache.get_or_run(Country::US, 2025..2026), &us));
+        assert!(!Arc::ptr_eq(
+            &cache.get_or_run(Country::GB, 2025..2026),
+            &gb
+        ));
+        assert_eq!((cache.hits(), cache.misses()), (2, 4));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        cache.get_or_run(Country::US, 2025..2026);
+        assert_eq!(cache.misses(), 5);
+
+        let disabled = QueryCache::new(0);
+        disabled.get_or_run(Country::US, 2025..2026);
+        disabled.get_or_run(Country::US, 2025..2026);
+        assert_eq!(
+            (disabled.hits(), disabled.misses(), disabled.len()),
+            (0, 2, 0)
+        );
+    }
+
+    #[test]
+    fn concurrent_access() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<QueryCache>();
+
+        let cache = QueryCache::new(16);
+        let countries = [Country::US, Country::GB, Country::DE, Country::FR];
+        std::thread::scope(|scope| {
+            for thread in 0..8 {
+                let cache = &cache;
+                scope.spawn(move || {
+                    for i in 0..100 {
+                        let country = countries[(thread + i) % countries.len()];
+                        let year = 2025 + (i % 3) as i64;
+                        let holidays = cache.get_or_run(country, year..year + 1);
+                        let expected = crate::get_holidays(country, year..year + 1);
+                        assert!(holidays.iter().copied().eq(expected));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(cache.hits() + cache.misses(), 800);
+        // nothing is evicted, so each query misses at most once per thread
+        assert!(cache.misses() <= 12 * 8);
+        assert_eq!(cache.len(), 12);
+    }
+}
@@ -3,13 +3,23 @@ use std::cmp::Reverse;
 use crate::Holiday;
 
 macro_rules! declare_countries {
-    ($($code: ident: $str_code: literal $name: literal $val:literal),* $(,)?) => {
+    ($($code: ident: $str_code: literal $name: literal $coverage: literal $time_zone: literal $name_language: literal $val:literal),* $(,)?) => {
         /// Two-letter country code as specified by ISO 3166-1 alpha-2.
+        ///
+        /// ```
+        /// use holidays::Country;
+        ///
+        /// let country: Country = "jp".parse().unwrap();
+        /// assert_eq!(country, Country::JP);
+        /// assert_eq!(country.to_string(), "JP");
+        /// ```
         #[allow(dead_code)]
         #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
         #[repr(u16)]
         pub enum Country {$(
             #[doc = $name]
+            #[doc = ""]
+            #[doc = $coverage]
             $code = $val
         ),*}
 
@@ -20,16 +30,34 @@ macro_rules! declare_countries {
             const NAMES: &[&'static str] = &[$(
                 $name
             ),*];
+            const TIME_ZONES: &[&'static str] = &[$(
+                $time_zone
+            ),*];
+            const NAME_LANGUAGES: &[&'static str] = &[$(
+                $name_language
+            ),*];
+            const ENABLED: &[Country] = &[$(
+                Country::$code
+            ),*];
         }
 
         impl std::str::FromStr for Country {
             type Err = CountryParseError;
 
             fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-                CODE_TO_COUNTRY.get(s).or_else(|| {
-                    let lower = s.to_lowercase();
-                    CODE_TO_COUNTRY.get(&lower)
-                }).cloned().ok_or(CountryParseError)
+                let country = match <[u8; 2]>::try_from(s.as_bytes()) {
+                    Ok(code) => Country::from_code_bytes(code),
+                    Err(_) => lookup_code(s),
+                };
+                if let Some(country) = country {
+                    return Ok(country);
+                }
+                let upper = s.to_ascii_uppercase();
+                if KNOWN_CODES.contains(upper.as_str()) {
+                    Err(CountryParseError::NotEnabled)
+                } else {
+                    Err(CountryParseError::Unknown)
+                }
             }
         }
     };
@@ -37,14 +65,84 @@ macro_rules! declare_countries {
 
 include!(concat!(env!("OUT_DIR"), "/decl_countries.rs"));
 
+/// Case-insensitive lookup of enabled country codes.
+fn lookup_code(code: &str) -> Option<Country> {
+    CODE_TO_COUNTRY
+        .get(code)
+        .or_else(|| CODE_TO_COUNTRY.get(code.to_ascii_uppercase().as_str()))
+        .copied()
+}
+
 impl Country {
-    const COUNT: usize = Self::CODES.len();
+    pub(crate) const COUNT: usize = Self::CODES.len();
+
+    /// Returns `true` if `code` is a country code whose holiday data has been
+    /// compiled in (i.e. its feature is enabled).
+    ///
+    /// Use [`str::parse`] to distinguish codes that are unknown from ones that
+    /// are known but weren't enabled.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// assert!(Country::is_enabled("US"));
+    /// assert!(Country::is_enabled("us"));
+    /// assert!(!Country::is_enabled("XX"));
+    /// ```
+    pub fn is_enabled(code: &str) -> bool {
+        lookup_code(code).is_some()
+    }
+
+    /// Returns the enabled country with a two-letter `code` in either case.
+    ///
+    /// Unlike parsing, this is a single table lookup that doesn't go through
+    /// UTF-8 validation, which is useful for parsing codes in bulk. Returns
+    /// `None` for codes that are unknown or whose features aren't enabled.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// assert_eq!(Country::from_code_bytes(*b"US"), Some(Country::US));
+    /// assert_eq!(Country::from_code_bytes(*b"gb"), Some(Country::GB));
+    /// assert_eq!(Country::from_code_bytes(*b"X1"), None);
+    /// ```
+    #[inline]
+    pub const fn from_code_bytes(code: [u8; 2]) -> Option<Country> {
+        // setting the lowercase bit maps letters of either case to lowercase
+        // ones, while every other byte ends up outside of the alphabet
+        let first = (code[0] | 0x20).wrapping_sub(b'a') as usize;
+        let second = (code[1] | 0x20).wrapping_sub(b'a') as usize;
+        if first >= 26 || second >= 26 {
+            return None;
+        }
+        match CODE_BYTES_TABLE[first * 26 + second] {
+            0 => None,
+            index => Some(Self::ENABLED[index as usize - 1]),
+        }
+    }
+
+    /// Returns information about embedded holiday data of the country.
+    ///
+    /// ```
+    /// use holidays::{coverage, Country};
+    ///
+    /// assert_eq!(Country::US.coverage(), coverage::US);
+    /// ```
+    pub const fn coverage(&self) -> crate::coverage::CountryCoverage {
+        crate::coverage::TABLE[*self as usize]
+    }
 
     /// Returns a long name of the country in english.
     /// 
     /// For localized names refer to projects like [`icu4x`].
     /// 
     /// [`icu4x`]: https://github.com/unicode-org/icu4x
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// assert_eq!(Country::GB.name(), "United Kingdom");
+    /// ```
     pub fn name(&self) -> &'static str {
         // SAFETY: `NAMES` table is generated by the build.rs and cover all
         // country variants
@@ -52,6 +150,56 @@ impl Country {
             Self::NAMES.get_unchecked(*self as usize)
         }
     }
+
+    /// Returns the IANA name of the default time zone of the country, which
+    /// is the time zone of its capital.
+    ///
+    /// Countries spanning multiple time zones have a single default one, e.g.
+    /// `America/New_York` for [`Country::US`], so it can be off by a day for
+    /// parts of the country around midnight.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// assert_eq!(Country::JP.time_zone_name(), "Asia/Tokyo");
+    /// ```
+    pub fn time_zone_name(&self) -> &'static str {
+        // SAFETY: `TIME_ZONES` table is generated by the build.rs and cover
+        // all country variants
+        unsafe { Self::TIME_ZONES.get_unchecked(*self as usize) }
+    }
+
+    /// Returns the BCP 47 tag of the language holiday names of the country
+    /// are in by default, e.g. to decide whether they need translating.
+    ///
+    /// Individual records can differ, see [`Holiday::name_language`].
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// assert_eq!(Country::US.name_language(), "en");
+    /// assert_eq!(Country::IT.name_language(), "it");
+    /// ```
+    pub fn name_language(&self) -> &'static str {
+        // SAFETY: `NAME_LANGUAGES` table is generated by the build.rs and
+        // cover all country variants
+        unsafe { Self::NAME_LANGUAGES.get_unchecked(*self as usize) }
+    }
+}
+
+/// Returns all countries compiled into the crate, in alphabetical order of
+/// their codes.
+///
+/// ```
+/// use holidays::Country;
+///
+/// let countries = holidays::enabled_countries();
+/// assert!(countries.windows(2).all(|it| it[0].as_ref() < it[1].as_ref()));
+/// # #[cfg(feature = "US")]
+/// assert!(countries.contains(&Country::US));
+/// ```
+pub const fn enabled_countries() -> &'static [Country] {
+    Country::ENABLED
 }
 
 impl std::fmt::Display for Country {
@@ -81,8 +229,21 @@ const WORD_BITS: usize = 64;
 const N_WORDS: usize = Country::COUNT.div_ceil(WORD_BITS);
 
 /// A simple dynamic bitset, storing one bit per country.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct CountrySet {
+///
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let europe: CountrySet = [Country::DE, Country::FR, Country::GB].into_iter().collect();
+/// let g7: CountrySet = [Country::US, Country::GB, Country::DE, Country::FR, Country::JP]
+///     .into_iter()
+///     .collect();
+/// let common = europe & g7;
+/// assert_eq!(common.len(), 3);
+/// assert!(!common.contains(Country::JP));
+/// assert!(holidays::get_holidays(common, 2025..2026).all(|it| europe.contains(it.code)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CountrySet {
     /// Each `u64` holds 64 bits; we need as many words as it takes
     /// to cover `NUM_COUNTRIES` bits.
     words: [u64; N_WORDS],
@@ -90,20 +251,64 @@ pub(crate) struct CountrySet {
 
 impl CountrySet {
     /// Create an empty set.
+    ///
+    /// ```
+    /// use holidays::CountrySet;
+    ///
+    /// assert!(CountrySet::new().is_empty());
+    /// ```
     pub const fn new() -> Self {
         CountrySet {
             words: [0; N_WORDS],
         }
     }
 
+    /// Set of all bits that correspond to enabled countries.
+    ///
+    /// Bits past the last country are never set; operations that combine raw
+    /// words mask results with this set to uphold that.
+    const VALID: CountrySet = {
+        let mut words = [u64::MAX; N_WORDS];
+        if !Country::COUNT.is_multiple_of(WORD_BITS) {
+            words[N_WORDS - 1] = (1 << (Country::COUNT % WORD_BITS)) - 1;
+        }
+        CountrySet { words }
+    };
+
     /// Create a full set.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let all = CountrySet::all();
+    /// assert!(all.contains(Country::US));
+    /// assert_eq!(all.len(), holidays::enabled_countries().len());
+    /// ```
     pub const fn all() -> Self {
-        CountrySet {
-            words: [u64::MAX; N_WORDS],
+        Self::VALID
+    }
+
+    /// Returns `words` with bits of non-existent countries cleared.
+    #[inline]
+    const fn masked(mut words: [u64; N_WORDS]) -> [u64; N_WORDS] {
+        let mut w = 0;
+        while w < N_WORDS {
+            words[w] &= Self::VALID.words[w];
+            w += 1;
         }
+        words
     }
 
     /// Insert one country.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let mut set = CountrySet::new();
+    /// set.insert(Country::FR);
+    /// set.insert(Country::FR);
+    /// assert_eq!(set.len(), 1);
+    /// ```
     #[inline]
     pub const fn insert(&mut self, country: Country) {
         let idx = country as usize;
@@ -113,6 +318,14 @@ impl CountrySet {
     }
 
     /// Check membership.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let set: CountrySet = [Country::DE, Country::AT].into_iter().collect();
+    /// assert!(set.contains(Country::AT));
+    /// assert!(!set.contains(Country::CH));
+    /// ```
     #[inline]
     pub const fn contains(&self, country: Country) -> bool {
         let idx = country as usize;
@@ -121,7 +334,46 @@ impl CountrySet {
         (self.words[word] >> bit) & 1 == 1
     }
 
+    /// Returns the number of countries in the set.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let set: CountrySet = [Country::DE, Country::AT, Country::DE].into_iter().collect();
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub const fn len(&self) -> usize {
+        let words = Self::masked(self.words);
+        let mut result = 0;
+        let mut w = 0;
+        while w < N_WORDS {
+            result += words[w].count_ones() as usize;
+            w += 1;
+        }
+        result
+    }
+
+    /// Returns `true` if the set contains no countries.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// assert!(CountrySet::new().is_empty());
+    /// assert!(!CountrySet::all().is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Extend from any iterator of countries.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let mut set = CountrySet::new();
+    /// set.extend(["NL", "BE", "LU"].map(|it| it.parse::<Country>().unwrap()));
+    /// assert_eq!(set.len(), 3);
+    /// ```
     pub fn extend<I>(&mut self, iter: I)
     where
         I: IntoIterator,
@@ -132,29 +384,206 @@ impl CountrySet {
         }
     }
 
+    /// Returns an iterator over countries in the set.
+    ///
+    /// Countries are always yielded in alphabetical order of their ISO 3166-1
+    /// alpha-2 codes.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let mut set = CountrySet::new();
+    /// set.extend([Country::US, Country::DE, Country::JP]);
+    ///
+    /// let countries: Vec<_> = set.iter().collect();
+    /// assert_eq!(countries, [Country::DE, Country::JP, Country::US]);
+    /// ```
     pub const fn iter(&self) -> CountrySetIter {
+        // iteration turns bits into countries, so invalid ones must never
+        // reach it
         CountrySetIter {
-            words: self.words,
-            word_idx: 0,
-            bit_idx: 0,
+            words: Self::masked(self.words),
         }
     }
 
-    pub fn holidays(&self) -> CountrySetHolidayIter {
-        let mut iterators: Vec<_> = self
-            .iter()
-            .map(|i| crate::data::COUNTRY_JUMP_TABLE[i as usize].iter())
-            .collect();
-
-        // Seed heap with initial elements
-        let mut heap = std::collections::BinaryHeap::with_capacity(iterators.len());
-        for (idx, it) in iterators.iter_mut().enumerate() {
-            if let Some(&val) = it.next() {
-                heap.push(Reverse((val, idx)));
+    /// Parses a set of countries from their codes, e.g. ones listed in
+    /// configuration.
+    ///
+    /// Codes are case-insensitive and surrounding whitespace is ignored.
+    /// Unlike collecting parsed codes, this doesn't stop on the first invalid
+    /// one, so all of them can be reported at once.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let set = CountrySet::from_codes(["US", " de", "jp "]).unwrap();
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [Country::DE, Country::JP, Country::US]);
+    ///
+    /// let err = CountrySet::from_codes(["US", "XX", "UK"]).unwrap_err();
+    /// assert_eq!(err.valid.len(), 1);
+    /// assert_eq!(err.to_string(), "invalid country codes: 'XX' (unknown), 'UK' (unknown)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodesError`] listing every invalid code along with countries
+    /// of the valid ones.
+    pub fn from_codes<I>(codes: I) -> Result<CountrySet, CodesError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut valid = CountrySet::new();
+        let mut invalid: Vec<InvalidCode> = Vec::new();
+        for (position, code) in codes.into_iter().enumerate() {
+            let code = code.as_ref().trim();
+            match code.parse() {
+                Ok(country) => valid.insert(country),
+                Err(reason) => match invalid.iter_mut().find(|it| it.code == code) {
+                    Some(it) => it.positions.push(position),
+                    None => invalid.push(InvalidCode {
+                        code: code.to_string(),
+                        positions: vec![position],
+                        reason,
+                    }),
+                },
             }
         }
+        if invalid.is_empty() {
+            Ok(valid)
+        } else {
+            Err(CodesError { valid, invalid })
+        }
+    }
+
+    /// Like [`CountrySet::from_codes`], but returns the set of valid codes
+    /// along with invalid ones, in order of their first occurrence.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let (set, invalid) = CountrySet::from_codes_lossy(["US", "XX", "GB", "XX"]);
+    /// assert_eq!(set.len(), 2);
+    /// assert_eq!(invalid, ["XX"]);
+    /// ```
+    pub fn from_codes_lossy<I>(codes: I) -> (CountrySet, Vec<String>)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        match Self::from_codes(codes) {
+            Ok(set) => (set, Vec::new()),
+            Err(err) => (
+                err.valid,
+                err.invalid.into_iter().map(|it| it.code).collect(),
+            ),
+        }
+    }
 
-        CountrySetHolidayIter { heap, iterators }
+    pub(crate) fn holidays(&self) -> CountrySetHolidayIter {
+        CountrySetHolidayIter::new(*self)
+    }
+}
+
+impl Default for CountrySet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let set: CountrySet = [Country::US, Country::DE].into_iter().collect();
+/// assert!(set.contains(Country::US) && set.contains(Country::DE));
+/// ```
+impl FromIterator<Country> for CountrySet {
+    fn from_iter<T: IntoIterator<Item = Country>>(iter: T) -> Self {
+        let mut result = CountrySet::new();
+        result.extend(iter);
+        result
+    }
+}
+
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let countries = [Country::US, Country::DE];
+/// let set: CountrySet = countries.iter().collect();
+/// assert_eq!(set.len(), 2);
+/// ```
+impl<'a> FromIterator<&'a Country> for CountrySet {
+    fn from_iter<T: IntoIterator<Item = &'a Country>>(iter: T) -> Self {
+        let mut result = CountrySet::new();
+        result.extend(iter);
+        result
+    }
+}
+
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let mut set = CountrySet::new();
+/// Extend::extend(&mut set, [Country::US, Country::DE]);
+/// assert_eq!(set.len(), 2);
+/// ```
+impl Extend<Country> for CountrySet {
+    fn extend<T: IntoIterator<Item = Country>>(&mut self, iter: T) {
+        CountrySet::extend(self, iter)
+    }
+}
+
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let mut set = CountrySet::new();
+/// Extend::extend(&mut set, &[Country::US, Country::DE]);
+/// assert_eq!(set.len(), 2);
+/// ```
+impl<'a> Extend<&'a Country> for CountrySet {
+    fn extend<T: IntoIterator<Item = &'a Country>>(&mut self, iter: T) {
+        CountrySet::extend(self, iter)
+    }
+}
+
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let set: CountrySet = [Country::US, Country::DE].into_iter().collect();
+/// let countries: Vec<_> = set.into_iter().collect();
+/// assert_eq!(countries, [Country::DE, Country::US]);
+/// ```
+impl IntoIterator for CountrySet {
+    type Item = Country;
+    type IntoIter = CountrySetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let set: CountrySet = [Country::US, Country::DE].into_iter().collect();
+/// for country in &set {
+///     assert!(set.contains(country));
+/// }
+///
+/// // sets can be used for queries without being consumed
+/// let christmas = holidays::get_holidays(&set, 2025..2026)
+///     .filter(|it| it.name == "Christmas Day")
+///     .count();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(christmas, 2);
+/// assert_eq!(set.len(), 2);
+/// ```
+impl IntoIterator for &CountrySet {
+    type Item = Country;
+    type IntoIter = CountrySetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -171,6 +600,7 @@ impl std::ops::BitOrAssign for CountrySet {
         for w in 0..N_WORDS {
             self.words[w] |= rhs.words[w];
         }
+        self.words = Self::masked(self.words);
     }
 }
 
@@ -187,81 +617,623 @@ impl std::ops::BitAndAssign for CountrySet {
         for w in 0..N_WORDS {
             self.words[w] &= rhs.words[w];
         }
+        self.words = Self::masked(self.words);
     }
 }
 
+/// An iterator over countries contained in a [`CountrySet`].
+///
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let set: CountrySet = [Country::US, Country::DE].into_iter().collect();
+/// let codes: Vec<String> = set.iter().map(|it| it.to_string()).collect();
+/// assert_eq!(codes, ["DE", "US"]);
+/// ```
 #[derive(Clone, Copy)]
-pub(crate) struct CountrySetIter {
+pub struct CountrySetIter {
+    /// Countries that haven't been yielded yet.
     words: [u64; N_WORDS],
-    word_idx: usize,
-    bit_idx: usize,
+}
+
+impl CountrySetIter {
+    /// Returns `true` if `country` hasn't been yielded yet.
+    pub(crate) const fn contains(&self, country: Country) -> bool {
+        CountrySet { words: self.words }.contains(country)
+    }
+
+    #[inline]
+    fn country(word: usize, bit: u32) -> Country {
+        let idx = word * WORD_BITS + bit as usize;
+        // checked in debug builds, so tests catch invalid bits even if they
+        // slip past masking
+        #[cfg(debug_assertions)]
+        {
+            Country::ENABLED[idx]
+        }
+        // SAFETY: `CountrySet::iter` masks words with `CountrySet::VALID`, so
+        // only bits of existing countries are set, and bit positions are
+        // created by casting Country discriminant into u16
+        #[cfg(not(debug_assertions))]
+        unsafe {
+            std::mem::transmute::<u16, Country>(idx as u16)
+        }
+    }
 }
 
 impl Iterator for CountrySetIter {
     type Item = Country;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.word_idx < N_WORDS {
-            let word = self.words[self.word_idx];
+        for w in 0..N_WORDS {
+            let word = self.words[w];
+            if word != 0 {
+                // clear lowest set bit
+                self.words[w] = word & (word - 1);
+                return Some(Self::country(w, word.trailing_zeros()));
+            }
+        }
+        None
+    }
 
-            if word == 0 {
-                self.word_idx += 1;
-                self.bit_idx = 0;
-                continue;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.words.iter().map(|it| it.count_ones() as usize).sum();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CountrySetIter {}
+
+// cleared bits are never set again
+impl std::iter::FusedIterator for CountrySetIter {}
+
+impl DoubleEndedIterator for CountrySetIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        for w in (0..N_WORDS).rev() {
+            let word = self.words[w];
+            if word != 0 {
+                let bit = WORD_BITS as u32 - 1 - word.leading_zeros();
+                self.words[w] = word & !(1 << bit);
+                return Some(Self::country(w, bit));
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over merged holiday indices from multiple country jump tables.
+///
+/// Remaining records are fully described by the set of countries and a window
+/// of `DATA` indices, so clones only copy those and re-derive the rest when
+/// advanced. Records of dense sets are found by scanning the window, while
+/// sparse sets merge per-country tables.
+pub(crate) struct CountrySetHolidayIter {
+    countries: CountrySet,
+    /// Window of `DATA` indices that contains all remaining records.
+    range: std::ops::Range<usize>,
+    /// Whether the window is scanned instead of merging country tables.
+    dense: bool,
+    /// Merge state of sparse sets, built on first use.
+    merge: Option<Merge>,
+}
+
+#[derive(Clone)]
+enum Merge {
+    /// Records of a single country are already sorted and need no heap.
+    Single(std::slice::Iter<'static, usize>),
+    Heap {
+        // Min‑heap of (value, which iterator)
+        heap: std::collections::BinaryHeap<Reverse<(usize, usize)>>,
+        // One iterator per country table
+        iterators: Vec<std::slice::Iter<'static, usize>>,
+    },
+}
+
+impl Merge {
+    fn new<I>(slices: I) -> Self
+    where
+        I: Iterator<Item = &'static [usize]>,
+    {
+        let mut iterators: Vec<_> = slices.map(|it| it.iter()).collect();
+
+        // Seed heap with initial elements
+        let mut heap = std::collections::BinaryHeap::with_capacity(iterators.len());
+        for (idx, it) in iterators.iter_mut().enumerate() {
+            if let Some(&val) = it.next() {
+                heap.push(Reverse((val, idx)));
             }
+        }
+
+        Merge::Heap { heap, iterators }
+    }
+}
 
-            while self.bit_idx < 64 {
-                let bit = self.bit_idx;
-                self.bit_idx += 1;
-
-                if (word >> bit) & 1 == 1 {
-                    let idx = self.word_idx * 64 + bit;
-
-                    if idx < Country::COUNT {
-                        // SAFETY: bit position (idx) is initially created by
-                        // casting Country discriminant into u16
-                        return Some(unsafe {
-                            std::mem::transmute::<u16, Country>(idx as u16)
-                        });
-                    } else {
-                        return None;
-                    }
+impl Iterator for Merge {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Merge::Single(indices) => indices.next().copied(),
+            Merge::Heap { heap, iterators } => {
+                // Pop the smallest head element
+                let Reverse((val, idx)) = heap.pop()?;
+                // Replenish that iterator
+                if let Some(&next_val) = iterators[idx].next() {
+                    heap.push(Reverse((next_val, idx)));
                 }
+                Some(val)
+            }
+        }
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let (mut heap, mut iterators) = match self {
+            Merge::Single(indices) => return indices.copied().fold(init, f),
+            Merge::Heap { heap, iterators } => (heap, iterators),
+        };
+        let mut acc = init;
+        while let Some(Reverse((val, idx))) = heap.pop() {
+            acc = f(acc, val);
+            // records of a country often come in runs, e.g. ones of a single
+            // day in a range of dates, which are yielded without the heap
+            let bound = heap.peek().map_or(usize::MAX, |Reverse((it, _))| *it);
+            let rest = iterators[idx].as_slice();
+            // runs are short, so they're found by a linear scan
+            let run = rest.iter().take_while(|it| **it < bound).count();
+            acc = rest[..run].iter().copied().fold(acc, &mut f);
+            if let Some(&next) = rest.get(run) {
+                heap.push(Reverse((next, idx)));
+                iterators[idx] = rest[run + 1..].iter();
+            }
+        }
+        acc
+    }
+}
+
+impl CountrySetHolidayIter {
+    /// Merges all records of `countries`.
+    pub(crate) fn new(countries: CountrySet) -> Self {
+        Self::within(countries, 0..crate::data::DATA.len())
+    }
+
+    /// Merges sorted slices of `DATA` indices, each of which holds records
+    /// of a different country within the same date range.
+    pub(crate) fn from_slices<I>(slices: I) -> Self
+    where
+        I: Iterator<Item = &'static [usize]>,
+    {
+        let mut countries = CountrySet::new();
+        let (mut start, mut end) = (usize::MAX, 0);
+        for slice in slices {
+            if let (Some(&first), Some(&last)) = (slice.first(), slice.last()) {
+                countries.insert(crate::data::DATA[first].code);
+                start = start.min(first);
+                end = end.max(last + 1);
             }
+        }
+        Self::within(countries, start.min(end)..end)
+    }
 
-            self.word_idx += 1;
-            self.bit_idx = 0;
+    fn within(countries: CountrySet, range: std::ops::Range<usize>) -> Self {
+        CountrySetHolidayIter {
+            countries,
+            range,
+            dense: countries.len() * 2 >= Country::COUNT,
+            merge: None,
         }
+    }
 
-        None
+    /// Returns `true` if `DATA[index]` is a remaining record.
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.range.contains(&index) && self.countries.contains(crate::data::DATA[index].code)
+    }
+
+    /// Returns `true` if there are no remaining records.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.countries
+            .iter()
+            .all(|it| self.remaining(it).is_empty())
+    }
+
+    /// Returns merge state of the remaining records of sparse sets.
+    fn start_merge(&self) -> Merge {
+        let mut countries = self.countries.iter();
+        match (countries.next(), countries.next()) {
+            (Some(only), None) => Merge::Single(self.remaining(only).iter()),
+            _ => Merge::new(self.countries.iter().map(|it| self.remaining(it))),
+        }
+    }
+
+    /// Returns indices of remaining records of `country`.
+    fn remaining(&self, country: Country) -> &'static [usize] {
+        crate::data::country_indices_within(country, &self.range)
     }
 }
 
-/// An iterator over merged holiday indices from multiple country jump tables.
-#[derive(Clone)]
-pub struct CountrySetHolidayIter {
-    // Min‑heap of (value, which iterator)
-    heap: std::collections::BinaryHeap<Reverse<(usize, usize)>>,
-    // One iterator per country table
-    iterators: Vec<std::slice::Iter<'static, usize>>,
+impl Clone for CountrySetHolidayIter {
+    fn clone(&self) -> Self {
+        // merge state is re-derived from the window if the clone is advanced
+        CountrySetHolidayIter {
+            countries: self.countries,
+            range: self.range.clone(),
+            dense: self.dense,
+            merge: None,
+        }
+    }
 }
 
 impl Iterator for CountrySetHolidayIter {
     type Item = &'static Holiday;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Pop the smallest head element
-        let Reverse((val, idx)) = self.heap.pop()?;
-        // Replenish that iterator
-        if let Some(&next_val) = self.iterators[idx].next() {
-            self.heap.push(Reverse((next_val, idx)));
+        if self.dense {
+            let countries = self.countries;
+            return self
+                .range
+                .by_ref()
+                .map(|i| &crate::data::DATA[i])
+                .find(|it| countries.contains(it.code));
+        }
+        if self.range.is_empty() {
+            return None;
         }
-        // Yield the value
+
+        let merge = match &mut self.merge {
+            Some(it) => it,
+            None => {
+                let merge = self.start_merge();
+                self.merge.insert(merge)
+            }
+        };
+        let Some(val) = merge.next() else {
+            // an empty window keeps the merge from being restarted, so
+            // exhausted iterators stay exhausted
+            self.range.start = self.range.end;
+            return None;
+        };
+        self.range.start = val + 1;
         Some(&crate::data::DATA[val])
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // records of the remaining window are counted even for dense sets,
+        // so collecting results allocates only once
+        let len = if self.countries == CountrySet::all() {
+            self.range.len()
+        } else {
+            self.countries
+                .iter()
+                .map(|it| self.remaining(it).len())
+                .sum()
+        };
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if !crate::data::prefer_nth_search(self.countries, n) {
+            for _ in 0..n {
+                self.next()?;
+            }
+            return self.next();
+        }
+        // merge state is re-derived from the window once it's moved
+        self.merge = None;
+        let Some(i) = crate::data::nth_index_within(self.countries, &self.range, n) else {
+            self.range.start = self.range.end;
+            return None;
+        };
+        self.range.start = i + 1;
+        Some(&crate::data::DATA[i])
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        if self.dense {
+            let countries = self.countries;
+            return crate::data::DATA[self.range]
+                .iter()
+                .filter(|it| countries.contains(it.code))
+                .fold(init, f);
+        }
+        if self.range.is_empty() {
+            return init;
+        }
+        let merge = match self.merge {
+            Some(it) => it,
+            None => self.start_merge(),
+        };
+        merge.fold(init, |acc, i| f(acc, &crate::data::DATA[i]))
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.dense {
+            let countries = self.countries;
+            return self
+                .range
+                .rev()
+                .map(|i| &crate::data::DATA[i])
+                .find(|it| countries.contains(it.code));
+        }
+        // merged indices are sorted, so the last one is the largest remaining
+        // index of any country
+        self.countries
+            .iter()
+            .filter_map(|it| self.remaining(it).last().copied())
+            .max()
+            .map(|it| &crate::data::DATA[it])
+    }
 }
 
-/// Error returned when attempting to parse unknown country code.
-#[derive(Debug, PartialEq, Eq)]
-pub struct CountryParseError;
-crate::error::error_msg!(CountryParseError, "Unknown country code");
+// dense sets scan a fused range, and sparse ones empty the window once their
+// merge runs out
+impl std::iter::FusedIterator for CountrySetHolidayIter {}
+
+/// Error returned when attempting to parse an unsupported country code.
+///
+/// ```
+/// use holidays::error::CountryParseError;
+/// use holidays::Country;
+///
+/// assert_eq!("XX".parse::<Country>(), Err(CountryParseError::Unknown));
+/// assert_eq!(CountryParseError::Unknown.to_string(), "Unknown country code");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountryParseError {
+    /// Code isn't a country known to the crate.
+    Unknown,
+    /// Code is a known country, but its feature wasn't enabled so its data
+    /// isn't compiled in.
+    NotEnabled,
+}
+impl std::fmt::Display for CountryParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CountryParseError::Unknown => write!(f, "Unknown country code"),
+            CountryParseError::NotEnabled => write!(f, "Country feature not enabled"),
+        }
+    }
+}
+impl core::error::Error for CountryParseError {}
+
+/// A code that couldn't be parsed by [`CountrySet::from_codes`].
+///
+/// ```
+/// use holidays::CountrySet;
+///
+/// let err = CountrySet::from_codes(["US", "XX", "GB", "XX"]).unwrap_err();
+/// let invalid = &err.invalid[0];
+/// assert_eq!((invalid.code.as_str(), invalid.positions.as_slice()), ("XX", &[1, 3][..]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCode {
+    /// The code, without surrounding whitespace.
+    pub code: String,
+    /// Positions of all occurrences of the code in the input.
+    pub positions: Vec<usize>,
+    /// Reason the code couldn't be parsed.
+    pub reason: CountryParseError,
+}
+
+/// Error returned by [`CountrySet::from_codes`] if any codes are invalid.
+///
+/// ```
+/// use holidays::{Country, CountrySet};
+///
+/// let err = CountrySet::from_codes(["US", "XX"]).unwrap_err();
+/// assert!(err.valid.contains(Country::US));
+/// assert_eq!(err.to_string(), "invalid country codes: 'XX' (unknown)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodesError {
+    /// Countries of valid codes.
+    pub valid: CountrySet,
+    /// Distinct invalid codes, in order of their first occurrence.
+    pub invalid: Vec<InvalidCode>,
+}
+
+impl std::fmt::Display for CodesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid country codes: ")?;
+        for (i, it) in self.invalid.iter().enumerate() {
+            let reason = match it.reason {
+                CountryParseError::Unknown => "unknown",
+                CountryParseError::NotEnabled => "not enabled",
+            };
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}'{}' ({reason})", it.code)?;
+        }
+        Ok(())
+    }
+}
+impl core::error::Error for CodesError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iteration_is_alphabetical() {
+        let codes: Vec<&str> = CountrySet::all()
+            .iter()
+            .map(|it| Country::CODES[it as usize])
+            .collect();
+
+        assert_eq!(codes.len(), Country::COUNT);
+        assert!(codes.windows(2).all(|w| w[0] < w[1]), "{codes:?}");
+    }
+
+    /// Also runs under miri, with `cargo +nightly miri test invalid_bits`.
+    #[test]
+    fn invalid_bits_are_ignored() {
+        let corrupt = CountrySet {
+            words: [u64::MAX; N_WORDS],
+        };
+
+        let countries: Vec<Country> = corrupt.iter().collect();
+        assert_eq!(countries.len(), Country::COUNT);
+        assert!(countries.iter().all(|it| (*it as usize) < Country::COUNT));
+        assert!(corrupt.iter().rev().eq(countries.iter().rev().copied()));
+        assert_eq!(corrupt.len(), Country::COUNT);
+
+        assert_eq!(corrupt | CountrySet::new(), CountrySet::all());
+        assert_eq!(corrupt & corrupt, CountrySet::all());
+        let mut set = CountrySet::new();
+        set |= corrupt;
+        assert_eq!(set, CountrySet::all());
+    }
+
+    #[test]
+    fn set_conversions() {
+        let countries = [Country::US, Country::DE, Country::JP];
+
+        let owned: CountrySet = countries.into_iter().collect();
+        let borrowed: CountrySet = countries.iter().collect();
+        assert_eq!(owned, borrowed);
+
+        let mut extended = CountrySet::new();
+        Extend::extend(&mut extended, &countries);
+        assert_eq!(extended, owned);
+
+        let by_ref: Vec<Country> = (&owned).into_iter().collect();
+        let by_value: Vec<Country> = owned.into_iter().collect();
+        assert_eq!(by_ref, by_value);
+        assert_eq!(by_value, [Country::DE, Country::JP, Country::US]);
+    }
+
+    #[test]
+    fn parse_classification() {
+        assert_eq!("US".parse(), Ok(Country::US));
+        assert_eq!("us".parse(), Ok(Country::US));
+        assert_eq!("XX".parse::<Country>(), Err(CountryParseError::Unknown));
+        assert_eq!("".parse::<Country>(), Err(CountryParseError::Unknown));
+        assert!(!Country::is_enabled("XX"));
+
+        let enabled = enabled_countries();
+        assert_eq!(enabled.len(), Country::COUNT);
+        assert!(enabled.iter().all(|it| Country::is_enabled(it.as_ref())));
+        assert!(enabled.iter().all(|it| it.as_ref().parse() == Ok(*it)));
+    }
+
+    #[test]
+    fn code_bytes_match_parsing() {
+        for first in 0..=u8::MAX {
+            for second in 0..=u8::MAX {
+                let code = [first, second];
+                let expected = std::str::from_utf8(&code).ok().and_then(lookup_code);
+                assert_eq!(Country::from_code_bytes(code), expected, "{code:?}");
+            }
+        }
+        for country in enabled_countries() {
+            let code: [u8; 2] = country.as_ref().as_bytes().try_into().unwrap();
+            assert_eq!(Country::from_code_bytes(code), Some(*country));
+            let lower = code.map(|it| it.to_ascii_lowercase());
+            assert_eq!(Country::from_code_bytes(lower), Some(*country));
+        }
+    }
+
+    #[test]
+    fn disabled_countries_are_known() {
+        // every known code is either enabled or reported as not enabled
+        for code in KNOWN_CODES.iter() {
+            match code.parse::<Country>() {
+                Ok(country) => assert_eq!(country.as_ref(), *code),
+                Err(err) => {
+                    assert_eq!(err, CountryParseError::NotEnabled);
+                    assert!(!Country::is_enabled(code));
+                }
+            }
+        }
+        assert!(KNOWN_CODES.len() >= Country::COUNT);
+    }
+
+    #[test]
+    fn codes_all_valid() {
+        let codes = vec!["US".to_string(), "gb".to_string(), " JP\t".to_string()];
+        let set = CountrySet::from_codes(codes);
+        assert_eq!(
+            set,
+            Ok([Country::US, Country::GB, Country::JP]
+                .into_iter()
+                .collect())
+        );
+        assert_eq!(CountrySet::from_codes(["US", "US"]).unwrap().len(), 1);
+        assert_eq!(
+            CountrySet::from_codes(Vec::<&str>::new()),
+            Ok(CountrySet::new())
+        );
+        assert_eq!(
+            CountrySet::from_codes_lossy(["DE"]),
+            ([Country::DE].into_iter().collect(), vec![])
+        );
+    }
+
+    #[test]
+    fn codes_all_invalid() {
+        let err = CountrySet::from_codes(["XX", "", "USA", "XX"]).unwrap_err();
+        assert!(err.valid.is_empty());
+        let codes: Vec<_> = err
+            .invalid
+            .iter()
+            .map(|it| (it.code.as_str(), it.positions.clone()))
+            .collect();
+        assert_eq!(codes, [("XX", vec![0, 3]), ("", vec![1]), ("USA", vec![2])]);
+        assert!(err
+            .invalid
+            .iter()
+            .all(|it| it.reason == CountryParseError::Unknown));
+
+        let (set, invalid) = CountrySet::from_codes_lossy(["XX", "", "USA", "XX"]);
+        assert!(set.is_empty());
+        assert_eq!(invalid, ["XX", "", "USA"]);
+    }
+
+    #[test]
+    fn codes_mixed() {
+        let codes = ["US", "XX", "de", "U$", "XX", "JP"];
+        let err = CountrySet::from_codes(codes).unwrap_err();
+        assert_eq!(
+            err.valid,
+            [Country::US, Country::DE, Country::JP]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(
+            err.invalid,
+            [
+                InvalidCode {
+                    code: "XX".to_string(),
+                    positions: vec![1, 4],
+                    reason: CountryParseError::Unknown,
+                },
+                InvalidCode {
+                    code: "U$".to_string(),
+                    positions: vec![3],
+                    reason: CountryParseError::Unknown,
+                },
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid country codes: 'XX' (unknown), 'U$' (unknown)"
+        );
+        let lossy = (err.valid, vec!["XX".to_string(), "U$".to_string()]);
+        assert_eq!(CountrySet::from_codes_lossy(codes), lossy);
+    }
+
+    #[cfg(not(feature = "AO"))]
+    #[test]
+    fn not_enabled() {
+        assert_eq!("AO".parse::<Country>(), Err(CountryParseError::NotEnabled));
+        assert_eq!("ao".parse::<Country>(), Err(CountryParseError::NotEnabled));
+        assert!(!Country::is_enabled("AO"));
+
+        let err = CountrySet::from_codes(["ao", "US"]).unwrap_err();
+        assert_eq!(err.invalid[0].reason, CountryParseError::NotEnabled);
+        assert_eq!(err.to_string(), "invalid country codes: 'ao' (not enabled)");
+    }
+}
@@ -0,0 +1,398 @@
+//! Per-country information about the embedded holiday data.
+//!
+//! Constants in this module are generated at build time and reflect the
+//! enabled countries as well as the `HOLIDAYS_MIN_YEAR` and
+//! `HOLIDAYS_MAX_YEAR` limits.
+
+use crate::selection::DateSelection;
+use crate::{Country, CountrySet, Date};
+
+/// Statistics about embedded holiday data of a single country.
+///
+/// ```
+/// use holidays::Country;
+///
+/// let coverage = Country::DE.coverage();
+/// assert!(coverage.first_year <= 2025 && 2025 <= coverage.last_year);
+/// let records = holidays::get_holidays::<_, holidays::Date, _>(Country::DE, holidays::Any);
+/// assert_eq!(coverage.record_count, records.count());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CountryCoverage {
+    /// Year of the first embedded holiday.
+    ///
+    /// For countries without any records this is the first requested year.
+    pub first_year: i64,
+    /// Year of the last embedded holiday.
+    ///
+    /// For countries without any records this is the last requested year.
+    pub last_year: i64,
+    /// Number of embedded holiday records.
+    pub record_count: usize,
+}
+
+include!(concat!(env!("OUT_DIR"), "/coverage.rs"));
+
+/// Inclusive range of years without any embedded holidays of a country.
+///
+/// Returned by [`coverage_gaps`].
+///
+/// ```
+/// // e.g. to list countries whose data should be updated
+/// let incomplete: Vec<_> = holidays::coverage_gaps().map(|it| it.country).collect();
+/// assert!(incomplete.iter().all(|it| holidays::enabled_countries().contains(it)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoverageGap {
+    /// The `Country` without holidays in these years.
+    pub country: Country,
+    /// First year without any holidays.
+    pub first_year: i64,
+    /// Last year without any holidays.
+    pub last_year: i64,
+}
+
+/// Returns ranges of years without any holidays of enabled countries, within
+/// the years covered by all embedded data.
+///
+/// Years without any holidays are essentially nonexistent, so gaps usually
+/// indicate data missing from the dataset. Gaps are ordered by country and
+/// year. The same gaps are reported as warnings when building the crate.
+///
+/// ```
+/// for gap in holidays::coverage_gaps() {
+///     eprintln!("no {} holidays in {}-{}", gap.country, gap.first_year, gap.last_year);
+/// }
+/// ```
+pub fn coverage_gaps() -> impl Iterator<Item = CoverageGap> {
+    use crate::data::{DATA, DATA_MAX_YEAR, DATA_MIN_YEAR};
+
+    CountrySet::all().into_iter().flat_map(|country| {
+        let years = crate::data::country_indices(country)
+            .iter()
+            .map(|&it| DATA[it].date.year());
+        // sentinels just outside of the window report leading and trailing
+        // gaps too
+        let bounds = std::iter::once(DATA_MIN_YEAR - 1)
+            .chain(years)
+            .chain(std::iter::once(DATA_MAX_YEAR + 1));
+        bounds
+            .clone()
+            .zip(bounds.skip(1))
+            .filter(|(previous, next)| next - previous > 1)
+            .map(move |(previous, next)| CoverageGap {
+                country,
+                first_year: previous + 1,
+                last_year: next - 1,
+            })
+    })
+}
+
+/// Dates of a selection clipped to the years of embedded data.
+///
+/// Returned by [`clip_to_coverage`]. It's a half-open range of dates, which
+/// can be passed to [`get_holidays`](crate::get_holidays) like any other
+/// range.
+///
+/// ```
+/// use holidays::{Country, Date};
+///
+/// let (clipped, _) = holidays::clip_to_coverage(Date::from_ymd(1900, 1, 1)..);
+/// assert_eq!(clipped.start(), Date::from_ymd(holidays::CoveredYear::MIN.get(), 1, 1));
+/// assert!(holidays::get_holidays(Country::US, clipped).next().is_some());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClippedSelection {
+    start: Date,
+    end: Date,
+}
+
+impl ClippedSelection {
+    /// Returns the first selected date.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let (clipped, _) = holidays::clip_to_coverage(Date::from_ymd(2025, 3, 1)..Date::from_ymd(2025, 4, 1));
+    /// assert_eq!(clipped.start(), Date::from_ymd(2025, 3, 1));
+    /// ```
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Returns the date after the last selected date.
+    ///
+    /// ```
+    /// use holidays::{CoveredYear, Date};
+    ///
+    /// let (clipped, _) = holidays::clip_to_coverage(2025..);
+    /// assert_eq!(clipped.end(), Date::from_ymd(CoveredYear::MAX.get() + 1, 1, 1));
+    /// ```
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Returns `true` if no dates are selected, i.e. none of the requested
+    /// dates are covered by embedded data.
+    ///
+    /// ```
+    /// let (clipped, report) = holidays::clip_to_coverage(1900..1950);
+    /// assert!(clipped.is_empty());
+    /// assert!(report.is_clipped());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.start.0 >= self.end.0
+    }
+}
+
+impl std::ops::RangeBounds<Date> for ClippedSelection {
+    fn start_bound(&self) -> std::ops::Bound<&Date> {
+        std::ops::Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&Date> {
+        std::ops::Bound::Excluded(&self.end)
+    }
+}
+
+/// Description of how [`clip_to_coverage`] clipped a date selection.
+///
+/// Starts are inclusive and ends are exclusive, like in
+/// [`Range`](std::ops::Range).
+///
+/// ```
+/// use holidays::Date;
+///
+/// let (_, report) = holidays::clip_to_coverage(2020..2100);
+/// if report.clipped_end {
+///     let last = report.effective_end.saturating_add_days(-1);
+///     println!("holidays are only known through {last}");
+/// }
+/// assert_eq!(report.requested_end, Some(Date::from_ymd(2100, 1, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClipReport {
+    /// First requested date, or `None` if the selection is unbounded.
+    pub requested_start: Option<Date>,
+    /// Date after the last requested date, or `None` if the selection is
+    /// unbounded.
+    pub requested_end: Option<Date>,
+    /// First date of the clipped selection.
+    pub effective_start: Date,
+    /// Date after the last date of the clipped selection.
+    pub effective_end: Date,
+    /// Whether requested dates before the first covered year were left out.
+    pub clipped_start: bool,
+    /// Whether requested dates after the last covered year were left out.
+    pub clipped_end: bool,
+}
+
+impl ClipReport {
+    /// Returns `true` if any requested dates were left out.
+    ///
+    /// ```
+    /// let (_, report) = holidays::clip_to_coverage(2020..2025);
+    /// assert!(!report.is_clipped());
+    ///
+    /// let (_, report) = holidays::clip_to_coverage(..2025);
+    /// assert!(report.is_clipped());
+    /// ```
+    pub const fn is_clipped(&self) -> bool {
+        self.clipped_start || self.clipped_end
+    }
+}
+
+/// Clips `date` selection to years covered by embedded data, and reports
+/// whether any requested dates were left out.
+///
+/// Accepts the same date selections as [`get_holidays`], which returns the
+/// same results for the clipped selection as for the requested one, as
+/// there are no records outside of covered years. Selections that don't
+/// overlap covered years are clipped to an empty selection.
+///
+/// Covered years are the same for all countries, and are limited by
+/// `HOLIDAYS_MIN_YEAR` and `HOLIDAYS_MAX_YEAR` at build time; see
+/// [`Country::coverage`] for years with records of a single country.
+///
+/// ```
+/// use holidays::{Country, Date};
+///
+/// let (clipped, report) = holidays::clip_to_coverage(2020..2100);
+/// assert!(!report.clipped_start && report.clipped_end);
+/// assert_eq!(report.effective_end, Date::from_ymd(holidays::CoveredYear::MAX.get() + 1, 1, 1));
+/// assert!(holidays::get_holidays(Country::US, clipped).eq(holidays::get_holidays(Country::US, 2020..2100)));
+/// ```
+///
+/// [`get_holidays`]: crate::get_holidays
+pub fn clip_to_coverage<DateLike, DateRange>(
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+) -> (ClippedSelection, ClipReport)
+where
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    use crate::data::{DATA_MAX_YEAR, DATA_MIN_YEAR};
+
+    let first = Date::saturating_from_year(DATA_MIN_YEAR);
+    let end = Date::saturating_from_year(DATA_MAX_YEAR.saturating_add(1));
+
+    let (requested_start, requested_end) = date.into().into_bounds();
+    let effective_start = requested_start.map_or(first, |it| it.max(first)).min(end);
+    let effective_end = requested_end
+        .map_or(end, |it| it.min(end))
+        .max(effective_start);
+
+    // requested dates, with unbounded sides extended to the limits of `Date`
+    let (lower, upper) = (
+        requested_start.unwrap_or(Date::MIN),
+        requested_end.unwrap_or(Date::MAX),
+    );
+    let report = ClipReport {
+        requested_start,
+        requested_end,
+        effective_start,
+        effective_end,
+        clipped_start: lower < upper.min(first),
+        clipped_end: lower.max(end) < upper,
+    };
+    let clipped = ClippedSelection {
+        start: effective_start,
+        end: effective_end,
+    };
+    (clipped, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+    use crate::{get_holidays, Country, CoveredYear};
+
+    #[test]
+    fn coverage_matches_queries() {
+        for (country, coverage) in [
+            (Country::US, US),
+            (Country::DE, DE),
+            (Country::JP, JP),
+            (Country::ZW, ZW),
+        ] {
+            assert_eq!(country.coverage(), coverage);
+
+            let dates: Vec<Date> = get_holidays::<_, Date, _>(country, crate::Any)
+                .map(|it| it.date().unwrap())
+                .collect();
+            assert_eq!(dates.len(), coverage.record_count);
+            assert_eq!(dates.first().unwrap().ymd().0, coverage.first_year);
+            assert_eq!(dates.last().unwrap().ymd().0, coverage.last_year);
+        }
+    }
+
+    #[test]
+    fn clipping_to_coverage() {
+        use crate::data::{DATA_MAX_YEAR, DATA_MIN_YEAR};
+
+        let first = Date::from_ymd(DATA_MIN_YEAR, 1, 1);
+        let end = Date::from_ymd(DATA_MAX_YEAR + 1, 1, 1);
+        let report = |start, end, clipped_start, clipped_end| ClipReport {
+            requested_start: Some(start),
+            requested_end: Some(end),
+            effective_start: start,
+            effective_end: end,
+            clipped_start,
+            clipped_end,
+        };
+        let assert_same_results = |clipped: ClippedSelection, start: Date, requested_end: Date| {
+            let clipped = get_holidays(crate::Any, clipped);
+            assert!(clipped.eq(get_holidays(crate::Any, start..requested_end)));
+        };
+
+        // no clipping
+        let (start, stop) = (Date::from_ymd(2025, 3, 1), Date::from_ymd(2026, 3, 1));
+        let (clipped, clip) = clip_to_coverage(start..stop);
+        assert_eq!(clip, report(start, stop, false, false));
+        assert!(!clip.is_clipped());
+        assert_eq!((clipped.start(), clipped.end()), (start, stop));
+        assert_same_results(clipped, start, stop);
+        let (_, clip) = clip_to_coverage(start);
+        assert_eq!(
+            clip,
+            report(start, start.saturating_add_days(1), false, false)
+        );
+
+        // left side
+        let before = Date::from_ymd(DATA_MIN_YEAR - 10, 6, 1);
+        let (clipped, clip) = clip_to_coverage(before..stop);
+        assert_eq!(
+            clip,
+            ClipReport {
+                effective_start: first,
+                ..report(before, stop, true, false)
+            }
+        );
+        assert_same_results(clipped, before, stop);
+
+        // right side
+        let after = Date::from_ymd(DATA_MAX_YEAR + 10, 6, 1);
+        let (clipped, clip) = clip_to_coverage(start..=after);
+        assert_eq!(
+            clip,
+            ClipReport {
+                effective_end: end,
+                ..report(start, after.saturating_add_days(1), false, true)
+            }
+        );
+        assert_same_results(clipped, start, after);
+
+        // both sides
+        let (clipped, clip) = clip_to_coverage::<Date, _>(crate::Any);
+        assert_eq!((clip.requested_start, clip.requested_end), (None, None));
+        assert!(clip.clipped_start && clip.clipped_end);
+        assert_eq!((clipped.start(), clipped.end()), (first, end));
+
+        // fully outside of coverage
+        for (start, stop, clipped_start, clipped_end) in [
+            (before, before.saturating_add_days(30), true, false),
+            (after, after.saturating_add_days(30), false, true),
+        ] {
+            let (clipped, clip) = clip_to_coverage(start..stop);
+            assert!(clipped.is_empty());
+            assert_eq!(
+                (clip.clipped_start, clip.clipped_end),
+                (clipped_start, clipped_end)
+            );
+            assert_eq!(
+                (clip.requested_start, clip.requested_end),
+                (Some(start), Some(stop))
+            );
+            assert!(get_holidays(crate::Any, clipped).next().is_none());
+        }
+
+        // empty selections aren't clipped
+        let (clipped, clip) = clip_to_coverage(after..before);
+        assert!(clipped.is_empty() && !clip.is_clipped());
+        assert_eq!(
+            (clip.requested_start, clip.requested_end),
+            (Some(after), Some(before))
+        );
+    }
+
+    #[test]
+    fn gaps_match_queries() {
+        let gaps: Vec<CoverageGap> = coverage_gaps().collect();
+        assert!(gaps.iter().all(|it| it.first_year <= it.last_year));
+
+        for country in CountrySet::all() {
+            for year in CoveredYear::all() {
+                let missing = gaps.iter().any(|it| {
+                    it.country == country && (it.first_year..=it.last_year).contains(&year.get())
+                });
+                assert_eq!(
+                    missing,
+                    get_holidays(country, year.dates()).next().is_none(),
+                    "{country} in {}",
+                    year.get()
+                );
+            }
+        }
+    }
+}
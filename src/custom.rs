@@ -0,0 +1,837 @@
+use std::ops::Bound;
+use std::sync::RwLock;
+
+use crate::business::{Calendar, ClosureRule};
+use crate::country::{Country, CountrySet};
+use crate::date::Date;
+use crate::query::selection::DateSelection;
+use crate::workweek::Workweek;
+
+/// Maximum number of custom calendars a process can register.
+const MAX_CALENDARS: usize = 64;
+
+/// Calendars registered so far, indexed by [`CustomCalendarId`].
+static REGISTRY: RwLock<Vec<&'static Registered>> = RwLock::new(Vec::new());
+
+struct Registered {
+    name: &'static str,
+    /// Entries sorted by date, with one entry per date.
+    entries: Box<[(Date, &'static str)]>,
+}
+
+impl Registered {
+    fn get(id: CustomCalendarId) -> &'static Registered {
+        // entries are only ever appended, so a panic of another thread can't
+        // leave them in an invalid state
+        let registry = REGISTRY.read().unwrap_or_else(|it| it.into_inner());
+        // ids are only handed out by `CustomCalendar::register`
+        registry[id.0 as usize]
+    }
+
+    fn observes(&self, date: Date) -> bool {
+        self.entries.binary_search_by_key(&date, |it| it.0).is_ok()
+    }
+
+    /// Returns entries on or after `start` and before `end`.
+    fn within(&self, start: Option<Date>, end: Option<Date>) -> &[(Date, &'static str)] {
+        let position = |date: Date| self.entries.partition_point(|it| it.0 < date);
+        let from = start.map_or(0, position);
+        let to = end.map_or(self.entries.len(), position);
+        &self.entries[from..to.max(from)]
+    }
+}
+
+/// Closure calendar defined at runtime, e.g. company closures, which can be
+/// queried along with countries once it's [registered](Self::register).
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{CalendarId, CalendarSet, Country, CustomCalendar};
+///
+/// let company = CustomCalendar::new("ACME")
+///     .with_entry(Date::from_ymd(2025, 12, 24), "Christmas Eve")
+///     .with_entry(Date::from_ymd(2025, 12, 31), "New Year's Eve")
+///     .register()
+///     .unwrap();
+///
+/// let calendars = CalendarSet::from_iter([CalendarId::Country(Country::US), company.into()]);
+/// let holidays = calendars.holidays(Date::from_ymd(2025, 12, 20)..);
+/// let names: Vec<_> = holidays.take(3).map(|it| it.name).collect();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(names, ["Christmas Eve", "Christmas Day", "New Year's Eve"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomCalendar {
+    name: String,
+    entries: Vec<(Date, String)>,
+}
+
+impl CustomCalendar {
+    /// Constructs an empty calendar named `name`.
+    ///
+    /// ```
+    /// use holidays::CustomCalendar;
+    ///
+    /// let office = CustomCalendar::new("Office").register().unwrap();
+    /// assert_eq!(office.name(), "Office");
+    /// ```
+    pub fn new(name: impl Into<String>) -> Self {
+        CustomCalendar {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the calendar with a closure on `date` named `name` added.
+    ///
+    /// Like holidays of countries, multiple entries on the same date are
+    /// kept as separate holidays, ordered by name.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{CalendarSet, CustomCalendar};
+    ///
+    /// let plant = CustomCalendar::new("Plant")
+    ///     .with_entry(Date::from_ymd(2025, 8, 4), "Maintenance")
+    ///     .with_entry(Date::from_ymd(2025, 8, 4), "Inventory")
+    ///     .register()
+    ///     .unwrap();
+    /// let names: Vec<_> = CalendarSet::from_iter([plant]).holidays(2025..2026).map(|it| it.name).collect();
+    /// assert_eq!(names, ["Inventory", "Maintenance"]);
+    /// ```
+    pub fn with_entry(mut self, date: impl Into<Date>, name: impl Into<String>) -> Self {
+        self.entries.push((date.into(), name.into()));
+        self
+    }
+
+    /// Registers the calendar, so it can be selected along with countries.
+    ///
+    /// Registered calendars can't be changed or removed and live until the
+    /// process exits, so they should be registered once, e.g. on startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooManyCalendars`] if 64 calendars were already registered.
+    ///
+    /// [`TooManyCalendars`]: crate::error::TooManyCalendars
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{CalendarSet, CustomCalendar};
+    ///
+    /// let id = CustomCalendar::new("Store")
+    ///     .with_entry(Date::from_ymd(2025, 11, 27), "Inventory")
+    ///     .register()
+    ///     .unwrap();
+    /// assert_eq!(CalendarSet::from_iter([id]).holidays(2025..2026).count(), 1);
+    /// ```
+    pub fn register(self) -> Result<CustomCalendarId, TooManyCalendars> {
+        let mut entries = self.entries;
+        entries.sort();
+        entries.dedup();
+
+        let mut registry = REGISTRY.write().unwrap_or_else(|it| it.into_inner());
+        if registry.len() >= MAX_CALENDARS {
+            return Err(TooManyCalendars);
+        }
+        let leak = |it: String| -> &'static str { Box::leak(it.into_boxed_str()) };
+        registry.push(Box::leak(Box::new(Registered {
+            name: leak(self.name),
+            entries: entries
+                .into_iter()
+                .map(|(date, name)| (date, leak(name)))
+                .collect(),
+        })));
+        Ok(CustomCalendarId((registry.len() - 1) as u8))
+    }
+}
+
+/// Error returned when registering more custom calendars than supported.
+///
+/// ```
+/// use holidays::error::TooManyCalendars;
+/// use holidays::CustomCalendar;
+///
+/// let registered = (0..100).map(|i| CustomCalendar::new(format!("Team {i}")).register());
+/// let (ok, err): (Vec<_>, Vec<_>) = registered.partition(Result::is_ok);
+/// assert_eq!(ok.len(), 64);
+/// assert!(err.iter().all(|it| *it == Err(TooManyCalendars)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyCalendars;
+crate::error::error_msg!(
+    TooManyCalendars,
+    "No more than 64 custom calendars can be registered"
+);
+
+/// Identifier of a registered [`CustomCalendar`].
+///
+/// ```
+/// use holidays::{CalendarSet, CustomCalendar};
+///
+/// let office = CustomCalendar::new("Office").register().unwrap();
+/// assert_eq!(office.name(), "Office");
+/// assert!(CalendarSet::from_iter([office]).contains(office));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CustomCalendarId(u8);
+
+impl CustomCalendarId {
+    /// Returns the name the calendar was registered with.
+    ///
+    /// ```
+    /// use holidays::CustomCalendar;
+    ///
+    /// let team = CustomCalendar::new("Support team").register().unwrap();
+    /// assert_eq!(team.name(), "Support team");
+    /// ```
+    pub fn name(self) -> &'static str {
+        Registered::get(self).name
+    }
+}
+
+/// Calendar of either an embedded country or a registered custom calendar.
+///
+/// ```
+/// use holidays::{CalendarId, Country, CustomCalendar};
+///
+/// let team = CustomCalendar::new("Platform team").register().unwrap();
+/// let ids = [CalendarId::from(Country::US), CalendarId::from(team)];
+/// let labels: Vec<_> = ids.iter().map(ToString::to_string).collect();
+/// assert_eq!(labels, ["US", "Platform team"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarId {
+    /// Embedded holidays of a country.
+    Country(Country),
+    /// Closures of a registered custom calendar.
+    Custom(CustomCalendarId),
+}
+
+impl From<Country> for CalendarId {
+    fn from(value: Country) -> Self {
+        CalendarId::Country(value)
+    }
+}
+
+impl From<CustomCalendarId> for CalendarId {
+    fn from(value: CustomCalendarId) -> Self {
+        CalendarId::Custom(value)
+    }
+}
+
+impl std::fmt::Display for CalendarId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarId::Country(country) => country.fmt(f),
+            CalendarId::Custom(custom) => f.write_str(custom.name()),
+        }
+    }
+}
+
+/// Holiday or closure returned by [`CalendarSet::holidays`].
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{CalendarId, CalendarSet, CustomCalendar};
+///
+/// let plant = CustomCalendar::new("Plant")
+///     .with_entry(Date::from_ymd(2025, 8, 4), "Maintenance")
+///     .register()
+///     .unwrap();
+/// let closure = CalendarSet::from_iter([plant]).holidays(2025..2026).next().unwrap();
+/// assert_eq!(closure.id, CalendarId::Custom(plant));
+/// assert_eq!((closure.date, closure.name), (Date::from_ymd(2025, 8, 4), "Maintenance"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarHoliday {
+    /// Calendar the holiday is observed in.
+    pub id: CalendarId,
+    /// The date of the holiday.
+    pub date: Date,
+    /// The name of the holiday, see [`Holiday::name`](crate::Holiday::name).
+    pub name: &'static str,
+}
+
+/// Set of countries and custom calendars, which supports the same queries as
+/// [`CountrySet`].
+///
+/// Sets without custom calendars are queried exactly like their countries.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{CalendarSet, ClosureRule, Country, CountrySet, CustomCalendar, Workweek};
+///
+/// let company = CustomCalendar::new("Company")
+///     .with_entry(Date::from_ymd(2025, 12, 24), "Christmas Eve")
+///     .register()
+///     .unwrap();
+/// let mut calendars = CalendarSet::from(CountrySet::from_iter([Country::US]));
+/// calendars.insert(company);
+///
+/// let (start, end) = (Date::from_ymd(2025, 12, 22), Date::from_ymd(2025, 12, 27));
+/// let rule = ClosureRule::AnyClosed;
+/// assert_eq!(calendars.business_days_between(rule, Workweek::MON_FRI, start, end), 3);
+/// assert_eq!(holidays::business_days_between(Country::US, rule, Workweek::MON_FRI, start, end), 4);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CalendarSet {
+    countries: CountrySet,
+    /// Bit `n` is set for `CustomCalendarId(n)`.
+    custom: u64,
+}
+
+impl CalendarSet {
+    /// Constructs an empty set.
+    ///
+    /// ```
+    /// use holidays::CalendarSet;
+    ///
+    /// assert!(CalendarSet::new().is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        CalendarSet {
+            countries: CountrySet::new(),
+            custom: 0,
+        }
+    }
+
+    /// Adds a calendar to the set.
+    ///
+    /// ```
+    /// use holidays::{CalendarSet, Country, CustomCalendar};
+    ///
+    /// let mut calendars = CalendarSet::new();
+    /// calendars.insert(Country::GB);
+    /// calendars.insert(CustomCalendar::new("London office").register().unwrap());
+    /// assert_eq!(calendars.len(), 2);
+    /// ```
+    pub fn insert(&mut self, id: impl Into<CalendarId>) {
+        match id.into() {
+            CalendarId::Country(country) => self.countries.insert(country),
+            CalendarId::Custom(custom) => self.custom |= 1 << custom.0,
+        }
+    }
+
+    /// Returns `true` if the set contains the calendar.
+    ///
+    /// ```
+    /// use holidays::{CalendarSet, Country};
+    ///
+    /// let calendars = CalendarSet::from_iter([Country::CA]);
+    /// assert!(calendars.contains(Country::CA));
+    /// assert!(!calendars.contains(Country::US));
+    /// ```
+    pub fn contains(&self, id: impl Into<CalendarId>) -> bool {
+        match id.into() {
+            CalendarId::Country(country) => self.countries.contains(country),
+            CalendarId::Custom(custom) => (self.custom >> custom.0) & 1 == 1,
+        }
+    }
+
+    /// Returns the number of calendars in the set.
+    ///
+    /// ```
+    /// use holidays::{CalendarId, CalendarSet, Country, CustomCalendar};
+    ///
+    /// let shop = CustomCalendar::new("Shop").register().unwrap();
+    /// let calendars = CalendarSet::from_iter([
+    ///     CalendarId::from(Country::IT),
+    ///     CalendarId::from(shop),
+    ///     CalendarId::from(Country::IT),
+    /// ]);
+    /// assert_eq!(calendars.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.countries.len() + self.custom.count_ones() as usize
+    }
+
+    /// Returns `true` if the set doesn't contain any calendars.
+    ///
+    /// ```
+    /// use holidays::{CalendarSet, Country};
+    ///
+    /// assert!(CalendarSet::default().is_empty());
+    /// assert!(!CalendarSet::from_iter([Country::IT]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.countries.is_empty() && self.custom == 0
+    }
+
+    /// Returns countries of the set.
+    ///
+    /// ```
+    /// use holidays::{CalendarSet, Country, CustomCalendar};
+    ///
+    /// let mut calendars = CalendarSet::from_iter([Country::ES]);
+    /// calendars.insert(CustomCalendar::new("Madrid office").register().unwrap());
+    /// assert_eq!(calendars.countries().len(), 1);
+    /// assert!(calendars.countries().contains(Country::ES));
+    /// ```
+    pub const fn countries(&self) -> CountrySet {
+        self.countries
+    }
+
+    /// Returns an iterator over calendars in the set, countries first.
+    ///
+    /// ```
+    /// use holidays::{CalendarId, CalendarSet, Country, CustomCalendar};
+    ///
+    /// let warehouse = CustomCalendar::new("Warehouse").register().unwrap();
+    /// let mut calendars = CalendarSet::from_iter([warehouse]);
+    /// calendars.insert(Country::PL);
+    /// let ids: Vec<_> = calendars.iter().collect();
+    /// assert_eq!(ids, [CalendarId::Country(Country::PL), CalendarId::Custom(warehouse)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = CalendarId> {
+        let custom = self.custom;
+        self.countries.iter().map(CalendarId::Country).chain(
+            (0..MAX_CALENDARS as u8)
+                .filter(move |it| (custom >> it) & 1 == 1)
+                .map(|it| CalendarId::Custom(CustomCalendarId(it))),
+        )
+    }
+
+    /// Returns holidays of the calendars on selected dates, ordered by date;
+    /// holidays of countries on the same date come first, like in
+    /// [`get_holidays`](crate::get_holidays) results, followed by the custom
+    /// calendars in order of their registration.
+    ///
+    /// Accepts the same dates as [`get_holidays`](crate::get_holidays).
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{CalendarId, CalendarSet, Country, CustomCalendar};
+    ///
+    /// let office = CustomCalendar::new("Office")
+    ///     .with_entry(Date::from_ymd(2025, 12, 25), "Closed")
+    ///     .register()
+    ///     .unwrap();
+    /// let mut calendars = CalendarSet::from_iter([Country::GB]);
+    /// calendars.insert(office);
+    ///
+    /// let ids: Vec<_> = calendars.holidays(Date::from_ymd(2025, 12, 25)).map(|it| it.id).collect();
+    /// assert_eq!(ids, [CalendarId::Country(Country::GB), CalendarId::Custom(office)]);
+    /// ```
+    pub fn holidays<DateLike, DateRange>(
+        self,
+        date: impl Into<DateSelection<DateLike, DateRange>>,
+    ) -> CalendarIter
+    where
+        DateLike: Into<Date> + Clone,
+        DateRange: std::ops::RangeBounds<DateLike>,
+    {
+        let (start, end) = date.into().into_query().date_range();
+        let bounds = (
+            start.map_or(Bound::Unbounded, Bound::Included),
+            end.map_or(Bound::Unbounded, Bound::Excluded),
+        );
+        let mut custom = Vec::new();
+        for id in self.custom_ids() {
+            let entries = Registered::get(id).within(start, end);
+            custom.extend(entries.iter().map(|&(date, name)| CalendarHoliday {
+                id: CalendarId::Custom(id),
+                date,
+                name,
+            }));
+        }
+        // the sort is stable and calendars are already in order of
+        // registration, so only dates need to be sorted
+        custom.sort_by_key(|it| it.date);
+
+        CalendarIter {
+            countries: crate::get_holidays(self.countries, bounds).peekable(),
+            custom: custom.into_iter().peekable(),
+        }
+    }
+
+    /// Returns `true` if `date` is a business day of the calendars, see
+    /// [`is_business_day`](crate::is_business_day).
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{CalendarSet, ClosureRule, Country, CustomCalendar, Workweek};
+    ///
+    /// let office = CustomCalendar::new("Office")
+    ///     .with_entry(Date::from_ymd(2025, 12, 24), "Christmas Eve")
+    ///     .register()
+    ///     .unwrap();
+    /// let mut calendars = CalendarSet::from_iter([Country::US]);
+    /// calendars.insert(office);
+    ///
+    /// let christmas_eve = Date::from_ymd(2025, 12, 24);
+    /// assert!(!calendars.is_business_day(ClosureRule::AnyClosed, Workweek::MON_FRI, christmas_eve));
+    /// assert!(calendars.is_business_day(ClosureRule::AllClosed, Workweek::MON_FRI, christmas_eve));
+    /// ```
+    pub fn is_business_day(
+        self,
+        rule: ClosureRule,
+        workweek: impl Into<Workweek>,
+        date: impl Into<Date>,
+    ) -> bool {
+        self.is_open(rule, workweek.into(), date.into())
+    }
+
+    /// Returns the number of business days of the calendars in `start..end`,
+    /// see [`business_days_between`](crate::business_days_between).
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{CalendarSet, ClosureRule, Country, CustomCalendar, Workweek};
+    ///
+    /// let office = CustomCalendar::new("Office")
+    ///     .with_entry(Date::from_ymd(2025, 12, 24), "Christmas Eve")
+    ///     .register()
+    ///     .unwrap();
+    /// let mut calendars = CalendarSet::from_iter([Country::US]);
+    /// calendars.insert(office);
+    ///
+    /// let (start, end) = (Date::from_ymd(2025, 12, 22), Date::from_ymd(2025, 12, 29));
+    /// assert_eq!(calendars.business_days_between(ClosureRule::AnyClosed, Workweek::MON_FRI, start, end), 3);
+    /// assert_eq!(calendars.business_days_between(ClosureRule::AnyClosed, Workweek::MON_FRI, end, start), -3);
+    /// ```
+    pub fn business_days_between(
+        self,
+        rule: ClosureRule,
+        workweek: impl Into<Workweek>,
+        start: impl Into<Date>,
+        end: impl Into<Date>,
+    ) -> i64 {
+        let workweek = workweek.into();
+        let (start, end) = (start.into(), end.into());
+        if self.custom == 0 {
+            return Calendar::new(self.countries, rule, workweek).days_between(start, end);
+        }
+        let (from, to, sign) = if start <= end {
+            (start.0, end.0, 1)
+        } else {
+            (end.0, start.0, -1)
+        };
+        let count = (from..to)
+            .filter(|&day| self.is_open(rule, workweek, Date(day)))
+            .count();
+        sign * count as i64
+    }
+
+    /// Moves `days` business days of the calendars away from `date`, see
+    /// [`add_business_days`](crate::add_business_days).
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{CalendarSet, ClosureRule, Country, CustomCalendar, Workweek};
+    ///
+    /// let office = CustomCalendar::new("Office")
+    ///     .with_entry(Date::from_ymd(2025, 12, 24), "Christmas Eve")
+    ///     .register()
+    ///     .unwrap();
+    /// let mut calendars = CalendarSet::from_iter([Country::US]);
+    /// calendars.insert(office);
+    ///
+    /// let next = calendars.add_business_days(ClosureRule::AnyClosed, Workweek::MON_FRI, Date::from_ymd(2025, 12, 23), 1);
+    /// assert_eq!(next, Date::from_ymd(2025, 12, 26));
+    /// ```
+    pub fn add_business_days(
+        self,
+        rule: ClosureRule,
+        workweek: impl Into<Workweek>,
+        date: impl Into<Date>,
+        days: i64,
+    ) -> Date {
+        let workweek = workweek.into();
+        if self.custom == 0 {
+            return Calendar::new(self.countries, rule, workweek).add_days(date.into(), days);
+        }
+        let step = days.signum();
+        let mut remaining = days.unsigned_abs();
+        let mut current = date.into();
+        while remaining > 0 {
+            let Some(next) = current.checked_add_days(step) else {
+                break;
+            };
+            current = next;
+            if self.is_open(rule, workweek, current) {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    fn is_open(self, rule: ClosureRule, workweek: Workweek, date: Date) -> bool {
+        if self.custom == 0 {
+            return Calendar::new(self.countries, rule, workweek).is_business_day(date);
+        }
+        if !workweek.contains(date.weekday()) {
+            return false;
+        }
+        let closed = CalendarSet {
+            countries: crate::query::observing_countries(date) & self.countries,
+            custom: self
+                .custom_ids()
+                .filter(|it| Registered::get(*it).observes(date))
+                .fold(0, |bits, it| bits | 1 << it.0),
+        };
+        match rule {
+            ClosureRule::AnyClosed => closed.is_empty(),
+            ClosureRule::AllClosed => closed != self,
+        }
+    }
+
+    fn custom_ids(self) -> impl Iterator<Item = CustomCalendarId> {
+        self.iter().filter_map(|it| match it {
+            CalendarId::Custom(id) => Some(id),
+            CalendarId::Country(_) => None,
+        })
+    }
+}
+
+impl From<CountrySet> for CalendarSet {
+    fn from(value: CountrySet) -> Self {
+        CalendarSet {
+            countries: value,
+            custom: 0,
+        }
+    }
+}
+
+impl<T: Into<CalendarId>> FromIterator<T> for CalendarSet {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = CalendarSet::new();
+        for it in iter {
+            set.insert(it);
+        }
+        set
+    }
+}
+
+impl std::ops::BitOr for CalendarSet {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl std::ops::BitOrAssign for CalendarSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.countries |= rhs.countries;
+        self.custom |= rhs.custom;
+    }
+}
+
+impl std::ops::BitAnd for CalendarSet {
+    type Output = Self;
+
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl std::ops::BitAndAssign for CalendarSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.countries &= rhs.countries;
+        self.custom &= rhs.custom;
+    }
+}
+
+/// Iterator over holidays of a [`CalendarSet`], returned by
+/// [`CalendarSet::holidays`].
+///
+/// ```
+/// use holidays::{CalendarSet, Country, CountrySet};
+///
+/// // without custom calendars, results are those of the countries
+/// let calendars = CalendarSet::from(CountrySet::from_iter([Country::FR]));
+/// let holidays = calendars.holidays(2025..2026);
+/// assert_eq!(holidays.count(), holidays::get_holidays(Country::FR, 2025..2026).count());
+/// ```
+#[derive(Clone)]
+pub struct CalendarIter {
+    countries: std::iter::Peekable<crate::Iter>,
+    custom: std::iter::Peekable<std::vec::IntoIter<CalendarHoliday>>,
+}
+
+impl Iterator for CalendarIter {
+    type Item = CalendarHoliday;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // countries go first on the same date
+        let country_first = match (self.countries.peek(), self.custom.peek()) {
+            (Some(country), Some(custom)) => country.date <= custom.date,
+            (country, _) => country.is_some(),
+        };
+        if !country_first {
+            return self.custom.next();
+        }
+        self.countries.next().map(|it| CalendarHoliday {
+            id: CalendarId::Country(it.code),
+            date: it.date,
+            name: it.name,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.countries.size_hint();
+        let custom = self.custom.len();
+        (lower + custom, upper.map(|it| it + custom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn company() -> CustomCalendarId {
+        CustomCalendar::new("ACME")
+            .with_entry(Date::from_ymd(2025, 12, 26), "Winter Break")
+            .with_entry(Date::from_ymd(2025, 12, 24), "Christmas Eve")
+            .with_entry(Date::from_ymd(2025, 12, 26), "Inventory")
+            .with_entry(Date::from_ymd(2025, 7, 4), "Picnic")
+            .register()
+            .unwrap()
+    }
+
+    #[test]
+    fn merged_ordering() {
+        let company = company();
+        let calendars = CalendarSet::from_iter([CalendarId::Country(Country::US), company.into()]);
+        let december = Date::from_ymd(2025, 12, 1)..Date::from_ymd(2026, 1, 1);
+        let holidays: Vec<_> = calendars
+            .holidays(december.clone())
+            .map(|it| (it.date.day(), it.id, it.name))
+            .collect();
+        // names of embedded records are empty with `no-names`
+        let christmas = if cfg!(feature = "no-names") {
+            ""
+        } else {
+            "Christmas Day"
+        };
+        assert_eq!(
+            holidays,
+            [
+                (24, CalendarId::Custom(company), "Christmas Eve"),
+                (25, CalendarId::Country(Country::US), christmas),
+                (26, CalendarId::Custom(company), "Inventory"),
+                (26, CalendarId::Custom(company), "Winter Break"),
+            ]
+        );
+
+        // same date as a country holiday
+        let july = calendars.holidays(Date::from_ymd(2025, 7, 4));
+        let ids: Vec<_> = july.map(|it| it.id).collect();
+        assert_eq!(
+            ids,
+            [
+                CalendarId::Country(Country::US),
+                CalendarId::Custom(company)
+            ]
+        );
+
+        // without custom calendars, results match country queries
+        let countries = CalendarSet::from(CountrySet::from_iter([Country::US, Country::GB]));
+        let expected = crate::get_holidays([Country::US, Country::GB], 2025..2027);
+        assert!(countries
+            .holidays(2025..2027)
+            .map(|it| (it.id, it.date, it.name))
+            .eq(expected.map(|it| (CalendarId::Country(it.code), it.date, it.name))));
+
+        let all: Vec<_> = calendars.holidays::<Date, _>(crate::Any).collect();
+        assert!(all.windows(2).all(|it| it[0].date <= it[1].date));
+        let us = crate::get_holidays::<_, Date, _>(Country::US, crate::Any).count();
+        assert_eq!(all.len(), us + 4);
+        let holidays = calendars.holidays(2025..2027);
+        assert_eq!(holidays.size_hint().1, Some(holidays.count()));
+    }
+
+    #[test]
+    fn set_operations() {
+        let company = company();
+        let other = CustomCalendar::new("Other").register().unwrap();
+        assert_eq!(company.name(), "ACME");
+        assert_eq!(CalendarId::Custom(other).to_string(), "Other");
+        assert_eq!(CalendarId::from(Country::DE).to_string(), "DE");
+
+        let a: CalendarSet = [
+            Country::US.into(),
+            Country::DE.into(),
+            CalendarId::Custom(company),
+        ]
+        .into_iter()
+        .collect();
+        let b: CalendarSet = [
+            Country::DE.into(),
+            CalendarId::Custom(company),
+            other.into(),
+        ]
+        .into_iter()
+        .collect();
+        let union = a | b;
+        assert_eq!(union.len(), 4);
+        assert!(union.contains(other) && union.contains(Country::US));
+        assert_eq!(
+            union.countries(),
+            CountrySet::from_iter([Country::US, Country::DE])
+        );
+
+        let common = a & b;
+        assert_eq!(
+            common.iter().collect::<Vec<_>>(),
+            [
+                CalendarId::Country(Country::DE),
+                CalendarId::Custom(company)
+            ]
+        );
+        assert!(!common.contains(Country::US) && !common.contains(other));
+        assert!((CalendarSet::from_iter([other]) & CalendarSet::from_iter([company])).is_empty());
+        assert!(CalendarSet::new().is_empty());
+    }
+
+    #[test]
+    fn custom_business_days() {
+        let company = company();
+        let us = CalendarSet::from_iter([Country::US]);
+        let both = CalendarSet::from_iter([CalendarId::Country(Country::US), company.into()]);
+        let only = CalendarSet::from_iter([company]);
+        let (start, end) = (Date::from_ymd(2025, 12, 22), Date::from_ymd(2026, 1, 5));
+        let any = ClosureRule::AnyClosed;
+
+        // without custom calendars, countries are queried as usual
+        assert_eq!(
+            us.business_days_between(any, Workweek::MON_FRI, start, end),
+            crate::business_days_between(Country::US, any, Workweek::MON_FRI, start, end)
+        );
+        // Christmas Eve and the Friday after Christmas are closures as well
+        assert_eq!(
+            both.business_days_between(any, Workweek::MON_FRI, start, end),
+            6
+        );
+        assert_eq!(
+            both.business_days_between(any, Workweek::MON_FRI, end, start),
+            -6
+        );
+        assert!(!both.is_business_day(any, Workweek::MON_FRI, Date::from_ymd(2025, 12, 24)));
+        assert!(only.is_business_day(any, Workweek::MON_FRI, Date::from_ymd(2025, 12, 25)));
+        // one of the calendars is open
+        let all = ClosureRule::AllClosed;
+        assert!(both.is_business_day(all, Workweek::MON_FRI, Date::from_ymd(2025, 12, 24)));
+        assert!(!both.is_business_day(all, Workweek::MON_FRI, Date::from_ymd(2025, 7, 4)));
+
+        assert_eq!(
+            both.add_business_days(any, Workweek::MON_FRI, Date::from_ymd(2025, 12, 23), 1),
+            Date::from_ymd(2025, 12, 29)
+        );
+        assert_eq!(
+            both.add_business_days(any, Workweek::MON_FRI, Date::from_ymd(2025, 12, 29), -1),
+            Date::from_ymd(2025, 12, 23)
+        );
+        assert_eq!(
+            us.add_business_days(any, Workweek::MON_FRI, Date::from_ymd(2025, 12, 23), 1),
+            crate::add_business_days(
+                Country::US,
+                any,
+                Workweek::MON_FRI,
+                Date::from_ymd(2025, 12, 23),
+                1
+            )
+        );
+    }
+}
@@ -1,11 +1,197 @@
-use std::hash::Hash;
-
-use crate::country::Country;
+use crate::country::{Country, CountrySet};
 use crate::date::Date;
 use crate::Holiday;
 
 include!(concat!(env!("OUT_DIR"), "/holiday_data.rs"));
 
+// hashing of generated `DATA_MAP` keys, shared with `build.rs`
+#[path = "../build/shared.rs"]
+mod shared;
+
+// Unsafe code and query results rely on invariants of generated tables, so
+// they're verified at compile time; a `build.rs` change that breaks them
+// fails the build instead of causing UB.
+const _: () = check_data_order();
+const _: () = check_year_jump_table();
+const _: () = check_country_jump_table();
+const _: () = check_name_language_overrides();
+const _: () = check_sort_key_tables();
+
+/// `DATA` is sorted by date, then by country and then by name, without
+/// duplicates. With `no-names`, same-day records of a country are merged by
+/// `build.rs`, so every record is still distinct.
+const fn check_data_order() {
+    let mut i = 1;
+    while i < DATA.len() {
+        let (prev, next) = (&DATA[i - 1], &DATA[i]);
+        let same_day = prev.date.0 == next.date.0 && prev.code as u16 == next.code as u16;
+        assert!(
+            prev.date.0 < next.date.0
+                || (prev.date.0 == next.date.0 && (prev.code as u16) < (next.code as u16))
+                || (same_day && names_ascending(prev.name, next.name)),
+            "DATA must be sorted by date, country and name"
+        );
+        i += 1;
+    }
+}
+
+/// Returns whether `a` is lexicographically less than `b`.
+const fn names_ascending(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// `YEAR_JUMP_TABLE` has an entry for every year in data range, pointing at
+/// the first record in or after that year.
+const fn check_year_jump_table() {
+    assert!(YEAR_JUMP_TABLE.len() as i64 == DATA_MAX_YEAR - DATA_MIN_YEAR + 1);
+    let mut i = 0;
+    while i < YEAR_JUMP_TABLE.len() {
+        let year = DATA_MIN_YEAR + i as i64;
+        let index = YEAR_JUMP_TABLE[i];
+        assert!(index <= DATA.len(), "YEAR_JUMP_TABLE index out of bounds");
+        assert!(index == DATA.len() || DATA[index].date.year() >= year);
+        assert!(index == 0 || DATA[index - 1].date.year() < year);
+        i += 1;
+    }
+}
+
+/// `COUNTRY_JUMP_TABLE` has an entry for every country, listing indices of
+/// its records in increasing order.
+const fn check_country_jump_table() {
+    assert!(COUNTRY_JUMP_TABLE.len() == Country::COUNT);
+    let mut country = 0;
+    while country < COUNTRY_JUMP_TABLE.len() {
+        let indices = COUNTRY_JUMP_TABLE[country];
+        let mut i = 0;
+        while i < indices.len() {
+            assert!(
+                indices[i] < DATA.len(),
+                "COUNTRY_JUMP_TABLE index out of bounds"
+            );
+            assert!(DATA[indices[i]].code as usize == country);
+            assert!(i == 0 || indices[i - 1] < indices[i]);
+            i += 1;
+        }
+        country += 1;
+    }
+}
+
+/// `NAME_LANGUAGE_OVERRIDES` lists distinct indices of records in increasing
+/// order.
+const fn check_name_language_overrides() {
+    let mut i = 0;
+    while i < NAME_LANGUAGE_OVERRIDES.len() {
+        let index = NAME_LANGUAGE_OVERRIDES[i].0;
+        assert!(
+            index < DATA.len(),
+            "NAME_LANGUAGE_OVERRIDES index out of bounds"
+        );
+        assert!(i == 0 || NAME_LANGUAGE_OVERRIDES[i - 1].0 < index);
+        i += 1;
+    }
+}
+
+/// `DAY_SEQUENCES` counts records of each date from `0`, and
+/// `STABLE_COUNTRY_IDS` follow country order, so sort keys increase along
+/// `DATA`.
+const fn check_sort_key_tables() {
+    assert!(DAY_SEQUENCES.len() == DATA.len());
+    let mut i = 0;
+    while i < DATA.len() {
+        let first_on_date = i == 0 || DATA[i - 1].date.0 != DATA[i].date.0;
+        let expected = if first_on_date {
+            0
+        } else {
+            DAY_SEQUENCES[i - 1] + 1
+        };
+        assert!(
+            DAY_SEQUENCES[i] == expected,
+            "DAY_SEQUENCES must count records of each date"
+        );
+        assert!(DAY_SEQUENCES[i] < 1 << 12);
+        i += 1;
+    }
+
+    assert!(STABLE_COUNTRY_IDS.len() == Country::COUNT);
+    let mut i = 0;
+    while i < STABLE_COUNTRY_IDS.len() {
+        assert!(STABLE_COUNTRY_IDS[i] < 1 << 12);
+        assert!(i == 0 || STABLE_COUNTRY_IDS[i - 1] < STABLE_COUNTRY_IDS[i]);
+        i += 1;
+    }
+}
+
+/// Returns indices of records of `country`, in ascending order.
+///
+/// `check_country_jump_table` guarantees an entry for every country, but a
+/// missing one would read as a country without records rather than panic.
+pub(crate) fn country_indices(country: Country) -> &'static [usize] {
+    COUNTRY_JUMP_TABLE
+        .get(country as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Returns indices of records of `country` within `range` of `DATA` indices.
+pub(crate) fn country_indices_within(
+    country: Country,
+    range: &std::ops::Range<usize>,
+) -> &'static [usize] {
+    let indices = country_indices(country);
+    let start = indices.partition_point(|it| *it < range.start);
+    let end = indices.partition_point(|it| *it < range.end);
+    &indices[start..end]
+}
+
+/// Returns `true` if the record `n` records ahead within a set of
+/// `countries` is found faster by [`nth_index_within`] than by walking the
+/// records before it.
+pub(crate) fn prefer_nth_search(countries: CountrySet, n: usize) -> bool {
+    // each step of the search costs a binary search per country
+    countries == CountrySet::all() || n >= countries.len().saturating_mul(64)
+}
+
+/// Returns the `DATA` index of the `n`th record (counting from zero) of
+/// `countries` within `range`, without visiting the records before it.
+pub(crate) fn nth_index_within(
+    countries: CountrySet,
+    range: &std::ops::Range<usize>,
+    n: usize,
+) -> Option<usize> {
+    if countries == CountrySet::all() {
+        return range.clone().nth(n);
+    }
+    let count_before = |end: usize| -> usize {
+        countries
+            .iter()
+            .map(|it| country_indices_within(it, &(range.start..end)).len())
+            .sum()
+    };
+    if count_before(range.end) <= n {
+        return None;
+    }
+    // the record is at the smallest index whose prefix of the range holds
+    // more than `n` records, and prefix counts only grow with the index
+    let (mut low, mut high) = (range.start, range.end - 1);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if count_before(mid + 1) > n {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Some(low)
+}
+
 pub(crate) fn year_to_index(year: i64) -> Option<usize> {
     if year < DATA_MIN_YEAR {
         return None;
@@ -15,36 +201,48 @@ pub(crate) fn year_to_index(year: i64) -> Option<usize> {
     }
     // SAFETY: Bounds explicitly handled by above returns. `year`s in
     // [DATA_MIN_YEAR, DATA_MAX_YEAR] range are ensured to be in YEAR_JUMP_TABLE
-    // by build.rs
+    // by `check_year_jump_table`
     unsafe {
         Some(*YEAR_JUMP_TABLE.get_unchecked((year - DATA_MIN_YEAR) as usize))
     }
 }
 
-pub(crate) fn date_to_index(date: Date) -> Option<usize> {
+/// Returns the index of the first record on or after `date`.
+///
+/// Dates before all data clamp to `0` and dates after all data to
+/// `DATA.len()`, so a range of indices between two dates never loses records
+/// because one of its ends is outside of data range.
+pub(crate) fn clamp_to_index(date: Date) -> usize {
     let y = date.year();
-    let start = year_to_index(y)?;
+    let Some(start) = year_to_index(y) else {
+        return if y < DATA_MIN_YEAR { 0 } else { DATA.len() };
+    };
     let end = year_to_index(y + 1).unwrap_or(DATA.len());
 
-    let index = DATA[start..end]
-        .binary_search_by(|entry| entry.date.cmp(&date))
-        .unwrap_or_else(|i| i);
+    // multiple records share the same date, so the first one must be found
+    start + DATA[start..end].partition_point(|entry| entry.date < date)
+}
 
-    let absolute_index = start + index;
+/// Returns indices of all records on `date`.
+pub(crate) fn date_to_indices(date: Date) -> std::ops::Range<usize> {
+    clamp_to_index(date)..clamp_to_index(Date(date.0 + 1))
+}
 
-    if absolute_index >= DATA.len() {
-        None
-    } else {
-        Some(absolute_index)
-    }
+/// Returns the index of `record` in `DATA`, or `None` if it isn't embedded.
+pub(crate) fn index_of(record: &Holiday) -> Option<usize> {
+    let range = DATA.as_ptr_range();
+    let record = record as *const Holiday;
+    range
+        .contains(&record)
+        .then(|| (record as usize - range.start as usize) / std::mem::size_of::<Holiday>())
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct Point(Country, Date);
 impl phf::PhfHash for Point {
     fn phf_hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        (self.0 as u16).hash(state);
-        (self.1 .0).hash(state);
+        // must match `FullSpec` of `build.rs`
+        shared::hash_point(self.0 as u16, self.1 .0, state);
     }
 }
 impl phf_shared::PhfBorrow<Point> for Point {
@@ -53,6 +251,190 @@ impl phf_shared::PhfBorrow<Point> for Point {
     }
 }
 
+/// Returns records whose normalized name equals normalized `name`, using
+/// generated name index.
+#[cfg(feature = "name-index")]
+pub(crate) fn holidays_with_name(name: &str) -> impl Iterator<Item = &'static Holiday> {
+    name_to_indices(&crate::normalize_name(name))
+        .iter()
+        .map(|it| &DATA[*it as usize])
+}
+
+/// Returns sorted indices of records whose normalized name is `normalized`,
+/// using generated name index.
+#[cfg(feature = "name-index")]
+pub(crate) fn name_to_indices(normalized: &str) -> &'static [u32] {
+    NAME_INDEX.get(normalized).copied().unwrap_or_default()
+}
+
+/// Returns records whose normalized name equals normalized `name`, by
+/// scanning all records.
+#[cfg(not(feature = "name-index"))]
+pub(crate) fn holidays_with_name(name: &str) -> impl Iterator<Item = &'static Holiday> + '_ {
+    scan_name(name)
+}
+
+#[cfg_attr(feature = "name-index", allow(dead_code))]
+pub(crate) fn scan_name(name: &str) -> impl Iterator<Item = &'static Holiday> + '_ {
+    use crate::name::{matches_normalized, Folding};
+
+    // all names are empty with `no-names` feature and nothing should match
+    let enabled = !cfg!(feature = "no-names");
+    let name = crate::normalize_name(name);
+    DATA.iter()
+        .filter(move |it| enabled && matches_normalized(it.name, &name, Folding::Ascii))
+}
+
 pub(crate) fn country_date_to_holiday(country: Country, date: Date) -> Option<&'static Holiday> {
-    DATA_MAP.get(&Point(country, date)).map(|i| &DATA[*i])
+    country_date_to_index(country, date).map(|i| &DATA[i])
+}
+
+/// Returns the language of the name of `DATA[index]` if it differs from the
+/// default language of its country.
+pub(crate) fn name_language_override(index: usize) -> Option<&'static str> {
+    NAME_LANGUAGE_OVERRIDES
+        .binary_search_by_key(&index, |it| it.0)
+        .ok()
+        .map(|it| NAME_LANGUAGE_OVERRIDES[it].1)
+}
+
+/// Returns the index of the record of `country` on `date` with the
+/// lexicographically smallest name, which is the first of its records on
+/// that date.
+pub(crate) fn country_date_to_index(country: Country, date: Date) -> Option<usize> {
+    DATA_MAP.get(&Point(country, date)).copied()
+}
+
+/// Returns indices of all records of `country` on `date`, which are adjacent
+/// in `DATA`.
+pub(crate) fn country_date_to_indices(country: Country, date: Date) -> std::ops::Range<usize> {
+    let Some(start) = country_date_to_index(country, date) else {
+        return 0..0;
+    };
+    let len = DATA[start..]
+        .iter()
+        .take_while(|it| it.code == country && it.date == date)
+        .count();
+    start..start + len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn country_indices_cover_all_records() {
+        let total: usize = crate::enabled_countries()
+            .iter()
+            .map(|it| country_indices(*it).len())
+            .sum();
+        assert_eq!(total, DATA.len());
+        let last = *crate::enabled_countries().last().unwrap();
+        assert!(country_indices(last)
+            .iter()
+            .all(|it| DATA[*it].code == last));
+    }
+
+    #[test]
+    fn data_map_is_consistent() {
+        let days = DATA
+            .windows(2)
+            .filter(|w| (w[0].code, w[0].date) != (w[1].code, w[1].date))
+            .count()
+            + 1;
+        assert_eq!(DATA_MAP.len(), days);
+        for (Point(country, date), index) in DATA_MAP.entries() {
+            let record = &DATA[*index];
+            assert_eq!((record.code, record.date), (*country, *date));
+            // the map points to the first record of a country on a date
+            assert!(
+                *index == 0 || (DATA[index - 1].code, DATA[index - 1].date) != (*country, *date)
+            );
+        }
+        let total: usize = COUNTRY_JUMP_TABLE.iter().map(|it| it.len()).sum();
+        assert_eq!(total, DATA.len());
+    }
+
+    #[test]
+    fn clamp_to_index_finds_first_record_of_a_date() {
+        for (index, record) in DATA.iter().enumerate() {
+            if index == 0 || DATA[index - 1].date != record.date {
+                assert_eq!(clamp_to_index(record.date), index, "{}", record.date);
+            }
+        }
+        let last = DATA.last().unwrap();
+        assert_eq!(last.date.year(), DATA_MAX_YEAR);
+        assert!(date_to_indices(last.date).contains(&(DATA.len() - 1)));
+    }
+
+    #[test]
+    fn every_record_is_found() {
+        // keys hashed differently by `build.rs` would make lookups miss
+        for (index, record) in DATA.iter().enumerate() {
+            let found = country_date_to_indices(record.code, record.date);
+            assert!(
+                found.contains(&index),
+                "{} {} not found",
+                record.code,
+                record.date
+            );
+        }
+    }
+
+    #[cfg(feature = "name-index")]
+    #[test]
+    fn name_index_is_consistent() {
+        for (name, indices) in NAME_INDEX.entries() {
+            assert!(indices.windows(2).all(|w| w[0] < w[1]));
+            for index in indices.iter() {
+                assert_eq!(crate::normalize_name(DATA[*index as usize].name), *name);
+            }
+        }
+        // names aren't indexed if they aren't embedded
+        let total: usize = NAME_INDEX.values().map(|it| it.len()).sum();
+        assert_eq!(
+            total,
+            if cfg!(feature = "no-names") {
+                0
+            } else {
+                DATA.len()
+            }
+        );
+    }
+
+    #[cfg(not(feature = "no-names"))]
+    #[test]
+    fn christmas_by_name() {
+        let christmas: Vec<_> = holidays_with_name("Christmas Day").collect();
+
+        assert!(christmas.iter().all(|it| it.name == "Christmas Day"));
+        assert!(christmas.windows(2).all(|w| w[0].date <= w[1].date));
+
+        let mut countries = crate::CountrySet::new();
+        countries.extend(christmas.iter().map(|it| it.code));
+        assert!(countries.len() > 10);
+        let first = christmas.first().unwrap().date.year();
+        let last = christmas.last().unwrap().date.year();
+        assert!(last - first > 10);
+
+        assert!(christmas.iter().copied().eq(scan_name("Christmas Day")));
+        assert_eq!(holidays_with_name("Not a Holiday").count(), 0);
+    }
+
+    #[cfg(not(feature = "no-names"))]
+    #[test]
+    fn normalized_name_search() {
+        let boxing: Vec<_> = holidays_with_name("boxing day").collect();
+        assert!(!boxing.is_empty());
+        assert!(boxing.iter().all(|it| it.name == "Boxing Day"));
+        assert!(boxing
+            .iter()
+            .copied()
+            .eq(holidays_with_name("  BOXING-DAY ")));
+        assert!(boxing.iter().copied().eq(scan_name("Boxing day")));
+
+        let ataturk = "commemoration of ataturk youth and sports day";
+        assert!(holidays_with_name(ataturk).all(|it| it.name.contains("Atatürk")));
+        assert!(holidays_with_name(ataturk).next().is_some());
+    }
 }
@@ -0,0 +1,131 @@
+//! Information about the embedded dataset as a whole.
+
+/// Attribution of the embedded holiday data, read from `ATTRIBUTION.toml`
+/// when the crate is built.
+///
+/// Displayed as `source (license), url`.
+///
+/// ```
+/// let attribution = holidays::dataset_info().attribution();
+/// let footer = format!("Holiday data: {attribution}");
+/// assert!(footer.contains(attribution.license));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Attribution {
+    /// Name of the upstream source of the data.
+    pub source: &'static str,
+    /// SPDX identifier of the license the data is distributed under.
+    pub license: &'static str,
+    /// Location of the upstream source.
+    pub url: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/attribution.rs"));
+include!(concat!(env!("OUT_DIR"), "/build_config.rs"));
+
+impl std::fmt::Display for Attribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}), {}", self.source, self.license, self.url)
+    }
+}
+
+/// Information about the embedded dataset, returned by
+/// [`dataset_info`](crate::dataset_info).
+///
+/// ```
+/// let info = holidays::dataset_info();
+/// assert_eq!(info, holidays::dataset_info());
+/// assert!(info.attribution().url.starts_with("https://"));
+/// assert_eq!(info.build_config(), holidays::build_config());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DatasetInfo {
+    attribution: Attribution,
+    build_config: &'static str,
+}
+
+impl DatasetInfo {
+    pub(crate) const EMBEDDED: DatasetInfo = DatasetInfo {
+        attribution: ATTRIBUTION,
+        build_config: BUILD_CONFIG,
+    };
+
+    /// Returns the attribution that has to accompany the data, e.g. in
+    /// "about" screens of applications that display holidays.
+    ///
+    /// ```
+    /// let attribution = holidays::dataset_info().attribution();
+    /// assert!(!attribution.source.is_empty());
+    /// ```
+    pub const fn attribution(&self) -> Attribution {
+        self.attribution
+    }
+
+    /// Returns the configuration the data was embedded with, see
+    /// [`build_config`](crate::build_config).
+    ///
+    /// ```
+    /// let config = holidays::dataset_info().build_config();
+    /// assert_eq!(config, holidays::build_config());
+    /// ```
+    pub const fn build_config(&self) -> &'static str {
+        self.build_config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn attribution_is_complete() {
+        let attribution = crate::dataset_info().attribution();
+        for field in [attribution.source, attribution.license, attribution.url] {
+            assert!(!field.trim().is_empty());
+        }
+        assert!(attribution.url.starts_with("https://"));
+        assert_eq!(
+            attribution.to_string(),
+            format!(
+                "{} ({}), {}",
+                attribution.source, attribution.license, attribution.url
+            )
+        );
+    }
+
+    #[test]
+    fn build_config_describes_data() {
+        let config = crate::build_config();
+        let value = |key: &str| {
+            config
+                .lines()
+                .find_map(|it| it.strip_prefix(key)?.strip_prefix('='))
+                .unwrap_or_else(|| panic!("missing {key} in build configuration"))
+        };
+
+        let countries: Vec<_> = value("countries").split(',').collect();
+        assert!(countries.contains(&crate::Country::US.as_ref()));
+        let enabled: Vec<_> = crate::enabled_countries()
+            .iter()
+            .map(|it| it.as_ref())
+            .collect();
+        assert_eq!(countries, enabled);
+
+        let data_years = format!(
+            "{}-{}",
+            crate::data::DATA_MIN_YEAR,
+            crate::data::DATA_MAX_YEAR
+        );
+        assert_eq!(value("data_years"), data_years);
+        let (first, last) = value("years").split_once('-').expect("malformed years");
+        assert!(first.parse::<i64>().unwrap() <= crate::data::DATA_MIN_YEAR);
+        assert!(last.parse::<i64>().unwrap() >= crate::data::DATA_MAX_YEAR);
+
+        let features: Vec<_> = value("features")
+            .split(',')
+            .filter(|it| !it.is_empty())
+            .collect();
+        assert_eq!(features.contains(&"no-names"), cfg!(feature = "no-names"));
+        assert_eq!(features.contains(&"serde"), cfg!(feature = "serde"));
+        // only whitelisted keys are reported
+        assert_eq!(config.lines().count(), 4);
+    }
+}
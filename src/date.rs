@@ -1,16 +1,47 @@
 use crate::query::selection::*;
 use std::time::Duration;
 
-/// Internal date representation.
+/// Calendar date in the proleptic Gregorian calendar, stored as the number
+/// of days since the UNIX epoch.
 ///
-/// This type is not part of public API and only serves as an intermediate
-/// representation of a date for this crate.
-///
-/// It can change at any time without affecting the semver and shouldn't be
-/// used outside the library.
+/// Queries accept and return it when no date library is used; with `chrono`
+/// or `time` features enabled their date types can be used instead.
 ///
 /// Smallest representable date: -25252734927764585-06-07
 /// Largest representable date:   25252734927766554-09-25
+///
+/// ```
+/// use holidays::{Country, Date, Weekday};
+///
+/// let date: Date = "2025-12-25".parse().unwrap();
+/// assert_eq!(date, Date::from_ymd(2025, 12, 25));
+/// assert_eq!(date.weekday(), Weekday::Thursday);
+/// assert!(holidays::is_holiday(Country::US, date));
+/// assert_eq!(date.to_string(), "2025-12-25");
+/// ```
+///
+/// # Overflow policy
+///
+/// Constructors that accept unvalidated input have checked variants
+/// ([`Date::from_ymd_opt`], [`Date::checked_from_year`],
+/// [`Date::checked_add_days`]) which return `None` for dates outside of
+/// [`Date::MIN`]`..=`[`Date::MAX`] range.
+///
+/// Differences between dates near opposite ends of that range don't fit
+/// their result types, so [`Date::signed_days_since`] and
+/// [`Date::duration_since`] saturate, and have checked variants
+/// ([`Date::checked_signed_days_since`], [`Date::checked_duration_since`])
+/// which return `None` instead.
+///
+/// Query bounds saturate instead: years and day offsets that would overflow
+/// are clamped to [`Date::MIN`] or [`Date::MAX`], which lie far outside of
+/// embedded data, so such queries never panic and return the same results as
+/// if the bounds were unlimited.
+///
+/// [`Date::from_ymd`] is meant for known dates and panics on invalid input in
+/// all builds, so no constructor produces a value outside of that range.
+/// Conversions between days and calendar dates rely on it, and only check it
+/// with debug assertions.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct Date(
@@ -30,21 +61,30 @@ impl Date {
 
     const UNIX_EPOCH_DAY: i64 = 719468;
 
+    /// Constructs a date from its calendar representation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if values don't represent a valid date within
+    /// [`Date::MIN_YEAR`]`..=`[`Date::MAX_YEAR`] range; use
+    /// [`Date::from_ymd_opt`] for unvalidated input.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let christmas = Date::from_ymd(2025, 12, 25);
+    /// assert_eq!(christmas.to_string(), "2025-12-25");
+    /// ```
     pub const fn from_ymd(year: i64, month: u8, day: u8) -> Self {
-        // Source: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
-
-        debug_assert!(month >= 1 && month <= 12, "month not in range [1, 12]");
-        debug_assert!(day >= 1 && day <= 31, "day not in range [1, 31]");
-        #[cfg(debug_assertions)]
-        match (year, month, day) {
-            (25252734927766554, 9, d) if d > 25 => panic!("date too large"),
-            (25252734927766554, m, _) if m > 9 => panic!("date too large"),
-            (y, ..) if y > 25252734927766554 => panic!("date too large"),
-            (-25252734927764585, 6, d) if d < 7 => panic!("date too small"),
-            (-25252734927764585, m, _) if m < 6 => panic!("date too small"),
-            (y, ..) if y < -25252734927764585 => panic!("date too small"),
-            _ => {}
+        match Self::from_ymd_opt(year, month, day) {
+            Some(it) => it,
+            None => panic!("invalid date"),
         }
+    }
+
+    /// Arithmetic of [`Date::from_ymd`]; callers must validate input.
+    const fn from_ymd_unchecked(year: i64, month: u8, day: u8) -> Self {
+        // Source: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
 
         let mut y = year;
         let m = month as i64;
@@ -54,6 +94,8 @@ impl Date {
             y -= 1
         }
 
+        // assertions below check invariants of the algorithm, which hold for
+        // all validated inputs
         let era = y.div_euclid(Self::ERA_YEARS as i64);
         let year_of_era = (y - era * Self::ERA_YEARS as i64) as u32;
         debug_assert!(year_of_era < Self::ERA_YEARS, "year_of_era >= ERA_YEARS");
@@ -63,22 +105,204 @@ impl Date {
 
         let day_of_era =
             year_of_era * Self::YEAR_DAYS + year_of_era / 4 - year_of_era / 100 + day_of_year;
-        debug_assert!(year_of_era < Self::ERA_YEARS, "year_of_era >= ERA_YEARS");
+        debug_assert!(day_of_era < Self::ERA_DAYS, "day_of_era >= ERA_DAYS");
 
         let days = era * Self::ERA_DAYS as i64 + (day_of_era as i64) - Self::UNIX_EPOCH_DAY;
 
         Self(days)
     }
 
+    /// Smallest year for which all days are representable.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::from_ymd_opt(Date::MIN_YEAR - 1, 12, 31), None);
+    /// ```
+    pub const MIN_YEAR: i64 = -25252734927764584;
+    /// Largest year for which all days are representable.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert!(Date::checked_from_year(Date::MAX_YEAR).is_some());
+    /// assert_eq!(Date::checked_from_year(Date::MAX_YEAR + 1), None);
+    /// ```
+    pub const MAX_YEAR: i64 = 25252734927766553;
+
+    /// First day of [`Date::MIN_YEAR`].
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::MIN.checked_add_days(-1), None);
+    /// ```
+    pub const MIN: Date = Date::from_ymd(Self::MIN_YEAR, 1, 1);
+    /// Last day of [`Date::MAX_YEAR`].
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::MAX.saturating_add_days(1), Date::MAX);
+    /// ```
+    pub const MAX: Date = Date::from_ymd(Self::MAX_YEAR, 12, 31);
+
+    /// Returns `true` if `year` is a leap year in proleptic Gregorian calendar.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert!(Date::is_leap_year(2024));
+    /// assert!(!Date::is_leap_year(1900));
+    /// assert!(Date::is_leap_year(2000));
+    /// ```
+    pub const fn is_leap_year(year: i64) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    /// Returns number of days in `month` of `year`.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::days_in_month(2025, 2), 28);
+    /// assert_eq!(Date::days_in_month(2028, 2), 29);
+    /// assert_eq!(Date::days_in_month(2025, 12), 31);
+    /// ```
+    pub const fn days_in_month(year: i64, month: u8) -> u8 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Like [`Date::from_ymd`], but returns `None` if provided values don't
+    /// represent a valid date.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert!(Date::from_ymd_opt(2028, 2, 29).is_some());
+    /// assert_eq!(Date::from_ymd_opt(2025, 2, 29), None);
+    /// assert_eq!(Date::from_ymd_opt(2025, 13, 1), None);
+    /// ```
+    pub const fn from_ymd_opt(year: i64, month: u8, day: u8) -> Option<Self> {
+        if year < Self::MIN_YEAR || year > Self::MAX_YEAR {
+            return None;
+        }
+        if month < 1 || month > 12 || day < 1 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Self::from_ymd_unchecked(year, month, day))
+    }
+
+    /// Returns the first day of `year`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year` is outside of [`Date::MIN_YEAR`]`..=`[`Date::MAX_YEAR`]
+    /// range; use [`Date::checked_from_year`] for unvalidated input.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::from_year(2025), Date::from_ymd(2025, 1, 1));
+    /// ```
     #[inline]
     pub const fn from_year(year: i64) -> Self {
         Self::from_ymd(year, 1, 1)
     }
 
+    /// Returns the first day of `year`, or `None` if `year` is outside of
+    /// [`Date::MIN_YEAR`]`..=`[`Date::MAX_YEAR`] range.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::checked_from_year(2025), Some(Date::from_ymd(2025, 1, 1)));
+    /// assert_eq!(Date::checked_from_year(i64::MAX), None);
+    /// ```
+    #[inline]
+    pub const fn checked_from_year(year: i64) -> Option<Self> {
+        Self::from_ymd_opt(year, 1, 1)
+    }
+
+    /// Returns the first day of `year`, clamped to [`Date::MIN`]`..=`[`Date::MAX`]
+    /// range.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::saturating_from_year(2025), Date::from_ymd(2025, 1, 1));
+    /// assert_eq!(Date::saturating_from_year(i64::MIN), Date::MIN);
+    /// assert_eq!(Date::saturating_from_year(i64::MAX), Date::MAX);
+    /// ```
+    pub const fn saturating_from_year(year: i64) -> Self {
+        if year < Self::MIN_YEAR {
+            Self::MIN
+        } else if year > Self::MAX_YEAR {
+            Self::MAX
+        } else {
+            Self::from_year(year)
+        }
+    }
+
+    /// Returns the date `days` after this one, or `None` if it's outside of
+    /// [`Date::MIN`]`..=`[`Date::MAX`] range.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let christmas = Date::from_ymd(2025, 12, 25);
+    /// assert_eq!(christmas.checked_add_days(7), Some(Date::from_ymd(2026, 1, 1)));
+    /// assert_eq!(Date::MAX.checked_add_days(1), None);
+    /// ```
+    pub const fn checked_add_days(self, days: i64) -> Option<Self> {
+        match self.0.checked_add(days) {
+            Some(it) if it >= Self::MIN.0 && it <= Self::MAX.0 => Some(Date(it)),
+            _ => None,
+        }
+    }
+
+    /// Returns the date `days` after this one, clamped to
+    /// [`Date::MIN`]`..=`[`Date::MAX`] range.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let christmas = Date::from_ymd(2025, 12, 25);
+    /// assert_eq!(christmas.saturating_add_days(-1), Date::from_ymd(2025, 12, 24));
+    /// assert_eq!(Date::MIN.saturating_add_days(-1), Date::MIN);
+    /// ```
+    pub const fn saturating_add_days(self, days: i64) -> Self {
+        match self.0.checked_add(days) {
+            Some(it) if it < Self::MIN.0 => Self::MIN,
+            Some(it) if it > Self::MAX.0 => Self::MAX,
+            Some(it) => Date(it),
+            None if days < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns the year, the month and the day of the month.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let (year, month, day) = Date::from_ymd(2025, 7, 4).ymd();
+    /// assert_eq!((year, month, day), (2025, 7, 4));
+    /// ```
     pub const fn ymd(&self) -> (i64, u8, u8) {
         // Source: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
 
-        debug_assert!(self.0 < i64::MAX - Self::UNIX_EPOCH_DAY, "date too large");
+        // constructors keep dates within range, so the addition can't
+        // overflow; assertions below check invariants of the algorithm
+        debug_assert!(
+            self.0 >= Self::MIN.0 && self.0 <= Self::MAX.0,
+            "date out of range"
+        );
         let julian_days = self.0 + Self::UNIX_EPOCH_DAY;
 
         let era = julian_days.div_euclid(Self::ERA_DAYS as i64);
@@ -119,23 +343,139 @@ impl Date {
     }
 
     /// Day of the month
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2025, 7, 4).day(), 4);
+    /// ```
     #[inline]
     pub const fn day(&self) -> u8 {
         self.ymd().2
     }
 
     /// Month of the year
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2025, 7, 4).month(), 7);
+    /// ```
     #[inline]
     pub const fn month(&self) -> u8 {
         self.ymd().1
     }
 
     /// Year
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2025, 7, 4).year(), 2025);
+    /// ```
     #[inline]
     pub const fn year(&self) -> i64 {
         self.ymd().0
     }
 
+    /// Current date in UTC
+    ///
+    /// ```
+    /// use holidays::{Country, Date};
+    ///
+    /// let today = Date::today();
+    /// let upcoming = holidays::get_holidays(Country::US, today..today.saturating_add_days(30));
+    /// for holiday in upcoming {
+    ///     println!("{holiday:?}");
+    /// }
+    /// ```
+    pub fn today() -> Self {
+        std::time::SystemTime::now().into()
+    }
+
+    /// Day of the week
+    ///
+    /// ```
+    /// use holidays::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::from_ymd(2025, 7, 4).weekday(), Weekday::Friday);
+    /// ```
+    pub const fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        match self.0.rem_euclid(7) {
+            0 => Weekday::Thursday,
+            1 => Weekday::Friday,
+            2 => Weekday::Saturday,
+            3 => Weekday::Sunday,
+            4 => Weekday::Monday,
+            5 => Weekday::Tuesday,
+            _ => Weekday::Wednesday,
+        }
+    }
+
+    /// Returns the number of days from `other` to this date, negative if
+    /// `other` is later.
+    ///
+    /// Saturates to [`i64::MIN`] or [`i64::MAX`] if the difference isn't
+    /// representable, which only happens for dates more than half of the
+    /// range apart; see [`Date::checked_signed_days_since`].
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let christmas = Date::from_ymd(2025, 12, 25);
+    /// let new_year = Date::from_ymd(2026, 1, 1);
+    /// assert_eq!(new_year.signed_days_since(&christmas), 7);
+    /// assert_eq!(christmas.signed_days_since(&new_year), -7);
+    /// assert_eq!(christmas.abs_days_between(&new_year), 7);
+    /// ```
+    pub const fn signed_days_since(&self, other: &Self) -> i64 {
+        self.0.saturating_sub(other.0)
+    }
+
+    /// Returns the number of days from `other` to this date, negative if
+    /// `other` is later, or `None` if the difference isn't representable.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let christmas = Date::from_ymd(2025, 12, 25);
+    /// assert_eq!(christmas.checked_signed_days_since(&Date::from_ymd(2025, 12, 1)), Some(24));
+    /// assert_eq!(Date::MAX.checked_signed_days_since(&Date::MIN), None);
+    /// ```
+    pub const fn checked_signed_days_since(&self, other: &Self) -> Option<i64> {
+        self.0.checked_sub(other.0)
+    }
+
+    /// Returns the number of days between this date and `other`, regardless
+    /// of which one is later.
+    ///
+    /// Differences of all representable dates fit, so this never saturates.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let (a, b) = (Date::from_ymd(2025, 1, 1), Date::from_ymd(2026, 1, 1));
+    /// assert_eq!(a.abs_days_between(&b), 365);
+    /// assert_eq!(b.abs_days_between(&a), 365);
+    /// ```
+    pub const fn abs_days_between(&self, other: &Self) -> u64 {
+        self.0.abs_diff(other.0)
+    }
+
+    /// Returns the number of days since `other` in `Ok`, or until `other` in
+    /// `Err` if `other` is later; equal dates are `Err(0)`.
+    ///
+    /// ```
+    /// use holidays::Date;
+    ///
+    /// let (a, b) = (Date::from_ymd(2025, 1, 1), Date::from_ymd(2025, 1, 11));
+    /// # #[allow(deprecated)]
+    /// assert_eq!(b.days_since(&a), Ok(10));
+    /// # #[allow(deprecated)]
+    /// assert_eq!(a.days_since(&b), Err(10));
+    /// ```
+    #[deprecated(note = "use `signed_days_since` or `abs_days_between`")]
     pub const fn days_since(&self, other: &Self) -> Result<usize, usize> {
         if self.0 > other.0 {
             Ok((self.0 - other.0) as usize)
@@ -144,26 +484,143 @@ impl Date {
         }
     }
 
+    /// Returns the time elapsed from midnight of `other` to midnight of this
+    /// date in `Ok`, or the time until `other` in `Err` if `other` is later.
+    ///
+    /// Like [`SystemTime::duration_since`](std::time::SystemTime::duration_since),
+    /// equal dates are `Ok(Duration::ZERO)`. Saturates to [`Duration::MAX`]
+    /// for dates more than ~584 billion years apart; see
+    /// [`Date::checked_duration_since`].
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use holidays::Date;
+    ///
+    /// let (a, b) = (Date::from_ymd(2025, 1, 1), Date::from_ymd(2025, 1, 2));
+    /// assert_eq!(b.duration_since(&a), Ok(Duration::from_secs(86_400)));
+    /// assert_eq!(a.duration_since(&b), Err(Duration::from_secs(86_400)));
+    /// ```
     pub const fn duration_since(&self, other: &Self) -> Result<Duration, Duration> {
-        if self.0 > other.0 {
-            Ok(Duration::from_secs(
-                SECONDS_IN_DAY as u64 * (self.0 - other.0) as u64,
-            ))
+        let duration = match Self::days_duration(self.abs_days_between(other)) {
+            Some(it) => it,
+            None => Duration::MAX,
+        };
+        if self.0 >= other.0 {
+            Ok(duration)
         } else {
-            Err(Duration::from_secs(
-                SECONDS_IN_DAY as u64 * (other.0 - self.0) as u64,
-            ))
+            Err(duration)
+        }
+    }
+
+    /// Returns the time elapsed from midnight of `other` to midnight of this
+    /// date, or `None` if `other` is later or the duration isn't
+    /// representable.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use holidays::Date;
+    ///
+    /// let (a, b) = (Date::from_ymd(2025, 1, 1), Date::from_ymd(2025, 1, 2));
+    /// assert_eq!(b.checked_duration_since(&a), Some(Duration::from_secs(86_400)));
+    /// assert_eq!(a.checked_duration_since(&b), None);
+    /// ```
+    pub const fn checked_duration_since(&self, other: &Self) -> Option<Duration> {
+        if self.0 < other.0 {
+            return None;
+        }
+        Self::days_duration(self.abs_days_between(other))
+    }
+
+    const fn days_duration(days: u64) -> Option<Duration> {
+        match days.checked_mul(SECONDS_IN_DAY as u64) {
+            Some(secs) => Some(Duration::from_secs(secs)),
+            None => None,
         }
     }
 }
 
 /// An `i64` value is treated like a year.
+///
+/// Years outside of representable range saturate to [`Date::MIN`] or
+/// [`Date::MAX`].
 impl From<i64> for Date {
     fn from(value: i64) -> Self {
-        Date::from_year(value)
+        Date::saturating_from_year(value)
+    }
+}
+
+/// Parses a date in ISO 8601 `YYYY-MM-DD` format.
+///
+/// Years must have at least four digits and can be prefixed by a sign for
+/// dates outside of `0000-9999` range.
+impl std::str::FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn digits(s: &str) -> bool {
+            !s.is_empty() && s.bytes().all(|it| it.is_ascii_digit())
+        }
+
+        let (negative, rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let mut parts = rest.splitn(3, '-');
+        let (year, month, day) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(y), Some(m), Some(d)) => (y, m, d),
+            _ => return Err(ParseDateError),
+        };
+        if year.len() < 4 || month.len() != 2 || day.len() != 2 {
+            return Err(ParseDateError);
+        }
+        if !digits(year) || !digits(month) || !digits(day) {
+            return Err(ParseDateError);
+        }
+
+        let year: i64 = year.parse().map_err(|_| ParseDateError)?;
+        let year = if negative { -year } else { year };
+        let month = month.parse().map_err(|_| ParseDateError)?;
+        let day = day.parse().map_err(|_| ParseDateError)?;
+
+        Date::from_ymd_opt(year, month, day).ok_or(ParseDateError)
     }
 }
 
+macro_rules! default_date {
+    ($(#[cfg($cfg: meta)] $ty: ty),*) => {$(
+        /// Date type returned by [`Holiday::date_default`](crate::Holiday::date_default).
+        ///
+        /// It's picked from enabled features, in order of precedence:
+        ///
+        /// - `time::Date` with the `time` feature,
+        /// - `chrono::NaiveDate` with the `chrono` feature,
+        /// - [`SystemTime`](std::time::SystemTime) at midnight UTC otherwise.
+        ///
+        /// Enabling a feature can change this type, so libraries should name the
+        /// type they need with [`Holiday::date`](crate::Holiday::date) instead.
+        ///
+        /// ```
+        /// use holidays::{Country, DefaultDate};
+        ///
+        /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+        /// let date: DefaultDate = holiday.date_default().unwrap();
+        /// assert_eq!(holiday.date::<DefaultDate>(), Ok(date));
+        /// ```
+        #[cfg($cfg)]
+        pub type DefaultDate = $ty;
+    )*};
+}
+default_date!(
+    #[cfg(feature = "time")]
+    time::Date,
+    #[cfg(all(feature = "chrono", not(feature = "time")))]
+    chrono::NaiveDate,
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    std::time::SystemTime
+);
+
 const SECONDS_IN_DAY: i64 = 86400;
 
 impl TryFrom<Date> for std::time::SystemTime {
@@ -198,152 +655,1295 @@ impl TryFrom<Date> for chrono::NaiveDate {
     type Error = DateConversionError;
 
     fn try_from(value: Date) -> Result<Self, Self::Error> {
-        if value.0 > i32::MAX as isize - 719163 {
-            return Err(DateConversionError);
-        }
-        chrono::NaiveDate::from_num_days_from_ce_opt(value.0 as i32 + 719163)
-            .ok_or(DateConversionError)
+        // 1970-01-01 is 719163th day of common era
+        let days = value
+            .0
+            .checked_add(719163)
+            .and_then(|it| i32::try_from(it).ok())
+            .ok_or(DateConversionError)?;
+        chrono::NaiveDate::from_num_days_from_ce_opt(days).ok_or(DateConversionError)
     }
 }
 #[cfg(feature = "chrono")]
 impl From<chrono::NaiveDate> for Date {
     fn from(value: chrono::NaiveDate) -> Self {
-        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-        let days = value.signed_duration_since(epoch).num_days();
-        Date(days as isize)
+        use chrono::Datelike;
+        Date(value.num_days_from_ce() as i64 - 719163)
+    }
+}
+/// Date is taken in UTC.
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Date {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        value.date_naive().into()
     }
 }
+/// Date is taken in local time zone.
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Local>> for Date {
+    fn from(value: chrono::DateTime<chrono::Local>) -> Self {
+        value.date_naive().into()
+    }
+}
+
+/// Julian day number of 1970-01-01.
+#[cfg(feature = "time")]
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2440588;
 
 #[cfg(feature = "time")]
 impl TryFrom<Date> for time::Date {
     type Error = DateConversionError;
 
     fn try_from(value: Date) -> Result<Self, Self::Error> {
-        if value.0.abs() > i64::MAX as isize {
-            return Err(DateConversionError);
-        }
-
-        // `Date` days since 1970-01-01; time::Date::from_julian_day starts from -4713-11-24
-        let epoch = time::Date::from_calendar_date(1970, time::Month::January, 1)
-            .map_err(|_| DateConversionError)?;
-        Ok(epoch.saturating_add(time::Duration::days(value.0 as i64)))
+        let julian_day = value
+            .0
+            .checked_add(UNIX_EPOCH_JULIAN_DAY)
+            .and_then(|it| i32::try_from(it).ok())
+            .ok_or(DateConversionError)?;
+        time::Date::from_julian_day(julian_day).map_err(|_| DateConversionError)
     }
 }
 #[cfg(feature = "time")]
 impl From<time::Date> for Date {
     fn from(value: time::Date) -> Self {
-        let epoch = time::Date::from_calendar_date(1970, time::Month::January, 1).unwrap();
-        let days = (value - epoch).whole_days();
-        Date(days as isize)
+        Date(value.to_julian_day() as i64 - UNIX_EPOCH_JULIAN_DAY)
     }
 }
-
-impl std::fmt::Debug for Date {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (y, m, d) = self.ymd();
-        write!(f, "Date({y:04}-{m:02}-{d:02})")
+/// Date is taken in the offset of the value, not UTC; convert the value with
+/// [`time::OffsetDateTime::to_utc`] first to get the UTC date.
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Date {
+    fn from(value: time::OffsetDateTime) -> Self {
+        value.date().into()
     }
 }
-
-/// Utility functions that extend all supported date types and provide methods
-/// on them to directly query holiday information.
-pub trait DateExt<DateLike, DateRange = std::ops::Range<DateLike>>:
-    Into<DateSelection<DateLike, DateRange>> + Clone
-where
-    DateLike: Into<Date> + Clone,
-    DateRange: std::ops::RangeBounds<DateLike>,
-{
-    // Most date(time) types are trivial to Clone
-
-    /// Returns an iterator of holidays that are observed on this date (range)
-    /// in specified `countries`.
-    ///
-    /// This is an alias for [`get_holidays`] method, see that method for more
-    /// details.
-    ///
-    /// [`get_holidays`]: crate::get_holidays
-    fn holidays<CountryIter>(
-        &self,
-        countries: impl Into<CountrySelection<CountryIter>>,
-    ) -> crate::Iter
-    where
-        CountryIter: IntoIterator,
-        CountryIter::Item: Into<crate::Country>,
-    {
-        crate::get_holidays(countries, self.clone())
+/// Time of day is ignored.
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for Date {
+    fn from(value: time::PrimitiveDateTime) -> Self {
+        value.date().into()
+    }
+}
+/// Date is taken in UTC.
+#[cfg(feature = "time")]
+impl From<time::UtcDateTime> for Date {
+    fn from(value: time::UtcDateTime) -> Self {
+        value.date().into()
     }
+}
+/// Produces midnight (UTC) at the start of the date.
+#[cfg(feature = "time")]
+impl TryFrom<Date> for time::UtcDateTime {
+    type Error = DateConversionError;
 
-    /// Returns `true` if any holidays are observed on this date (range) in
-    /// specified `countries`.
-    ///
-    /// This is an alias for [`is_holiday`] method, see that method for more
-    /// details.
-    ///
-    /// [`is_holiday`]: crate::is_holiday
-    fn is_holiday<CountryIter>(&self, countries: impl Into<CountrySelection<CountryIter>>) -> bool
-    where
-        CountryIter: IntoIterator,
-        CountryIter::Item: Into<crate::Country>,
-    {
-        crate::is_holiday(countries, self.clone())
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        let date = time::Date::try_from(value)?;
+        Ok(time::UtcDateTime::new(date, time::Time::MIDNIGHT))
     }
 }
 
-macro_rules! impl_ext_for_t {
-    (if $guard: literal $($param: tt)*) => {
-        #[cfg(feature = $guard)]
-        impl DateExt<$($param)*> for $($param)* {}
-        #[cfg(feature = $guard)]
-        impl<R> DateExt<$($param)*, R> for R where
-            R: std::ops::RangeBounds<$($param)*> + Clone {}
-    };
-    ($($param: tt)*) => {
-        impl DateExt<$($param)*> for $($param)* {}
-        impl<R> DateExt<$($param)*, R> for R where
-            R: std::ops::RangeBounds<$($param)*> + Clone {}
-    };
+/// Handling of dates that can't be converted into a requested date type.
+///
+/// Accepted by [`Iter::dates_with`](crate::Iter::dates_with) and
+/// [`ExportOptions`](crate::export::ExportOptions).
+///
+/// ```
+/// use holidays::{Country, OnConversionError};
+///
+/// // years outside of the embedded data are covered by every date type, so
+/// // nothing is skipped here
+/// let dates: Vec<_> = holidays::get_holidays(Country::US, 2025..2026)
+///     .dates_with::<std::time::SystemTime>(OnConversionError::Skip)
+///     .collect();
+/// assert_eq!(dates.len(), holidays::get_holidays(Country::US, 2025..2026).count());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OnConversionError {
+    /// Leaves out records with such dates.
+    Skip,
+    /// Reports a [`DateConversionError`].
+    #[default]
+    Fail,
+    /// Replaces such dates with the nearest date the type can represent.
+    ClampToBounds,
 }
-impl_ext_for_t!(std::time::SystemTime);
-impl_ext_for_t!(if "chrono" chrono::NaiveDate);
-impl_ext_for_t!(if "chrono" chrono::DateTime<chrono::Utc>);
-impl_ext_for_t!(if "chrono" chrono::DateTime<chrono::Local>);
-impl_ext_for_t!(if "time" time::Date);
-impl_ext_for_t!(if "time" time::OffsetDateTime);
-impl_ext_for_t!(if "time" time::PrimitiveDateTime);
 
-/// Error returned when conversion to/from another date format can't be
-/// performed because one has larger span than the other and conversion would
-/// cause an overflow.
+impl OnConversionError {
+    /// Converts `date` into `D` according to the policy, returning `None` if
+    /// it's skipped.
+    pub(crate) fn convert<D: DateBounds>(
+        self,
+        date: Date,
+    ) -> Option<Result<D, DateConversionError>> {
+        let date = match self {
+            OnConversionError::ClampToBounds => {
+                let (min, max) = D::bounds();
+                date.clamp(min, max)
+            }
+            _ => date,
+        };
+        match D::try_from(date) {
+            Ok(it) => Some(Ok(it)),
+            Err(_) if self == OnConversionError::Skip => None,
+            Err(_) => Some(Err(DateConversionError)),
+        }
+    }
+}
+
+/// Date types with a known range of dates they can be converted from, used
+/// by [`OnConversionError::ClampToBounds`].
 ///
-/// In most practical use cases this won't happen because dates that are stored
-/// in holidays table can be reasonably converted to most time libraries.
-#[derive(Debug, PartialEq, Eq)]
-pub struct DateConversionError;
-crate::error::error_msg!(DateConversionError, "Date is too large for conversion");
+/// ```
+/// use std::time::SystemTime;
+/// use holidays::{Date, DateBounds};
+///
+/// let (first, last) = <SystemTime as DateBounds>::bounds();
+/// assert!(first < Date::from_ymd(1970, 1, 1) && Date::from_ymd(2100, 1, 1) < last);
+/// assert!(SystemTime::try_from(last).is_ok());
+/// ```
+pub trait DateBounds: TryFrom<Date> {
+    /// Returns the first and the last date that can be converted.
+    fn bounds() -> (Date, Date);
+}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::Country;
-    use std::{hint::black_box, time::SystemTime};
+impl DateBounds for Date {
+    fn bounds() -> (Date, Date) {
+        (Date::MIN, Date::MAX)
+    }
+}
 
-    fn round_trip(y: i64, m: u8, d: u8) {
-        let date = black_box(Date::from_ymd(y, m, d));
-        let (y_out, m_out, d_out) = date.ymd();
-        assert_eq!(y, y_out);
-        assert_eq!(m, m_out);
-        assert_eq!(d, d_out);
+impl DateBounds for CivilDate {
+    fn bounds() -> (Date, Date) {
+        (Date::MIN, Date::MAX)
     }
+}
 
-    #[test]
-    fn ymd_to_days() {
-        // test ymd is inverse of from_ymd
-        round_trip(2025, 5, 20);
-        round_trip(4423, 1, 18);
-        round_trip(-2344, 2, 6);
+impl DateBounds for DisplayDate {
+    fn bounds() -> (Date, Date) {
+        (Date::MIN, Date::MAX)
+    }
+}
 
-        // days are computed using: https://aa.usno.navy.mil/data/JulianDate
-        // with 2440588 offset from their
+impl DateBounds for std::time::SystemTime {
+    fn bounds() -> (Date, Date) {
+        // seconds since the epoch are stored as `i64`
+        (
+            Date(i64::MIN / SECONDS_IN_DAY),
+            Date(i64::MAX / SECONDS_IN_DAY),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateBounds for chrono::NaiveDate {
+    fn bounds() -> (Date, Date) {
+        (chrono::NaiveDate::MIN.into(), chrono::NaiveDate::MAX.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateBounds for time::Date {
+    fn bounds() -> (Date, Date) {
+        (time::Date::MIN.into(), time::Date::MAX.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateBounds for time::UtcDateTime {
+    fn bounds() -> (Date, Date) {
+        time::Date::bounds()
+    }
+}
+
+impl Date {
+    /// Writes the date in ISO 8601 `YYYY-MM-DD` format without allocating.
+    ///
+    /// Years are zero-padded to 4 digits. Years before 0 are prefixed with
+    /// `-` and years after 9999 with `+`, which is the same format accepted
+    /// by [`FromStr`](std::str::FromStr).
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// let mut out = String::new();
+    /// Date::from_ymd(2025, 1, 1).format_iso(&mut out).unwrap();
+    /// assert_eq!(out, "2025-01-01");
+    /// ```
+    pub fn format_iso<W: std::fmt::Write + ?Sized>(&self, out: &mut W) -> std::fmt::Result {
+        let (y, m, d) = self.ymd();
+        if y < 0 {
+            write!(out, "-{:04}", y.unsigned_abs())?;
+        } else if y > 9999 {
+            write!(out, "+{y}")?;
+        } else {
+            write!(out, "{y:04}")?;
+        }
+        write!(out, "-{m:02}-{d:02}")
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_iso(f)
+    }
+}
+
+impl std::fmt::Debug for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Date({self})")
+    }
+}
+
+/// Adapter that displays a date in ISO 8601 `YYYY-MM-DD` format.
+///
+/// Returned by [`Holiday::display_date`](crate::Holiday::display_date); see
+/// [`Date::format_iso`] for details on formatting.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let holiday = holidays::get_holidays(Country::US, Date::from_ymd(2025, 7, 4)).next().unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(format!("{}: {}", holiday.display_date(), holiday.name), "2025-07-04: Independence Day");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayDate(pub(crate) Date);
+
+impl From<Date> for DisplayDate {
+    fn from(value: Date) -> Self {
+        DisplayDate(value)
+    }
+}
+
+impl std::fmt::Display for DisplayDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.format_iso(f)
+    }
+}
+
+/// Date formatted in ISO 8601 `YYYY-MM-DD` format, stored inline.
+///
+/// Unlike [`DisplayDate`], the date is formatted once when the buffer is
+/// created, so it can be borrowed as a `&str` or written out repeatedly
+/// without going through [`std::fmt`] or allocating. Returned by
+/// [`Holiday::iso_date_buf`](crate::Holiday::iso_date_buf) and
+/// [`Iter::iso_dates`](crate::Iter::iso_dates); see [`Date::format_iso`] for
+/// details on formatting.
+///
+/// ```
+/// use std::io::Write;
+/// use holidays::{Country, IsoDateBuf};
+/// # use holidays::Date;
+///
+/// let mut out = Vec::new();
+/// for date in holidays::get_holidays(Country::US, 2025..2026).iso_dates() {
+///     out.extend_from_slice(date.as_bytes());
+///     out.push(b'\n');
+/// }
+/// assert!(out.starts_with(b"2025-01-01\n2025-01-20\n"));
+/// assert_eq!(IsoDateBuf::from(Date::from_ymd(2025, 7, 4)).as_str(), "2025-07-04");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoDateBuf {
+    bytes: [u8; IsoDateBuf::CAPACITY],
+    len: u8,
+}
+
+impl IsoDateBuf {
+    /// Length of the longest formatted date, with a signed 17 digit year.
+    const CAPACITY: usize = 24;
+
+    /// Returns the formatted date.
+    ///
+    /// ```
+    /// use holidays::{Date, IsoDateBuf};
+    ///
+    /// let date = IsoDateBuf::from(Date::from_ymd(2025, 12, 25));
+    /// assert_eq!(date.as_str(), "2025-12-25");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(self.as_bytes()).expect("formatted dates are ASCII")
+    }
+
+    /// Returns bytes of the formatted date.
+    ///
+    /// ```
+    /// use holidays::{Date, IsoDateBuf};
+    ///
+    /// let date = IsoDateBuf::from(Date::from_ymd(-44, 3, 15));
+    /// assert_eq!(date.as_bytes(), b"-0044-03-15");
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+}
+
+impl From<Date> for IsoDateBuf {
+    fn from(value: Date) -> Self {
+        let mut buf = IsoDateBuf {
+            bytes: [0; IsoDateBuf::CAPACITY],
+            len: 10,
+        };
+        let (y, m, d) = value.ymd();
+        if (0..=9999).contains(&y) {
+            // dates of embedded data, written without `std::fmt`
+            let y = y as u16;
+            let digits = [y / 1000, y / 100 % 10, y / 10 % 10, y % 10];
+            for (byte, digit) in buf.bytes.iter_mut().zip(digits) {
+                *byte = b'0' + digit as u8;
+            }
+            let [m, d] = [m, d].map(|it| [b'0' + it / 10, b'0' + it % 10]);
+            buf.bytes[4..10].copy_from_slice(&[b'-', m[0], m[1], b'-', d[0], d[1]]);
+            return buf;
+        }
+
+        struct Cursor<'a>(&'a mut IsoDateBuf);
+        impl std::fmt::Write for Cursor<'_> {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                let start = self.0.len as usize;
+                let end = start + s.len();
+                let bytes = self.0.bytes.get_mut(start..end).ok_or(std::fmt::Error)?;
+                bytes.copy_from_slice(s.as_bytes());
+                self.0.len = end as u8;
+                Ok(())
+            }
+        }
+        buf.len = 0;
+        value
+            .format_iso(&mut Cursor(&mut buf))
+            .expect("capacity fits dates of all years");
+        buf
+    }
+}
+
+impl AsRef<str> for IsoDateBuf {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for IsoDateBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::fmt::Debug for IsoDateBuf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IsoDateBuf({:?})", self.as_str())
+    }
+}
+
+/// Calendar date in proleptic Gregorian calendar, available regardless of
+/// enabled features.
+///
+/// Libraries built on this crate can use it in their public APIs without
+/// picking a time crate for their users, who convert it to one they use.
+/// Components are always valid, and the value converts to and from [`Date`]
+/// without loss.
+///
+/// Ordered chronologically, displayed and parsed in ISO 8601 `YYYY-MM-DD`
+/// format like [`Date`]. With the `serde` feature, it serializes as such a
+/// string.
+///
+/// ```
+/// use holidays::{CivilDate, Country};
+///
+/// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+/// let date: CivilDate = holiday.civil_date();
+/// assert_eq!((date.year(), date.month(), date.day()), (2025, 1, 1));
+/// assert_eq!(date.to_string().parse(), Ok(date));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDate {
+    year: i64,
+    month: u8,
+    day: u8,
+}
+
+impl CivilDate {
+    /// Constructs a date from its components, returning `None` if they don't
+    /// represent a valid date within [`Date::MIN_YEAR`]`..=`[`Date::MAX_YEAR`]
+    /// range.
+    ///
+    /// ```
+    /// use holidays::CivilDate;
+    ///
+    /// assert!(CivilDate::new(2024, 2, 29).is_some());
+    /// assert_eq!(CivilDate::new(2025, 2, 29), None);
+    /// ```
+    pub const fn new(year: i64, month: u8, day: u8) -> Option<Self> {
+        match Date::from_ymd_opt(year, month, day) {
+            Some(_) => Some(CivilDate { year, month, day }),
+            None => None,
+        }
+    }
+
+    /// Year
+    ///
+    /// ```
+    /// use holidays::CivilDate;
+    ///
+    /// assert_eq!(CivilDate::new(2025, 7, 4).map(|it| it.year()), Some(2025));
+    /// ```
+    #[inline]
+    pub const fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Month of the year, in `1..=12` range.
+    ///
+    /// ```
+    /// use holidays::CivilDate;
+    ///
+    /// assert_eq!(CivilDate::new(2025, 7, 4).map(|it| it.month()), Some(7));
+    /// ```
+    #[inline]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, starting with 1.
+    ///
+    /// ```
+    /// use holidays::CivilDate;
+    ///
+    /// assert_eq!(CivilDate::new(2025, 7, 4).map(|it| it.day()), Some(4));
+    /// ```
+    #[inline]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl From<Date> for CivilDate {
+    fn from(value: Date) -> Self {
+        let (year, month, day) = value.ymd();
+        CivilDate { year, month, day }
+    }
+}
+
+impl From<CivilDate> for Date {
+    fn from(value: CivilDate) -> Self {
+        // components were validated on construction
+        Date::from_ymd_unchecked(value.year, value.month, value.day)
+    }
+}
+
+impl TryFrom<CivilDate> for std::time::SystemTime {
+    type Error = DateConversionError;
+
+    fn try_from(value: CivilDate) -> Result<Self, Self::Error> {
+        Date::from(value).try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<CivilDate> for chrono::NaiveDate {
+    type Error = DateConversionError;
+
+    fn try_from(value: CivilDate) -> Result<Self, Self::Error> {
+        Date::from(value).try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<CivilDate> for time::Date {
+    type Error = DateConversionError;
+
+    fn try_from(value: CivilDate) -> Result<Self, Self::Error> {
+        Date::from(value).try_into()
+    }
+}
+
+impl std::fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Date::from(*self).format_iso(f)
+    }
+}
+
+/// Parses a date in ISO 8601 `YYYY-MM-DD` format, like [`Date`].
+impl std::str::FromStr for CivilDate {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Date>().map(CivilDate::from)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CivilDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CivilDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Month of the year.
+///
+/// Values are always in `1..=12` range; construction from arbitrary numbers is
+/// validated by [`Month::new`] or [`TryFrom<u8>`].
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// let june = Month::try_from(6).unwrap();
+/// assert_eq!(june, Month::JUNE);
+/// assert!(Month::new(13).is_none());
+/// let holidays = holidays::get_holidays(Country::US, 2025..2026).in_month(june);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(holidays.names().collect::<Vec<_>>(), ["Juneteenth National Independence Day"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Month(u8);
+
+#[allow(missing_docs)]
+impl Month {
+    pub const JANUARY: Month = Month(1);
+    pub const FEBRUARY: Month = Month(2);
+    pub const MARCH: Month = Month(3);
+    pub const APRIL: Month = Month(4);
+    pub const MAY: Month = Month(5);
+    pub const JUNE: Month = Month(6);
+    pub const JULY: Month = Month(7);
+    pub const AUGUST: Month = Month(8);
+    pub const SEPTEMBER: Month = Month(9);
+    pub const OCTOBER: Month = Month(10);
+    pub const NOVEMBER: Month = Month(11);
+    pub const DECEMBER: Month = Month(12);
+}
+
+impl Month {
+    /// Creates a month from its number, returning `None` if `value` isn't in
+    /// `1..=12` range.
+    ///
+    /// ```
+    /// use holidays::Month;
+    ///
+    /// assert_eq!(Month::new(12), Some(Month::DECEMBER));
+    /// assert_eq!(Month::new(0), None);
+    /// ```
+    pub const fn new(value: u8) -> Option<Self> {
+        if value >= 1 && value <= 12 {
+            Some(Month(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of the month in `1..=12` range.
+    ///
+    /// ```
+    /// use holidays::Month;
+    ///
+    /// assert_eq!(Month::MARCH.number(), 3);
+    /// ```
+    #[inline]
+    pub const fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the english name of the month.
+    ///
+    /// ```
+    /// use holidays::Month;
+    ///
+    /// assert_eq!(Month::MARCH.name(), "March");
+    /// ```
+    pub const fn name(self) -> &'static str {
+        const NAMES: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        NAMES[self.0 as usize - 1]
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = InvalidMonth;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Month::new(value).ok_or(InvalidMonth(value))
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(value: Month) -> Self {
+        value.0
+    }
+}
+
+/// Error returned when constructing a [`Month`] from a number outside of
+/// `1..=12` range.
+///
+/// ```
+/// use holidays::error::InvalidMonth;
+/// use holidays::Month;
+///
+/// assert_eq!(Month::try_from(13), Err(InvalidMonth(13)));
+/// assert_eq!(InvalidMonth(13).to_string(), "Month 13 not in range [1, 12]");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidMonth(pub u8);
+crate::error::error_msg!(InvalidMonth, "Month {} not in range [1, 12]", 0);
+
+/// Day of the week.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Weekday;
+///
+/// let date = Date::from_ymd(2025, 1, 1);
+/// assert_eq!(date.weekday(), Weekday::Wednesday);
+/// assert_eq!(Weekday::Wednesday.number_from_monday(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 7,
+}
+
+impl Weekday {
+    /// Returns ISO 8601 number of the day, starting with `1` for Monday and
+    /// ending with `7` for Sunday.
+    ///
+    /// ```
+    /// use holidays::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.number_from_monday(), 1);
+    /// assert_eq!(Weekday::Sunday.number_from_monday(), 7);
+    /// ```
+    #[inline]
+    pub const fn number_from_monday(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the english name of the day.
+    ///
+    /// ```
+    /// use holidays::Weekday;
+    ///
+    /// assert_eq!(Weekday::Saturday.name(), "Saturday");
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Month> for time::Month {
+    fn from(value: Month) -> Self {
+        time::Month::try_from(value.0).expect("Month is always in 1..=12 range")
+    }
+}
+#[cfg(feature = "time")]
+impl From<time::Month> for Month {
+    fn from(value: time::Month) -> Self {
+        Month(value as u8)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Weekday> for time::Weekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Monday => time::Weekday::Monday,
+            Weekday::Tuesday => time::Weekday::Tuesday,
+            Weekday::Wednesday => time::Weekday::Wednesday,
+            Weekday::Thursday => time::Weekday::Thursday,
+            Weekday::Friday => time::Weekday::Friday,
+            Weekday::Saturday => time::Weekday::Saturday,
+            Weekday::Sunday => time::Weekday::Sunday,
+        }
+    }
+}
+#[cfg(feature = "time")]
+impl From<time::Weekday> for Weekday {
+    fn from(value: time::Weekday) -> Self {
+        match value {
+            time::Weekday::Monday => Weekday::Monday,
+            time::Weekday::Tuesday => Weekday::Tuesday,
+            time::Weekday::Wednesday => Weekday::Wednesday,
+            time::Weekday::Thursday => Weekday::Thursday,
+            time::Weekday::Friday => Weekday::Friday,
+            time::Weekday::Saturday => Weekday::Saturday,
+            time::Weekday::Sunday => Weekday::Sunday,
+        }
+    }
+}
+
+/// A year within the range covered by embedded holiday data.
+///
+/// APIs that take years accept any `impl TryInto<CoveredYear>`, so plain
+/// integers can be used, but years without any data are rejected with
+/// [`YearOutOfRange`] instead of silently matching nothing.
+///
+/// ```
+/// use holidays::{Country, CoveredYear};
+///
+/// let year = CoveredYear::try_from(2025).unwrap();
+/// assert_eq!(holidays::get_holidays(Country::JP, year.dates()).count(), 19);
+///
+/// assert!(CoveredYear::try_from(1000).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoveredYear(i64);
+
+impl CoveredYear {
+    /// First year with embedded data.
+    ///
+    /// ```
+    /// use holidays::{Country, CoveredYear};
+    ///
+    /// assert!(holidays::get_holidays(Country::US, CoveredYear::MIN.dates()).next().is_some());
+    /// assert_eq!(CoveredYear::new(CoveredYear::MIN.get() - 1), None);
+    /// ```
+    pub const MIN: CoveredYear = CoveredYear(crate::data::DATA_MIN_YEAR);
+    /// Last year with embedded data.
+    ///
+    /// ```
+    /// use holidays::{Country, CoveredYear};
+    ///
+    /// assert!(holidays::get_holidays(Country::US, CoveredYear::MAX.dates()).next().is_some());
+    /// assert_eq!(CoveredYear::new(CoveredYear::MAX.get() + 1), None);
+    /// ```
+    pub const MAX: CoveredYear = CoveredYear(crate::data::DATA_MAX_YEAR);
+
+    /// Returns `year` if it's covered by embedded data.
+    ///
+    /// ```
+    /// use holidays::CoveredYear;
+    ///
+    /// assert_eq!(CoveredYear::new(2025).map(CoveredYear::get), Some(2025));
+    /// assert_eq!(CoveredYear::new(1800), None);
+    /// ```
+    pub const fn new(year: i64) -> Option<Self> {
+        if year >= Self::MIN.0 && year <= Self::MAX.0 {
+            Some(CoveredYear(year))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the year number.
+    ///
+    /// ```
+    /// use holidays::CoveredYear;
+    ///
+    /// let year = CoveredYear::try_from(2030).unwrap();
+    /// assert_eq!(year.get(), 2030);
+    /// ```
+    #[inline]
+    pub const fn get(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the range of all dates in the year.
+    ///
+    /// ```
+    /// use holidays::{CoveredYear, Date};
+    ///
+    /// let dates = CoveredYear::try_from(2025).unwrap().dates();
+    /// assert_eq!(dates, Date::from_ymd(2025, 1, 1)..Date::from_ymd(2026, 1, 1));
+    /// ```
+    pub const fn dates(self) -> std::ops::Range<Date> {
+        Date::from_ymd(self.0, 1, 1)..Date::from_ymd(self.0 + 1, 1, 1)
+    }
+
+    /// Returns an iterator over all covered years.
+    ///
+    /// ```
+    /// use holidays::CoveredYear;
+    ///
+    /// let years: Vec<_> = CoveredYear::all().collect();
+    /// assert_eq!(years.first(), Some(&CoveredYear::MIN));
+    /// assert_eq!(years.last(), Some(&CoveredYear::MAX));
+    /// ```
+    pub fn all() -> impl DoubleEndedIterator<Item = CoveredYear> {
+        (Self::MIN.0..=Self::MAX.0).map(CoveredYear)
+    }
+
+    /// Converts bounds of `years` into an inclusive range of covered years,
+    /// which is empty if `years` is.
+    ///
+    /// Unbounded ends default to [`MIN`](Self::MIN) and [`MAX`](Self::MAX),
+    /// while bounded ones must be covered, even if they're excluded.
+    pub(crate) fn range<Y, R>(years: R) -> Result<std::ops::RangeInclusive<i64>, YearOutOfRange>
+    where
+        Y: TryInto<CoveredYear> + Clone,
+        YearOutOfRange: From<Y::Error>,
+        R: std::ops::RangeBounds<Y>,
+    {
+        use std::ops::Bound;
+
+        let first = match years.start_bound() {
+            Bound::Included(year) => year.clone().try_into()?.0,
+            Bound::Excluded(year) => year.clone().try_into()?.0 + 1,
+            Bound::Unbounded => Self::MIN.0,
+        };
+        let last = match years.end_bound() {
+            Bound::Included(year) => year.clone().try_into()?.0,
+            Bound::Excluded(year) => year.clone().try_into()?.0 - 1,
+            Bound::Unbounded => Self::MAX.0,
+        };
+        Ok(first..=last)
+    }
+}
+
+macro_rules! impl_covered_year_from {
+    ($($int: ty),*) => {$(
+        impl TryFrom<$int> for CoveredYear {
+            type Error = YearOutOfRange;
+
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                i64::try_from(value)
+                    .ok()
+                    .and_then(CoveredYear::new)
+                    .ok_or(YearOutOfRange)
+            }
+        }
+    )*};
+}
+impl_covered_year_from!(i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl From<CoveredYear> for i64 {
+    fn from(value: CoveredYear) -> Self {
+        value.0
+    }
+}
+
+/// Error returned when a year is outside of the range covered by embedded
+/// data.
+///
+/// ```
+/// use holidays::error::YearOutOfRange;
+/// use holidays::CoveredYear;
+///
+/// assert_eq!(CoveredYear::try_from(1000), Err(YearOutOfRange));
+/// assert!(CoveredYear::try_from(2025).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YearOutOfRange;
+impl std::fmt::Display for YearOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Year not in covered range [{}, {}]",
+            CoveredYear::MIN.0,
+            CoveredYear::MAX.0
+        )
+    }
+}
+impl core::error::Error for YearOutOfRange {}
+impl From<std::convert::Infallible> for YearOutOfRange {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
+/// Utility functions that extend all supported date types and provide methods
+/// on them to directly query holiday information.
+///
+/// Besides dates themselves, methods are available on these ranges of any
+/// supported date type `T`, or references to it:
+///
+/// - `a..b`, `a..=b`, `a..`, `..b` and `..=b`;
+/// - `(Bound<T>, Bound<T>)` tuples, for ranges with bounds that are
+///   inclusive or exclusive independently, e.g. ones produced by other APIs;
+/// - `..`, which selects all dates.
+///
+/// ```
+/// use std::ops::Bound;
+/// use std::time::{Duration, SystemTime};
+/// use holidays::{Country, DateExt};
+///
+/// let now = SystemTime::now();
+/// let next_week = now + Duration::from_secs(7 * 86400);
+/// let _ = (now..next_week).holidays(Country::US);
+/// let _ = (Bound::Excluded(now), Bound::Included(next_week)).is_holiday(Country::US);
+/// ```
+pub trait DateExt<DateLike, DateRange = std::ops::Range<DateLike>>:
+    Into<DateSelection<DateLike, DateRange>> + Clone
+where
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    // Most date(time) types are trivial to Clone
+
+    /// Returns an iterator of holidays that are observed on this date (range)
+    /// in specified `countries`.
+    ///
+    /// This is an alias for [`get_holidays`] method, see that method for more
+    /// details.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, DateExt};
+    ///
+    /// let (eve, boxing_day) = (Date::from_ymd(2025, 12, 24), Date::from_ymd(2025, 12, 26));
+    /// assert_eq!(eve.holidays(Country::GB).count(), 0);
+    /// assert_eq!((eve..boxing_day).holidays(Country::GB).count(), 1);
+    /// assert_eq!((eve..=boxing_day).holidays(Country::GB).count(), 2);
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!((..boxing_day).holidays(Country::GB).last().map(|it| it.name), Some("Christmas Day"));
+    /// ```
+    ///
+    /// [`get_holidays`]: crate::get_holidays
+    fn holidays<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+    ) -> crate::Iter
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        crate::get_holidays(countries, self.clone())
+    }
+
+    /// Returns `true` if any holidays are observed on this date (range) in
+    /// specified `countries`.
+    ///
+    /// This is an alias for [`is_holiday`] method, see that method for more
+    /// details.
+    ///
+    /// [`is_holiday`]: crate::is_holiday
+    fn is_holiday<CountryIter>(&self, countries: impl Into<CountrySelection<CountryIter>>) -> bool
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        crate::is_holiday(countries, self.clone())
+    }
+
+    /// Returns an iterator over distinct names of holidays that are observed
+    /// on this date (range) in specified `countries`, in order of their
+    /// first occurrence.
+    ///
+    /// Unlike [`Iter::names`](crate::Iter::names), names shared by multiple
+    /// countries or days are only yielded once.
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    /// use holidays::{Country, Date, DateExt};
+    ///
+    /// let christmas: SystemTime = Date::from_ymd(2025, 12, 25).try_into().unwrap();
+    /// let names: Vec<_> = christmas.holiday_names([Country::US, Country::GB]).collect();
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(names, ["Christmas Day"]);
+    /// ```
+    fn holiday_names<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+    ) -> impl Iterator<Item = &'static str>
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        let mut seen = std::collections::HashSet::new();
+        self.holidays(countries)
+            .names()
+            .filter(move |it| seen.insert(*it))
+    }
+
+    /// Returns the number of holidays that are observed on this date (range)
+    /// in specified `countries`.
+    ///
+    /// Holidays of multiple countries are counted separately, even if they
+    /// share a name.
+    fn holiday_count<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+    ) -> usize
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        self.holidays(countries).count()
+    }
+
+    /// Returns an iterator of holidays that are observed in specified
+    /// `countries` on this date or any of the following `days`.
+    ///
+    /// This is an alias for [`within`] method, see that method for more
+    /// details.
+    ///
+    /// [`within`]: crate::within
+    fn holidays_within<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+        days: u32,
+    ) -> crate::Iter
+    where
+        Self: Into<Date>,
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        crate::within(countries, self.clone(), days)
+    }
+
+    /// Returns the holiday observed in specified `countries` nearest to this
+    /// date, if there's one at most `tolerance_days` away, along with its
+    /// signed offset in days (negative if the holiday is before this date).
+    ///
+    /// This is an alias for [`nearest_holiday`] method, see that method for
+    /// more details.
+    ///
+    /// [`nearest_holiday`]: crate::nearest_holiday
+    fn is_near_holiday<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+        tolerance_days: u8,
+    ) -> Option<(i8, &'static crate::Holiday)>
+    where
+        Self: Into<Date>,
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        crate::nearest_holiday(countries, self.clone(), tolerance_days)
+    }
+}
+
+macro_rules! impl_ext_for_t {
+    (@owned $t: ty: $($range: ty),* $(,)?) => {$(
+        impl DateExt<$t, $range> for $range {}
+    )*};
+    (@borrowed $t: ty: $($range: ty),* $(,)?) => {$(
+        impl<'a> DateExt<$t, $range> for $range {}
+    )*};
+    (if $guard: literal $t: ty) => {
+        #[cfg(feature = $guard)]
+        impl_ext_for_t!($t);
+    };
+    ($t: ty) => {
+        impl DateExt<$t> for $t {}
+        impl_ext_for_t!(@owned $t:
+            std::ops::Range<$t>,
+            std::ops::RangeInclusive<$t>,
+            std::ops::RangeFrom<$t>,
+            std::ops::RangeTo<$t>,
+            std::ops::RangeToInclusive<$t>,
+            (std::ops::Bound<$t>, std::ops::Bound<$t>),
+        );
+        impl_ext_for_t!(@borrowed $t:
+            std::ops::Range<&'a $t>,
+            std::ops::RangeInclusive<&'a $t>,
+            std::ops::RangeFrom<&'a $t>,
+            std::ops::RangeTo<&'a $t>,
+            std::ops::RangeToInclusive<&'a $t>,
+            (std::ops::Bound<&'a $t>, std::ops::Bound<&'a $t>),
+        );
+    };
+}
+impl_ext_for_t!(Date);
+impl_ext_for_t!(std::time::SystemTime);
+impl_ext_for_t!(if "chrono" chrono::NaiveDate);
+impl_ext_for_t!(if "chrono" chrono::DateTime<chrono::Utc>);
+impl_ext_for_t!(if "chrono" chrono::DateTime<chrono::Local>);
+impl_ext_for_t!(if "time" time::Date);
+impl_ext_for_t!(if "time" time::OffsetDateTime);
+impl_ext_for_t!(if "time" time::PrimitiveDateTime);
+impl_ext_for_t!(if "time" time::UtcDateTime);
+
+/// `..` selects all dates, like [`Any`].
+impl DateExt<Date, std::ops::RangeFull> for std::ops::RangeFull {}
+
+/// Fallible counterpart of [`DateExt`] for dates written in ISO 8601
+/// `YYYY-MM-DD` format, and ranges of them.
+///
+/// ```
+/// use holidays::{Country, TryDateExt};
+///
+/// assert_eq!("2025-12-25".is_holiday_str(Country::DE), Ok(true));
+/// assert_eq!(("2025-01-01".."2025-02-01").holidays_str(Country::DE).map(Iterator::count), Ok(1));
+/// assert!("25.12.2025".is_holiday_str(Country::DE).is_err());
+/// ```
+pub trait TryDateExt {
+    /// Parses this date (range) and returns an iterator of holidays that are
+    /// observed on it in specified `countries`.
+    ///
+    /// See [`get_holidays`] for more details.
+    ///
+    /// [`get_holidays`]: crate::get_holidays
+    fn holidays_str<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+    ) -> Result<crate::Iter, ParseDateError>
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>;
+
+    /// Parses this date (range) and returns `true` if any holidays are
+    /// observed on it in specified `countries`.
+    ///
+    /// See [`is_holiday`] for more details.
+    ///
+    /// [`is_holiday`]: crate::is_holiday
+    fn is_holiday_str<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+    ) -> Result<bool, ParseDateError>
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        Ok(self.holidays_str(countries)?.next().is_some())
+    }
+}
+
+impl TryDateExt for str {
+    fn holidays_str<CountryIter>(
+        &self,
+        countries: impl Into<CountrySelection<CountryIter>>,
+    ) -> Result<crate::Iter, ParseDateError>
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<crate::Country>,
+    {
+        let date: Date = self.parse()?;
+        Ok(crate::get_holidays(countries, date))
+    }
+}
+
+/// Parses both endpoints of a `range` of dates written in ISO 8601
+/// `YYYY-MM-DD` format.
+///
+/// Returned bounds can be used as a date selection.
+///
+/// ```
+/// use holidays::Country;
+///
+/// let range = holidays::parse_date_range(&("2025-12-24"..="2025-12-26")).unwrap();
+///
+/// assert!(holidays::is_holiday(Country::DE, range));
+/// ```
+pub fn parse_date_range<S, R>(
+    range: &R,
+) -> Result<(std::ops::Bound<Date>, std::ops::Bound<Date>), ParseDateError>
+where
+    S: AsRef<str>,
+    R: std::ops::RangeBounds<S>,
+{
+    fn parse<S: AsRef<str>>(
+        bound: std::ops::Bound<&S>,
+    ) -> Result<std::ops::Bound<Date>, ParseDateError> {
+        Ok(match bound {
+            std::ops::Bound::Included(it) => std::ops::Bound::Included(it.as_ref().parse()?),
+            std::ops::Bound::Excluded(it) => std::ops::Bound::Excluded(it.as_ref().parse()?),
+            std::ops::Bound::Unbounded => std::ops::Bound::Unbounded,
+        })
+    }
+
+    Ok((parse(range.start_bound())?, parse(range.end_bound())?))
+}
+
+macro_rules! impl_try_ext_for_range {
+    ($($range: ident),*) => {$(
+        impl<S: AsRef<str>> TryDateExt for std::ops::$range<S> {
+            fn holidays_str<CountryIter>(
+                &self,
+                countries: impl Into<CountrySelection<CountryIter>>,
+            ) -> Result<crate::Iter, ParseDateError>
+            where
+                CountryIter: IntoIterator,
+                CountryIter::Item: Into<crate::Country>,
+            {
+                Ok(crate::get_holidays(countries, parse_date_range(self)?))
+            }
+        }
+    )*};
+}
+impl_try_ext_for_range!(Range, RangeInclusive, RangeFrom, RangeTo, RangeToInclusive);
+
+/// Error returned when parsing a date that isn't in ISO 8601 `YYYY-MM-DD`
+/// format or doesn't exist.
+///
+/// ```
+/// use holidays::error::ParseDateError;
+/// use holidays::Date;
+///
+/// assert_eq!("2025-02-29".parse::<Date>(), Err(ParseDateError));
+/// assert_eq!("25.12.2025".parse::<Date>(), Err(ParseDateError));
+/// assert!("2024-02-29".parse::<Date>().is_ok());
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDateError;
+crate::error::error_msg!(ParseDateError, "Invalid date, expected YYYY-MM-DD format");
+
+/// Error returned when conversion to/from another date format can't be
+/// performed because one has larger span than the other and conversion would
+/// cause an overflow.
+///
+/// In most practical use cases this won't happen because dates that are stored
+/// in holidays table can be reasonably converted to most time libraries.
+///
+/// ```
+/// use std::time::SystemTime;
+/// use holidays::error::DateConversionError;
+/// use holidays::Date;
+///
+/// assert_eq!(SystemTime::try_from(Date::MAX), Err(DateConversionError));
+/// assert!(SystemTime::try_from(Date::from_ymd(2025, 1, 1)).is_ok());
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct DateConversionError;
+crate::error::error_msg!(DateConversionError, "Date is too large for conversion");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Country, CountrySet};
+    use std::{hint::black_box, ops::Bound, time::SystemTime};
+
+    fn round_trip(y: i64, m: u8, d: u8) {
+        let date = black_box(Date::from_ymd(y, m, d));
+        let (y_out, m_out, d_out) = date.ymd();
+        assert_eq!(y, y_out);
+        assert_eq!(m, m_out);
+        assert_eq!(d, d_out);
+    }
+
+    #[test]
+    fn ymd_to_days() {
+        // test ymd is inverse of from_ymd
+        round_trip(2025, 5, 20);
+        round_trip(4423, 1, 18);
+        round_trip(-2344, 2, 6);
+
+        // days are computed using: https://aa.usno.navy.mil/data/JulianDate
+        // with 2440588 offset from their
 
         println!("{:?}", Date(-2440588));
 
@@ -397,5 +1997,776 @@ mod tests {
         let _ = time_range.holidays(&[Country::US, Country::JP]);
         let _ = time_range.holidays([Country::US, Country::JP]);
         let _ = time_range.holidays(vec![Country::DE, Country::HR]);
+
+        let set: CountrySet = [Country::US, Country::JP].into_iter().collect();
+
+        let _ = time.holidays(&set);
+        let _ = time_ref.holidays(&set);
+        let _ = time_range.holidays(&set);
+        let _ = time.holidays(set);
+        let _ = crate::get_holidays(&set, 2025);
+        let _ = crate::get_holidays(set.iter(), 2025);
+        let _ = crate::get_holidays(set, 2025);
+
+        let _ = time.holiday_names(Any);
+        let _ = time.holiday_names(country_opt);
+        let _ = time.holiday_names(&[Country::US, Country::JP]);
+        let _ = time_ref.holiday_names(Country::US);
+        let _ = time_range.holiday_names(vec![Country::DE, Country::HR]);
+        let _ = time_range.holiday_names(&set);
+        let _ = time.holiday_count(Any);
+        let _ = time.holiday_count(country_opt);
+        let _ = time_ref.holiday_count([Country::US, Country::JP]);
+        let _ = time_range.holiday_count(Country::US);
+        let _ = time_range.holiday_count(set);
+
+        let _ = (time..time).holidays(Country::US);
+        let _ = (time..=time).holidays(Country::US);
+        let _ = (time..).holidays(Country::US);
+        let _ = (..time).holidays(Country::US);
+        let _ = (..=time).holidays(Country::US);
+        let _ = (Bound::Included(time), Bound::Excluded(time)).holidays(Country::US);
+        let _ = (Bound::Excluded(time), Bound::Unbounded).is_holiday(Country::US);
+        let _ = (&time..&time).holidays(Country::US);
+        let _ = (..=&time).holiday_count(Country::US);
+        let _ = (Bound::Included(&time), Bound::Included(&time)).holidays(Country::US);
+        let _ = (..).holidays(Country::US);
+        let _ = (..).is_holiday(Any);
+
+        let _ = time.is_near_holiday(Any, 2);
+        let _ = time.is_near_holiday(Country::US, 2);
+        let _ = time.is_near_holiday([Country::US, Country::JP], 2);
+        let _ = time.holidays_within(Any, 14);
+        let _ = time.holidays_within(Country::US, 14);
+        let _ = time.holidays_within([Country::US, Country::JP], 14);
+    }
+
+    #[test]
+    fn date_ext_names_and_counts() {
+        let countries = [Country::US, Country::GB, Country::DE, Country::JP];
+        let date: SystemTime = Date::from_ymd(2025, 1, 1).try_into().unwrap();
+        assert_eq!(date.holiday_count(countries), 4);
+        assert_eq!(date.holiday_count(Country::US), 1);
+        assert_eq!(
+            date.holiday_count(Any),
+            crate::get_holidays(Any, date).fold(0, |n, _| n + 1)
+        );
+
+        #[cfg(not(feature = "no-names"))]
+        {
+            let names: Vec<_> = date.holiday_names(countries).collect();
+            let all: Vec<_> = date.holidays(countries).names().collect();
+            assert_eq!(all.len(), 4);
+            assert!(names.len() < all.len());
+            assert!(names.contains(&"New Year's Day"));
+            assert!(all.iter().all(|it| names.contains(it)));
+        }
+
+        let year = date..Date::from_ymd(2026, 1, 1).try_into().unwrap();
+        let names: Vec<_> = year.holiday_names(Country::US).collect();
+        let mut expected: Vec<_> = year.holidays(Country::US).names().collect();
+        expected.dedup();
+        assert_eq!(names, expected);
+        assert_eq!(year.holiday_count(Country::US), 11);
+    }
+
+    /// SplitMix64, good enough for generating test inputs without extra
+    /// dependencies.
+    struct Rng(u64);
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        }
+
+        fn range(&mut self, range: std::ops::Range<i64>) -> i64 {
+            range.start + (self.next() % (range.end - range.start) as u64) as i64
+        }
+    }
+
+    #[test]
+    fn prop_days_round_trip() {
+        let mut rng = Rng(0x5EED);
+        for _ in 0..100_000 {
+            let date = Date(rng.range(-10_000_000..10_000_001));
+            let (y, m, d) = date.ymd();
+            assert_eq!(Date::from_ymd(y, m, d), date);
+            assert_eq!(Date::from_ymd_opt(y, m, d), Some(date));
+        }
+    }
+
+    #[test]
+    fn prop_civil_days_monotonic() {
+        let mut rng = Rng(0xDA7E);
+        for _ in 0..100_000 {
+            let year = rng.range(-30_000..30_001);
+            let month = rng.range(1..13) as u8;
+            let day = rng.range(1..Date::days_in_month(year, month) as i64 + 1) as u8;
+
+            let (next_year, next_month, next_day) = if day < Date::days_in_month(year, month) {
+                (year, month, day + 1)
+            } else if month < 12 {
+                (year, month + 1, 1)
+            } else {
+                (year + 1, 1, 1)
+            };
+
+            let date = Date::from_ymd(year, month, day);
+            let next = Date::from_ymd(next_year, next_month, next_day);
+            assert_eq!(next.0, date.0 + 1, "{date:?} -> {next:?}");
+            assert_eq!(date.ymd(), (year, month, day));
+        }
+    }
+
+    #[test]
+    fn ymd_matches_chrono() {
+        use chrono::Datelike;
+
+        let start = Date::from_ymd(1900, 1, 1);
+        let end = Date::from_ymd(2101, 1, 1);
+        let mut expected = chrono::NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+        for day in start.0..end.0 {
+            let (y, m, d) = Date(day).ymd();
+            assert_eq!(
+                (y, m, d),
+                (
+                    expected.year() as i64,
+                    expected.month() as u8,
+                    expected.day() as u8
+                )
+            );
+            assert_eq!(
+                Date(day).weekday().number_from_monday() as u32,
+                expected.weekday().number_from_monday()
+            );
+            expected = expected.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn iso_format_matches_chrono() {
+        let mut out = String::new();
+        for holiday in crate::data::DATA.iter().step_by(97) {
+            let (y, m, d) = holiday.date.ymd();
+            let expected = chrono::NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).unwrap();
+
+            out.clear();
+            holiday.format_date(&mut out).unwrap();
+            assert_eq!(out, expected.format("%Y-%m-%d").to_string());
+            assert_eq!(holiday.display_date().to_string(), out);
+        }
+
+        for (y, m, d) in [
+            (0, 1, 1),
+            (-1, 12, 31),
+            (-12345, 6, 7),
+            (9999, 12, 31),
+            (10000, 1, 1),
+        ] {
+            let expected = chrono::NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).unwrap();
+            assert_eq!(
+                Date::from_ymd(y, m, d).to_string(),
+                expected.format("%Y-%m-%d").to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn iso_date_buf_matches_format_iso() {
+        let mut years = std::collections::BTreeSet::new();
+        for holiday in crate::data::DATA.iter().step_by(13) {
+            let (y, m, d) = holiday.date.ymd();
+            let date = chrono::NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).unwrap();
+            let expected = date.format("%Y-%m-%d").to_string();
+
+            let buf = holiday.iso_date_buf();
+            assert_eq!(buf.as_bytes(), expected.as_bytes());
+            assert_eq!(buf.to_string(), expected);
+            years.insert(y);
+        }
+        // the sample spans all covered years
+        assert_eq!(years.first(), Some(&crate::data::DATA_MIN_YEAR));
+        assert_eq!(years.last(), Some(&crate::data::DATA_MAX_YEAR));
+
+        let dates = [
+            Date::MIN,
+            Date::MAX,
+            Date::from_ymd(-12345, 6, 7),
+            Date::from_ymd(-1, 12, 31),
+            Date::from_ymd(0, 1, 1),
+            Date::from_ymd(9999, 12, 31),
+            Date::from_ymd(10000, 1, 1),
+        ];
+        for date in dates {
+            assert_eq!(IsoDateBuf::from(date).as_str(), date.to_string());
+        }
+
+        let query = crate::get_holidays(crate::Any, 2024..2027);
+        let formatted = query.clone().iso_dates().map(|it| it.to_string());
+        assert!(formatted.eq(query.map(|it| it.display_date().to_string())));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_type_interface() {
+        // This test pins down supported `chrono` types and their ranges. It's
+        // failing if it doesn't compile.
+
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let utc = chrono::Utc::now();
+        let local = chrono::Local::now();
+
+        let _ = date.holidays(Country::US);
+        let _ = (date..date).holidays(Country::US);
+        let _ = (date..=date).holidays(Country::US);
+        let _ = (date..).holidays(Country::US);
+        let _ = (..date).holidays(Country::US);
+        let _ = (..=date).holidays(Country::US);
+        let _ = (Bound::Included(date), Bound::Excluded(date)).holidays(Country::US);
+        let _ = (Bound::Unbounded, Bound::Included(date)).is_holiday([Country::US, Country::JP]);
+        let _ = (&date..=&date).holidays(Country::US);
+        let _ = (Bound::Excluded(&date), Bound::Unbounded).holidays(Country::US);
+        let _ = crate::get_holidays(Country::US, (Bound::Included(date), Bound::Excluded(date)));
+
+        let _ = (utc..utc).holidays(Country::US);
+        let _ = (Bound::Included(utc), Bound::Unbounded).holidays(Country::US);
+        let _ = (..=local).holidays(Country::US);
+        let _ = (Bound::Excluded(local), Bound::Excluded(local)).holidays(Country::US);
+    }
+
+    #[test]
+    fn mixed_bounds() {
+        let day = |d| SystemTime::try_from(Date::from_ymd(2025, 12, d)).unwrap();
+        let count =
+            |range: (Bound<SystemTime>, Bound<SystemTime>)| range.holiday_count(Country::GB);
+        let collect = |iter: crate::Iter| iter.collect::<Vec<_>>();
+
+        // Christmas Day and Boxing Day
+        assert_eq!(
+            count((Bound::Included(day(25)), Bound::Included(day(26)))),
+            2
+        );
+        assert_eq!(
+            count((Bound::Excluded(day(25)), Bound::Included(day(26)))),
+            1
+        );
+        assert_eq!(
+            count((Bound::Included(day(25)), Bound::Excluded(day(26)))),
+            1
+        );
+        assert_eq!(
+            count((Bound::Excluded(day(25)), Bound::Excluded(day(26)))),
+            0
+        );
+        assert_eq!(
+            collect((Bound::Excluded(day(24)), Bound::Unbounded).holidays(Country::GB)),
+            collect((day(25)..).holidays(Country::GB))
+        );
+        assert_eq!(
+            collect((..).holidays(Country::GB)),
+            collect(crate::get_holidays::<_, Date, _>(Country::GB, Any))
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_type_interface() {
+        // This test pins down supported `time` types. It's failing if it
+        // doesn't compile.
+
+        let utc = time::UtcDateTime::now();
+        let offset = time::OffsetDateTime::now_utc();
+        let primitive = time::PrimitiveDateTime::new(offset.date(), offset.time());
+
+        let _ = utc.holidays(Any);
+        let _ = utc.holidays(Country::US);
+        let _ = (utc..utc).holidays(Country::US);
+        let _ = utc.is_holiday([Country::US, Country::JP]);
+        let _ = utc.holidays_within(Country::US, 14);
+        let _ = offset.holidays(Country::US);
+        let _ = (offset..=offset).holidays(Country::US);
+        let _ = primitive.holidays(Country::US);
+        let _ = offset.date().holidays(Country::US);
+
+        let _: Result<time::UtcDateTime, _> = crate::get_holidays(Country::US, utc)
+            .next()
+            .map(|it| it.date())
+            .unwrap_or(Err(DateConversionError));
+    }
+
+    #[test]
+    fn conversion_error_policies() {
+        use std::time::SystemTime;
+
+        let (_, last) = SystemTime::bounds();
+        let far = Date::from_ymd(300_000_000_000_000, 1, 1);
+        assert!(far > last);
+        let convert = |policy: OnConversionError, date| policy.convert::<SystemTime>(date);
+
+        assert_eq!(
+            convert(OnConversionError::Fail, far),
+            Some(Err(DateConversionError))
+        );
+        assert_eq!(convert(OnConversionError::Skip, far), None);
+        let clamped = convert(OnConversionError::ClampToBounds, far)
+            .unwrap()
+            .unwrap();
+        assert_eq!(Date::from(clamped), last);
+        let clamped = convert(OnConversionError::ClampToBounds, Date::MIN)
+            .unwrap()
+            .unwrap();
+        assert_eq!(Date::from(clamped), SystemTime::bounds().0);
+
+        // dates in range are converted the same way by all policies
+        let date = Date::from_ymd(2025, 7, 4);
+        for policy in [
+            OnConversionError::Skip,
+            OnConversionError::Fail,
+            OnConversionError::ClampToBounds,
+        ] {
+            assert_eq!(convert(policy, date), Some(date.try_into()));
+            assert_eq!(policy.convert::<Date>(Date::MAX), Some(Ok(Date::MAX)));
+        }
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            OnConversionError::ClampToBounds.convert::<chrono::NaiveDate>(far),
+            Some(Ok(chrono::NaiveDate::MAX))
+        );
+        #[cfg(feature = "time")]
+        assert_eq!(
+            OnConversionError::ClampToBounds.convert::<time::Date>(Date::MIN),
+            Some(Ok(time::Date::MIN))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversion_boundaries() {
+        use chrono::NaiveDate;
+
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        for date in [NaiveDate::MIN, NaiveDate::MAX, epoch] {
+            assert_eq!(NaiveDate::try_from(Date::from(date)), Ok(date));
+        }
+        assert_eq!(Date::from(epoch), Date(0));
+        assert_eq!(
+            NaiveDate::try_from(Date::from(NaiveDate::MAX).saturating_add_days(1)),
+            Err(DateConversionError)
+        );
+        assert_eq!(
+            NaiveDate::try_from(Date::from(NaiveDate::MIN).saturating_add_days(-1)),
+            Err(DateConversionError)
+        );
+        assert_eq!(NaiveDate::try_from(Date::MAX), Err(DateConversionError));
+        assert_eq!(NaiveDate::try_from(Date::MIN), Err(DateConversionError));
+
+        assert_eq!(SystemTime::try_from(Date::MAX), Err(DateConversionError));
+        assert_eq!(SystemTime::try_from(Date::MIN), Err(DateConversionError));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_conversion_boundaries() {
+        use time::macros::{datetime, utc_datetime};
+
+        // offset date-times use the date in their own offset
+        let late = datetime!(2025-07-04 23:30 -5);
+        assert_eq!(Date::from(late), Date::from_ymd(2025, 7, 4));
+        assert_eq!(Date::from(late.to_utc()), Date::from_ymd(2025, 7, 5));
+        let early = datetime!(2025-07-05 00:30 +2);
+        assert_eq!(Date::from(early), Date::from_ymd(2025, 7, 5));
+        assert_eq!(Date::from(early.to_utc()), Date::from_ymd(2025, 7, 4));
+
+        assert_eq!(
+            Date::from(utc_datetime!(2025-12-24 23:59:59.999)),
+            Date::from_ymd(2025, 12, 24)
+        );
+        assert_eq!(
+            Date::from(utc_datetime!(2025-12-25 00:00)),
+            Date::from_ymd(2025, 12, 25)
+        );
+        assert_eq!(
+            Date::from(datetime!(2025-12-25 23:59:59)),
+            Date::from_ymd(2025, 12, 25)
+        );
+
+        let christmas = time::UtcDateTime::try_from(Date::from_ymd(2025, 12, 25)).unwrap();
+        assert_eq!(christmas, utc_datetime!(2025-12-25 00:00));
+        assert!(christmas.is_holiday(Country::GB));
+
+        for date in [
+            time::Date::MIN,
+            time::Date::MAX,
+            time::Date::from_julian_day(0).unwrap(),
+        ] {
+            assert_eq!(time::Date::try_from(Date::from(date)), Ok(date));
+        }
+        assert_eq!(
+            time::Date::try_from(Date::from(time::Date::MAX).saturating_add_days(1)),
+            Err(DateConversionError)
+        );
+        assert_eq!(time::Date::try_from(Date::MAX), Err(DateConversionError));
+        assert_eq!(
+            time::UtcDateTime::try_from(Date::MIN),
+            Err(DateConversionError)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_weekday_month() {
+        let mut date = time::Date::from_calendar_date(2025, time::Month::January, 1).unwrap();
+        for _ in 0..400 {
+            assert_eq!(Weekday::from(date.weekday()), Date::from(date).weekday());
+            assert_eq!(
+                time::Weekday::from(Date::from(date).weekday()),
+                date.weekday()
+            );
+            assert_eq!(Month::from(date.month()).number(), u8::from(date.month()));
+            assert_eq!(time::Month::from(Month::from(date.month())), date.month());
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn covered_year_bounds() {
+        let min = crate::data::DATA_MIN_YEAR;
+        let max = crate::data::DATA_MAX_YEAR;
+
+        assert_eq!(CoveredYear::try_from(min).map(CoveredYear::get), Ok(min));
+        assert_eq!(CoveredYear::try_from(max).map(CoveredYear::get), Ok(max));
+        assert_eq!(CoveredYear::try_from(min - 1), Err(YearOutOfRange));
+        assert_eq!(CoveredYear::try_from(max + 1), Err(YearOutOfRange));
+        assert_eq!(CoveredYear::try_from(i64::MIN), Err(YearOutOfRange));
+        assert_eq!(CoveredYear::try_from(u64::MAX), Err(YearOutOfRange));
+        assert_eq!(CoveredYear::try_from(max as isize), Ok(CoveredYear::MAX));
+        assert_eq!(CoveredYear::try_from(min as i32), Ok(CoveredYear::MIN));
+
+        assert_eq!(CoveredYear::all().count() as i64, max - min + 1);
+        assert_eq!(CoveredYear::all().next(), Some(CoveredYear::MIN));
+        assert_eq!(CoveredYear::all().next_back(), Some(CoveredYear::MAX));
+
+        // every covered year has data, and dates don't spill into neighbours
+        for year in CoveredYear::all() {
+            let dates = year.dates();
+            let records: Vec<Date> = crate::get_holidays(Any, dates.clone())
+                .map(|it| it.date().unwrap())
+                .collect();
+            assert!(!records.is_empty(), "{year:?}");
+            assert!(records.iter().all(|it| it.year() == year.get()));
+        }
+
+        let message = YearOutOfRange.to_string();
+        assert!(message.contains(&min.to_string()) && message.contains(&max.to_string()));
+    }
+
+    #[test]
+    fn extreme_dates() {
+        round_trip(Date::MIN_YEAR, 1, 1);
+        round_trip(Date::MAX_YEAR, 12, 31);
+        assert_eq!(Date::MIN.ymd(), (Date::MIN_YEAR, 1, 1));
+        assert_eq!(Date::MAX.ymd(), (Date::MAX_YEAR, 12, 31));
+
+        assert_eq!(Date::checked_from_year(Date::MIN_YEAR), Some(Date::MIN));
+        assert_eq!(Date::checked_from_year(Date::MIN_YEAR - 1), None);
+        assert_eq!(Date::checked_from_year(Date::MAX_YEAR + 1), None);
+        assert_eq!(Date::checked_from_year(i64::MAX), None);
+        assert_eq!(Date::checked_from_year(i64::MIN), None);
+        assert_eq!(Date::saturating_from_year(i64::MAX), Date::MAX);
+        assert_eq!(Date::saturating_from_year(i64::MIN), Date::MIN);
+        assert_eq!(Date::from(i64::MAX), Date::MAX);
+        assert_eq!(Date::from(i64::MIN), Date::MIN);
+
+        assert_eq!(Date::MAX.checked_add_days(1), None);
+        assert_eq!(Date::MIN.checked_add_days(-1), None);
+        assert_eq!(Date::MAX.checked_add_days(-1), Some(Date(Date::MAX.0 - 1)));
+        assert_eq!(Date::MAX.saturating_add_days(i64::MAX), Date::MAX);
+        assert_eq!(Date::MIN.saturating_add_days(i64::MIN), Date::MIN);
+        assert_eq!(Date(0).saturating_add_days(i64::MAX), Date::MAX);
+        assert_eq!(Date(0).saturating_add_days(i64::MIN), Date::MIN);
+    }
+
+    #[test]
+    fn day_differences() {
+        let christmas = Date::from_ymd(2025, 12, 25);
+        let new_year = Date::from_ymd(2026, 1, 1);
+        let week = Duration::from_secs(7 * 86400);
+
+        assert_eq!(new_year.signed_days_since(&christmas), 7);
+        assert_eq!(christmas.signed_days_since(&new_year), -7);
+        assert_eq!(new_year.checked_signed_days_since(&christmas), Some(7));
+        assert_eq!(christmas.checked_signed_days_since(&new_year), Some(-7));
+        assert_eq!(new_year.abs_days_between(&christmas), 7);
+        assert_eq!(christmas.abs_days_between(&new_year), 7);
+        assert_eq!(new_year.duration_since(&christmas), Ok(week));
+        assert_eq!(christmas.duration_since(&new_year), Err(week));
+        assert_eq!(new_year.checked_duration_since(&christmas), Some(week));
+        assert_eq!(christmas.checked_duration_since(&new_year), None);
+
+        // equal dates are zero apart in either direction
+        assert_eq!(christmas.signed_days_since(&christmas), 0);
+        assert_eq!(christmas.checked_signed_days_since(&christmas), Some(0));
+        assert_eq!(christmas.abs_days_between(&christmas), 0);
+        assert_eq!(christmas.duration_since(&christmas), Ok(Duration::ZERO));
+        assert_eq!(
+            christmas.checked_duration_since(&christmas),
+            Some(Duration::ZERO)
+        );
+
+        // differences agree with day arithmetic
+        for days in [-400, -1, 1, 59, 366, 146097] {
+            let other = christmas.saturating_add_days(days);
+            assert_eq!(other.signed_days_since(&christmas), days);
+            assert_eq!(christmas.signed_days_since(&other), -days);
+            assert_eq!(other.abs_days_between(&christmas), days.unsigned_abs());
+        }
+
+        #[allow(deprecated)]
+        {
+            assert_eq!(new_year.days_since(&christmas), Ok(7));
+            assert_eq!(christmas.days_since(&new_year), Err(7));
+            assert_eq!(christmas.days_since(&christmas), Err(0));
+        }
+    }
+
+    #[test]
+    fn extreme_day_differences() {
+        let span = Date::MAX.0.abs_diff(Date::MIN.0);
+        assert_eq!(Date::MAX.abs_days_between(&Date::MIN), span);
+        assert_eq!(Date::MIN.abs_days_between(&Date::MAX), span);
+
+        assert_eq!(Date::MAX.checked_signed_days_since(&Date::MIN), None);
+        assert_eq!(Date::MIN.checked_signed_days_since(&Date::MAX), None);
+        assert_eq!(Date::MAX.signed_days_since(&Date::MIN), i64::MAX);
+        assert_eq!(Date::MIN.signed_days_since(&Date::MAX), i64::MIN);
+        assert_eq!(
+            Date::MAX.checked_signed_days_since(&Date(0)),
+            Some(Date::MAX.0)
+        );
+        assert_eq!(
+            Date::MIN.checked_signed_days_since(&Date(0)),
+            Some(Date::MIN.0)
+        );
+
+        assert_eq!(Date::MAX.checked_duration_since(&Date::MIN), None);
+        assert_eq!(Date::MAX.duration_since(&Date::MIN), Ok(Duration::MAX));
+        assert_eq!(Date::MIN.duration_since(&Date::MAX), Err(Duration::MAX));
+        assert_eq!(Date::MAX.duration_since(&Date::MAX), Ok(Duration::ZERO));
+        assert_eq!(
+            Date::MIN.checked_duration_since(&Date::MIN),
+            Some(Duration::ZERO)
+        );
+
+        // largest difference that still fits a duration
+        let days = (u64::MAX / 86400) as i64;
+        let later = Date(0).saturating_add_days(days);
+        let expected = Duration::from_secs(days as u64 * 86400);
+        assert_eq!(later.checked_duration_since(&Date(0)), Some(expected));
+        let later = later.saturating_add_days(1);
+        assert_eq!(later.checked_duration_since(&Date(0)), None);
+        assert_eq!(Date(0).duration_since(&later), Err(Duration::MAX));
+    }
+
+    #[test]
+    fn invalid_components() {
+        // these are rejected in all builds, see `ndebug` profile
+        for (y, m, d) in [
+            (2025, 0, 1),
+            (2025, 13, 1),
+            (2025, 1, 0),
+            (2025, 2, 29),
+            (2025, 4, 31),
+            (2025, 1, 32),
+            (2025, u8::MAX, u8::MAX),
+            (Date::MAX_YEAR + 1, 1, 1),
+            (Date::MIN_YEAR - 1, 12, 31),
+            (i64::MAX, 12, 31),
+            (i64::MIN, 1, 1),
+        ] {
+            assert_eq!(Date::from_ymd_opt(y, m, d), None, "{y}-{m}-{d}");
+            let panicked = std::panic::catch_unwind(|| Date::from_ymd(y, m, d)).is_err();
+            assert!(panicked, "{y}-{m}-{d}");
+        }
+
+        for extreme in [
+            "25252734927766554-01-01",
+            "-25252734927764585-12-31",
+            "9223372036854775807-12-31",
+            "-9223372036854775808-01-01",
+        ] {
+            assert_eq!(extreme.parse::<Date>(), Err(ParseDateError), "{extreme:?}");
+        }
+    }
+
+    #[test]
+    fn extreme_queries() {
+        // none of these should overflow
+        let _ = crate::get_holidays(Any, i64::MIN..i64::MAX).count();
+        let _ = crate::get_holidays(Any, i64::MIN..=i64::MAX).count();
+        let _ = crate::get_holidays(Any, ..=i64::MAX).count();
+        let _ = crate::get_holidays(Any, i64::MIN..).count();
+        let _ = crate::get_holidays(Any, Date::MIN..=Date::MAX).count();
+        let _ = crate::get_holidays(Any, ..=Date::MAX).count();
+        let _ = crate::get_holidays(
+            Any,
+            (
+                std::ops::Bound::Excluded(Date::MIN),
+                std::ops::Bound::Unbounded,
+            ),
+        )
+        .count();
+
+        assert_eq!(crate::get_holidays(Any, i64::MAX..i64::MAX).count(), 0);
+        assert_eq!(crate::get_holidays(Any, i64::MIN..i64::MIN).count(), 0);
+        assert_eq!(crate::get_holidays(Any, i64::MAX).count(), 0);
+        assert_eq!(crate::get_holidays(Any, i64::MIN).count(), 0);
+        assert_eq!(crate::get_holidays(Any, Date::MAX..=Date::MAX).count(), 0);
+        assert_eq!(crate::get_holidays(Any, Date::MAX).count(), 0);
+        assert_eq!(crate::get_holidays(Any, Date::MIN).count(), 0);
+        assert_eq!(crate::within(Any, Date::MAX, u32::MAX).count(), 0);
+        assert_eq!(
+            crate::bounding_in(Country::US, Date::MAX).next(),
+            Some((Country::US, None))
+        );
+    }
+
+    #[test]
+    fn civil_dates() {
+        use chrono::Datelike;
+
+        for holiday in crate::data::DATA.iter().step_by(97) {
+            let civil = holiday.civil_date();
+            assert_eq!(Date::from(civil), holiday.date);
+            assert_eq!(civil.to_string().parse(), Ok(civil));
+            assert_eq!(civil.to_string(), holiday.display_date().to_string());
+
+            let (y, m, d) = holiday.date.ymd();
+            let expected = chrono::NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).unwrap();
+            assert_eq!(
+                (civil.year(), civil.month() as u32, civil.day() as u32),
+                (expected.year() as i64, expected.month(), expected.day())
+            );
+            #[cfg(feature = "chrono")]
+            assert_eq!(chrono::NaiveDate::try_from(civil), Ok(expected));
+            assert_eq!(SystemTime::try_from(civil), holiday.date::<SystemTime>());
+        }
+
+        let min = CivilDate::from(Date::MIN);
+        let max = CivilDate::from(Date::MAX);
+        assert_eq!(CivilDate::new(Date::MIN_YEAR, 1, 1), Some(min));
+        assert_eq!(max.to_string().parse(), Ok(max));
+        assert!(min < CivilDate::new(-1, 12, 31).unwrap());
+        assert!(CivilDate::new(2025, 1, 31).unwrap() < CivilDate::new(2025, 2, 1).unwrap());
+        assert_eq!(CivilDate::new(2025, 2, 29), None);
+        assert_eq!(CivilDate::new(Date::MAX_YEAR + 1, 1, 1), None);
+        assert_eq!("2025-02-29".parse::<CivilDate>(), Err(ParseDateError));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn civil_date_serde() {
+        let date = CivilDate::new(2025, 12, 25).unwrap();
+        assert_eq!(serde_json::to_string(&date).unwrap(), "\"2025-12-25\"");
+        assert_eq!(
+            serde_json::from_str::<CivilDate>("\"2025-12-25\"").unwrap(),
+            date
+        );
+        assert!(serde_json::from_str::<CivilDate>("\"2025-13-01\"").is_err());
+    }
+
+    #[test]
+    fn parse_iso_dates() {
+        assert_eq!("2025-12-25".parse(), Ok(Date::from_ymd(2025, 12, 25)));
+        assert_eq!("2024-02-29".parse(), Ok(Date::from_ymd(2024, 2, 29)));
+        assert_eq!("-0044-03-15".parse(), Ok(Date::from_ymd(-44, 3, 15)));
+        assert_eq!("+12025-01-01".parse(), Ok(Date::from_ymd(12025, 1, 1)));
+
+        for invalid in [
+            "",
+            "2025",
+            "2025-12",
+            "2025-12-25-",
+            "2025-13-01",
+            "2025-00-10",
+            "2025-02-29",
+            "2025-04-31",
+            "2025-1-01",
+            "25-01-01",
+            "2025-01-01T00:00",
+            "2025/01/01",
+            "--2025-01-01",
+            "2025-+1-01",
+            "99999999999999999999-01-01",
+        ] {
+            assert_eq!(invalid.parse::<Date>(), Err(ParseDateError), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn try_date_ext_type_interface() {
+        // This test pins down type interface requirements of TryDateExt.
+        // It's failing if it doesn't compile.
+
+        let country_opt: Option<Country> = None;
+        let owned = String::from("2025-12-25");
+
+        let _ = "2025-12-25".holidays_str(Any);
+        let _ = "2025-12-25".holidays_str(country_opt);
+        let _ = "2025-12-25".holidays_str(Country::US);
+        let _ = "2025-12-25".holidays_str(&[Country::US, Country::JP]);
+        let _ = owned.holidays_str([Country::US, Country::JP]);
+        let _ = owned.holidays_str(vec![Country::DE, Country::HR]);
+
+        let _ = ("2025-01-01".."2025-02-01").holidays_str(Country::US);
+        let _ = ("2025-01-01"..="2025-02-01").holidays_str(Country::US);
+        let _ = ("2025-01-01"..).holidays_str(Country::US);
+        let _ = (.."2025-02-01").holidays_str(Country::US);
+        let _ = (..="2025-02-01").holidays_str(Country::US);
+        let _ = (owned.clone()..owned.clone()).holidays_str(Country::US);
+    }
+
+    #[test]
+    fn try_date_ext() {
+        assert_eq!("2025-12-25".is_holiday_str(Country::DE), Ok(true));
+        assert_eq!("2025-12-23".is_holiday_str(Country::DE), Ok(false));
+        assert_eq!(
+            "2025-12-32".is_holiday_str(Country::DE),
+            Err(ParseDateError)
+        );
+        assert_eq!(
+            ("2025-12-01".."2026-01-01")
+                .holidays_str(Country::DE)
+                .map(Iterator::count),
+            Ok(2)
+        );
+        assert_eq!(
+            ("2025-12-01".."2026-13-01").is_holiday_str(Country::DE),
+            Err(ParseDateError)
+        );
+        assert_eq!(
+            ("yesterday"..="2026-01-01").is_holiday_str(Country::DE),
+            Err(ParseDateError)
+        );
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(Date::from_ymd(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(Date::from_ymd(2025, 6, 16).weekday(), Weekday::Monday);
+        assert_eq!(Date::from_ymd(2025, 6, 22).weekday(), Weekday::Sunday);
+        assert_eq!(Date::from_ymd(1969, 12, 31).weekday(), Weekday::Wednesday);
+        assert_eq!(Date::from_ymd(1602, 10, 12).weekday(), Weekday::Saturday);
+    }
+
+    #[test]
+    fn month_validation() {
+        assert_eq!(Month::new(1), Some(Month::JANUARY));
+        assert_eq!(Month::new(12), Some(Month::DECEMBER));
+        assert_eq!(Month::new(0), None);
+        assert_eq!(Month::new(13), None);
+        assert_eq!(Month::try_from(13), Err(InvalidMonth(13)));
     }
 }
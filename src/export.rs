@@ -0,0 +1,1452 @@
+//! Streaming export of holidays into common file formats.
+//!
+//! Exporters write each record as soon as it's received and only keep a
+//! constant amount of state, so even the full dataset can be exported with
+//! bounded memory:
+//!
+//! ```
+//! use holidays::export::{self, Csv};
+//!
+//! let mut out = Vec::new();
+//! let count = export::export_query(holidays::get_holidays(holidays::Country::US, 2025..2026), &mut Csv::new(&mut out), None)?;
+//! assert_eq!(count, 11);
+//! # #[cfg(not(feature = "no-names"))]
+//! assert!(out.starts_with(b"date,country,name\n2025-01-01,US,New Year's Day\n"));
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! Other formats can be added by implementing [`Exporter`].
+//!
+//! A [`manifest`] of embedded records can be persisted by
+//! applications caching holidays, to find out which of them changed after
+//! the crate is updated with [`diff_manifests`].
+
+use crate::{Date, Holiday, OnConversionError};
+use std::borrow::Cow;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// Output format that holidays are written in, one record at a time.
+///
+/// ```
+/// use std::io;
+/// use holidays::export::{self, Exporter};
+/// use holidays::{Country, Holiday};
+///
+/// /// Collects names instead of writing them anywhere.
+/// struct Names(Vec<&'static str>);
+///
+/// impl Exporter for Names {
+///     fn write_record(&mut self, holiday: &Holiday) -> io::Result<()> {
+///         self.0.push(holiday.name);
+///         Ok(())
+///     }
+///
+///     fn flush(&mut self) -> io::Result<()> {
+///         Ok(())
+///     }
+///
+///     fn finish(&mut self) -> io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let mut names = Names(Vec::new());
+/// export::export_query(holidays::get_holidays(Country::US, 2025..2026), &mut names, None)?;
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(names.0.first(), Some(&"New Year's Day"));
+/// # Ok::<(), io::Error>(())
+/// ```
+pub trait Exporter {
+    /// Writes a single `holiday` record.
+    fn write_record(&mut self, holiday: &Holiday) -> io::Result<()>;
+
+    /// Flushes records written so far into the underlying writer.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Writes any trailing content required by the format and flushes the
+    /// underlying writer.
+    ///
+    /// No records should be written after this is called.
+    fn finish(&mut self) -> io::Result<()>;
+
+    /// Returns the first and the last date the format can represent.
+    ///
+    /// Records outside of them are handled according to
+    /// [`ExportOptions::with_on_conversion_error`] before they're written.
+    fn date_bounds(&self) -> (Date, Date) {
+        (Date::MIN, Date::MAX)
+    }
+}
+
+impl<E: Exporter + ?Sized> Exporter for &mut E {
+    fn write_record(&mut self, holiday: &Holiday) -> io::Result<()> {
+        (**self).write_record(holiday)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (**self).flush()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        (**self).finish()
+    }
+
+    fn date_bounds(&self) -> (Date, Date) {
+        (**self).date_bounds()
+    }
+}
+
+/// Options of [`export_query_with`].
+///
+/// ```
+/// use holidays::export::{self, ExportOptions, JsonLines};
+/// use holidays::{Any, OnConversionError};
+///
+/// let options = ExportOptions::DEFAULT
+///     .with_flush_interval(100)
+///     .with_progress(Some(|count| eprintln!("{count} records written")))
+///     .with_on_conversion_error(OnConversionError::Skip);
+/// let query = holidays::get_holidays(Any, 2025..2026);
+/// let count = export::export_query_with(query.clone(), JsonLines::new(std::io::sink()), options)?;
+/// assert_eq!(count, query.count());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ExportOptions {
+    flush_interval: usize,
+    progress: Option<fn(usize)>,
+    on_conversion_error: OnConversionError,
+}
+
+impl ExportOptions {
+    /// Flushes every 1024 records, doesn't report progress and fails on
+    /// dates the format can't represent.
+    ///
+    /// ```
+    /// use holidays::export::{self, Csv, ExportOptions};
+    /// use holidays::Country;
+    ///
+    /// let query = holidays::get_holidays(Country::US, 2025..2026);
+    /// let count = export::export_query_with(query, Csv::new(std::io::sink()), ExportOptions::DEFAULT)?;
+    /// assert_eq!(count, 11);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const DEFAULT: ExportOptions = ExportOptions {
+        flush_interval: 1024,
+        progress: None,
+        on_conversion_error: OnConversionError::Fail,
+    };
+
+    /// Sets the number of records written between flushes.
+    ///
+    /// An interval of `0` only flushes once all records are written.
+    ///
+    /// ```
+    /// use holidays::export::{self, Csv, ExportOptions};
+    /// use holidays::Country;
+    ///
+    /// // small intervals let readers of the output see records sooner
+    /// let options = ExportOptions::DEFAULT.with_flush_interval(10);
+    /// let query = holidays::get_holidays(Country::US, 2025..2027);
+    /// let count = export::export_query_with(query.clone(), Csv::new(std::io::sink()), options)?;
+    /// assert_eq!(count, query.count());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn with_flush_interval(mut self, records: usize) -> Self {
+        self.flush_interval = records;
+        self
+    }
+
+    /// Sets a function that's called with the number of records written so
+    /// far after every flush.
+    ///
+    /// ```
+    /// use holidays::export::{self, Csv, ExportOptions};
+    /// use holidays::Country;
+    ///
+    /// let options = ExportOptions::DEFAULT
+    ///     .with_flush_interval(5)
+    ///     .with_progress(Some(|written| println!("{written} holidays exported")));
+    /// export::export_query_with(holidays::get_holidays(Country::US, 2025..2026), Csv::new(std::io::sink()), options)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn with_progress(mut self, progress: Option<fn(usize)>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Sets how records with dates outside of [`Exporter::date_bounds`] are
+    /// handled.
+    ///
+    /// With [`OnConversionError::Fail`], export stops with an
+    /// [`InvalidData`](io::ErrorKind::InvalidData) error wrapping a
+    /// [`DateConversionError`](crate::error::DateConversionError).
+    ///
+    /// ```
+    /// use holidays::export::{self, ExportOptions, Ics};
+    /// use holidays::{Country, OnConversionError};
+    ///
+    /// // iCalendar dates have 4 digit years, so years after 9999 would be skipped
+    /// let options = ExportOptions::DEFAULT.with_on_conversion_error(OnConversionError::Skip);
+    /// let query = holidays::get_holidays(Country::US, 2025..2026);
+    /// let count = export::export_query_with(query, Ics::new(std::io::sink()), options)?;
+    /// assert_eq!(count, 11);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn with_on_conversion_error(mut self, on_error: OnConversionError) -> Self {
+        self.on_conversion_error = on_error;
+        self
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions::DEFAULT
+    }
+}
+
+/// Writes all `holidays` with the `exporter` and finishes it, returning the
+/// number of written records.
+///
+/// Records are flushed periodically, after which `progress` is called with
+/// the number of records written so far. See [`ExportOptions::DEFAULT`] for
+/// the flush interval.
+///
+/// # Errors
+///
+/// Returns the first error returned by the `exporter`. Records written
+/// before the error may be left unflushed.
+///
+/// ```
+/// use holidays::export::{self, JsonLines};
+/// use holidays::Country;
+///
+/// let mut out = Vec::new();
+/// let query = holidays::get_holidays(Country::DE, 2025..2026);
+/// let count = export::export_query(query, JsonLines::new(&mut out), None)?;
+/// assert_eq!(String::from_utf8(out).unwrap().lines().count(), count);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn export_query<'a, E: Exporter>(
+    holidays: impl IntoIterator<Item = &'a Holiday>,
+    exporter: E,
+    progress: Option<fn(usize)>,
+) -> io::Result<usize> {
+    export_query_with(
+        holidays,
+        exporter,
+        ExportOptions::DEFAULT.with_progress(progress),
+    )
+}
+
+/// Like [`export_query`], with custom flush interval and progress reporting.
+///
+/// # Errors
+///
+/// Returns the first error returned by the `exporter`, or an
+/// [`InvalidData`](io::ErrorKind::InvalidData) error for dates the exporter
+/// can't represent, unless they're skipped or clamped.
+///
+/// ```
+/// use holidays::export::{self, ExportOptions, Ics};
+/// use holidays::Country;
+///
+/// let mut out = Vec::new();
+/// let options = ExportOptions::DEFAULT.with_flush_interval(0);
+/// let query = holidays::get_holidays(Country::FR, 2025..2026);
+/// export::export_query_with(query, Ics::new(&mut out), options)?;
+/// assert!(out.ends_with(b"END:VCALENDAR\r\n"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn export_query_with<'a, E: Exporter>(
+    holidays: impl IntoIterator<Item = &'a Holiday>,
+    mut exporter: E,
+    options: ExportOptions,
+) -> io::Result<usize> {
+    let (min, max) = exporter.date_bounds();
+    let mut count = 0;
+    for holiday in holidays {
+        if holiday.date < min || holiday.date > max {
+            match options.on_conversion_error {
+                OnConversionError::Skip => continue,
+                OnConversionError::Fail => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        crate::error::DateConversionError,
+                    ))
+                }
+                OnConversionError::ClampToBounds => {
+                    let clamped = Holiday {
+                        date: holiday.date.clamp(min, max),
+                        ..*holiday
+                    };
+                    exporter.write_record(&clamped)?;
+                }
+            }
+        } else {
+            exporter.write_record(holiday)?;
+        }
+        count += 1;
+        if options.flush_interval != 0 && count % options.flush_interval == 0 {
+            exporter.flush()?;
+            if let Some(progress) = options.progress {
+                progress(count);
+            }
+        }
+    }
+    exporter.finish()?;
+    if let Some(progress) = options.progress {
+        progress(count);
+    }
+    Ok(count)
+}
+
+/// Writes a comment line with the attribution of embedded data, used by
+/// line based formats.
+fn write_attribution_comment<W: Write>(out: &mut W) -> io::Result<()> {
+    writeln!(
+        out,
+        "# Holiday data: {}",
+        crate::dataset_info().attribution()
+    )
+}
+
+/// Comma separated values with a `date,country,name` header.
+///
+/// Names are quoted if they contain commas, quotes or line breaks.
+///
+/// ```
+/// use holidays::export::{self, Csv};
+/// use holidays::Country;
+///
+/// let mut out = Vec::new();
+/// let query = holidays::get_holidays(Country::GB, 2025..2026);
+/// export::export_query(query, Csv::new(&mut out), None)?;
+/// let csv = String::from_utf8(out).unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(csv.lines().any(|it| it == "2025-12-26,GB,Boxing Day"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Csv<W: Write> {
+    out: W,
+    header_written: bool,
+    attribution: bool,
+}
+
+impl<W: Write> Csv<W> {
+    /// Constructs an exporter that writes into `out`.
+    ///
+    /// ```
+    /// use holidays::export::{self, Csv};
+    /// use holidays::Country;
+    ///
+    /// let mut csv = Csv::new(Vec::new());
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut csv, None)?;
+    /// assert!(csv.into_inner().starts_with(b"date,country,name\n"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn new(out: W) -> Self {
+        Csv {
+            out,
+            header_written: false,
+            attribution: false,
+        }
+    }
+
+    /// Sets whether a `# Holiday data: ...` comment line with the
+    /// [attribution](crate::Attribution) of embedded data is written before
+    /// the header.
+    ///
+    /// Readers need to skip comment lines, e.g. with
+    /// `csv::ReaderBuilder::comment`.
+    ///
+    /// ```
+    /// use holidays::export::{self, Csv};
+    /// use holidays::Country;
+    ///
+    /// let mut csv = Csv::new(Vec::new()).with_attribution(true);
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut csv, None)?;
+    /// let out = String::from_utf8(csv.into_inner()).unwrap();
+    /// assert!(out.starts_with("# Holiday data: "));
+    /// assert_eq!(out.lines().nth(1), Some("date,country,name"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn with_attribution(mut self, enabled: bool) -> Self {
+        self.attribution = enabled;
+        self
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// ```
+    /// use holidays::export::{self, Csv};
+    /// use holidays::Country;
+    ///
+    /// let mut csv = Csv::new(Vec::new());
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut csv, None)?;
+    /// let out = csv.into_inner();
+    /// assert_eq!(out.iter().filter(|it| **it == b'\n').count(), 12);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.header_written {
+            if self.attribution {
+                write_attribution_comment(&mut self.out)?;
+            }
+            self.out.write_all(b"date,country,name\n")?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Exporter for Csv<W> {
+    fn write_record(&mut self, holiday: &Holiday) -> io::Result<()> {
+        self.write_header()?;
+        self.out.write_all(holiday.iso_date_buf().as_bytes())?;
+        write!(self.out, ",{},", holiday.code)?;
+        if holiday.name.contains([',', '"', '\n', '\r']) {
+            self.out.write_all(b"\"")?;
+            for (i, part) in holiday.name.split('"').enumerate() {
+                if i != 0 {
+                    self.out.write_all(b"\"\"")?;
+                }
+                self.out.write_all(part.as_bytes())?;
+            }
+            self.out.write_all(b"\"\n")
+        } else {
+            writeln!(self.out, "{}", holiday.name)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.write_header()?;
+        self.out.flush()
+    }
+}
+
+/// JSON Lines, with a `{"date": ..., "country": ..., "name": ...}` object on
+/// each line.
+///
+/// ```
+/// use holidays::export::{self, JsonLines};
+/// use holidays::Country;
+///
+/// let mut out = Vec::new();
+/// let july_4th = holidays::Date::from_ymd(2025, 7, 4);
+/// export::export_query(holidays::get_holidays(Country::US, july_4th), JsonLines::new(&mut out), None)?;
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(
+///     String::from_utf8(out).unwrap(),
+///     "{\"date\":\"2025-07-04\",\"country\":\"US\",\"name\":\"Independence Day\"}\n"
+/// );
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct JsonLines<W: Write> {
+    out: W,
+    /// Whether the attribution comment is pending.
+    attribution: bool,
+}
+
+impl<W: Write> JsonLines<W> {
+    /// Constructs an exporter that writes into `out`.
+    ///
+    /// ```
+    /// use holidays::export::{self, JsonLines};
+    /// use holidays::Country;
+    ///
+    /// let mut json = JsonLines::new(Vec::new());
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut json, None)?;
+    /// assert!(json.into_inner().starts_with(b"{\"date\":\"2025-01-01\",\"country\":\"FR\""));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn new(out: W) -> Self {
+        JsonLines {
+            out,
+            attribution: false,
+        }
+    }
+
+    /// Sets whether a `# Holiday data: ...` comment line with the
+    /// [attribution](crate::Attribution) of embedded data is written before
+    /// the first record.
+    ///
+    /// Comments aren't part of JSON Lines, so readers need to skip lines
+    /// starting with `#`.
+    ///
+    /// ```
+    /// use holidays::export::{self, JsonLines};
+    /// use holidays::Country;
+    ///
+    /// let mut json = JsonLines::new(Vec::new()).with_attribution(true);
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut json, None)?;
+    /// let out = String::from_utf8(json.into_inner()).unwrap();
+    /// assert!(out.lines().next().is_some_and(|it| it.starts_with('#')));
+    /// assert!(out.lines().skip(1).all(|it| it.starts_with('{')));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn with_attribution(mut self, enabled: bool) -> Self {
+        self.attribution = enabled;
+        self
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// ```
+    /// use holidays::export::{self, JsonLines};
+    /// use holidays::Country;
+    ///
+    /// let mut json = JsonLines::new(Vec::new());
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut json, None)?;
+    /// assert_eq!(String::from_utf8(json.into_inner()).unwrap().lines().count(), 11);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if std::mem::take(&mut self.attribution) {
+            write_attribution_comment(&mut self.out)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Exporter for JsonLines<W> {
+    fn write_record(&mut self, holiday: &Holiday) -> io::Result<()> {
+        self.write_header()?;
+        self.out.write_all(b"{\"date\":\"")?;
+        self.out.write_all(holiday.iso_date_buf().as_bytes())?;
+        write!(self.out, "\",\"country\":\"{}\",\"name\":\"", holiday.code)?;
+        for c in holiday.name.chars() {
+            match c {
+                '"' => self.out.write_all(b"\\\"")?,
+                '\\' => self.out.write_all(b"\\\\")?,
+                c if c.is_control() => write!(self.out, "\\u{:04x}", c as u32)?,
+                c => write!(self.out, "{c}")?,
+            }
+        }
+        self.out.write_all(b"\"}\n")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.write_header()?;
+        self.out.flush()
+    }
+}
+
+/// iCalendar (RFC 5545) calendar with an all-day event for each holiday.
+///
+/// Event summaries are formatted as `CODE: Name`, and event UIDs are
+/// [`Holiday::uid`] followed by `@holidays-rs`.
+///
+/// ```
+/// use holidays::export::{self, Ics};
+/// use holidays::Country;
+///
+/// let mut out = Vec::new();
+/// let query = holidays::get_holidays(Country::US, 2025..2026);
+/// export::export_query(query, Ics::new(&mut out), None)?;
+/// let calendar = String::from_utf8(out).unwrap();
+/// assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 11);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(calendar.contains("SUMMARY:US: Independence Day"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Ics<W: Write> {
+    out: W,
+    record_count: usize,
+    attribution: bool,
+}
+
+impl<W: Write> Ics<W> {
+    /// Constructs an exporter that writes into `out`.
+    ///
+    /// ```
+    /// use holidays::export::{self, Ics};
+    /// use holidays::Country;
+    ///
+    /// let mut ics = Ics::new(Vec::new());
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut ics, None)?;
+    /// let calendar = String::from_utf8(ics.into_inner()).unwrap();
+    /// assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+    /// assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn new(out: W) -> Self {
+        Ics {
+            out,
+            record_count: 0,
+            attribution: false,
+        }
+    }
+
+    /// Sets whether the calendar has an `X-HOLIDAYS-ATTRIBUTION` property
+    /// with the [attribution](crate::Attribution) of embedded data, which
+    /// calendar applications ignore.
+    ///
+    /// ```
+    /// use holidays::export::{self, Ics};
+    /// use holidays::Country;
+    ///
+    /// let mut ics = Ics::new(Vec::new()).with_attribution(true);
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut ics, None)?;
+    /// let calendar = String::from_utf8(ics.into_inner()).unwrap();
+    /// assert!(calendar.contains("X-HOLIDAYS-ATTRIBUTION:"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub const fn with_attribution(mut self, enabled: bool) -> Self {
+        self.attribution = enabled;
+        self
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// ```
+    /// use holidays::export::{self, Ics};
+    /// use holidays::Country;
+    ///
+    /// let mut ics = Ics::new(Vec::new());
+    /// export::export_query(holidays::get_holidays(Country::FR, 2025..2026), &mut ics, None)?;
+    /// let calendar = String::from_utf8(ics.into_inner()).unwrap();
+    /// assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 11);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        self.out.write_all(
+            b"BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//holidays-rs//holidays//EN\r\n",
+        )?;
+        if self.attribution {
+            const PREFIX: &str = "X-HOLIDAYS-ATTRIBUTION:";
+            self.out.write_all(PREFIX.as_bytes())?;
+            let attribution = crate::dataset_info().attribution().to_string();
+            self.write_text(PREFIX.len(), &attribution)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `SUMMARY` content line.
+    fn write_summary(&mut self, holiday: &Holiday) -> io::Result<()> {
+        // country codes are ASCII letters, so the prefix is 12 bytes long
+        write!(self.out, "SUMMARY:{}: ", holiday.code)?;
+        self.write_text(12, holiday.name)
+    }
+
+    /// Writes the rest of a content line that's `line_len` bytes long so far,
+    /// escaping `text` and folding it into lines of at most 75 bytes.
+    fn write_text(&mut self, mut line_len: usize, text: &str) -> io::Result<()> {
+        let mut buf = [0; 4];
+        for c in text.chars() {
+            let escaped = match c {
+                '\\' => "\\\\",
+                ';' => "\\;",
+                ',' => "\\,",
+                '\n' => "\\n",
+                '\r' => continue,
+                c => c.encode_utf8(&mut buf),
+            };
+            if line_len + escaped.len() > 75 {
+                self.out.write_all(b"\r\n ")?;
+                line_len = 1;
+            }
+            self.out.write_all(escaped.as_bytes())?;
+            line_len += escaped.len();
+        }
+        self.out.write_all(b"\r\n")
+    }
+}
+
+impl<W: Write> Exporter for Ics<W> {
+    fn write_record(&mut self, holiday: &Holiday) -> io::Result<()> {
+        if self.record_count == 0 {
+            self.write_header()?;
+        }
+        self.record_count += 1;
+
+        // UIDs are derived from records rather than their position in the
+        // export, so subscribers of the calendar see the same events after
+        // dataset updates; long ones are folded like any other text
+        self.out.write_all(b"BEGIN:VEVENT\r\nUID:")?;
+        self.write_text(4, &format!("{}@holidays-rs", holiday.uid()))?;
+
+        let (y, m, d) = holiday.date.ymd();
+        let (next_y, next_m, next_d) = holiday.date.saturating_add_days(1).ymd();
+        write!(
+            self.out,
+            "DTSTAMP:{y:04}{m:02}{d:02}T000000Z\r\n\
+             DTSTART;VALUE=DATE:{y:04}{m:02}{d:02}\r\n\
+             DTEND;VALUE=DATE:{next_y:04}{next_m:02}{next_d:02}\r\n",
+        )?;
+        self.write_summary(holiday)?;
+        self.out.write_all(b"END:VEVENT\r\n")
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if self.record_count == 0 {
+            self.write_header()?;
+        }
+        self.out.write_all(b"END:VCALENDAR\r\n")?;
+        self.out.flush()
+    }
+
+    fn date_bounds(&self) -> (Date, Date) {
+        // dates have 4 digit years, and events end on the following day
+        (Date::from_ymd(0, 1, 1), Date::from_ymd(9999, 12, 30))
+    }
+}
+
+/// Version of the manifest format written by [`manifest`].
+///
+/// Manifests of older versions stay readable by [`diff_manifests`], the
+/// version is only bumped on changes to the format itself.
+///
+/// ```
+/// use holidays::export;
+///
+/// let mut manifest = Vec::new();
+/// export::manifest(&mut manifest)?;
+/// let header = format!("holidays-manifest {}", export::MANIFEST_VERSION);
+/// assert!(manifest.starts_with(header.as_bytes()));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub const MANIFEST_VERSION: u32 = 1;
+
+const MANIFEST_MAGIC: &str = "holidays-manifest";
+
+/// A record of a manifest, as written by [`manifest`].
+///
+/// Fields are kept as text, so records of countries that aren't enabled in
+/// the current build can still be compared.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::export::ManifestRecord;
+/// use holidays::Country;
+///
+/// let holiday = holidays::get_holidays(Country::DE, Date::from_ymd(2025, 10, 3)).next().unwrap();
+/// let record = ManifestRecord::from(holiday);
+/// assert_eq!((record.country.as_str(), record.date.as_str()), ("DE", "2025-10-03"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ManifestRecord {
+    /// Two-letter code of the country.
+    pub country: String,
+    /// Date in `YYYY-MM-DD` format.
+    pub date: String,
+    /// Name of the holiday, empty with `no-names` feature.
+    pub name: String,
+}
+
+impl From<&Holiday> for ManifestRecord {
+    fn from(holiday: &Holiday) -> Self {
+        ManifestRecord {
+            country: holiday.code.to_string(),
+            date: holiday.display_date().to_string(),
+            name: holiday.name.to_string(),
+        }
+    }
+}
+
+/// Differences between two manifests, returned by [`diff_manifests`].
+///
+/// All lists are in canonical order of records, i.e. by country, date and
+/// name.
+///
+/// ```
+/// use holidays::export;
+///
+/// let mut persisted = Vec::new();
+/// export::manifest(&mut persisted)?;
+/// let mut current = Vec::new();
+/// export::manifest(&mut current)?;
+///
+/// let diff = export::diff_manifests(persisted.as_slice(), current.as_slice())?;
+/// for (old, new) in &diff.renamed {
+///     println!("{} {}: {} is now {}", new.country, new.date, old.name, new.name);
+/// }
+/// assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.is_empty());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestDiff {
+    /// Records only present in the new manifest.
+    pub added: Vec<ManifestRecord>,
+    /// Records only present in the old manifest.
+    pub removed: Vec<ManifestRecord>,
+    /// Pairs of old and new records with the same country and date, but a
+    /// different name.
+    pub renamed: Vec<(ManifestRecord, ManifestRecord)>,
+}
+
+impl ManifestDiff {
+    /// Returns `true` if manifests contain the same records.
+    ///
+    /// ```
+    /// use holidays::export::{ManifestDiff, ManifestRecord};
+    ///
+    /// let mut diff = ManifestDiff::default();
+    /// assert!(diff.is_empty());
+    /// diff.added.push(ManifestRecord {
+    ///     country: "US".to_string(),
+    ///     date: "2025-07-04".to_string(),
+    ///     name: "Independence Day".to_string(),
+    /// });
+    /// assert!(!diff.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.renamed.is_empty()
+    }
+}
+
+/// FNV-1a hash of manifest record lines, which unlike [`std::hash`] hashers
+/// is guaranteed to stay the same between builds.
+#[derive(Debug, Clone, Copy)]
+struct ManifestHash(u64);
+
+impl ManifestHash {
+    const fn new() -> Self {
+        ManifestHash(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+fn escape_field(value: &str) -> Cow<'_, str> {
+    if !value.contains(['\\', '\t', '\n', '\r']) {
+        return Cow::Borrowed(value);
+    }
+    let mut result = String::with_capacity(value.len() + 2);
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            c => result.push(c),
+        }
+    }
+    Cow::Owned(result)
+}
+
+fn unescape_field(value: &str) -> io::Result<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        result.push(match chars.next() {
+            Some('\\') => '\\',
+            Some('t') => '\t',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            _ => return Err(invalid_manifest("invalid escape sequence")),
+        });
+    }
+    Ok(result)
+}
+
+fn invalid_manifest(reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid manifest: {reason}"),
+    )
+}
+
+/// Writes `records` into `out` in canonical order, returning their number.
+fn write_manifest<'a, W: Write>(
+    records: impl IntoIterator<Item = &'a Holiday>,
+    mut out: W,
+) -> io::Result<usize> {
+    let mut records: Vec<&Holiday> = records.into_iter().collect();
+    records.sort_unstable_by(|a, b| {
+        (a.code.as_ref(), a.date, a.name).cmp(&(b.code.as_ref(), b.date, b.name))
+    });
+
+    writeln!(out, "{MANIFEST_MAGIC} {MANIFEST_VERSION}")?;
+    writeln!(out, "records {}", records.len())?;
+    let mut hash = ManifestHash::new();
+    let mut line = String::new();
+    for holiday in &records {
+        line.clear();
+        {
+            use std::fmt::Write;
+            // writing into a `String` can't fail
+            let _ = writeln!(
+                line,
+                "{}\t{}\t{}",
+                holiday.code,
+                holiday.display_date(),
+                escape_field(holiday.name)
+            );
+        }
+        hash.update(line.as_bytes());
+        out.write_all(line.as_bytes())?;
+    }
+    writeln!(out, "fnv1a64 {:016x}", hash.0)?;
+    out.flush()?;
+    Ok(records.len())
+}
+
+/// Writes a manifest of all embedded records into `out`, returning the
+/// number of written records.
+///
+/// Manifests are line based text, starting with a header that contains
+/// [`MANIFEST_VERSION`] and the number of records. Each record is written
+/// on its own line as tab separated country code, ISO date and name, in
+/// order of country codes, dates and names. A hash of record lines follows
+/// them, so manifests corrupted or edited since are rejected by
+/// [`diff_manifests`]. Output is the same for the same data, regardless of
+/// the platform or the order of enabled features.
+///
+/// ```
+/// use holidays::export;
+///
+/// let mut out = Vec::new();
+/// let count = export::manifest(&mut out)?;
+/// assert!(count > 0);
+/// assert!(out.starts_with(b"holidays-manifest 1\n"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns errors returned by `out`.
+pub fn manifest<W: Write>(out: W) -> io::Result<usize> {
+    write_manifest(crate::data::DATA, out)
+}
+
+/// Reads and validates records of a manifest written by [`manifest`].
+fn read_manifest(input: impl Read) -> io::Result<Vec<ManifestRecord>> {
+    let mut lines = BufReader::new(input).lines();
+    let mut next_line = || {
+        lines
+            .next()
+            .unwrap_or_else(|| Err(invalid_manifest("unexpected end")))
+    };
+
+    let header = next_line()?;
+    let version = header
+        .strip_prefix(MANIFEST_MAGIC)
+        .and_then(|it| it.strip_prefix(' '))
+        .and_then(|it| it.parse::<u32>().ok())
+        .ok_or_else(|| invalid_manifest("missing header"))?;
+    if version == 0 || version > MANIFEST_VERSION {
+        return Err(invalid_manifest(&format!("unsupported version {version}")));
+    }
+    let count = next_line()?
+        .strip_prefix("records ")
+        .and_then(|it| it.parse::<usize>().ok())
+        .ok_or_else(|| invalid_manifest("missing record count"))?;
+
+    let mut hash = ManifestHash::new();
+    // count is untrusted until the hash is verified
+    let mut records = Vec::with_capacity(count.min(1 << 16));
+    for _ in 0..count {
+        let line = next_line()?;
+        hash.update(line.as_bytes());
+        hash.update(b"\n");
+        let mut fields = line.split('\t');
+        let (Some(country), Some(date), Some(name), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid_manifest("malformed record"));
+        };
+        records.push(ManifestRecord {
+            country: country.to_string(),
+            date: date.to_string(),
+            name: unescape_field(name)?,
+        });
+    }
+
+    let expected = next_line()?
+        .strip_prefix("fnv1a64 ")
+        .and_then(|it| u64::from_str_radix(it, 16).ok())
+        .ok_or_else(|| invalid_manifest("missing hash"))?;
+    if expected != hash.0 {
+        return Err(invalid_manifest("hash mismatch"));
+    }
+    if next_line().is_ok() {
+        return Err(invalid_manifest("trailing content"));
+    }
+    records.sort_unstable();
+    Ok(records)
+}
+
+/// Compares manifests written by [`manifest`] in different versions of the
+/// crate, e.g. one persisted by an application with its cached holidays and
+/// one of the current build.
+///
+/// Records with the same country and date are matched by name; unmatched
+/// ones of the same day are reported as renamed, and any remaining ones as
+/// added or removed.
+///
+/// ```
+/// use holidays::export;
+///
+/// let mut old = Vec::new();
+/// export::manifest(&mut old)?;
+/// let mut new = Vec::new();
+/// export::manifest(&mut new)?;
+/// assert!(export::diff_manifests(old.as_slice(), new.as_slice())?.is_empty());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns errors of reading the manifests, and [`InvalidData`] errors if
+/// either of them is malformed, of an unsupported version, or its contents
+/// don't match its hash.
+///
+/// [`InvalidData`]: io::ErrorKind::InvalidData
+pub fn diff_manifests(old: impl Read, new: impl Read) -> io::Result<ManifestDiff> {
+    let old = read_manifest(old)?;
+    let new = read_manifest(new)?;
+
+    let mut diff = ManifestDiff::default();
+    let (mut old, mut new) = (old.as_slice(), new.as_slice());
+    fn day(record: &ManifestRecord) -> (&str, &str) {
+        (&record.country, &record.date)
+    }
+    while !old.is_empty() || !new.is_empty() {
+        // records of the first remaining day in either manifest
+        let key = match (old.first(), new.first()) {
+            (Some(a), Some(b)) => day(a).min(day(b)),
+            (Some(a), None) => day(a),
+            (None, Some(b)) => day(b),
+            (None, None) => unreachable!(),
+        };
+        let old_len = old.iter().take_while(|it| day(it) == key).count();
+        let new_len = new.iter().take_while(|it| day(it) == key).count();
+        let (old_day, new_day) = (&old[..old_len], &new[..new_len]);
+        old = &old[old_len..];
+        new = &new[new_len..];
+
+        let removed = old_day.iter().filter(|it| !new_day.contains(it));
+        let mut added = new_day.iter().filter(|it| !old_day.contains(it));
+        for record in removed {
+            match added.next() {
+                Some(renamed) => diff.renamed.push((record.clone(), renamed.clone())),
+                None => diff.removed.push(record.clone()),
+            }
+        }
+        diff.added.extend(added.cloned());
+    }
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+    use crate::{Any, Country};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Writer that only counts written bytes and flushes.
+    #[derive(Default)]
+    struct Counter {
+        bytes: usize,
+        flushes: usize,
+    }
+
+    impl Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.bytes += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    fn export_all<E: Exporter>(exporter: E, interval: usize) -> usize {
+        let options = ExportOptions::DEFAULT.with_flush_interval(interval);
+        export_query_with(
+            crate::get_holidays::<_, Date, _>(Any, Any),
+            exporter,
+            options,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn full_dataset_is_streamed() {
+        let total = crate::data::DATA.len();
+
+        let mut csv = Csv::new(Counter::default());
+        assert_eq!(export_all(&mut csv, 1000), total);
+        assert_eq!(csv.out.flushes, total / 1000 + 1);
+
+        let mut jsonl = JsonLines::new(Counter::default());
+        assert_eq!(export_all(&mut jsonl, 0), total);
+        assert_eq!(jsonl.out.flushes, 1);
+
+        let mut ics = Ics::new(Counter::default());
+        assert_eq!(export_all(&mut ics, 4096), total);
+        assert_eq!(ics.record_count, total);
+        assert!(ics.out.bytes > jsonl.out.bytes && jsonl.out.bytes > csv.out.bytes);
+
+        // exporters don't accumulate records, their state is constant
+        assert!(std::mem::size_of::<Csv<io::Sink>>() <= std::mem::size_of::<usize>());
+        assert!(std::mem::size_of::<JsonLines<io::Sink>>() <= std::mem::size_of::<usize>());
+        // a record counter and options
+        assert!(std::mem::size_of::<Ics<io::Sink>>() <= 2 * std::mem::size_of::<usize>());
+    }
+
+    #[test]
+    fn progress_is_reported() {
+        static LAST: AtomicUsize = AtomicUsize::new(0);
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn progress(count: usize) {
+            assert!(count >= LAST.swap(count, Ordering::Relaxed));
+            CALLS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let holidays = crate::get_holidays(Country::US, 2020..2030);
+        let count = holidays.clone().count();
+        let options = ExportOptions::DEFAULT
+            .with_flush_interval(10)
+            .with_progress(Some(progress));
+        assert_eq!(
+            export_query_with(holidays, Csv::new(io::sink()), options).unwrap(),
+            count
+        );
+        assert_eq!(LAST.load(Ordering::Relaxed), count);
+        assert_eq!(CALLS.load(Ordering::Relaxed), count / 10 + 1);
+    }
+
+    #[test]
+    fn formats() {
+        let holidays = [
+            Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day"),
+            Holiday::new(
+                Country::GB,
+                Date::from_ymd(2025, 12, 31),
+                "Quoted \"Eve\", Part\\2",
+            ),
+        ];
+
+        let mut csv = Csv::new(Vec::new());
+        export_query(&holidays, &mut csv, None).unwrap();
+        assert_eq!(
+            String::from_utf8(csv.into_inner()).unwrap(),
+            "date,country,name\n2025-07-04,US,Independence Day\n2025-12-31,GB,\"Quoted \"\"Eve\"\", Part\\2\"\n"
+        );
+
+        let mut jsonl = JsonLines::new(Vec::new());
+        export_query(&holidays, &mut jsonl, None).unwrap();
+        assert_eq!(
+            String::from_utf8(jsonl.into_inner()).unwrap(),
+            "{\"date\":\"2025-07-04\",\"country\":\"US\",\"name\":\"Independence Day\"}\n\
+             {\"date\":\"2025-12-31\",\"country\":\"GB\",\"name\":\"Quoted \\\"Eve\\\", Part\\\\2\"}\n"
+        );
+
+        let mut ics = Ics::new(Vec::new());
+        export_query(&holidays, &mut ics, None).unwrap();
+        let ics = String::from_utf8(ics.into_inner()).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20251231\r\nDTEND;VALUE=DATE:20260101\r\n"));
+        assert!(ics.contains("SUMMARY:GB: Quoted \"Eve\"\\, Part\\\\2\r\n"));
+        assert!(ics.contains("UID:US-20250704-independence-day@holidays-rs\r\n"));
+        assert!(ics.contains("UID:GB-20251231-quoted-eve-part-2@holidays-rs\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+    }
+
+    #[test]
+    fn ics_uids_are_stable() {
+        let uids = |holidays: &[Holiday]| {
+            let mut ics = Ics::new(Vec::new());
+            export_query(holidays, &mut ics, None).unwrap();
+            let ics = String::from_utf8(ics.into_inner()).unwrap();
+            // unfolds long lines
+            let ics = ics.replace("\r\n ", "");
+            ics.lines()
+                .filter_map(|it| it.strip_prefix("UID:"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        let date = Date::from_ymd(2025, 3, 17);
+        let original = [
+            Holiday::new(Country::MX, date, "Benito Juárez's birthday"),
+            Holiday::new(
+                Country::US,
+                date,
+                "Saint Patrick's Day (observed) with a name too long for a line",
+            ),
+        ];
+        let respelled = [
+            Holiday::new(Country::MX, date, "Benito Juarez’s Birthday"),
+            Holiday::new(
+                Country::US,
+                date,
+                "Saint Patricks Day - observed, with a name too long for a line",
+            ),
+        ];
+        assert_eq!(
+            uids(&original),
+            [
+                "MX-20250317-benito-juarezs-birthday@holidays-rs",
+                "US-20250317-saint-patricks-day-observed-with-a-name-too-long-for-a-line@holidays-rs",
+            ]
+        );
+        assert_eq!(uids(&original), uids(&respelled));
+
+        // exporting a subset doesn't change UIDs of the remaining events
+        assert_eq!(uids(&original[1..]), uids(&original)[1..]);
+    }
+
+    #[test]
+    fn out_of_range_dates() {
+        let holidays = [
+            Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day"),
+            Holiday::new(Country::US, Date::from_ymd(12025, 7, 4), "Far Future Day"),
+        ];
+        let export = |on_error| {
+            let mut ics = Ics::new(Vec::new());
+            let options = ExportOptions::DEFAULT.with_on_conversion_error(on_error);
+            let count = export_query_with(&holidays, &mut ics, options)?;
+            Ok::<_, io::Error>((count, String::from_utf8(ics.into_inner()).unwrap()))
+        };
+
+        let err = export(OnConversionError::Fail).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            ExportOptions::default().on_conversion_error,
+            OnConversionError::Fail
+        );
+
+        let (count, skipped) = export(OnConversionError::Skip).unwrap();
+        assert_eq!(count, 1);
+        assert!(!skipped.contains("Far Future Day"));
+
+        let (count, clamped) = export(OnConversionError::ClampToBounds).unwrap();
+        assert_eq!(count, 2);
+        assert!(clamped.contains("DTSTART;VALUE=DATE:99991230\r\nDTEND;VALUE=DATE:99991231\r\n"));
+        assert!(clamped.contains("SUMMARY:US: Far Future Day"));
+
+        // formats with expanded years write all dates
+        let mut csv = Csv::new(Vec::new());
+        assert_eq!(export_query(&holidays, &mut csv, None).unwrap(), 2);
+        assert!(String::from_utf8(csv.into_inner())
+            .unwrap()
+            .contains("+12025-07-04,US,Far Future Day"));
+    }
+
+    #[test]
+    fn empty_exports_are_valid() {
+        let mut csv = Csv::new(Vec::new());
+        assert_eq!(export_query([], &mut csv, None).unwrap(), 0);
+        assert_eq!(csv.into_inner(), b"date,country,name\n");
+
+        let mut ics = Ics::new(Vec::new());
+        export_query([], &mut ics, None).unwrap();
+        assert!(String::from_utf8(ics.into_inner())
+            .unwrap()
+            .ends_with("EN\r\nEND:VCALENDAR\r\n"));
+    }
+
+    fn record(country: &str, date: &str, name: &str) -> ManifestRecord {
+        ManifestRecord {
+            country: country.to_string(),
+            date: date.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn manifest_round_trip() {
+        let mut out = Vec::new();
+        let count = manifest(&mut out).unwrap();
+        assert_eq!(count, crate::data::DATA.len());
+
+        let records = read_manifest(out.as_slice()).unwrap();
+        let mut expected: Vec<_> = crate::data::DATA.iter().map(ManifestRecord::from).collect();
+        expected.sort_unstable();
+        assert_eq!(records, expected);
+        assert!(diff_manifests(out.as_slice(), out.as_slice())
+            .unwrap()
+            .is_empty());
+
+        // output is canonical
+        let mut again = Vec::new();
+        write_manifest(crate::data::DATA.iter().rev(), &mut again).unwrap();
+        assert_eq!(again, out);
+    }
+
+    #[test]
+    fn manifest_names_are_escaped() {
+        let holidays = [Holiday::new(
+            Country::US,
+            Date::from_ymd(2025, 1, 1),
+            "Tab\\t\tand\nlines\r",
+        )];
+        let mut out = Vec::new();
+        write_manifest(&holidays, &mut out).unwrap();
+        let text = String::from_utf8(out.clone()).unwrap();
+        assert_eq!(text.lines().count(), 4);
+        assert!(text.contains("US\t2025-01-01\tTab\\\\t\\tand\\nlines\\r\n"));
+        assert_eq!(
+            read_manifest(out.as_slice()).unwrap(),
+            [ManifestRecord::from(&holidays[0])]
+        );
+    }
+
+    #[test]
+    fn invalid_manifests() {
+        let mut out = Vec::new();
+        write_manifest(&crate::data::DATA[..10], &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let check = |text: &str| {
+            let err = read_manifest(text.as_bytes()).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            err.to_string()
+        };
+
+        assert!(
+            check(&text.replacen("manifest 1", "manifest 2", 1)).contains("unsupported version 2")
+        );
+        assert!(check(&text.replacen("records 10", "records 9", 1)).contains("missing hash"));
+        assert!(check(&text.replacen("records 10", "records 11", 1)).contains("malformed"));
+        let last = text.lines().nth(11).unwrap();
+        assert!(
+            check(&text.replacen(last, &format!("{last} (altered)"), 1)).contains("hash mismatch")
+        );
+        assert!(check(&format!("{text}extra\n")).contains("trailing"));
+        assert!(check(&text[..text.len() / 2]).contains("invalid manifest"));
+        assert!(check("").contains("unexpected end"));
+    }
+
+    #[test]
+    fn fixture_manifests_diff() {
+        let old = include_bytes!("../tests/fixtures/manifest_old.txt");
+        let new = include_bytes!("../tests/fixtures/manifest_new.txt");
+
+        let diff = diff_manifests(&old[..], &new[..]).unwrap();
+        assert_eq!(
+            diff,
+            ManifestDiff {
+                added: vec![record(
+                    "US",
+                    "2025-06-19",
+                    "Juneteenth National Independence Day"
+                )],
+                removed: vec![record("US", "2025-11-11", "Veterans Day")],
+                renamed: vec![(
+                    record("DE", "2025-10-03", "Day of German Unity"),
+                    record("DE", "2025-10-03", "German Unity Day"),
+                )],
+            }
+        );
+
+        let reverse = diff_manifests(&new[..], &old[..]).unwrap();
+        assert_eq!(reverse.added, diff.removed);
+        assert_eq!(reverse.removed, diff.added);
+        assert_eq!(reverse.renamed.len(), 1);
+        assert!(diff_manifests(&old[..], &old[..]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn attribution_headers() {
+        let attribution = crate::dataset_info().attribution();
+        let comment = format!("# Holiday data: {attribution}\n");
+        let holidays = [Holiday::new(
+            Country::US,
+            Date::from_ymd(2025, 7, 4),
+            "Independence Day",
+        )];
+
+        let mut ics = Ics::new(Vec::new()).with_attribution(true);
+        export_query(&holidays, &mut ics, None).unwrap();
+        let ics = String::from_utf8(ics.into_inner()).unwrap();
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+        let unfolded = ics.replace("\r\n ", "");
+        let escaped = attribution.to_string().replace(',', "\\,");
+        let header =
+            format!("PRODID:-//holidays-rs//holidays//EN\r\nX-HOLIDAYS-ATTRIBUTION:{escaped}\r\n");
+        assert!(unfolded.contains(&(header + "BEGIN:VEVENT")));
+        assert_eq!(unfolded.matches("X-HOLIDAYS-ATTRIBUTION").count(), 1);
+
+        let mut csv = Csv::new(Vec::new()).with_attribution(true);
+        export_query(&holidays, &mut csv, None).unwrap();
+        let csv = String::from_utf8(csv.into_inner()).unwrap();
+        assert_eq!(
+            csv,
+            comment.clone() + "date,country,name\n2025-07-04,US,Independence Day\n"
+        );
+
+        let mut jsonl = JsonLines::new(Vec::new()).with_attribution(true);
+        export_query(&holidays, &mut jsonl, None).unwrap();
+        let jsonl = String::from_utf8(jsonl.into_inner()).unwrap();
+        assert!(jsonl.starts_with(&comment));
+        assert_eq!(jsonl.lines().count(), 2);
+
+        // comments are written even without records
+        let mut csv = Csv::new(Vec::new()).with_attribution(true);
+        export_query([], &mut csv, None).unwrap();
+        let csv = String::from_utf8(csv.into_inner()).unwrap();
+        assert_eq!(csv, comment.clone() + "date,country,name\n");
+        let mut jsonl = JsonLines::new(Vec::new()).with_attribution(true);
+        export_query([], &mut jsonl, None).unwrap();
+        assert_eq!(String::from_utf8(jsonl.into_inner()).unwrap(), comment);
+
+        // off by default
+        let mut ics = Ics::new(Vec::new());
+        export_query(&holidays, &mut ics, None).unwrap();
+        assert!(!String::from_utf8(ics.into_inner())
+            .unwrap()
+            .contains("X-HOLIDAYS"));
+    }
+
+    #[test]
+    fn ics_lines_are_folded() {
+        let name =
+            "An Exceptionally Long Holiday Name, Which Doesn't Fit Into A Single Content Line";
+        let mut ics = Ics::new(Vec::new());
+        export_query(
+            [&Holiday::new(Country::JP, Date::from_ymd(2025, 1, 1), name)],
+            &mut ics,
+            None,
+        )
+        .unwrap();
+        let ics = String::from_utf8(ics.into_inner()).unwrap();
+
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains(
+            "SUMMARY:JP: An Exceptionally Long Holiday Name\\, Which Doesn't Fit Into A Single Content Line\r\n"
+        ));
+    }
+}
@@ -0,0 +1,452 @@
+//! Post-filters of query results.
+//!
+//! Filters are applied with [`Iter::with_filter`], which keeps returning an
+//! [`Iter`], so filtered results can still be used with its adapters and
+//! report [size hints](Iterator::size_hint) of the underlying query.
+//!
+//! [`Iter`]: crate::Iter
+//! [`Iter::with_filter`]: crate::Iter::with_filter
+
+use std::borrow::Cow;
+
+use crate::{Country, CountrySet, Holiday, Source, Weekday};
+
+/// Predicate that selects holidays to keep in query results.
+///
+/// Implemented for closures taking a `&Holiday`, as well as for the filters
+/// in this module.
+///
+/// ```
+/// use holidays::{Country, Holiday, HolidayFilter};
+///
+/// struct LongNames(usize);
+///
+/// impl HolidayFilter for LongNames {
+///     fn matches(&self, holiday: &Holiday) -> bool {
+///         holiday.name.len() > self.0
+///     }
+/// }
+///
+/// let long = holidays::get_holidays(Country::US, 2025..2026).with_filter(LongNames(20));
+/// assert!(long.names().all(|name| name.len() > 20));
+/// ```
+pub trait HolidayFilter {
+    /// Returns `true` if `holiday` should be kept.
+    fn matches(&self, holiday: &Holiday) -> bool;
+}
+
+impl<F> HolidayFilter for F
+where
+    F: Fn(&Holiday) -> bool,
+{
+    fn matches(&self, holiday: &Holiday) -> bool {
+        self(holiday)
+    }
+}
+
+/// Keeps holidays whose name contains a string, compared after
+/// [normalization](crate::normalize_name).
+///
+/// Nothing matches with `no-names` feature, unless the string is empty.
+///
+/// ```
+/// use holidays::filter::NameContains;
+/// use holidays::Country;
+///
+/// let mut easter = holidays::get_holidays(Country::DE, 2025..2026).with_filter(NameContains::new("easter"));
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(easter.next().map(|it| it.name), Some("Easter Monday"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NameContains(String);
+
+impl NameContains {
+    /// Constructs a filter of names containing `needle`.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::filter::{HolidayFilter, NameContains};
+    /// use holidays::{Country, Holiday};
+    ///
+    /// // needles are normalized like names, so case doesn't matter
+    /// let filter = NameContains::new("CHRISTMAS");
+    /// let christmas = Holiday::new(Country::GB, Date::from_ymd(2025, 12, 25), "Christmas Day");
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert!(filter.matches(&christmas));
+    /// ```
+    pub fn new(needle: &str) -> Self {
+        NameContains(crate::normalize_name(needle).into_owned())
+    }
+}
+
+impl HolidayFilter for NameContains {
+    fn matches(&self, holiday: &Holiday) -> bool {
+        let name: Cow<str> = crate::normalize_name(holiday.name);
+        name.contains(self.0.as_str())
+    }
+}
+
+/// Keeps holidays named exactly like a string, compared after
+/// [normalization](crate::normalize_name).
+///
+/// Embedded records are checked against the name index if the crate is built
+/// with `name-index` feature, like in [`with_name`](crate::with_name), and by
+/// comparing names otherwise. Nothing matches with `no-names` feature.
+///
+/// ```
+/// use holidays::filter::NameIs;
+/// use holidays::{Any, Country};
+///
+/// let christmas = holidays::get_holidays(Any, 2025..2026).with_filter(NameIs::new("christmas day"));
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(christmas.countries().any(|it| it == Country::DE));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NameIs {
+    name: String,
+    #[cfg(feature = "name-index")]
+    records: &'static [u32],
+}
+
+impl NameIs {
+    /// Constructs a filter of names equal to `name`.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::filter::{HolidayFilter, NameIs};
+    /// use holidays::{Country, Holiday};
+    ///
+    /// let filter = NameIs::new("boxing day");
+    /// let boxing_day = Holiday::new(Country::GB, Date::from_ymd(2025, 12, 26), "Boxing Day");
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert!(filter.matches(&boxing_day));
+    /// assert!(!NameIs::new("boxing").matches(&boxing_day));
+    /// ```
+    pub fn new(name: &str) -> Self {
+        let name = crate::normalize_name(name).into_owned();
+        NameIs {
+            #[cfg(feature = "name-index")]
+            records: crate::data::name_to_indices(&name),
+            name,
+        }
+    }
+}
+
+impl HolidayFilter for NameIs {
+    fn matches(&self, holiday: &Holiday) -> bool {
+        if cfg!(feature = "no-names") {
+            return false;
+        }
+        #[cfg(feature = "name-index")]
+        if let Some(index) = crate::data::index_of(holiday) {
+            return self.records.binary_search(&(index as u32)).is_ok();
+        }
+        crate::name::matches_normalized(holiday.name, &self.name, crate::Folding::Ascii)
+    }
+}
+
+/// Keeps holidays that fall on the given day of the week.
+///
+/// ```
+/// use holidays::filter::OnWeekday;
+/// use holidays::{Country, Weekday};
+///
+/// let mondays = holidays::get_holidays(Country::GB, 2025..2026).with_filter(OnWeekday(Weekday::Monday));
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(mondays.names().any(|name| name == "Spring Bank Holiday"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OnWeekday(pub Weekday);
+
+impl HolidayFilter for OnWeekday {
+    fn matches(&self, holiday: &Holiday) -> bool {
+        holiday.date.weekday() == self.0
+    }
+}
+
+/// Keeps holidays observed in any of the countries of a set.
+///
+/// Useful to narrow down results of a query whose countries aren't known
+/// upfront, e.g. one received from elsewhere.
+///
+/// ```
+/// use holidays::filter::InCountries;
+/// use holidays::{Any, Country, CountrySet, Date};
+///
+/// let europe: CountrySet = [Country::DE, Country::FR].into_iter().collect();
+/// let christmas = holidays::get_holidays(Any, Date::from_ymd(2025, 12, 25))
+///     .with_filter(InCountries(europe));
+/// assert!(christmas.map(|it| it.code).eq([Country::DE, Country::FR]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InCountries(pub CountrySet);
+
+impl HolidayFilter for InCountries {
+    fn matches(&self, holiday: &Holiday) -> bool {
+        self.0.contains(holiday.code)
+    }
+}
+
+impl FromIterator<Country> for InCountries {
+    fn from_iter<T: IntoIterator<Item = Country>>(iter: T) -> Self {
+        InCountries(iter.into_iter().collect())
+    }
+}
+
+/// Keeps holidays that come from any of the selected [sources](Source).
+///
+/// ```
+/// use holidays::filter::FromSources;
+/// use holidays::{Country, Source};
+///
+/// // e.g. for audits of upstream data
+/// let upstream = holidays::get_holidays(Country::US, 2025..2026)
+///     .with_filter(FromSources::ALL.without(Source::Extra).without(Source::Overlay));
+/// assert!(upstream.into_iter().all(|it| it.source() == Source::Upstream));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromSources {
+    mask: u8,
+}
+
+impl FromSources {
+    /// Selects records of all sources.
+    ///
+    /// ```
+    /// use holidays::filter::FromSources;
+    /// use holidays::Country;
+    ///
+    /// let all = holidays::get_holidays(Country::US, 2025..2026).with_filter(FromSources::ALL);
+    /// assert_eq!(all.count(), holidays::get_holidays(Country::US, 2025..2026).count());
+    /// ```
+    pub const ALL: FromSources = FromSources { mask: 0b111 };
+    /// Doesn't select any records.
+    ///
+    /// ```
+    /// use holidays::filter::FromSources;
+    /// use holidays::{Country, Source};
+    ///
+    /// let extra_only = FromSources::NONE.with(Source::Extra);
+    /// assert!(!extra_only.contains(Source::Upstream));
+    /// ```
+    pub const NONE: FromSources = FromSources { mask: 0 };
+
+    const fn bit(source: Source) -> u8 {
+        match source {
+            Source::Upstream => 1,
+            Source::Extra => 2,
+            Source::Overlay => 4,
+        }
+    }
+
+    /// Returns the filter with records from `source` selected.
+    ///
+    /// ```
+    /// use holidays::filter::FromSources;
+    /// use holidays::Source;
+    ///
+    /// let sources = FromSources::NONE.with(Source::Upstream).with(Source::Extra);
+    /// assert!(sources.contains(Source::Extra));
+    /// assert!(!sources.contains(Source::Overlay));
+    /// ```
+    pub const fn with(self, source: Source) -> Self {
+        FromSources {
+            mask: self.mask | Self::bit(source),
+        }
+    }
+
+    /// Returns the filter with records from `source` excluded.
+    ///
+    /// ```
+    /// use holidays::filter::FromSources;
+    /// use holidays::Source;
+    ///
+    /// let sources = FromSources::ALL.without(Source::Overlay);
+    /// assert!(sources.contains(Source::Upstream));
+    /// assert!(!sources.contains(Source::Overlay));
+    /// ```
+    pub const fn without(self, source: Source) -> Self {
+        FromSources {
+            mask: self.mask & !Self::bit(source),
+        }
+    }
+
+    /// Returns `true` if records from `source` are selected.
+    ///
+    /// ```
+    /// use holidays::filter::FromSources;
+    /// use holidays::Source;
+    ///
+    /// assert!(FromSources::ALL.contains(Source::Overlay));
+    /// assert!(!FromSources::NONE.contains(Source::Upstream));
+    /// ```
+    pub const fn contains(self, source: Source) -> bool {
+        self.mask & Self::bit(source) != 0
+    }
+}
+
+impl HolidayFilter for FromSources {
+    fn matches(&self, holiday: &Holiday) -> bool {
+        self.contains(holiday.source())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Any;
+    use crate::Date;
+
+    /// Keeps holidays in the first half of a month.
+    struct FirstHalf;
+
+    impl HolidayFilter for FirstHalf {
+        fn matches(&self, holiday: &Holiday) -> bool {
+            holiday.date.day() <= 15
+        }
+    }
+
+    #[test]
+    fn custom_filter_matches_manual_filter() {
+        let range = Date::from_ymd(2024, 3, 1)..Date::from_ymd(2026, 9, 1);
+        for countries in [
+            CountrySet::all(),
+            [Country::US].into_iter().collect(),
+            [Country::DE, Country::JP, Country::BR]
+                .into_iter()
+                .collect(),
+        ] {
+            let manual: Vec<_> = crate::get_holidays(countries, range.clone())
+                .filter(|it| FirstHalf.matches(it))
+                .collect();
+            let filtered: Vec<_> = crate::get_holidays(countries, range.clone())
+                .with_filter(FirstHalf)
+                .collect();
+            assert!(!manual.is_empty());
+            assert_eq!(filtered, manual);
+            assert_eq!(
+                crate::get_holidays(countries, range.clone())
+                    .with_filter(FirstHalf)
+                    .last(),
+                manual.last().copied()
+            );
+        }
+    }
+
+    #[test]
+    fn filters_compose() {
+        let filtered: Vec<_> = crate::get_holidays(Any, 2025..2026)
+            .with_filter(InCountries(
+                [Country::DE, Country::FR].into_iter().collect(),
+            ))
+            .with_filter(OnWeekday(Weekday::Monday))
+            .with_filter(|it: &Holiday| it.date.month() == 4)
+            .collect();
+        let manual: Vec<_> = crate::get_holidays([Country::DE, Country::FR], 2025..2026)
+            .filter(|it| it.date.weekday() == Weekday::Monday && it.date.month() == 4)
+            .collect();
+        assert_eq!(filtered, manual);
+        assert_eq!(filtered.len(), 2);
+    }
+
+    #[test]
+    fn filters_keep_size_hints() {
+        let query = || crate::get_holidays(Any, 2025..2026);
+        let count = query().count();
+        assert_eq!(query().size_hint(), (count, Some(count)));
+        assert_eq!(
+            query().with_filter(OnWeekday(Weekday::Sunday)).size_hint(),
+            (0, Some(count))
+        );
+
+        let query = || crate::get_holidays(Country::US, 2025..2026);
+        let (_, upper) = query().size_hint();
+        assert!(upper.unwrap() >= query().count());
+        assert_eq!(
+            query().with_filter(OnWeekday(Weekday::Sunday)).size_hint(),
+            (0, upper)
+        );
+    }
+
+    #[test]
+    fn source_filters() {
+        use crate::HolidayFlags;
+
+        let date = Date::from_ymd(2025, 1, 1);
+        let records = [Source::Upstream, Source::Extra, Source::Overlay].map(|it| {
+            Holiday::new(Country::US, date, "").with_flags(HolidayFlags::EMPTY.with_source(it))
+        });
+        let kept = |filter: FromSources| {
+            records
+                .iter()
+                .filter(|it| filter.matches(it))
+                .map(Holiday::source)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kept(FromSources::ALL),
+            [Source::Upstream, Source::Extra, Source::Overlay]
+        );
+        assert_eq!(
+            kept(FromSources::ALL.without(Source::Overlay)),
+            [Source::Upstream, Source::Extra]
+        );
+        assert_eq!(kept(FromSources::NONE.with(Source::Extra)), [Source::Extra]);
+        assert_eq!(kept(FromSources::NONE), []);
+
+        // embedded data only has upstream records unless built with extras
+        let upstream = FromSources::NONE.with(Source::Upstream);
+        assert!(crate::get_holidays(Any, 2025..2026)
+            .with_filter(upstream)
+            .eq(crate::get_holidays(Any, 2025..2026)));
+        assert!(crate::get_holidays(Any, 2025..2026)
+            .with_filter(FromSources::ALL.without(Source::Upstream))
+            .is_empty());
+    }
+
+    #[cfg(not(feature = "no-names"))]
+    #[test]
+    fn name_is_matches_with_name() {
+        for name in [
+            "Christmas Day",
+            "new year's day",
+            "Ano Nuevo",
+            "Nonexistent Day",
+        ] {
+            let filtered: Vec<_> = crate::get_holidays(Any, 2020..2030)
+                .with_filter(NameIs::new(name))
+                .collect();
+            let named: Vec<_> = crate::with_name(name)
+                .filter(|it| (2020..2030).contains(&it.date.year()))
+                .collect();
+            assert_eq!(filtered, named, "{name}");
+        }
+
+        // records that aren't embedded are compared by name
+        let date = Date::from_ymd(2025, 12, 26);
+        assert!(NameIs::new("boxing day").matches(&Holiday::new(Country::GB, date, "Boxing Day")));
+        assert!(!NameIs::new("boxing day").matches(&Holiday::new(
+            Country::GB,
+            date,
+            "Boxing Day (observed)"
+        )));
+    }
+
+    #[cfg(not(feature = "no-names"))]
+    #[test]
+    fn name_contains() {
+        let names: Vec<_> = crate::get_holidays(Country::US, 2025..2026)
+            .with_filter(NameContains::new("DAY"))
+            .names()
+            .collect();
+        assert!(names.contains(&"Independence Day"));
+        assert_eq!(
+            crate::get_holidays(Country::US, 2025..2026)
+                .with_filter(NameContains::new("christmas"))
+                .names()
+                .collect::<Vec<_>>(),
+            ["Christmas Day"]
+        );
+    }
+}
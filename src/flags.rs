@@ -0,0 +1,365 @@
+//! Packed attributes of holiday records.
+
+/// Attributes of a holiday record, packed into 16 bits.
+///
+/// All embedded records currently have empty flags; the bits are allocated
+/// up front so data features can be added without changing the layout of
+/// [`Holiday`](crate::Holiday) or the generated tables:
+///
+/// | Bits       | Meaning                                                |
+/// |------------|--------------------------------------------------------|
+/// | `0`        | [observed](HolidayFlags::is_observed) on a moved date  |
+/// | `1`        | [substitute](HolidayFlags::is_substitute) day          |
+/// | `2`        | [regional](HolidayFlags::is_regional) holiday          |
+/// | `3..=4`    | [portion](HolidayFlags::portion) of the day            |
+/// | `5..=6`    | [source](HolidayFlags::source) of the record           |
+/// | `7`        | reserved                                               |
+/// | `8..=11`   | [category](HolidayFlags::category), `0` if unspecified |
+/// | `12..=15`  | reserved                                               |
+///
+/// Reserved bits may be assigned a meaning in any minor release, so code
+/// shouldn't depend on them being `0`.
+///
+/// ```
+/// use holidays::HolidayFlags;
+///
+/// let flags = HolidayFlags::from_bits(0x0301);
+/// assert!(flags.is_observed());
+/// assert!(!flags.is_substitute());
+/// assert_eq!(flags.category(), 3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayFlags(pub(crate) u16);
+
+impl HolidayFlags {
+    /// Flags without any attributes.
+    ///
+    /// ```
+    /// use holidays::{Country, HolidayFlags};
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// assert_eq!(holiday.flags(), HolidayFlags::EMPTY);
+    /// ```
+    pub const EMPTY: HolidayFlags = HolidayFlags(0);
+
+    const OBSERVED: u16 = 1 << 0;
+    const SUBSTITUTE: u16 = 1 << 1;
+    const REGIONAL: u16 = 1 << 2;
+    const PORTION_SHIFT: u16 = 3;
+    const PORTION_MASK: u16 = 0b11 << Self::PORTION_SHIFT;
+    const SOURCE_SHIFT: u16 = 5;
+    const SOURCE_MASK: u16 = 0b11 << Self::SOURCE_SHIFT;
+    const CATEGORY_SHIFT: u16 = 8;
+    const CATEGORY_MASK: u16 = 0xF << Self::CATEGORY_SHIFT;
+
+    /// Constructs flags from their packed representation.
+    ///
+    /// ```
+    /// use holidays::{DayPortion, HolidayFlags};
+    ///
+    /// let flags = HolidayFlags::from_bits(0b1_0000);
+    /// assert_eq!(flags.portion(), DayPortion::HalfPm);
+    /// ```
+    pub const fn from_bits(bits: u16) -> Self {
+        HolidayFlags(bits)
+    }
+
+    /// Returns the packed representation of flags.
+    ///
+    /// ```
+    /// use holidays::{DayPortion, HolidayFlags};
+    ///
+    /// let flags = HolidayFlags::EMPTY.with_portion(DayPortion::HalfAm);
+    /// assert_eq!(HolidayFlags::from_bits(flags.bits()), flags);
+    /// ```
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if no flags are set.
+    ///
+    /// ```
+    /// use holidays::{HolidayFlags, Source};
+    ///
+    /// assert!(HolidayFlags::EMPTY.is_empty());
+    /// assert!(!HolidayFlags::EMPTY.with_source(Source::Extra).is_empty());
+    /// ```
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the holiday is observed on a different date than it
+    /// falls on, e.g. on Monday when it falls on a weekend.
+    ///
+    /// ```
+    /// use holidays::HolidayFlags;
+    ///
+    /// assert!(HolidayFlags::from_bits(0b1).is_observed());
+    /// assert!(!HolidayFlags::EMPTY.is_observed());
+    /// ```
+    pub const fn is_observed(self) -> bool {
+        self.0 & Self::OBSERVED != 0
+    }
+
+    /// Returns `true` if the record is a substitute day granted in place of
+    /// another holiday, rather than the holiday itself.
+    ///
+    /// ```
+    /// use holidays::HolidayFlags;
+    ///
+    /// assert!(HolidayFlags::from_bits(0b10).is_substitute());
+    /// assert!(!HolidayFlags::EMPTY.is_substitute());
+    /// ```
+    pub const fn is_substitute(self) -> bool {
+        self.0 & Self::SUBSTITUTE != 0
+    }
+
+    /// Returns `true` if the holiday is observed in some subdivisions of the
+    /// country only.
+    ///
+    /// ```
+    /// use holidays::HolidayFlags;
+    ///
+    /// assert!(HolidayFlags::from_bits(0b100).is_regional());
+    /// assert!(!HolidayFlags::EMPTY.is_regional());
+    /// ```
+    pub const fn is_regional(self) -> bool {
+        self.0 & Self::REGIONAL != 0
+    }
+
+    /// Returns the portion of the day the holiday is observed in.
+    ///
+    /// The unassigned value `3` is reported as [`DayPortion::Full`].
+    ///
+    /// ```
+    /// use holidays::{DayPortion, HolidayFlags};
+    ///
+    /// assert_eq!(HolidayFlags::EMPTY.portion(), DayPortion::Full);
+    /// assert_eq!(HolidayFlags::from_bits(0b1000).portion(), DayPortion::HalfAm);
+    /// ```
+    pub const fn portion(self) -> DayPortion {
+        match (self.0 & Self::PORTION_MASK) >> Self::PORTION_SHIFT {
+            1 => DayPortion::HalfAm,
+            2 => DayPortion::HalfPm,
+            _ => DayPortion::Full,
+        }
+    }
+
+    /// Returns the flags with the portion of the day replaced.
+    ///
+    /// Like [`Holiday::with_flags`](crate::Holiday::with_flags), this is
+    /// meant for building fixtures.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, DayPortion, Holiday, HolidayFlags};
+    ///
+    /// let eve = Holiday::new(Country::SE, Date::from_ymd(2025, 12, 24), "Christmas Eve")
+    ///     .with_flags(HolidayFlags::EMPTY.with_portion(DayPortion::HalfPm));
+    /// assert_eq!(eve.portion(), DayPortion::HalfPm);
+    /// ```
+    pub const fn with_portion(self, portion: DayPortion) -> Self {
+        let bits = match portion {
+            DayPortion::Full => 0,
+            DayPortion::HalfAm => 1,
+            DayPortion::HalfPm => 2,
+        };
+        HolidayFlags((self.0 & !Self::PORTION_MASK) | (bits << Self::PORTION_SHIFT))
+    }
+
+    /// Returns the dataset the record comes from.
+    ///
+    /// The unassigned value `3` is reported as [`Source::Upstream`].
+    ///
+    /// ```
+    /// use holidays::{HolidayFlags, Source};
+    ///
+    /// assert_eq!(HolidayFlags::EMPTY.source(), Source::Upstream);
+    /// assert_eq!(HolidayFlags::from_bits(0b100_0000).source(), Source::Overlay);
+    /// ```
+    pub const fn source(self) -> Source {
+        match (self.0 & Self::SOURCE_MASK) >> Self::SOURCE_SHIFT {
+            1 => Source::Extra,
+            2 => Source::Overlay,
+            _ => Source::Upstream,
+        }
+    }
+
+    /// Returns the flags with the source of the record replaced.
+    ///
+    /// Like [`Holiday::with_flags`](crate::Holiday::with_flags), this is
+    /// meant for building fixtures.
+    ///
+    /// ```
+    /// use holidays::{HolidayFlags, Source};
+    ///
+    /// let flags = HolidayFlags::EMPTY.with_source(Source::Extra);
+    /// assert_eq!(flags.source(), Source::Extra);
+    /// assert_eq!(flags.with_source(Source::Upstream), HolidayFlags::EMPTY);
+    /// ```
+    pub const fn with_source(self, source: Source) -> Self {
+        let bits = match source {
+            Source::Upstream => 0,
+            Source::Extra => 1,
+            Source::Overlay => 2,
+        };
+        HolidayFlags((self.0 & !Self::SOURCE_MASK) | (bits << Self::SOURCE_SHIFT))
+    }
+
+    /// Returns the category of the holiday, or `0` if it's unspecified.
+    ///
+    /// Category values aren't assigned yet.
+    ///
+    /// ```
+    /// use holidays::HolidayFlags;
+    ///
+    /// assert_eq!(HolidayFlags::EMPTY.category(), 0);
+    /// assert_eq!(HolidayFlags::from_bits(0x0500).category(), 5);
+    /// ```
+    pub const fn category(self) -> u8 {
+        ((self.0 & Self::CATEGORY_MASK) >> Self::CATEGORY_SHIFT) as u8
+    }
+}
+
+/// Portion of the day a holiday is observed in.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, DayPortion, Holiday, HolidayFlags};
+///
+/// let eve = Holiday::new(Country::DE, Date::from_ymd(2025, 12, 24), "Christmas Eve")
+///     .with_flags(HolidayFlags::EMPTY.with_portion(DayPortion::HalfPm));
+/// let hours_off = 8.0 * eve.portion().fraction();
+/// assert_eq!(hours_off, 4.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPortion {
+    /// The whole day.
+    #[default]
+    Full,
+    /// The morning, e.g. until noon.
+    HalfAm,
+    /// The afternoon, e.g. Christmas Eve afternoon.
+    HalfPm,
+}
+
+impl DayPortion {
+    /// Returns the fraction of the day the holiday is observed in, `0.5` for
+    /// half days.
+    ///
+    /// ```
+    /// use holidays::DayPortion;
+    ///
+    /// assert_eq!(DayPortion::Full.fraction(), 1.0);
+    /// assert_eq!(DayPortion::HalfAm.fraction(), 0.5);
+    /// ```
+    pub const fn fraction(self) -> f64 {
+        match self {
+            DayPortion::Full => 1.0,
+            DayPortion::HalfAm | DayPortion::HalfPm => 0.5,
+        }
+    }
+
+    /// Returns `(morning, afternoon)`, each `true` if the holiday is
+    /// observed in that half of the day.
+    pub(crate) const fn halves(self) -> (bool, bool) {
+        match self {
+            DayPortion::Full => (true, true),
+            DayPortion::HalfAm => (true, false),
+            DayPortion::HalfPm => (false, true),
+        }
+    }
+}
+
+/// Dataset a holiday record comes from.
+///
+/// ```
+/// use holidays::{Country, Source};
+///
+/// let extra = holidays::get_holidays(Country::US, 2025..2026)
+///     .filter(|it| it.source() == Source::Extra)
+///     .count();
+/// println!("{extra} records were added by the build configuration");
+/// // queries only return embedded records
+/// let mut results = holidays::get_holidays(Country::US, 2025..2026);
+/// assert!(results.all(|it| it.source() != Source::Overlay));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// Upstream `holidays.csv` data.
+    #[default]
+    Upstream,
+    /// Extra CSV file merged into the data at build time, see
+    /// `HOLIDAYS_EXTRA_CSV` in [crate docs](crate#build-configuration).
+    ///
+    /// Upstream records duplicated by extra ones are labeled as extra as
+    /// well.
+    Extra,
+    /// Record added at runtime, rather than embedded in the crate.
+    Overlay,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_flags_are_empty() {
+        assert!(crate::data::DATA.iter().all(|it| it.flags().is_empty()));
+        assert_eq!(HolidayFlags::default(), HolidayFlags::EMPTY);
+    }
+
+    #[test]
+    fn accessors_round_trip() {
+        for bits in [0u16, 0b001, 0b010, 0b100, 0x0F00, 0x0507, 0xFFFF] {
+            let flags = HolidayFlags::from_bits(bits);
+            assert_eq!(flags.bits(), bits);
+            assert_eq!(flags.is_empty(), bits == 0);
+            assert_eq!(flags.is_observed(), bits & 1 != 0);
+            assert_eq!(flags.is_substitute(), bits & 2 != 0);
+            assert_eq!(flags.is_regional(), bits & 4 != 0);
+            assert_eq!(flags.category() as u16, (bits >> 8) & 0xF);
+        }
+    }
+
+    #[test]
+    fn portions() {
+        assert_eq!(HolidayFlags::EMPTY.portion(), DayPortion::Full);
+        assert_eq!(
+            HolidayFlags::from_bits(0b01_000).portion(),
+            DayPortion::HalfAm
+        );
+        assert_eq!(
+            HolidayFlags::from_bits(0b10_000).portion(),
+            DayPortion::HalfPm
+        );
+        assert_eq!(
+            HolidayFlags::from_bits(0b11_000).portion(),
+            DayPortion::Full
+        );
+
+        for portion in [DayPortion::Full, DayPortion::HalfAm, DayPortion::HalfPm] {
+            let flags = HolidayFlags::from_bits(0xFFFF).with_portion(portion);
+            assert_eq!(flags.portion(), portion);
+            assert_eq!(flags.bits() | 0b11_000, 0xFFFF);
+        }
+        assert!(crate::data::DATA
+            .iter()
+            .all(|it| it.portion() == DayPortion::Full));
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(HolidayFlags::EMPTY.source(), Source::Upstream);
+        assert_eq!(
+            HolidayFlags::from_bits(0b11 << 5).source(),
+            Source::Upstream
+        );
+        for source in [Source::Upstream, Source::Extra, Source::Overlay] {
+            let flags = HolidayFlags::from_bits(0xFFFF).with_source(source);
+            assert_eq!(flags.source(), source);
+            assert_eq!(flags.portion(), DayPortion::Full);
+            assert_eq!(flags.bits() | 0b11 << 5, 0xFFFF);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Query results grouped by country.
+
+use crate::{Country, CountrySet, Holiday};
+
+/// Holidays of each selected country, created with
+/// [`get_holidays_grouped`](crate::get_holidays_grouped).
+///
+/// Every selected country has a group, even if it observes no holidays on
+/// selected dates, so countries without data can be told apart from ones
+/// that weren't selected. Groups are ordered by country code, and holidays of
+/// each group by date.
+///
+/// ```
+/// use holidays::Country;
+///
+/// let grouped = holidays::get_holidays_grouped([Country::US, Country::GB], 2026..2027);
+/// for (country, holidays) in grouped.iter() {
+///     println!("{country}: {} holidays", holidays.len());
+/// }
+///
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(grouped.get(Country::US).map(|it| it[0].name), Some("New Year's Day"));
+/// assert_eq!(grouped.get(Country::JP), None);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct GroupedHolidays {
+    /// Groups sorted by country code, without duplicates.
+    groups: Vec<(Country, Vec<&'static Holiday>)>,
+}
+
+impl GroupedHolidays {
+    pub(crate) fn new(groups: impl Iterator<Item = (Country, Vec<&'static Holiday>)>) -> Self {
+        GroupedHolidays {
+            groups: groups.collect(),
+        }
+    }
+
+    /// Returns holidays of `country`, or `None` if it wasn't selected.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let grouped = holidays::get_holidays_grouped([Country::FR, Country::DE], 2025..2026);
+    /// assert_eq!(grouped.get(Country::FR).map(<[_]>::len), Some(11));
+    /// assert_eq!(grouped.get(Country::IT), None);
+    /// ```
+    pub fn get(&self, country: Country) -> Option<&[&'static Holiday]> {
+        let i = self
+            .groups
+            .binary_search_by_key(&(country as u16), |(it, _)| *it as u16)
+            .ok()?;
+        Some(&self.groups[i].1)
+    }
+
+    /// Returns the set of selected countries.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// let grouped = holidays::get_holidays_grouped([Country::NO, Country::SE, Country::NO], 2025..2026);
+    /// assert_eq!(grouped.countries(), CountrySet::from_iter([Country::NO, Country::SE]));
+    /// ```
+    pub fn countries(&self) -> CountrySet {
+        self.groups.iter().map(|(it, _)| *it).collect()
+    }
+
+    /// Returns the number of groups, i.e. of selected countries.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let grouped = holidays::get_holidays_grouped([Country::NO, Country::SE, Country::NO], 2025..2026);
+    /// assert_eq!(grouped.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if no countries were selected.
+    ///
+    /// ```
+    /// use holidays::{Country, CountrySet};
+    ///
+    /// assert!(holidays::get_holidays_grouped(CountrySet::new(), 2025..2026).is_empty());
+    /// assert!(!holidays::get_holidays_grouped(Country::FI, 1900..1901).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns an iterator over selected countries paired with their
+    /// holidays.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let grouped = holidays::get_holidays_grouped([Country::US, Country::CA], 2025..2026);
+    /// let counts: Vec<_> = grouped.iter().map(|(country, holidays)| (country, holidays.len())).collect();
+    /// assert_eq!(counts[0].0, Country::CA);
+    /// assert_eq!(counts[1], (Country::US, 11));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Country, &[&'static Holiday])> + '_ {
+        self.groups
+            .iter()
+            .map(|(country, it)| (*country, it.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Any, Country, Date};
+
+    #[test]
+    fn groups_match_queries() {
+        let countries = [Country::JP, Country::US, Country::DE, Country::US];
+        let dates = Date::from_ymd(2025, 3, 1)..Date::from_ymd(2026, 3, 1);
+        let grouped = crate::get_holidays_grouped(countries, dates.clone());
+
+        assert_eq!(grouped.len(), 3);
+        assert_eq!(grouped.countries(), countries.into_iter().collect());
+        let order: Vec<_> = grouped.iter().map(|(it, _)| it).collect();
+        assert_eq!(order, [Country::DE, Country::JP, Country::US]);
+        for (country, holidays) in grouped.iter() {
+            assert!(!holidays.is_empty());
+            assert!(holidays
+                .iter()
+                .copied()
+                .eq(crate::get_holidays(country, dates.clone())));
+            assert_eq!(grouped.get(country), Some(holidays));
+        }
+        assert_eq!(grouped.get(Country::GB), None);
+
+        let flat: usize = grouped.iter().map(|(_, it)| it.len()).sum();
+        assert_eq!(flat, crate::get_holidays(countries, dates).count());
+    }
+
+    #[test]
+    fn selected_countries_without_holidays() {
+        // no US holidays in this week
+        let dates = Date::from_ymd(2025, 8, 4)..Date::from_ymd(2025, 8, 11);
+        let grouped = crate::get_holidays_grouped(Country::US, dates.clone());
+        assert_eq!(grouped.get(Country::US), Some(&[][..]));
+        assert_eq!(grouped.len(), 1);
+
+        let all = crate::get_holidays_grouped(Any, dates);
+        assert_eq!(all.countries(), crate::CountrySet::all());
+        assert!(all.iter().any(|(_, it)| it.is_empty()));
+
+        let none = crate::get_holidays_grouped(crate::CountrySet::new(), 2025..2026);
+        assert!(none.is_empty());
+        assert_eq!(none.get(Country::US), None);
+    }
+}
@@ -0,0 +1,145 @@
+//! Handles that identify embedded holiday records.
+
+use crate::data::DATA;
+use crate::Holiday;
+
+/// Opaque handle of an embedded holiday record, usable as a map key.
+///
+/// Handles are cheap to copy and compare, and order the same as query
+/// results, i.e. by date and then by country code. Use [`HolidayRef::get`] to
+/// access the record.
+///
+/// # Stability
+///
+/// A handle identifies the same record for the lifetime of the process, but
+/// not across builds of the crate with different data or features, so it
+/// shouldn't be persisted.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use holidays::{Country, HolidayRef};
+///
+/// let mut notes: HashMap<HolidayRef, &str> = HashMap::new();
+/// for handle in holidays::get_holidays(Country::US, 2025..2026).handles() {
+///     if handle.get().name == "Independence Day" {
+///         notes.insert(handle, "fireworks");
+///     }
+/// }
+///
+/// # #[cfg(not(feature = "no-names"))]
+/// let july_4th = holidays::get_holidays(Country::US, 2025..2026)
+///     .find(|it| it.name == "Independence Day")
+///     .unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(notes.get(&july_4th.as_ref_handle().unwrap()), Some(&"fireworks"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HolidayRef(u32);
+
+impl HolidayRef {
+    /// Bit reserved for handles of records that aren't embedded, so they can
+    /// be added without changing the representation of existing handles.
+    const OVERLAY: u32 = 1 << 31;
+
+    /// Returns the handle of `DATA[index]`.
+    pub(crate) const fn from_index(index: usize) -> Self {
+        assert!(index < DATA.len());
+        HolidayRef(index as u32)
+    }
+
+    /// Returns the handle of an embedded `record`, which must be a reference
+    /// into `DATA`.
+    pub(crate) fn of_static(record: &'static Holiday) -> Self {
+        Self::from_index(crate::data::index_of(record).expect("record must be embedded"))
+    }
+
+    /// Returns the index of the record in `DATA`.
+    pub(crate) const fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Returns the record identified by the handle.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let christmas = holidays::get_holidays(Country::DE, 2025..2026).last().unwrap();
+    /// let handle = christmas.as_ref_handle().unwrap();
+    /// assert_eq!(handle.get(), christmas);
+    /// ```
+    pub fn get(self) -> &'static Holiday {
+        debug_assert!(self.0 & Self::OVERLAY == 0);
+        &DATA[self.0 as usize]
+    }
+}
+
+impl Holiday {
+    /// Returns the handle of the record if it's embedded in the crate, or
+    /// `None` for records that are constructed with [`Holiday::new`] and
+    /// don't match any embedded one.
+    ///
+    /// Embedded records are found by their address, and copies of them by
+    /// value.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, Holiday};
+    ///
+    /// let embedded = holidays::get_holidays(Country::FR, Date::from_ymd(2025, 7, 14)).next().unwrap();
+    /// assert!(embedded.as_ref_handle().is_some());
+    ///
+    /// let custom = Holiday::new(Country::FR, Date::from_ymd(2025, 7, 15), "Company Day");
+    /// assert_eq!(custom.as_ref_handle(), None);
+    /// ```
+    pub fn as_ref_handle(&self) -> Option<HolidayRef> {
+        crate::data::index_of(self)
+            .or_else(|| {
+                crate::data::country_date_to_indices(self.code, self.date)
+                    .find(|it| DATA[*it] == *self)
+            })
+            .map(HolidayRef::from_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+    use crate::{Any, Country};
+
+    #[test]
+    fn equivalent_queries_share_handles() {
+        let date = Date::from_ymd(2025, 12, 25);
+        let by_date: Vec<_> = crate::get_holidays(Country::DE, date).handles().collect();
+        let by_year: Vec<_> = crate::get_holidays([Country::DE, Country::US], 2025..2026)
+            .filter(|it| it.code == Country::DE && it.date == date)
+            .map(|it| it.as_ref_handle().unwrap())
+            .collect();
+        assert_eq!(by_date.len(), 1);
+        assert_eq!(by_date, by_year);
+    }
+
+    #[test]
+    fn handles_round_trip() {
+        let records: Vec<_> = crate::get_holidays(Any, 2025..2026).collect();
+        let handles: Vec<_> = crate::get_holidays(Any, 2025..2026).handles().collect();
+        assert!(handles.windows(2).all(|w| w[0] < w[1]));
+        for (record, handle) in records.iter().zip(&handles) {
+            assert!(std::ptr::eq(*record, handle.get()));
+            // copies resolve to the same handle
+            let copy: Holiday = **record;
+            assert_eq!(copy.as_ref_handle(), Some(*handle));
+        }
+
+        let fixture = Holiday::new(
+            Country::US,
+            Date::from_ymd(2025, 7, 4),
+            "Not Independence Day",
+        );
+        assert_eq!(fixture.as_ref_handle(), None);
+        assert_eq!(
+            Holiday::new(Country::US, Date::from_ymd(2025, 7, 5), "").as_ref_handle(),
+            None
+        );
+    }
+}
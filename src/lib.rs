@@ -26,7 +26,7 @@
 //! # Example
 //!
 //! ```
-//! # use holidays::internal::Date;
+//! # use holidays::Date;
 //! use holidays::Country;
 //!
 //! let holidays: Vec<_> = holidays::get_holidays(
@@ -49,6 +49,10 @@
 //! - Minimal heap allocations, used only when necessary
 //! - Extension trait for external types
 //! - Optional support for external time libraries: `chrono`, `time`
+//! - Optional `chrono-tz` feature for checking holidays in IANA time zones
+//! - Optional `no-names` feature which strips holiday names from embedded data
+//!   for size constrained targets that only need to check dates
+//! - Optional `cache` feature which memoizes results of repeated queries
 //!
 //! # Performance
 //!
@@ -56,6 +60,10 @@
 //! and optimized for performance. Query execution uses tight loops, minimal
 //! branching, and completely avoids dynamic dispatch.
 //!
+//! Queries of exact dates, of a single country, and of date ranges don't
+//! allocate; see `test_support` module (`test-support` feature) for the
+//! complete list of allocating paths and a way to verify them.
+//!
 //! # When to Use
 //!
 //! This crate is ideal if you need:
@@ -68,24 +76,100 @@
 //!
 //! All holiday data is generated at compile time. There is no dependency on
 //! runtime files, databases, or network access.
+//!
+//! # Build configuration
+//!
+//! Embedded data can be adjusted with environment variables read by the
+//! build script:
+//!
+//! - `HOLIDAYS_MIN_YEAR` and `HOLIDAYS_MAX_YEAR` limit embedded years,
+//!   `2000` and `2035` by default;
+//! - `HOLIDAYS_EXTRA_CSV` is a path to a file in `holidays.csv` format, with
+//!   rows sorted by date, whose records are merged into the data and labeled
+//!   as [`Source::Extra`];
+//! - `HOLIDAYS_ALLOW_EXTRA_COLUMNS` makes the build ignore unknown CSV
+//!   columns instead of failing.
+//!
+//! [`build_config`] reports enabled countries, features and embedded years of
+//! a build, e.g. for bug reports.
 
 #![warn(missing_docs)]
 #![warn(clippy::undocumented_unsafe_blocks)]
+// invariants relied upon are named with `expect` instead, unwraps are only
+// allowed in tests (see clippy.toml)
+#![deny(clippy::unwrap_used)]
 
+mod business;
+#[cfg(any(test, feature = "cache"))]
+pub mod cache;
 mod country;
+pub mod coverage;
+mod custom;
 mod data;
+mod dataset;
 mod date;
+pub mod export;
+pub mod filter;
+mod flags;
+mod grouped;
+mod handle;
+mod name;
 mod query;
+#[cfg(feature = "raw-tables")]
+pub mod raw;
+pub mod render;
+mod snapshot;
+mod stats;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+pub mod workweek;
+#[cfg(feature = "chrono")]
+mod zone;
 
-use date::{Date, DateConversionError};
+use date::DateConversionError;
 use query::selection::*;
 
-pub use country::Country;
-pub use date::DateExt;
+pub use business::{ClosureRule, DayKind, DayKinds, WindowClip};
+pub use country::{enabled_countries, Country, CountrySet, CountrySetIter};
+pub use coverage::{clip_to_coverage, coverage_gaps};
+pub use custom::{
+    CalendarHoliday, CalendarId, CalendarIter, CalendarSet, CustomCalendar, CustomCalendarId,
+};
+pub use dataset::{Attribution, DatasetInfo};
+pub use date::{
+    parse_date_range, CivilDate, CoveredYear, Date, DateBounds, DateExt, DefaultDate, DisplayDate,
+    IsoDateBuf, Month, OnConversionError, TryDateExt, Weekday,
+};
+pub use filter::HolidayFilter;
+pub use flags::{DayPortion, HolidayFlags, Source};
+pub use grouped::GroupedHolidays;
+pub use handle::HolidayRef;
+pub use name::{normalize_name, normalize_name_with, Folding};
+pub use query::selection;
 pub use query::selection::Any;
-pub use query::Iter;
+pub use query::{
+    ByDay, Iter, Order, Pivot, PreparedQuery, QueryOptions, SharedIter, Strategy, TryWithDates,
+    WithDates,
+};
+pub use render::HolidayFormat;
+pub use snapshot::HolidaySnapshot;
+pub use stats::{Axis, CountrySummary, MovedHoliday, YearDelta};
+pub use workweek::{WeekendRelation, Workweek};
+#[cfg(feature = "chrono")]
+pub use zone::TimeZoneRef;
 
 /// Represents a holiday with an associated country, date, and name.
+///
+/// ```
+/// use holidays::{Country, Date, Weekday, WeekendRelation, Workweek};
+///
+/// let holiday = holidays::get_holidays(Country::US, Date::from_ymd(2025, 7, 4)).next().unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!((holiday.code, holiday.name), (Country::US, "Independence Day"));
+/// assert_eq!(holiday.date::<Date>()?.weekday(), Weekday::Friday);
+/// assert_eq!(holiday.weekend_relation(Workweek::MON_FRI), WeekendRelation::AdjacentToWeekend);
+/// # Ok::<(), holidays::error::DateConversionError>(())
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Holiday {
     /// The `Country` this holiday is observed in.
@@ -93,11 +177,200 @@ pub struct Holiday {
     /// The date of the holiday.
     date: Date,
     /// The name of the holiday.
+    ///
+    /// A country can observe multiple holidays on the same date, each with a
+    /// record of its own, ordered by name. APIs that return a single record
+    /// per country and date return the one with the lexicographically
+    /// smallest name, so they don't depend on the order of source data.
+    ///
+    /// Always empty if the crate is built with `no-names` feature, in which
+    /// case holidays a country observes on the same date share a record.
     pub name: &'static str,
+    /// Packed attributes of the holiday.
+    flags: HolidayFlags,
 }
 
 impl Holiday {
+    /// Constructs a holiday record.
+    ///
+    /// This is primarily useful for building fixtures in tests of code that
+    /// consumes holidays. Constructed values aren't guaranteed to exist in
+    /// the embedded dataset, and aren't returned by any queries.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, Holiday};
+    ///
+    /// // downstream code under test
+    /// fn describe(holiday: &Holiday) -> String {
+    ///     format!("{} ({}): {}", holiday.display_date(), holiday.code, holiday.name)
+    /// }
+    ///
+    /// let fixture = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// assert_eq!(describe(&fixture), "2025-07-04 (US): Independence Day");
+    /// ```
+    pub fn new(code: Country, date: impl Into<Date>, name: &'static str) -> Self {
+        Holiday {
+            code,
+            date: date.into(),
+            name,
+            flags: HolidayFlags::EMPTY,
+        }
+    }
+
+    /// Returns the holiday with `flags` replacing its attributes.
+    ///
+    /// Like [`Holiday::new`], this is meant for building fixtures.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, Holiday, HolidayFlags};
+    ///
+    /// let observed = Holiday::new(Country::US, Date::from_ymd(2026, 7, 3), "Independence Day")
+    ///     .with_flags(HolidayFlags::from_bits(0b1));
+    /// assert!(observed.flags().is_observed());
+    /// ```
+    pub fn with_flags(self, flags: HolidayFlags) -> Self {
+        Holiday { flags, ..self }
+    }
+
+    /// Returns attributes of the holiday.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// assert!(!holiday.flags().is_substitute());
+    /// ```
+    pub fn flags(&self) -> HolidayFlags {
+        self.flags
+    }
+
+    /// Returns the dataset the record comes from, see [`HolidayFlags::source`].
+    ///
+    /// ```
+    /// use holidays::{Country, Source};
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// assert_eq!(holiday.source(), Source::Upstream);
+    /// ```
+    pub fn source(&self) -> Source {
+        self.flags.source()
+    }
+
+    /// Returns an integer key that orders records like date-ordered query
+    /// results.
+    ///
+    /// Keys are strictly increasing along results ordered by
+    /// [`Order::DateThenCountry`], the default, so they can be used to
+    /// merge-join holidays with other date-keyed datasets sorted externally.
+    /// Results ordered by [`Order::CountryThenDate`] have distinct keys, but
+    /// need to be sorted by them first. From the most significant bits, a key
+    /// is composed of:
+    ///
+    /// - 40 bits of the date, as days since 1970-01-01 offset by `2^39`;
+    /// - 12 bits of a country id, assigned in alphabetical order of all
+    ///   known country codes, so it doesn't depend on enabled features;
+    /// - 12 bits of the position of the record among records of enabled
+    ///   countries on its date, taken from its place in the embedded table.
+    ///
+    /// Keys are stable for the same dataset version and enabled countries.
+    /// Dates beyond the 40 bit range, which is more than a billion years,
+    /// saturate, and records that aren't embedded have position `0`.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Any, Country};
+    ///
+    /// let keys: Vec<u64> = holidays::get_holidays(Any, Date::from_ymd(2025, 12, 25))
+    ///     .map(|it| it.sort_key())
+    ///     .collect();
+    /// assert!(keys.windows(2).all(|it| it[0] < it[1]));
+    /// ```
+    pub fn sort_key(&self) -> u64 {
+        const DAY_OFFSET: i64 = 1 << 39;
+        let day = self
+            .date
+            .0
+            .saturating_add(DAY_OFFSET)
+            .clamp(0, (1 << 40) - 1) as u64;
+        let country = data::STABLE_COUNTRY_IDS[self.code as usize] as u64;
+        // embedded records are found by address, so records that are equal
+        // by value still get their own position
+        let sequence = self
+            .as_ref_handle()
+            .map_or(0, |it| data::DAY_SEQUENCES[it.index()] as u64);
+        (day << 24) | (country << 12) | sequence
+    }
+
+    /// Returns the position of the holiday relative to the weekend of
+    /// `workweek`.
+    ///
+    /// The crate has no per-country weekend data yet, so pass
+    /// [`Workweek::default`] for Saturday and Sunday weekends, or the
+    /// workweek of the holiday's country where it differs.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, WeekendRelation, Workweek};
+    ///
+    /// // Independence Day 2025 is on a Friday
+    /// let holiday = holidays::get_holidays(Country::US, Date::from_ymd(2025, 7, 4)).next().unwrap();
+    /// assert_eq!(holiday.weekend_relation(Workweek::default()), WeekendRelation::AdjacentToWeekend);
+    /// assert_eq!(holiday.weekend_relation(Workweek::SUN_THU), WeekendRelation::OnWeekend);
+    /// ```
+    pub fn weekend_relation(&self, workweek: impl Into<Workweek>) -> WeekendRelation {
+        workweek.into().weekend_relation(self.date.weekday())
+    }
+
+    /// Returns the portion of the day the holiday is observed in, see
+    /// [`HolidayFlags::portion`].
+    ///
+    /// ```
+    /// use holidays::{Country, DayPortion};
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// assert_eq!(holiday.portion(), DayPortion::Full);
+    /// ```
+    pub fn portion(&self) -> DayPortion {
+        self.flags.portion()
+    }
+
+    /// Returns the BCP 47 tag of the language [`name`](Holiday::name) is in,
+    /// so UIs can decide whether to translate it.
+    ///
+    /// Most records are in the [default
+    /// language](Country::name_language) of their country, which is also
+    /// assumed for records constructed with [`Holiday::new`].
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let date = Date::from_ymd(2025, 1, 1);
+    /// let us = holidays::get_holidays(Country::US, date).next().unwrap();
+    /// let it = holidays::get_holidays(Country::IT, date).next().unwrap();
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!((us.name, us.name_language()), ("New Year's Day", "en"));
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!((it.name, it.name_language()), ("Capodanno", "it"));
+    /// ```
+    pub fn name_language(&self) -> &'static str {
+        self.as_ref_handle()
+            .and_then(|it| data::name_language_override(it.index()))
+            .unwrap_or_else(|| self.code.name_language())
+    }
+
     /// Returns the date of the holiday in specified format.
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    /// use holidays::{Country, Date};
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// assert_eq!(holiday.date::<Date>(), Ok(Date::from_ymd(2025, 1, 1)));
+    /// assert!(holiday.date::<SystemTime>().is_ok());
+    /// ```
     pub fn date<D>(&self) -> Result<D, DateConversionError>
     where
         D: TryFrom<Date>,
@@ -108,6 +381,367 @@ impl Holiday {
         // cleaned up.
         <D as TryFrom<Date>>::try_from(self.date).map_err(|_| DateConversionError)
     }
+
+    /// Returns the date of the holiday as [`DefaultDate`], which depends on
+    /// enabled features.
+    ///
+    /// Unlike [`Holiday::date`], the result type never needs to be
+    /// annotated, which is convenient for examples and scripts. Dates of
+    /// embedded records always convert successfully.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// let date = holiday.date_default().unwrap();
+    /// # let _: holidays::DefaultDate = date;
+    /// ```
+    pub fn date_default(&self) -> Result<DefaultDate, DateConversionError> {
+        self.date()
+    }
+
+    /// Returns the date of the holiday as a [`CivilDate`], which doesn't
+    /// depend on enabled features and can't fail.
+    ///
+    /// Prefer it over [`Holiday::date`] in public APIs of libraries, so
+    /// their users can convert it to the time crate of their choice.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// let date = holiday.civil_date();
+    /// assert_eq!((date.year(), date.month(), date.day()), (2025, 1, 1));
+    /// ```
+    pub fn civil_date(&self) -> CivilDate {
+        self.date.into()
+    }
+
+    /// Writes the date of the holiday in ISO 8601 `YYYY-MM-DD` format into
+    /// `buf`, without allocating.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    ///
+    /// let mut buf = String::new();
+    /// holiday.format_date(&mut buf).unwrap();
+    /// assert_eq!(buf, "2025-01-01");
+    /// ```
+    pub fn format_date<W: std::fmt::Write + ?Sized>(&self, buf: &mut W) -> std::fmt::Result {
+        self.date.format_iso(buf)
+    }
+
+    /// Returns an adapter that displays the date of the holiday in ISO 8601
+    /// `YYYY-MM-DD` format.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(format!("{}: {}", holiday.display_date(), holiday.name), "2025-01-01: New Year's Day");
+    /// ```
+    pub fn display_date(&self) -> DisplayDate {
+        DisplayDate(self.date)
+    }
+
+    /// Returns the date of the holiday formatted in ISO 8601 `YYYY-MM-DD`
+    /// format into an inline buffer, without allocating.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, 2025..2026).next().unwrap();
+    /// let date = holiday.iso_date_buf();
+    /// assert_eq!(date.as_str(), "2025-01-01");
+    /// assert_eq!(date.to_string(), holiday.display_date().to_string());
+    /// ```
+    pub fn iso_date_buf(&self) -> IsoDateBuf {
+        IsoDateBuf::from(self.date)
+    }
+
+    /// Returns an adapter that displays an identifier of the holiday which
+    /// is stable across dataset updates, e.g. for UIDs of calendar events.
+    ///
+    /// The identifier is formatted as `{code}-{yyyymmdd}-{slug}`, where the
+    /// slug is the [normalized](normalize_name) name with words joined by
+    /// `-`, so records that only differ in punctuation, letter case or
+    /// diacritics of their names share it. Countries are identified by their
+    /// code, which unlike [sort keys](Holiday::sort_key) doesn't change when
+    /// countries are added. With `no-names` feature the slug is empty, so
+    /// holidays a country observes on the same date share their identifier.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let holiday = holidays::get_holidays(Country::US, Date::from_ymd(2025, 1, 20)).next().unwrap();
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(holiday.uid().to_string(), "US-20250120-martin-luther-king-jr-day");
+    /// ```
+    pub fn uid(&self) -> impl std::fmt::Display {
+        HolidayUid(*self)
+    }
+}
+
+/// Adapter returned by [`Holiday::uid`].
+struct HolidayUid(Holiday);
+
+impl std::fmt::Display for HolidayUid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        let (y, m, d) = self.0.date.ymd();
+        write!(f, "{}-{y:04}{m:02}{d:02}-", self.0.code)?;
+        name::slug_chars(self.0.name).try_for_each(|it| f.write_char(it))
+    }
+}
+
+/// An owned counterpart of [`Holiday`], for names that aren't `'static`.
+///
+/// Like [`Holiday::new`], constructed values aren't guaranteed to exist in
+/// the embedded dataset.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, HolidayRecord};
+///
+/// let name = String::from("Company Anniversary");
+/// let record = HolidayRecord::new(Country::DE, Date::from_ymd(2025, 3, 14), name);
+/// assert_eq!(record.display_date().to_string(), "2025-03-14");
+///
+/// let holiday = holidays::get_holidays(Country::DE, Date::from_ymd(2025, 12, 25)).next().unwrap();
+/// let owned = HolidayRecord::from(holiday);
+/// assert_eq!(owned.name, holiday.name);
+/// assert_eq!(owned.date::<Date>(), holiday.date::<Date>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayRecord {
+    /// The `Country` this holiday is observed in.
+    pub code: Country,
+    /// The date of the holiday.
+    date: Date,
+    /// The name of the holiday.
+    pub name: String,
+}
+
+impl HolidayRecord {
+    /// Constructs a holiday record.
+    ///
+    /// ```
+    /// use holidays::{Country, Date, HolidayRecord};
+    ///
+    /// let record = HolidayRecord::new(Country::NL, Date::from_ymd(2025, 4, 27), format!("King's Day"));
+    /// assert_eq!(record.name, "King's Day");
+    /// ```
+    pub fn new(code: Country, date: impl Into<Date>, name: impl Into<String>) -> Self {
+        HolidayRecord {
+            code,
+            date: date.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Returns the date of the holiday in specified format.
+    ///
+    /// ```
+    /// use holidays::{CivilDate, Country, Date, HolidayRecord};
+    ///
+    /// let record = HolidayRecord::new(Country::NL, Date::from_ymd(2025, 4, 27), "King's Day");
+    /// assert_eq!(record.date::<CivilDate>(), Ok(CivilDate::new(2025, 4, 27).unwrap()));
+    /// ```
+    pub fn date<D>(&self) -> Result<D, DateConversionError>
+    where
+        D: TryFrom<Date>,
+    {
+        <D as TryFrom<Date>>::try_from(self.date).map_err(|_| DateConversionError)
+    }
+
+    /// Returns an adapter that displays the date of the holiday in ISO 8601
+    /// `YYYY-MM-DD` format.
+    ///
+    /// ```
+    /// use holidays::{Country, Date, HolidayRecord};
+    ///
+    /// let record = HolidayRecord::new(Country::NL, Date::from_ymd(2025, 4, 27), "King's Day");
+    /// assert_eq!(format!("{}: {}", record.display_date(), record.name), "2025-04-27: King's Day");
+    /// ```
+    pub fn display_date(&self) -> DisplayDate {
+        DisplayDate(self.date)
+    }
+}
+
+impl From<&Holiday> for HolidayRecord {
+    fn from(value: &Holiday) -> Self {
+        HolidayRecord {
+            code: value.code,
+            date: value.date,
+            name: value.name.to_string(),
+        }
+    }
+}
+
+impl From<Holiday> for HolidayRecord {
+    fn from(value: Holiday) -> Self {
+        HolidayRecord::from(&value)
+    }
+}
+
+/// Represents a holiday shared by a set of countries that observe it on the same
+/// date under the same name.
+///
+/// Produced by [`Iter::collapse_shared`].
+///
+/// ```
+/// use holidays::Country;
+///
+/// for shared in holidays::get_holidays([Country::US, Country::CA], 2025..2026).collapse_shared() {
+///     let countries: Vec<_> = shared.countries.into_iter().map(|it| it.to_string()).collect();
+///     println!("{}: {} ({})", shared.display_date(), shared.name, countries.join(", "));
+/// }
+///
+/// let new_year = holidays::get_holidays([Country::US, Country::CA], 2025..2026)
+///     .collapse_shared()
+///     .next()
+///     .unwrap();
+/// assert_eq!(new_year.countries.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SharedHoliday {
+    /// The date of the holiday.
+    date: Date,
+    /// The name of the holiday.
+    pub name: &'static str,
+    /// Countries that observe this holiday.
+    pub countries: CountrySet,
+}
+
+impl SharedHoliday {
+    /// Returns the date of the holiday in specified format.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let shared = holidays::get_holidays([Country::AT, Country::DE], Date::from_ymd(2025, 12, 25))
+    ///     .collapse_shared()
+    ///     .next()
+    ///     .unwrap();
+    /// assert_eq!(shared.date::<Date>(), Ok(Date::from_ymd(2025, 12, 25)));
+    /// ```
+    pub fn date<D>(&self) -> Result<D, DateConversionError>
+    where
+        D: TryFrom<Date>,
+    {
+        <D as TryFrom<Date>>::try_from(self.date).map_err(|_| DateConversionError)
+    }
+
+    /// Writes the date of the holiday in ISO 8601 `YYYY-MM-DD` format into
+    /// `buf`, without allocating.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let shared = holidays::get_holidays([Country::AT, Country::DE], 2025..2026)
+    ///     .collapse_shared()
+    ///     .next()
+    ///     .unwrap();
+    /// let mut buf = String::from("Date: ");
+    /// shared.format_date(&mut buf).unwrap();
+    /// assert_eq!(buf, "Date: 2025-01-01");
+    /// ```
+    pub fn format_date<W: std::fmt::Write + ?Sized>(&self, buf: &mut W) -> std::fmt::Result {
+        self.date.format_iso(buf)
+    }
+
+    /// Returns an adapter that displays the date of the holiday in ISO 8601
+    /// `YYYY-MM-DD` format.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let shared = holidays::get_holidays([Country::AT, Country::DE], 2025..2026)
+    ///     .collapse_shared()
+    ///     .next()
+    ///     .unwrap();
+    /// assert_eq!(shared.display_date().to_string(), "2025-01-01");
+    /// ```
+    pub fn display_date(&self) -> DisplayDate {
+        DisplayDate(self.date)
+    }
+}
+
+/// Represents all holidays of a query observed on a single day.
+///
+/// Produced by [`Iter::by_day`].
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Any;
+///
+/// let new_year = holidays::get_holidays(Any, 2025..2026).by_day().next().unwrap();
+/// assert_eq!(new_year.date::<Date>(), Ok(Date::from_ymd(2025, 1, 1)));
+/// // some countries observe multiple holidays on New Year's Day
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(new_year.countries().len() < new_year.holidays.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayHolidays {
+    /// The date of the holidays.
+    date: Date,
+    /// Holidays observed on the date, ordered by country code and then by
+    /// name.
+    pub holidays: Vec<&'static Holiday>,
+}
+
+impl DayHolidays {
+    /// Returns the date of the holidays in specified format.
+    ///
+    /// ```
+    /// use holidays::{CivilDate, Country};
+    ///
+    /// let day = holidays::get_holidays([Country::FR, Country::IT], 2025..2026).by_day().next().unwrap();
+    /// assert_eq!(day.date::<CivilDate>(), Ok(CivilDate::new(2025, 1, 1).unwrap()));
+    /// ```
+    pub fn date<D>(&self) -> Result<D, DateConversionError>
+    where
+        D: TryFrom<Date>,
+    {
+        <D as TryFrom<Date>>::try_from(self.date).map_err(|_| DateConversionError)
+    }
+
+    /// Returns an adapter that displays the date of the holidays in ISO 8601
+    /// `YYYY-MM-DD` format.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// for day in holidays::get_holidays([Country::FR, Country::IT], 2025..2026).by_day() {
+    ///     println!("{}: {} holidays", day.display_date(), day.holidays.len());
+    /// }
+    /// ```
+    pub fn display_date(&self) -> DisplayDate {
+        DisplayDate(self.date)
+    }
+
+    /// Returns the set of countries that observe the holidays.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let day = holidays::get_holidays([Country::FR, Country::IT, Country::US], Date::from_ymd(2025, 8, 15))
+    ///     .by_day()
+    ///     .next()
+    ///     .unwrap();
+    /// assert!(day.countries().contains(Country::IT));
+    /// assert!(!day.countries().contains(Country::US));
+    /// ```
+    pub fn countries(&self) -> CountrySet {
+        self.holidays.iter().map(|it| it.code).collect()
+    }
 }
 
 /// Queries holidays by countries and date selection and returns an iterator
@@ -116,10 +750,10 @@ impl Holiday {
 /// # Parameters
 /// - `countries`: A value that represents a country selection. It can be:
 ///   - [`Any`] to query all countries,
-///   - [`Option`] acts as [`Any`] if `None`,
+///   - [`Option`] of a country or a container acts as [`Any`] if `None`,
 ///   - a single [`Country`], or
-///   - any [iterable] container of [`Country`]s (an array, slice, [`Vec`],
-///     etc.).
+///   - any [container] of [`Country`]s (an array, slice, [`Vec`], etc.), or
+///     [`CountrySelection::Many`] of any [iterable].
 /// - `date`: A value that represents a date range. It can be:
 ///   - [`Any`] to query all available dates,
 ///   - [`Option`] acts as [`Any`] if `None`,
@@ -127,7 +761,8 @@ impl Holiday {
 ///   - a [range] of dates.
 ///
 /// # Type Parameters
-/// - `CountryIter`: An [iterable] collection of `Country` values.
+/// - `CountryIter`: A [container] of `Country` values, or any [iterable] of
+///   them in [`CountrySelection::Many`].
 /// - `DateLike`: A type that can be converted into a date. Can be any of:
 ///   - an `isize` representing a Gregorian calendar year,
 ///   - a [`SystemTime`],
@@ -137,7 +772,7 @@ impl Holiday {
 )]
 #[cfg_attr(
     feature = "time",
-    doc = "  - a [`time::Date`], [`time::OffsetDateTime`], or [`time::PrimitiveDateTime`],"
+    doc = "  - a [`time::Date`], [`time::OffsetDateTime`], [`time::PrimitiveDateTime`], or [`time::UtcDateTime`],"
 )]
 /// - `DateRange`: A type implementing [`RangeBounds<DateLike>`], like
 ///   [`std::ops::Range`] or [`std::ops::RangeInclusive`].
@@ -145,11 +780,17 @@ impl Holiday {
 /// In most cases these type parameters can be automatically inferred from
 /// provided arguments and don't need to be explicitly specified.
 /// 
+/// # Ordering
+///
+/// Holidays are yielded sorted by date, then by country code and then by
+/// name, for any selection and [`Strategy`]. Switching from a single
+/// date to a range of dates, or between strategies, never reorders results.
+///
 /// # Examples
 ///
 /// Query holidays for a single country and a single date:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::Country;
 ///
 /// let mut holidays = holidays::get_holidays(
@@ -158,6 +799,7 @@ impl Holiday {
 /// );
 /// let holiday = holidays.next().expect("missing data");
 /// 
+/// # #[cfg(not(feature = "no-names"))]
 /// assert_eq!(holiday.name, "Independence Day");
 /// ```
 /// 
@@ -171,12 +813,12 @@ impl Holiday {
 /// );
 /// let observed_holidays = holidays.count();
 /// 
-/// assert_eq!(observed_holidays, 19);
+/// assert_eq!(observed_holidays, 20);
 /// ```
 /// 
 /// Query holidays over a specific range of dates:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::Country;
 ///
 /// let start = Date::from_ymd(2025, 12, 20);
@@ -189,21 +831,24 @@ impl Holiday {
 ///
 /// let names: Vec<_> = holidays.map(|h| h.name).collect();
 ///
+/// # #[cfg(not(feature = "no-names"))]
 /// assert!(names.contains(&"Christmas Day"));
+/// # #[cfg(not(feature = "no-names"))]
 /// assert!(names.contains(&"New Year's Day"));
 /// ```
 /// 
 /// Query holidays for multiple countries on a specific date:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::Country;
 ///
 /// let mut holidays = holidays::get_holidays(
-///   &[Country::JP, Country::US], // can be any IntoIterator<Item = Into<Country>>
+///   &[Country::JP, Country::US], // can be any container of countries
 ///   Date::from_ymd(2025, 9, 23)
 /// );
 /// let holiday = holidays.next().unwrap();
 /// 
+/// # #[cfg(not(feature = "no-names"))]
 /// assert_eq!(holiday.name, "Autumnal Equinox");
 /// // Autumnal Equinox wasn't observed in US.
 /// assert_eq!(holidays.next(), None);
@@ -211,7 +856,7 @@ impl Holiday {
 /// 
 /// Use [`Any`] to include all countries or all dates without filtering:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::Any;
 ///
 /// let holidays = holidays::get_holidays(
@@ -225,9 +870,11 @@ impl Holiday {
 /// ```
 /// 
 /// [iterable]: std::iter::IntoIterator
+/// [container]: selection::CountryCollection
 /// [`SystemTime`]: std::time::SystemTime
 /// [range]: std::ops::RangeBounds
 /// [`RangeBounds<DateLike>`]: std::ops::RangeBounds
+#[inline]
 pub fn get_holidays<CountryIter, DateLike, DateRange>(
     countries: impl Into<CountrySelection<CountryIter>>,
     date: impl Into<DateSelection<DateLike, DateRange>>,
@@ -238,9 +885,89 @@ where
     DateLike: Into<Date> + Clone,
     DateRange: std::ops::RangeBounds<DateLike>,
 {
-    let country_query = countries.into().into_query();
-    let date_query = date.into().into_query();
-    country_query.and(date_query).into_iter()
+    PreparedQuery::new(countries, date).iter()
+}
+
+/// Returns holidays observed in the specified countries and date selection,
+/// yielded in the given `order`.
+///
+/// Accepts the same inputs as [`get_holidays`], which is equivalent to
+/// [`Order::DateThenCountry`]. With [`Order::CountryThenDate`] all holidays of
+/// a country are yielded before any of the next one, which is cheaper than
+/// merging them by date when results are consumed country by country.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Order};
+///
+/// let mut holidays = holidays::get_holidays_ordered(
+///     [Country::US, Country::FR],
+///     2026..2027,
+///     Order::CountryThenDate,
+/// );
+///
+/// let first = holidays.next().unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!((first.code, first.name), (Country::FR, "New Year's Day"));
+/// assert_eq!(holidays.nth(10).map(|it| it.code), Some(Country::US));
+/// ```
+pub fn get_holidays_ordered<CountryIter, DateLike, DateRange>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+    order: Order,
+) -> query::Iter
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    let options = QueryOptions::DEFAULT.with_order(order);
+    PreparedQuery::new(countries, date)
+        .with_options(options)
+        .iter()
+}
+
+/// Returns holidays observed in the specified countries and date selection,
+/// grouped by country.
+///
+/// Accepts the same inputs as [`get_holidays`]. Each selected country has a
+/// group, which is empty if the country observes no holidays on selected
+/// dates, so [`GroupedHolidays::get`] tells countries without holidays apart
+/// from ones that weren't selected; [`Any`] selects, and so groups, all
+/// countries.
+///
+/// Groups are filled from per-country record tables bounded by the selected
+/// dates, without scanning records of other countries.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// let countries = [Country::US, Country::FR, Country::JP];
+/// let grouped = holidays::get_holidays_grouped(countries, 2026..2027);
+///
+/// assert_eq!(grouped.len(), 3);
+/// assert_eq!(grouped.get(Country::FR).map(|it| it.len()), Some(11));
+/// assert_eq!(grouped.get(Country::DE), None);
+/// ```
+pub fn get_holidays_grouped<CountryIter, DateLike, DateRange>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+) -> GroupedHolidays
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    let query = date.into().into_query();
+    GroupedHolidays::new(countries.into().into_set().iter().map(|country| {
+        let holidays = query.dates_of(country).iter().map(|it| &data::DATA[*it]);
+        (country, holidays.collect())
+    }))
 }
 
 /// Returns `true` if any holidays are observed in the specified countries
@@ -253,9 +980,10 @@ where
 /// # Parameters
 /// - `countries`: A value that represents a country selection. It can be:
 ///   - [`Any`] to check across all countries,
-///   - [`Option`] (treated as [`Any`] if `None`),
+///   - [`Option`] of a country or a container (treated as [`Any`] if `None`),
 ///   - a single [`Country`], or
-///   - any [iterable] container of [`Country`]s (an array, slice, [`Vec`], etc.).
+///   - any [container] of [`Country`]s (an array, slice, [`Vec`], etc.), or
+///     [`CountrySelection::Many`] of any [iterable].
 /// - `date`: A value that represents a date range. It can be:
 ///   - [`Any`] to check across all dates,
 ///   - [`Option`] (treated as [`Any`] if `None`),
@@ -263,7 +991,8 @@ where
 ///   - a [range] of dates.
 ///
 /// # Type Parameters
-/// - `CountryIter`: An [iterable] collection of `Country` values.
+/// - `CountryIter`: A [container] of `Country` values, or any [iterable] of
+///   them in [`CountrySelection::Many`].
 /// - `DateLike`: A type that can be converted into a date. Can be:
 ///   - an `isize` representing a Gregorian calendar year,
 ///   - a [`SystemTime`],
@@ -273,7 +1002,7 @@ where
 )]
 #[cfg_attr(
     feature = "time",
-    doc = "  - a [`time::Date`], [`time::OffsetDateTime`], or [`time::PrimitiveDateTime`],"
+    doc = "  - a [`time::Date`], [`time::OffsetDateTime`], [`time::PrimitiveDateTime`], or [`time::UtcDateTime`],"
 )]
 /// - `DateRange`: A type implementing [`RangeBounds<DateLike>`], such as
 ///   [`std::ops::Range`] or [`std::ops::RangeInclusive`].
@@ -282,7 +1011,7 @@ where
 ///
 /// Check if a specific day is a holiday in a given country:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::{Country, is_holiday};
 ///
 /// assert!(is_holiday(Country::US, Date::from_ymd(2025, 7, 4)));
@@ -291,7 +1020,7 @@ where
 ///
 /// Check for any holidays in multiple countries:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::{Country, is_holiday};
 ///
 /// let countries = &[Country::US, Country::JP];
@@ -302,7 +1031,7 @@ where
 ///
 /// Check for holidays within a date range:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// use holidays::{Country, is_holiday};
 ///
 /// let range = Date::from_ymd(2025, 12, 24)..=Date::from_ymd(2025, 12, 26);
@@ -312,13 +1041,14 @@ where
 ///
 /// Use [`Any`] to check if *any* country observes a holiday on a given date:
 /// ```
-/// # use holidays::internal::Date;
+/// # use holidays::Date;
 /// # use holidays::{Any, is_holiday};
 ///
 /// assert!(is_holiday(Any, Date::from_ymd(2025, 1, 1)));
 /// ```
 ///
 /// [iterable]: std::iter::IntoIterator
+/// [container]: selection::CountryCollection
 /// [`SystemTime`]: std::time::SystemTime
 /// [range]: std::ops::RangeBounds
 /// [`RangeBounds<DateLike>`]: std::ops::RangeBounds
@@ -336,61 +1066,2042 @@ where
     get_holidays(countries, date).next().is_some()
 }
 
+/// Returns `true` if the `country` currently observes a holiday, with the
+/// current date taken in the time zone `tz`.
+///
+/// Requires the `chrono` feature. With the `chrono-tz` feature enabled,
+/// [`chrono_tz::Tz`] and [`Country`] are also accepted as `tz`, the latter
+/// using the [default time zone](Country::time_zone_name) of the country.
+///
+/// # Multiple time zones
+///
+/// A country observes a holiday on the same calendar date everywhere, but
+/// the date changes at different instants across its time zones. The
+/// default time zone of a country is the one of its capital, so for
+/// countries spanning multiple time zones, such as [`Country::US`] or
+/// [`Country::RU`], the result can differ from the one in other parts of the
+/// country for hours around midnight. Pass the time zone of the place in
+/// question whenever it's known.
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use holidays::{is_holiday_now_in, Country};
+///
+/// // New Zealand daylight time
+/// let nzdt = FixedOffset::east_opt(13 * 3600).unwrap();
+/// let _ = is_holiday_now_in(Country::NZ, nzdt);
+/// ```
+///
+/// [`chrono_tz::Tz`]: https://docs.rs/chrono-tz/latest/chrono_tz/enum.Tz.html
+#[cfg(feature = "chrono")]
+pub fn is_holiday_now_in(country: Country, tz: impl Into<TimeZoneRef>) -> bool {
+    is_holiday_at(country, tz, chrono::Utc::now())
+}
+
+/// [`is_holiday_now_in`] at a fixed `instant`.
+#[cfg(feature = "chrono")]
+pub(crate) fn is_holiday_at(
+    country: Country,
+    tz: impl Into<TimeZoneRef>,
+    instant: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    is_holiday(country, tz.into().date_at(instant))
+}
+
+/// Queries holidays of a country with an optional `code`, treating `None` as
+/// [`Any`].
+///
+/// This is a convenience for codes that come from user input, such as HTTP
+/// query parameters; see [`CountrySelection::try_from_code`].
+///
+/// # Errors
+///
+/// Returns [`CountryParseError`](error::CountryParseError) if `code` isn't a
+/// valid country code. Empty codes are errors too and don't select all
+/// countries.
+///
+/// # Examples
+///
+/// ```
+/// let count = holidays::get_holidays_opt_code(Some("JP"), 2025..2027).unwrap().count();
+/// assert_eq!(count, 37);
+///
+/// assert!(holidays::get_holidays_opt_code(Some("XX"), 2025..2027).is_err());
+/// ```
+pub fn get_holidays_opt_code<DateLike, DateRange>(
+    code: Option<&str>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+) -> Result<query::Iter, error::CountryParseError>
+where
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    Ok(get_holidays(CountrySelection::try_from_code(code)?, date))
+}
+
+/// Returns information about the embedded dataset, such as its
+/// [attribution](DatasetInfo::attribution).
+///
+/// # Examples
+///
+/// ```
+/// let attribution = holidays::dataset_info().attribution();
+/// println!("Holiday data: {attribution}");
+/// # assert!(!attribution.license.is_empty());
+/// ```
+pub const fn dataset_info() -> DatasetInfo {
+    DatasetInfo::EMBEDDED
+}
+
+/// Returns the configuration the crate was built with, e.g. to include in
+/// bug reports.
+///
+/// The configuration consists of `key=value` lines, each listing:
+///
+/// - `countries`: comma separated codes of enabled countries;
+/// - `features`: comma separated optional features other than countries,
+///   such as `chrono` or `no-names`;
+/// - `years`: embedded years requested by `HOLIDAYS_MIN_YEAR` and
+///   `HOLIDAYS_MAX_YEAR`, see [build configuration](crate#build-configuration);
+/// - `data_years`: years of the first and the last embedded record.
+///
+/// Nothing else of the build environment is included.
+///
+/// # Examples
+///
+/// ```
+/// let config = holidays::build_config();
+/// println!("holidays build:\n{config}");
+/// assert!(config.lines().any(|it| it.starts_with("data_years=")));
+/// ```
+pub const fn build_config() -> &'static str {
+    DatasetInfo::EMBEDDED.build_config()
+}
+
+/// Returns an iterator over all holidays with `name`, sorted by date.
+///
+/// Names are compared after [normalization](normalize_name), so
+/// `"boxing day"` matches "Boxing Day", and `"ano nuevo"` matches
+/// "Año Nuevo".
+///
+/// Lookup uses the embedded name index if the crate is built with `name-index`
+/// feature, and scans all records otherwise. With `no-names` feature no
+/// holidays are returned. Results of other queries are narrowed down to a
+/// name with the [`NameIs`](filter::NameIs) filter.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// let christmas = holidays::with_name("Christmas Day");
+///
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(christmas.map(|it| it.code).any(|it| it == Country::GB));
+/// ```
+pub fn with_name(name: &str) -> impl Iterator<Item = &'static Holiday> + '_ {
+    data::holidays_with_name(name)
+}
+
 /// Returns an iterator that provides dates of first and last event for all
 /// given `countries` in requested `DateFormat`.
-/// 
-/// # Panics
-/// 
-/// Returned iterator will panic if requested `DateFormat` can't represent date
-/// of first or last event for some country.
-pub fn get_bounding_entries<DateFormat, CountryIter>(
+///
+/// Any type that [`Holiday::date`] converts to can be requested; use
+/// [`get_bounding_dates`] for conversions that can't fail.
+///
+/// # Errors
+///
+/// Bounds are a [`DateConversionError`] if requested `DateFormat` can't
+/// represent date of first or last event of the country.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::SystemTime;
+/// use holidays::Country;
+///
+/// for (country, bounds) in holidays::try_get_bounding_entries::<SystemTime, _>([Country::US, Country::JP]) {
+///     let (first, last) = bounds.unwrap()?;
+///     assert!(first < last, "{country} records aren't ordered");
+/// }
+/// # Ok::<(), holidays::error::DateConversionError>(())
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn try_get_bounding_entries<DateFormat, CountryIter>(
     countries: impl Into<CountrySelection<CountryIter>>,
-) -> impl Iterator<Item = (Country, Option<(DateFormat, DateFormat)>)>
+) -> impl Iterator<
+    Item = (
+        Country,
+        Option<Result<(DateFormat, DateFormat), DateConversionError>>,
+    ),
+>
 where
-    DateFormat: TryFrom<Date, Error = DateConversionError>,
+    DateFormat: TryFrom<Date>,
     CountryIter: IntoIterator,
     CountryIter::Item: Into<crate::Country>,
 {
     countries.into().bounds().map(|(country, bounds)| {
         (
             country,
-            bounds.map(|(min, max)| (min.date().unwrap(), max.date().unwrap())),
+            bounds.map(|(min, max)| Ok((min.date()?, max.date()?))),
         )
     })
 }
 
-/// Error types returned from the crate.
-pub mod error {
-    pub use crate::country::CountryParseError;
-    pub use crate::date::DateConversionError;
-
-    macro_rules! error_msg {
-        ($err: ty, $message: literal $(, $($arg: tt),+)?) => {
-            impl std::fmt::Display for $err {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    write!(f, $message $(, $(self.$arg),+)?)
-                }
-            }
-            impl core::error::Error for $err {}
-        };
-    }
-    pub(crate) use error_msg;
-}
+/// Like [`try_get_bounding_entries`], but panics on dates that can't be
+/// converted.
+///
+/// # Panics
+///
+/// Returned iterator will panic if requested `DateFormat` can't represent date
+/// of first or last event for some country.
+///
+/// ```
+/// # #![allow(deprecated)]
+/// use holidays::{Country, Date};
+///
+/// for (country, bounds) in holidays::get_bounding_entries::<Date, _>([Country::US, Country::JP]) {
+///     let (first, last) = bounds.unwrap();
+///     assert!(first < last, "{country} records aren't ordered");
+/// }
+/// ```
+#[deprecated(
+    note = "use `try_get_bounding_entries`, or `get_bounding_dates` for infallible bounds"
+)]
+pub fn get_bounding_entries<DateFormat, CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+) -> impl Iterator<Item = (Country, Option<(DateFormat, DateFormat)>)>
+where
+    DateFormat: TryFrom<Date>,
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    try_get_bounding_entries(countries).map(|(country, bounds)| {
+        let bounds =
+            bounds.map(|it| it.expect("DateFormat can't represent bounds of embedded data"));
+        (country, bounds)
+    })
+}
+
+/// Returns an iterator that provides dates of first and last event for all
+/// given `countries`.
+///
+/// This is an infallible variant of [`try_get_bounding_entries`].
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// let (country, bounds) = holidays::get_bounding_dates(Country::US).next().unwrap();
+/// let (first, last) = bounds.unwrap();
+///
+/// assert_eq!(country, Country::US);
+/// assert_eq!(first.to_string(), format!("{}-01-01", holidays::CoveredYear::MIN.get()));
+/// assert!(last.to_string().ends_with("-12-25"));
+/// ```
+pub fn get_bounding_dates<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+) -> impl Iterator<Item = (Country, Option<(DisplayDate, DisplayDate)>)>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    countries.into().bounds().map(|(country, bounds)| {
+        (
+            country,
+            bounds.map(|(min, max)| (min.display_date(), max.display_date())),
+        )
+    })
+}
+
+/// Returns an iterator that provides first and last holiday within the `date`
+/// selection for all given `countries`.
+///
+/// Countries that don't observe any holidays within the selected dates are
+/// paired with `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let (country, bounds) = holidays::bounding_in(Country::US, 2025..2026)
+///     .next()
+///     .unwrap();
+/// let (first, last) = bounds.unwrap();
+///
+/// assert_eq!(country, Country::US);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(first.name, "New Year's Day");
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(last.name, "Christmas Day");
+/// ```
+pub fn bounding_in<CountryIter, DateLike, DateRange>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+) -> impl Iterator<Item = (Country, Option<(&'static Holiday, &'static Holiday)>)>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    countries.into().bounds_in(date.into())
+}
+
+/// Returns an iterator over holidays observed in the specified `countries` on
+/// the `start` date or any of the following `days`.
+///
+/// The window is inclusive, so `days` equal to `0` only selects the `start`
+/// date. Parts of the window outside of embedded data yield no holidays.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let holidays = holidays::within(Country::US, Date::from_ymd(2025, 12, 20), 14);
+/// let names: Vec<_> = holidays.names().collect();
+///
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(names, ["Christmas Day", "New Year's Day"]);
+/// ```
+pub fn within<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    start: impl Into<Date>,
+    days: u32,
+) -> Iter
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    let start = start.into();
+    let end = start.saturating_add_days(days as i64);
+    get_holidays(countries, start..=end)
+}
+
+/// Returns a date selection of all days at most `tolerance_days` away from
+/// `date`, in either direction.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// // is there a holiday within 2 days of 2025-12-27?
+/// assert!(holidays::is_holiday(Country::US, holidays::near(Date::from_ymd(2025, 12, 27), 2)));
+/// assert!(!holidays::is_holiday(Country::US, holidays::near(Date::from_ymd(2025, 12, 28), 2)));
+/// ```
+pub fn near(
+    date: impl Into<Date>,
+    tolerance_days: u8,
+) -> DateSelection<Date, std::ops::RangeInclusive<Date>> {
+    let date = date.into();
+    let tolerance = tolerance_days as i64;
+    DateSelection::Range(date.saturating_add_days(-tolerance)..=date.saturating_add_days(tolerance))
+}
+
+/// Returns dates on `day` of `month` in every year covered by embedded
+/// holidays of `country`, skipping years without such a date.
+fn month_day_dates(country: Country, month: Month, day: u8) -> impl Iterator<Item = Date> {
+    let coverage = country.coverage();
+    (coverage.first_year..=coverage.last_year)
+        .filter_map(move |year| Date::from_ymd_opt(year, month.number(), day))
+}
+
+/// Returns holidays of `country` on `day` of `month`, one per year at most,
+/// sorted by date.
+///
+/// Each year covered by the country's [coverage](Country::coverage) is
+/// looked up directly, without scanning its records.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// let first = holidays::on_month_day(Country::FR, Month::JULY, 14).next().unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(first.name, "National Day");
+/// ```
+pub fn on_month_day(
+    country: Country,
+    month: Month,
+    day: u8,
+) -> impl Iterator<Item = &'static Holiday> {
+    month_day_dates(country, month, day)
+        .filter_map(move |date| data::country_date_to_holiday(country, date))
+}
+
+/// Returns `true` if `country` observes a holiday on `day` of `month` in
+/// every year covered by its [coverage](Country::coverage).
+///
+/// Only years that have such a date are considered, so February 29 is
+/// checked in leap years alone. Returns `false` if no covered year has the
+/// date, e.g. for April 31.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// assert!(holidays::always_holiday(Country::FR, Month::JULY, 14));
+/// assert!(!holidays::always_holiday(Country::FR, Month::JULY, 15));
+/// ```
+pub fn always_holiday(country: Country, month: Month, day: u8) -> bool {
+    let mut dates = month_day_dates(country, month, day).peekable();
+    dates.peek().is_some() && dates.all(|date| data::country_date_to_index(country, date).is_some())
+}
+
+/// Returns years covered by embedded holidays of `country` in which `day`
+/// of `month` is a holiday, in ascending order.
+///
+/// Each year is looked up directly. Years without such a date, e.g. common
+/// years for February 29, are left out of both this and [`missing_years`].
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// let years = holidays::occurrence_years(Country::US, Month::JULY, 4);
+/// let coverage = Country::US.coverage();
+/// assert_eq!(years.len() as i64, coverage.last_year - coverage.first_year + 1);
+/// assert_eq!(years[0].get(), coverage.first_year);
+/// ```
+pub fn occurrence_years(country: Country, month: Month, day: u8) -> Vec<CoveredYear> {
+    month_day_dates(country, month, day)
+        .filter(|date| data::country_date_to_index(country, *date).is_some())
+        .filter_map(|date| CoveredYear::new(date.year()))
+        .collect()
+}
+
+/// Returns years covered by embedded holidays of `country` in which `day`
+/// of `month` isn't a holiday, in ascending order.
+///
+/// The complement of [`occurrence_years`] within the country's
+/// [coverage](Country::coverage).
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// assert!(holidays::missing_years(Country::GB, Month::DECEMBER, 25).is_empty());
+/// ```
+pub fn missing_years(country: Country, month: Month, day: u8) -> Vec<CoveredYear> {
+    month_day_dates(country, month, day)
+        .filter(|date| data::country_date_to_index(country, *date).is_none())
+        .filter_map(|date| CoveredYear::new(date.year()))
+        .collect()
+}
+
+/// Returns the first day of fiscal `year` starting on `start_day` of
+/// `start_month`, clamping the day into the month.
+fn fiscal_year_start(start_month: Month, start_day: u8, year: i64) -> Date {
+    let year = year.clamp(Date::MIN_YEAR, Date::MAX_YEAR);
+    let month = start_month.number();
+    Date::from_ymd(
+        year,
+        month,
+        start_day.clamp(1, Date::days_in_month(year, month)),
+    )
+}
+
+/// Returns a date selection of a fiscal year that starts on `start_day` of
+/// `start_month` in `year`, and lasts until the same day of the next year.
+///
+/// Fiscal years are identified by the calendar year they start in; for
+/// conventions that name them by the year they end in, such as the US
+/// federal fiscal year, pass `year - 1`.
+///
+/// Start days past the end of the month are clamped to its last day, so a
+/// fiscal year starting on February 29 starts on February 28 in common years,
+/// and a `start_day` of `0` is treated as `1`.
+///
+/// # Errors
+///
+/// Returns [`YearOutOfRange`](error::YearOutOfRange) if `year` isn't a
+/// [`CoveredYear`].
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// // fiscal year 2025 runs from 2025-04-01 to 2026-03-31
+/// let names: Vec<_> = holidays::get_holidays(Country::JP, holidays::fiscal_year(Month::APRIL, 1, 2025)?)
+///     .names()
+///     .collect();
+///
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(names.first(), Some(&"Showa Day"));
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(names.last(), Some(&"Vernal Equinox Day"));
+/// assert!(holidays::fiscal_year(Month::APRIL, 1, 1000).is_err());
+/// # Ok::<(), holidays::error::YearOutOfRange>(())
+/// ```
+pub fn fiscal_year<Y>(
+    start_month: Month,
+    start_day: u8,
+    year: Y,
+) -> Result<DateSelection<Date, std::ops::Range<Date>>, error::YearOutOfRange>
+where
+    Y: TryInto<CoveredYear> + Clone,
+    error::YearOutOfRange: From<Y::Error>,
+{
+    fiscal_years(start_month, start_day, year.clone()..=year)
+}
+
+/// Returns a date selection of consecutive fiscal years in `years`, see
+/// [`fiscal_year`].
+///
+/// Unbounded ends of `years` default to the first and the last
+/// [`CoveredYear`].
+///
+/// # Errors
+///
+/// Returns [`YearOutOfRange`](error::YearOutOfRange) if a bound of `years`
+/// isn't a [`CoveredYear`].
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, Month};
+///
+/// // fiscal years 2024 and 2025 starting on October 1st
+/// let selection = holidays::fiscal_years(Month::OCTOBER, 1, 2024..2026)?;
+/// let first = holidays::get_holidays(Country::US, selection).next().unwrap();
+///
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(first.name, "Columbus Day");
+/// # Ok::<(), holidays::error::YearOutOfRange>(())
+/// ```
+pub fn fiscal_years<Y, R>(
+    start_month: Month,
+    start_day: u8,
+    years: R,
+) -> Result<DateSelection<Date, std::ops::Range<Date>>, error::YearOutOfRange>
+where
+    Y: TryInto<CoveredYear> + Clone,
+    error::YearOutOfRange: From<Y::Error>,
+    R: std::ops::RangeBounds<Y>,
+{
+    let years = CoveredYear::range(years)?;
+    let start = fiscal_year_start(start_month, start_day, *years.start());
+    let end = fiscal_year_start(start_month, start_day, years.end() + 1);
+    Ok(DateSelection::Range(start..end.max(start)))
+}
+
+/// Returns an iterator over years in `years`, each paired with a query of
+/// holidays observed in `countries` during that year.
+///
+/// Queries are constructed as the iterator advances, so reports can process
+/// one year at a time without buffering results of the whole range. Years
+/// without any records are paired with empty queries. Unbounded ends of
+/// `years` default to the first and the last [`CoveredYear`].
+///
+/// # Errors
+///
+/// Returns [`YearOutOfRange`](error::YearOutOfRange) if a bound of `years`
+/// isn't a [`CoveredYear`].
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// for (year, holidays) in holidays::iter_years([Country::US, Country::GB], 2025..=2026)? {
+///     println!("{}: {} holidays", year.get(), holidays.count());
+/// }
+/// # Ok::<(), holidays::error::YearOutOfRange>(())
+/// ```
+pub fn iter_years<CountryIter, Y, R>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    years: R,
+) -> Result<impl Iterator<Item = (CoveredYear, Iter)> + Clone, error::YearOutOfRange>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    Y: TryInto<CoveredYear> + Clone,
+    error::YearOutOfRange: From<Y::Error>,
+    R: std::ops::RangeBounds<Y>,
+{
+    let years = CoveredYear::range(years)?;
+    let countries = countries.into().into_set();
+    Ok(years
+        .filter_map(CoveredYear::new)
+        .map(move |year| (year, get_holidays(countries, year.dates()))))
+}
+
+/// Returns the holiday observed in `countries` nearest to `date`, if there's
+/// one at most `tolerance_days` away, along with its signed offset in days.
+///
+/// The offset is negative if the holiday is before `date`, positive if it's
+/// after it, and `0` if it's on the `date`. If holidays on both sides are
+/// equally far away, the earlier one is returned. Tolerance is limited to
+/// `127` days so that offsets fit into an `i8`. Of multiple holidays a
+/// country observes on the same date, the one with the lexicographically
+/// smallest name is returned, see [`Holiday::name`].
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let (offset, holiday) = holidays::nearest_holiday(Country::US, Date::from_ymd(2025, 7, 6), 3).unwrap();
+/// assert_eq!(offset, -2);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(holiday.name, "Independence Day");
+/// ```
+pub fn nearest_holiday<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<Date>,
+    tolerance_days: u8,
+) -> Option<(i8, &'static Holiday)>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    let date = date.into();
+    get_holidays(countries, near(date, tolerance_days.min(i8::MAX as u8)))
+        .map(|it| ((it.date.0 - date.0) as i8, it))
+        // results are sorted by date, and `min_by_key` keeps the first minimum
+        .min_by_key(|(offset, _)| offset.unsigned_abs())
+}
+
+/// Returns the latest holiday observed in `countries` before `date`, and the
+/// earliest one on or after it.
+///
+/// The pivot `date` itself counts as "next", so a holiday on `date` is
+/// returned as the second element; see [`neighbors_with`] to exclude it.
+/// If multiple countries observe the neighboring holiday on the same date,
+/// the records adjacent to the pivot in query results ordering are returned,
+/// i.e. the previous holiday of the country with the greatest code and the
+/// next holiday of the country with the least code. Of multiple holidays a
+/// country observes on the same date, the one with the lexicographically
+/// smallest name is returned, see [`Holiday::name`].
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let (previous, next) = holidays::neighbors(Country::US, Date::from_ymd(2025, 8, 1));
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(previous.unwrap().name, "Independence Day");
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(next.unwrap().name, "Labor Day");
+/// ```
+#[inline]
+pub fn neighbors<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<Date>,
+) -> (Option<&'static Holiday>, Option<&'static Holiday>)
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    neighbors_with(countries, date, Pivot::Next)
+}
+
+/// Returns the holidays observed in `countries` that neighbor `date`, with
+/// `pivot` determining whether holidays on `date` itself are returned as
+/// next holidays.
+///
+/// See [`neighbors`] for details.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, Pivot};
+///
+/// let date = Date::from_ymd(2025, 7, 4);
+/// let (_, next) = holidays::neighbors_with(Country::US, date, Pivot::Next);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(next.unwrap().name, "Independence Day");
+/// let (previous, next) = holidays::neighbors_with(Country::US, date, Pivot::Excluded);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(previous.unwrap().name, "Juneteenth National Independence Day");
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(next.unwrap().name, "Labor Day");
+/// ```
+#[inline]
+pub fn neighbors_with<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<Date>,
+    pivot: Pivot,
+) -> (Option<&'static Holiday>, Option<&'static Holiday>)
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    countries.into().neighbors(date.into(), pivot)
+}
+
+/// Returns the number of countries that observe a holiday on `date`.
+///
+/// Unlike counting results of a [`get_holidays`] query, countries with
+/// multiple holidays on the same date are counted only once.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// let count = holidays::observing_count(Date::from_ymd(2025, 1, 1));
+/// assert!(count > 90);
+/// ```
+pub fn observing_count(date: impl Into<Date>) -> usize {
+    observing_countries(date).len()
+}
+
+/// Returns the set of countries that observe a holiday on `date`.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let countries = holidays::observing_countries(Date::from_ymd(2025, 7, 4));
+/// assert!(countries.contains(Country::US));
+/// assert!(!countries.contains(Country::GB));
+/// ```
+pub fn observing_countries(date: impl Into<Date>) -> CountrySet {
+    query::observing_countries(date.into())
+}
+
+/// Returns `true` if `date` is a business day of the specified `countries`.
+///
+/// Days outside of the `workweek` are closed in all countries, and are never
+/// business days; pass `None` for the default workweek, Monday through
+/// Friday, which the crate uses for all countries.
+/// Otherwise, `rule` determines whether holidays observed by only some of the
+/// countries close the calendar. An empty selection is only closed outside of
+/// the `workweek`.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{ClosureRule, Country, Workweek};
+///
+/// // Independence Day is only observed in the US
+/// let date = Date::from_ymd(2025, 7, 4);
+/// let countries = [Country::US, Country::GB];
+/// assert!(!holidays::is_business_day(countries, ClosureRule::AnyClosed, Workweek::MON_FRI, date));
+/// assert!(holidays::is_business_day(countries, ClosureRule::AllClosed, Workweek::MON_FRI, date));
+/// assert!(holidays::is_business_day(countries, ClosureRule::AllClosed, None, date));
+///
+/// let sunday = Date::from_ymd(2025, 3, 2);
+/// assert!(!holidays::is_business_day(Country::IL, ClosureRule::AnyClosed, Workweek::MON_FRI, sunday));
+/// assert!(holidays::is_business_day(Country::IL, ClosureRule::AnyClosed, Workweek::SUN_THU, sunday));
+/// ```
+pub fn is_business_day<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    rule: ClosureRule,
+    workweek: impl Into<Workweek>,
+    date: impl Into<Date>,
+) -> bool
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    business::Calendar::new(countries.into().into_set(), rule, workweek.into())
+        .is_business_day(date.into())
+}
+
+/// Returns the number of business days of the specified `countries` in
+/// `start..end`.
+///
+/// The `start` is included and the `end` isn't. If `end` is before `start`,
+/// business days in `end..start` are counted and the result is negative.
+///
+/// See [`is_business_day`] for details about `rule` and `workweek`.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{ClosureRule, Country, Weekday, Workweek};
+///
+/// let start = Date::from_ymd(2025, 12, 22);
+/// let end = Date::from_ymd(2025, 12, 29);
+/// let (rule, workweek) = (ClosureRule::AnyClosed, Workweek::MON_FRI);
+/// // Christmas is observed in both, Boxing Day only in GB
+/// assert_eq!(holidays::business_days_between(Country::US, rule, workweek, start, end), 4);
+/// assert_eq!(holidays::business_days_between([Country::US, Country::GB], rule, workweek, start, end), 3);
+/// assert_eq!(holidays::business_days_between([Country::US, Country::GB], rule, workweek, end, start), -3);
+///
+/// use Weekday::*;
+/// let six_days = Workweek::from_days(&[Monday, Tuesday, Wednesday, Thursday, Friday, Saturday])?;
+/// // Saturday is open, Christmas is closed
+/// assert_eq!(holidays::business_days_between(Country::US, rule, six_days, start, end), 5);
+/// # Ok::<(), holidays::error::EmptyWorkweek>(())
+/// ```
+pub fn business_days_between<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    rule: ClosureRule,
+    workweek: impl Into<Workweek>,
+    start: impl Into<Date>,
+    end: impl Into<Date>,
+) -> i64
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    business::Calendar::new(countries.into().into_set(), rule, workweek.into())
+        .days_between(start.into(), end.into())
+}
+
+/// Like [`business_days_between`], with [half-day](DayPortion) holidays
+/// counting as half of a business day.
+///
+/// With [`ClosureRule::AnyClosed`], a half of the day is closed if any
+/// country observes a holiday in it, and with [`ClosureRule::AllClosed`] if
+/// all of them do. Integer business day functions treat half-day holidays as
+/// full closures.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{ClosureRule, Country, Workweek};
+///
+/// let start = Date::from_ymd(2025, 12, 22);
+/// let end = Date::from_ymd(2025, 12, 29);
+/// let days = holidays::business_days_between_fractional(Country::US, ClosureRule::AnyClosed, Workweek::MON_FRI, start, end);
+/// assert_eq!(days, 4.0);
+/// ```
+pub fn business_days_between_fractional<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    rule: ClosureRule,
+    workweek: impl Into<Workweek>,
+    start: impl Into<Date>,
+    end: impl Into<Date>,
+) -> f64
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    business::Calendar::new(countries.into().into_set(), rule, workweek.into())
+        .fractional_days_between(start.into(), end.into())
+}
+
+/// Returns the date `days` business days of the specified `countries` after
+/// `date`, or before it if `days` is negative.
+///
+/// The `date` itself isn't counted and doesn't have to be a business day.
+/// If `days` is `0`, `date` is returned unchanged. The result saturates at
+/// the limits of [`Date`].
+///
+/// See [`is_business_day`] for details about `rule` and `workweek`.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{ClosureRule, Country, Weekday, Workweek};
+///
+/// // T+1 settlement of a trade on Thursday 2025-07-03
+/// let date = Date::from_ymd(2025, 7, 3);
+/// let rule = ClosureRule::AnyClosed;
+/// let settled = holidays::add_business_days([Country::US, Country::GB], rule, Workweek::MON_FRI, date, 1);
+/// assert_eq!(settled, Date::from_ymd(2025, 7, 7));
+///
+/// use Weekday::*;
+/// let six_days = Workweek::from_days(&[Monday, Tuesday, Wednesday, Thursday, Friday, Saturday])?;
+/// // Independence Day falls on Friday, so Saturday is the next business day
+/// let next = holidays::add_business_days(Country::US, rule, six_days, date, 1);
+/// assert_eq!(next, Date::from_ymd(2025, 7, 5));
+/// # Ok::<(), holidays::error::EmptyWorkweek>(())
+/// ```
+pub fn add_business_days<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    rule: ClosureRule,
+    workweek: impl Into<Workweek>,
+    date: impl Into<Date>,
+    days: i64,
+) -> Date
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    business::Calendar::new(countries.into().into_set(), rule, workweek.into())
+        .add_days(date.into(), days)
+}
+
+/// Returns the [`DayKind`] of each day of `dates` in the specified
+/// `countries`, in order.
+///
+/// Days on which any of the countries observes a holiday are holidays, even
+/// if they're outside of the `workweek`. Unbounded sides of `dates` end at
+/// the coverage of the countries, and `clip` determines whether the rest of
+/// `dates` is limited to it as well; the result has one element for each
+/// day of the resulting window.
+///
+/// Holidays of the whole window are looked up at once, so this is much
+/// faster than checking the days one by one with [`is_holiday`].
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, DayKind, WindowClip, Workweek};
+///
+/// let week = Date::from_ymd(2025, 12, 22)..Date::from_ymd(2025, 12, 29);
+/// let kinds = holidays::classify_days(Country::GB, week, Workweek::MON_FRI, WindowClip::Exact);
+/// assert_eq!(kinds.len(), 7);
+/// assert_eq!(kinds[3], DayKind::Holiday); // Christmas
+/// assert_eq!(kinds[4], DayKind::Holiday); // Boxing Day
+/// assert_eq!(kinds[5], DayKind::Weekend);
+///
+/// let far = Date::from_ymd(1000, 1, 1)..=Date::from_ymd(1000, 12, 31);
+/// assert!(holidays::classify_days(Country::GB, far, Workweek::MON_FRI, WindowClip::ToCoverage).is_empty());
+/// ```
+pub fn classify_days<CountryIter, DateLike>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    dates: impl std::ops::RangeBounds<DateLike>,
+    workweek: impl Into<Workweek>,
+    clip: WindowClip,
+) -> Vec<DayKind>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+{
+    iter_day_kinds(countries, dates, workweek, clip)
+        .map(|(_, kind)| kind)
+        .collect()
+}
+
+/// Like [`classify_days`], returning an iterator over days of the window
+/// along with their kind.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Country, DayKind, WindowClip, Workweek};
+///
+/// let workdays = holidays::iter_day_kinds(Country::US, 2025..2026, Workweek::MON_FRI, WindowClip::Exact)
+///     .filter(|(_, kind)| *kind == DayKind::Workday)
+///     .count();
+/// assert_eq!(workdays, 250);
+/// ```
+pub fn iter_day_kinds<CountryIter, DateLike>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    dates: impl std::ops::RangeBounds<DateLike>,
+    workweek: impl Into<Workweek>,
+    clip: WindowClip,
+) -> DayKinds
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+{
+    let resolve = |bound: std::ops::Bound<&DateLike>| bound.map(|it| it.clone().into());
+    DayKinds::new(
+        countries.into().into_set(),
+        resolve(dates.start_bound()),
+        resolve(dates.end_bound()),
+        workweek.into(),
+        clip,
+    )
+}
+
+/// Returns an iterator over holidays observed in the specified `countries`
+/// today (in UTC) or any of the following `days`.
+///
+/// See [`within`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// if holidays::within_next([Country::US, Country::GB], 14).next().is_some() {
+///     println!("Holidays ahead!");
+/// }
+/// ```
+pub fn within_next<CountryIter>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    days: u32,
+) -> Iter
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+{
+    within(countries, Date::today(), days)
+}
+
+/// Counts holidays observed in the specified countries and date selection,
+/// grouped into buckets along the given `axis`.
+///
+/// Returns `(label, count)` pairs sorted by label. [`Axis::Month`] and
+/// [`Axis::Weekday`] always return all buckets of their domain, including empty
+/// ones, while [`Axis::Year`] only returns years with at least one holiday.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Axis, Country};
+///
+/// let months = holidays::histogram(Country::US, 2025..2026, Axis::Month);
+///
+/// assert_eq!(months.len(), 12);
+/// assert_eq!(months[10], (11, 2)); // Veterans Day and Thanksgiving Day
+/// ```
+pub fn histogram<CountryIter, DateLike, DateRange>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+    axis: Axis,
+) -> Vec<(u32, usize)>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    stats::histogram(get_holidays(countries, date), axis)
+}
+
+/// Returns statistics of holidays observed within the `date` selection for all
+/// given `countries`.
+///
+/// Statistics are computed from per-country record tables bounded by the
+/// selected dates, without scanning unrelated records.
+///
+/// # Examples
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::Country;
+///
+/// let summary = holidays::summary([Country::US, Country::JP], 2025..2026);
+///
+/// assert_eq!(summary[0].country, Country::US);
+/// assert_eq!(summary[0].count, 11);
+/// assert_eq!(summary[0].first::<Date>(), Some(Ok(Date::from_ymd(2025, 1, 1))));
+/// ```
+pub fn summary<CountryIter, DateLike, DateRange>(
+    countries: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+) -> Vec<CountrySummary>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    countries
+        .into()
+        .slices_in(date.into())
+        .map(|(country, indices)| CountrySummary::new(country, indices))
+        .collect()
+}
+
+/// Returns differences between holidays of `country` in `year` and in the
+/// year before it, such as new holidays or movable feasts that shifted.
+///
+/// See [`YearDelta`] for how holidays of both years are matched.
+///
+/// # Errors
+///
+/// Returns [`YearOutOfRange`](error::YearOutOfRange) unless `year` is a
+/// [`CoveredYear`], and both it and the year before it are within the
+/// [coverage](Country::coverage) of `country`, as holidays of uncovered
+/// years would otherwise be reported as added or removed.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// let delta = holidays::year_over_year(Country::DE, 2026)?;
+/// // Easter falls on a different day each year
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(delta.moved.iter().any(|it| it.name == "Easter Monday"));
+/// assert!(delta.added.is_empty() && delta.removed.is_empty());
+///
+/// let first_year = Country::DE.coverage().first_year;
+/// assert!(holidays::year_over_year(Country::DE, first_year).is_err());
+/// # Ok::<(), holidays::error::YearOutOfRange>(())
+/// ```
+pub fn year_over_year<Y>(country: Country, year: Y) -> Result<YearDelta, error::YearOutOfRange>
+where
+    Y: TryInto<CoveredYear>,
+    error::YearOutOfRange: From<Y::Error>,
+{
+    let year = year.try_into()?.get();
+    let coverage = country.coverage();
+    if year <= coverage.first_year || year > coverage.last_year {
+        return Err(error::YearOutOfRange);
+    }
+    Ok(stats::year_over_year(country, year))
+}
+
+/// Returns how similar holiday calendars of countries `a` and `b` are within
+/// the `date` selection.
+///
+/// Similarity is the Jaccard index of the sets of dates on which each
+/// country observes a holiday: the number of dates observed by both divided
+/// by the number of dates observed by either. Names of holidays are ignored,
+/// so the score ranges from `0.0` for calendars without any shared dates to
+/// `1.0` for calendars with the same dates, including ones without any
+/// holidays in the selection.
+///
+/// The score is computed from per-country record tables bounded by the
+/// selected dates, without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::Country;
+///
+/// let score = holidays::similarity(Country::US, Country::CA, 2025..2026);
+/// assert!(0.0 < score && score < 1.0);
+/// assert_eq!(score, holidays::similarity(Country::CA, Country::US, 2025..2026));
+/// assert_eq!(holidays::similarity(Country::US, Country::US, 2025..2026), 1.0);
+/// ```
+pub fn similarity<DateLike, DateRange>(
+    a: Country,
+    b: Country,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+) -> f64
+where
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    let query = date.into().into_query();
+    stats::jaccard(query.dates_of(a), query.dates_of(b))
+}
+
+/// Returns up to `n` of the `candidates` whose holiday calendars are the
+/// most similar to the one of `country` within the `date` selection, paired
+/// with their [`similarity`] scores.
+///
+/// Results are ordered by descending score, and candidates with equal scores
+/// by country code. `country` itself is never included, so all other
+/// countries can be ranked by passing [`Any`] candidates.
+///
+/// # Examples
+///
+/// ```
+/// use holidays::{Any, Country};
+///
+/// let neighbors = holidays::most_similar(Country::AT, Any, 2025..2026, 3);
+/// assert_eq!(neighbors.len(), 3);
+/// assert!(neighbors.windows(2).all(|w| w[0].1 >= w[1].1));
+/// assert!(neighbors.iter().all(|(country, _)| *country != Country::AT));
+/// ```
+pub fn most_similar<CountryIter, DateLike, DateRange>(
+    country: Country,
+    candidates: impl Into<CountrySelection<CountryIter>>,
+    date: impl Into<DateSelection<DateLike, DateRange>>,
+    n: usize,
+) -> Vec<(Country, f64)>
+where
+    CountryIter: IntoIterator,
+    CountryIter::Item: Into<crate::Country>,
+    DateLike: Into<Date> + Clone,
+    DateRange: std::ops::RangeBounds<DateLike>,
+{
+    let query = date.into().into_query();
+    let dates = query.dates_of(country);
+    let mut scores: Vec<_> = candidates
+        .into()
+        .into_set()
+        .iter()
+        .filter(|it| *it != country)
+        .map(|it| (it, stats::jaccard(dates, query.dates_of(it))))
+        .collect();
+    // the sort is stable, so equal scores stay ordered by country code
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scores.truncate(n);
+    scores
+}
+
+/// Reference of embedded holidays, with a module per country listing its
+/// holidays over the next few years.
+///
+/// Modules only contain documentation and are generated with the
+/// `country-docs` feature, which is enabled on docs.rs.
+#[cfg(feature = "country-docs")]
+#[allow(non_snake_case)]
+pub mod countries_doc {
+    include!(concat!(env!("OUT_DIR"), "/countries_doc.rs"));
+}
+
+/// Error types returned from the crate.
+pub mod error {
+    pub use crate::country::{CodesError, CountryParseError, InvalidCode};
+    pub use crate::custom::TooManyCalendars;
+    pub use crate::date::DateConversionError;
+    pub use crate::date::InvalidMonth;
+    pub use crate::date::ParseDateError;
+    pub use crate::date::YearOutOfRange;
+    pub use crate::workweek::EmptyWorkweek;
+
+    macro_rules! error_msg {
+        ($err: ty, $message: literal $(, $($arg: tt),+)?) => {
+            impl std::fmt::Display for $err {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, $message $(, $(self.$arg),+)?)
+                }
+            }
+            impl core::error::Error for $err {}
+        };
+    }
+    pub(crate) use error_msg;
+}
 
 /// This module provides direct access to internals that aren't part of public
 /// API and can change at any time without affecting semver.
+///
+/// # Migrating from `internal::Date`
+///
+/// [`Date`](crate::Date) is exported from the crate root. The alias in this
+/// module is deprecated and will be removed in a future release, after which
+/// only the new path compiles:
+///
+/// ```
+/// // before
+/// #[allow(deprecated)]
+/// use holidays::internal::Date as OldDate;
+/// // after
+/// use holidays::Date;
+///
+/// let date: OldDate = Date::from_ymd(2025, 1, 1);
+/// assert_eq!(holidays::get_holidays(holidays::Country::US, date..=date).count(), 1);
+/// ```
 #[doc(hidden)]
 pub mod internal {
-    pub use crate::date::Date;
+    /// Deprecated alias of [`Date`](crate::Date).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use holidays::internal::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2025, 1, 1), holidays::Date::from_ymd(2025, 1, 1));
+    /// ```
+    #[deprecated(note = "use `holidays::Date` instead")]
+    pub type Date = crate::Date;
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::Date;
+    use crate::{Any, ClosureRule, Country, DateExt, Holiday, Weekday, Workweek};
+
+    #[test]
+    #[allow(deprecated)]
+    fn bounding_entries_conversions() {
+        use crate::DisplayDate;
+        use std::time::SystemTime;
+
+        let expected: Vec<_> = crate::get_bounding_dates(Country::US).collect();
+        let (_, Some((first, last))) = expected[0] else {
+            panic!("no US records");
+        };
+
+        // explicitly requested formats
+        let system = crate::get_bounding_entries::<SystemTime, _>(Country::US).next();
+        assert_eq!(
+            system,
+            Some((
+                Country::US,
+                Some((first.0.try_into().unwrap(), last.0.try_into().unwrap()))
+            ))
+        );
+        let display = crate::get_bounding_entries::<DisplayDate, _>(Country::US).next();
+        assert_eq!(display, Some((Country::US, Some((first, last)))));
+        let internal = crate::get_bounding_entries::<Date, _>(Country::US).next();
+        assert_eq!(internal, Some((Country::US, Some((first.0, last.0)))));
+
+        // formats inferred from usage
+        let system: Vec<(Country, Option<(SystemTime, SystemTime)>)> =
+            crate::get_bounding_entries(Country::US).collect();
+        assert_eq!(system.len(), 1);
+        let display: Vec<(_, Option<(DisplayDate, _)>)> =
+            crate::get_bounding_entries([Country::US]).collect();
+        assert_eq!(display, expected);
+
+        #[cfg(feature = "chrono")]
+        {
+            use chrono::NaiveDate;
+
+            let explicit = crate::get_bounding_entries::<NaiveDate, _>(Country::US).next();
+            let inferred: Option<(_, Option<(NaiveDate, NaiveDate)>)> =
+                crate::get_bounding_entries(Country::US).next();
+            assert_eq!(explicit, inferred);
+            assert_eq!(explicit.unwrap().1.unwrap().0, first.0.try_into().unwrap());
+        }
+    }
+
+    #[test]
+    fn bounding_entries_conversion_errors() {
+        use crate::error::DateConversionError;
+
+        /// Dates of the last century, like a legacy two digit year format.
+        #[derive(Debug, PartialEq)]
+        struct LastCentury(Date);
+
+        impl TryFrom<Date> for LastCentury {
+            type Error = DateConversionError;
+
+            fn try_from(value: Date) -> Result<Self, Self::Error> {
+                match value.year() {
+                    1900..=1999 => Ok(LastCentury(value)),
+                    _ => Err(DateConversionError),
+                }
+            }
+        }
+
+        let (first, last) = crate::get_bounding_dates(Country::US)
+            .next()
+            .unwrap()
+            .1
+            .unwrap();
+        let bounds = crate::try_get_bounding_entries::<LastCentury, _>(Country::US).next();
+        assert_eq!(bounds, Some((Country::US, Some(Err(DateConversionError)))));
+
+        let bounds = crate::try_get_bounding_entries::<Date, _>([Country::US, Country::JP]);
+        assert!(bounds
+            .map(|it| it.1)
+            .all(|it| matches!(it, Some(Ok((min, max))) if min < max)));
+        let bounds = crate::try_get_bounding_entries::<Date, _>(Country::US).next();
+        assert_eq!(bounds, Some((Country::US, Some(Ok((first.0, last.0))))));
+
+        #[allow(deprecated)]
+        let panicked = std::panic::catch_unwind(|| {
+            crate::get_bounding_entries::<LastCentury, _>(Country::US).count()
+        });
+        assert!(panicked.is_err());
+    }
+
+    #[test]
+    fn bounds_of_edge_countries() {
+        // the country with the largest discriminant
+        let last = *crate::enabled_countries().last().unwrap();
+        let bounds: Vec<_> = crate::get_bounding_dates(last).collect();
+        assert_eq!(bounds.len(), 1);
+        assert!(bounds[0].1.is_some());
+        assert_eq!(crate::get_bounding_dates(Any).count(), 0);
+        assert_eq!(crate::bounding_in(Any, 2025..2026).count(), Country::COUNT);
+        assert_eq!(
+            crate::bounding_in(Any, 2025..2026).last().map(|it| it.0),
+            Some(last)
+        );
+
+        // countries without records have no bounds, e.g. UA in builds with
+        // `HOLIDAYS_MIN_YEAR=2023`
+        for (country, bounds) in crate::get_bounding_dates(crate::enabled_countries()) {
+            assert_eq!(
+                bounds.is_none(),
+                crate::data::country_indices(country).is_empty(),
+                "{country}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-names"))]
+    fn same_day_holidays_are_separate() {
+        // Easter Monday and Labor Day coincide in Greece in 2000
+        let date = Date::from_ymd(2000, 5, 1);
+        let expected = "Easter Monday";
+
+        let names: Vec<_> = crate::get_holidays(Country::GR, date)
+            .map(|it| it.name)
+            .collect();
+        assert_eq!(names, [expected, "Labor Day"]);
+        assert_eq!(
+            crate::data::country_date_to_holiday(Country::GR, date)
+                .unwrap()
+                .name,
+            expected
+        );
+        assert_eq!(
+            crate::nearest_holiday(Country::GR, date, 0).unwrap().1.name,
+            expected
+        );
+        assert_eq!(
+            crate::neighbors(Country::GR, date).1.unwrap().name,
+            expected
+        );
+        let next_day = date.saturating_add_days(1);
+        assert_eq!(
+            crate::neighbors(Country::GR, next_day).0.unwrap().name,
+            expected
+        );
+    }
+
+    #[test]
+    fn fiscal_years() {
+        use crate::Month;
+
+        let fy2025: Vec<_> = crate::get_holidays(
+            Country::JP,
+            crate::fiscal_year(Month::APRIL, 1, 2025).unwrap(),
+        )
+        .map(|it| it.date)
+        .collect();
+        // Children's Day and New Year's Day
+        assert!(fy2025.contains(&Date::from_ymd(2025, 5, 5)));
+        assert!(fy2025.contains(&Date::from_ymd(2026, 1, 1)));
+        assert!(!fy2025.contains(&Date::from_ymd(2025, 1, 1)));
+        assert!(!fy2025.contains(&Date::from_ymd(2026, 4, 29)));
+
+        let two_years: Vec<_> = crate::get_holidays(
+            Country::JP,
+            crate::fiscal_years(Month::APRIL, 1, 2025..=2026).unwrap(),
+        )
+        .collect();
+        let fy2026 = crate::get_holidays(
+            Country::JP,
+            crate::fiscal_year(Month::APRIL, 1, 2026).unwrap(),
+        )
+        .count();
+        assert_eq!(two_years.len(), fy2025.len() + fy2026);
+        assert!(crate::get_holidays(
+            Country::JP,
+            crate::fiscal_years(Month::APRIL, 1, 2026..2026).unwrap()
+        )
+        .next()
+        .is_none());
+
+        // February 29 start days are clamped to February 28 in common years
+        let range = |selection: Result<_, _>| match selection.unwrap() {
+            crate::selection::DateSelection::Range(it) => it,
+            _ => unreachable!(),
+        };
+        let leap = range(crate::fiscal_year(Month::FEBRUARY, 29, 2024));
+        assert_eq!(
+            leap,
+            Date::from_ymd(2024, 2, 29)..Date::from_ymd(2025, 2, 28)
+        );
+        assert_eq!(
+            leap,
+            range(crate::fiscal_years(Month::FEBRUARY, 29, 2024..2025))
+        );
+        let common = range(crate::fiscal_year(Month::FEBRUARY, 29, 2025));
+        assert_eq!(
+            common,
+            Date::from_ymd(2025, 2, 28)..Date::from_ymd(2026, 2, 28)
+        );
+
+        // years are range-checked, including excluded bounds
+        let (min, max) = (crate::CoveredYear::MIN.get(), crate::CoveredYear::MAX.get());
+        assert!(matches!(
+            crate::fiscal_year(Month::APRIL, 1, min - 1),
+            Err(crate::error::YearOutOfRange)
+        ));
+        assert!(matches!(
+            crate::fiscal_years(Month::APRIL, 1, 2025..max + 1),
+            Err(crate::error::YearOutOfRange)
+        ));
+        let all = range(crate::fiscal_years(
+            Month::JANUARY,
+            1,
+            ..=crate::CoveredYear::MAX,
+        ));
+        assert_eq!(
+            all,
+            Date::from_ymd(min, 1, 1)..Date::from_ymd(max + 1, 1, 1)
+        );
+    }
+
+    #[test]
+    fn opt_code_selection() {
+        use crate::error::CountryParseError;
+
+        let all = crate::get_holidays(Any, 2025..2026).count();
+        let jp = crate::get_holidays(Country::JP, 2025..2026).count();
+
+        assert_eq!(
+            crate::get_holidays_opt_code(None, 2025..2026).map(Iterator::count),
+            Ok(all)
+        );
+        assert_eq!(
+            crate::get_holidays_opt_code(Some("JP"), 2025..2026).map(Iterator::count),
+            Ok(jp)
+        );
+        assert_eq!(
+            crate::get_holidays_opt_code(Some("XX"), 2025..2026).map(Iterator::count),
+            Err(CountryParseError::Unknown)
+        );
+        assert_eq!(
+            crate::get_holidays_opt_code(Some(""), 2025..2026).map(Iterator::count),
+            Err(CountryParseError::Unknown)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-names"))]
+    fn nearest_holiday_offsets() {
+        let probe = |y, m, d, tolerance| {
+            crate::nearest_holiday(Country::US, Date::from_ymd(y, m, d), tolerance)
+                .map(|(offset, it)| (offset, it.name))
+        };
+
+        // 2025-07-04 is Independence Day
+        assert_eq!(probe(2025, 7, 4, 0), Some((0, "Independence Day")));
+        assert_eq!(probe(2025, 7, 6, 2), Some((-2, "Independence Day")));
+        assert_eq!(probe(2025, 7, 2, 2), Some((2, "Independence Day")));
+        assert_eq!(probe(2025, 7, 7, 2), None);
+
+        // Christmas Day and New Year's Day are a week apart
+        assert_eq!(probe(2025, 12, 27, 7), Some((-2, "Christmas Day")));
+        assert_eq!(probe(2025, 12, 29, 7), Some((3, "New Year's Day")));
+        // Veterans Day and Thanksgiving Day are 16 days apart; exact tie
+        // returns the earlier holiday
+        assert_eq!(probe(2025, 11, 19, 8), Some((-8, "Veterans Day")));
+        assert_eq!(probe(2025, 11, 19, 7), None);
+        assert_eq!(probe(2025, 11, 20, 8), Some((7, "Thanksgiving Day")));
+
+        // tolerance is capped so offsets fit into i8
+        assert!(probe(2025, 7, 4, u8::MAX).is_some_and(|(offset, _)| offset == 0));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-names"))]
+    fn neighbors_around_pivot() {
+        use crate::Pivot;
+
+        let names = |(previous, next): (Option<&Holiday>, Option<&Holiday>)| {
+            (
+                previous.map(|it| (it.code, it.name)),
+                next.map(|it| (it.code, it.name)),
+            )
+        };
+        let us = |y, m, d, pivot| {
+            names(crate::neighbors_with(
+                Country::US,
+                Date::from_ymd(y, m, d),
+                pivot,
+            ))
+        };
+
+        // pivot on a holiday
+        assert_eq!(
+            us(2025, 7, 4, Pivot::Next),
+            (
+                Some((Country::US, "Juneteenth National Independence Day")),
+                Some((Country::US, "Independence Day"))
+            )
+        );
+        assert_eq!(
+            us(2025, 7, 4, Pivot::Excluded),
+            (
+                Some((Country::US, "Juneteenth National Independence Day")),
+                Some((Country::US, "Labor Day"))
+            )
+        );
+        // pivot between holidays
+        assert_eq!(us(2025, 7, 5, Pivot::Next), us(2025, 7, 5, Pivot::Excluded));
+        assert_eq!(
+            us(2025, 7, 5, Pivot::Next),
+            (
+                Some((Country::US, "Independence Day")),
+                Some((Country::US, "Labor Day"))
+            )
+        );
+
+        // pivots outside of coverage
+        let first = crate::get_holidays::<_, Date, _>(Country::US, Any).next();
+        let last = crate::get_holidays::<_, Date, _>(Country::US, Any).last();
+        assert_eq!(
+            crate::neighbors(Country::US, Date::from_ymd(1000, 1, 1)),
+            (None, first)
+        );
+        assert_eq!(
+            crate::neighbors(Country::US, Date::from_ymd(3000, 1, 1)),
+            (last, None)
+        );
+        assert_eq!(
+            crate::neighbors(Country::US, first.unwrap().date),
+            (None, first)
+        );
+        assert_eq!(
+            crate::neighbors_with(Country::US, last.unwrap().date, Pivot::Excluded).1,
+            None
+        );
+
+        // ties follow results ordering and respect the selection
+        let both = [Country::US, Country::GB];
+        let (previous, next) = names(crate::neighbors(both, Date::from_ymd(2025, 12, 25)));
+        assert_eq!(next, Some((Country::GB, "Christmas Day")));
+        assert_eq!(previous.unwrap().0, Country::US);
+        let (previous, next) = names(crate::neighbors(both, Date::from_ymd(2025, 12, 26)));
+        assert_eq!(previous, Some((Country::US, "Christmas Day")));
+        assert_eq!(next.unwrap().0, Country::GB);
+        let (previous, _) = names(crate::neighbors(Country::GB, Date::from_ymd(2025, 12, 26)));
+        assert_eq!(previous, Some((Country::GB, "Christmas Day")));
+
+        // all countries agree with a full query
+        let date = Date::from_ymd(2025, 3, 10);
+        assert_eq!(
+            crate::neighbors(Any, date),
+            (
+                crate::get_holidays(Any, ..date).last(),
+                crate::get_holidays(Any, date..date.saturating_add_days(400)).next()
+            )
+        );
+    }
+
+    #[test]
+    fn workweeks_change_business_days() {
+        let start = Date::from_ymd(2025, 3, 1);
+        let end = Date::from_ymd(2025, 4, 1);
+        let count = |workweek| {
+            crate::business_days_between(Any, ClosureRule::AllClosed, workweek, start, end)
+        };
+
+        // March 2025 starts on a Saturday, so it has 5 Saturdays, Sundays and
+        // Mondays, and 4 of every other weekday
+        assert_eq!(count(Workweek::MON_FRI), 21);
+        assert_eq!(count(Workweek::SUN_THU), 22);
+        assert_eq!(count(Workweek::default()), 21);
+        // `None` is the default workweek
+        assert_eq!(
+            crate::business_days_between(Any, ClosureRule::AllClosed, None, start, end),
+            21
+        );
+        let weekend = Workweek::from_days(&[Weekday::Saturday, Weekday::Sunday]).unwrap();
+        assert_eq!(count(weekend), 10);
+
+        let friday = Date::from_ymd(2025, 3, 7);
+        let next = |workweek| {
+            crate::add_business_days(Country::US, ClosureRule::AnyClosed, workweek, friday, 1)
+        };
+        assert_eq!(next(Workweek::MON_FRI), Date::from_ymd(2025, 3, 10));
+        assert_eq!(next(Workweek::SUN_THU), Date::from_ymd(2025, 3, 9));
+        assert_eq!(next(None.into()), Date::from_ymd(2025, 3, 10));
+    }
+
+    #[test]
+    fn business_day_rules_diverge() {
+        let both = [Country::US, Country::GB];
+        let week = Workweek::MON_FRI;
+        let open =
+            |rule, y, m, d| crate::is_business_day(both, rule, week, Date::from_ymd(y, m, d));
+
+        // Independence Day, US only
+        assert!(!open(ClosureRule::AnyClosed, 2025, 7, 4));
+        assert!(open(ClosureRule::AllClosed, 2025, 7, 4));
+        // May Day, GB only
+        assert!(!open(ClosureRule::AnyClosed, 2025, 5, 5));
+        assert!(open(ClosureRule::AllClosed, 2025, 5, 5));
+        // Christmas, observed in both
+        assert!(!open(ClosureRule::AnyClosed, 2025, 12, 25));
+        assert!(!open(ClosureRule::AllClosed, 2025, 12, 25));
+        // regular weekdays and weekends
+        assert!(open(ClosureRule::AnyClosed, 2025, 7, 3));
+        assert!(!open(ClosureRule::AllClosed, 2025, 7, 5));
+
+        let start = Date::from_ymd(2025, 5, 1);
+        let end = Date::from_ymd(2025, 9, 1);
+        let any = crate::business_days_between(both, ClosureRule::AnyClosed, week, start, end);
+        let all = crate::business_days_between(both, ClosureRule::AllClosed, week, start, end);
+        // May Day, Juneteenth and Independence Day
+        assert_eq!(all - any, 3);
+        assert_eq!(
+            crate::business_days_between(both, ClosureRule::AnyClosed, week, end, start),
+            -any
+        );
+        assert_eq!(
+            crate::business_days_between(both, ClosureRule::AnyClosed, week, start, start),
+            0
+        );
+
+        assert_eq!(
+            crate::add_business_days(both, ClosureRule::AnyClosed, week, start, any),
+            // Labor Day is on 2025-09-01
+            Date::from_ymd(2025, 9, 2)
+        );
+        assert_eq!(
+            crate::add_business_days(
+                both,
+                ClosureRule::AnyClosed,
+                week,
+                Date::from_ymd(2025, 7, 7),
+                -1
+            ),
+            Date::from_ymd(2025, 7, 3)
+        );
+        assert_eq!(
+            crate::add_business_days(
+                both,
+                ClosureRule::AllClosed,
+                week,
+                Date::from_ymd(2025, 7, 7),
+                -1
+            ),
+            Date::from_ymd(2025, 7, 4)
+        );
+
+        // empty selections only close on weekends
+        let none: [Country; 0] = [];
+        assert!(crate::is_business_day(
+            none,
+            ClosureRule::AllClosed,
+            week,
+            Date::from_ymd(2025, 12, 25)
+        ));
+    }
+
+    #[test]
+    fn within_matches_range() {
+        let countries = [Country::US, Country::GB, Country::JP];
+        let start = Date::from_ymd(2025, 4, 20);
+
+        for days in [0, 1, 14, 90, 400] {
+            let end = Date(start.0 + days as i64);
+            let expected: Vec<_> = crate::get_holidays(countries, start..=end).collect();
+
+            assert_eq!(
+                crate::within(countries, start, days).collect::<Vec<_>>(),
+                expected
+            );
+
+            let time: std::time::SystemTime = start.try_into().unwrap();
+            assert!(time.holidays_within(countries, days).eq(expected));
+        }
+
+        assert_eq!(
+            crate::within(Country::US, start, 0).count(),
+            crate::get_holidays(Country::US, start).count()
+        );
+
+        let last = Date::from_ymd(2035, 12, 31);
+        assert_eq!(
+            crate::within(Any, last, 365).count(),
+            crate::get_holidays(Any, last).count()
+        );
+    }
+
+    #[test]
+    fn month_day_across_years() {
+        use crate::Month;
+
+        let coverage = Country::FR.coverage();
+        let bastille_days: Vec<_> = crate::on_month_day(Country::FR, Month::JULY, 14).collect();
+        assert_eq!(
+            bastille_days.len() as i64,
+            coverage.last_year - coverage.first_year + 1
+        );
+        assert!(bastille_days.windows(2).all(|it| it[0].date < it[1].date));
+        assert!(crate::always_holiday(Country::FR, Month::JULY, 14));
+
+        assert!(!crate::always_holiday(Country::FR, Month::JULY, 15));
+        assert!(!crate::always_holiday(Country::FR, Month::APRIL, 31));
+        assert_eq!(
+            crate::on_month_day(Country::FR, Month::APRIL, 31).count(),
+            0
+        );
+
+        let leap_days: Vec<_> = crate::month_day_dates(Country::SE, Month::FEBRUARY, 29).collect();
+        assert!(!leap_days.is_empty());
+        assert!(leap_days.iter().all(|it| Date::is_leap_year(it.year())));
+        assert!(crate::on_month_day(Country::SE, Month::FEBRUARY, 29).all(|it| it.date.day() == 29));
+        assert!(!crate::always_holiday(Country::SE, Month::FEBRUARY, 29));
+    }
+
+    #[test]
+    fn name_languages_default_per_country() {
+        assert_eq!(Country::DE.name_language(), "en");
+        assert_eq!(Country::US.name_language(), "en");
+        assert_eq!(Country::IT.name_language(), "it");
+
+        for country in [Country::DE, Country::IT] {
+            assert!(crate::get_holidays(country, 2025..2026)
+                .all(|it| it.name_language() == country.name_language()));
+        }
+        let fixture = Holiday::new(Country::IT, Date::from_ymd(2025, 1, 2), "Fixture");
+        assert_eq!(fixture.name_language(), "it");
+    }
+
+    #[test]
+    fn sort_keys_follow_result_order() {
+        let keys: Vec<u64> = crate::get_holidays::<_, Date, _>(Any, Any)
+            .map(Holiday::sort_key)
+            .collect();
+        assert_eq!(keys.len(), crate::data::DATA.len());
+        assert!(keys.windows(2).all(|it| it[0] < it[1]));
+
+        let new_year: Vec<_> = crate::get_holidays(Any, Date::from_ymd(2025, 1, 1)).collect();
+        for pair in new_year.windows(2) {
+            let (a, b) = (pair[0].sort_key(), pair[1].sort_key());
+            assert_eq!(a >> 24, b >> 24);
+            assert_eq!((b & 0xFFF) - (a & 0xFFF), 1);
+        }
+        assert_eq!(new_year[0].sort_key() & 0xFFF, 0);
+
+        let mut by_country: Vec<u64> =
+            crate::get_holidays_ordered(Any, 2025..2026, crate::Order::CountryThenDate)
+                .map(Holiday::sort_key)
+                .collect();
+        by_country.sort_unstable();
+        assert!(by_country.windows(2).all(|it| it[0] < it[1]));
+
+        let fixture = Holiday::new(Country::US, Date::MAX, "Fixture");
+        assert_eq!(fixture.sort_key() >> 24, (1 << 40) - 1);
+    }
+
+    #[test]
+    fn uids_are_pinned() {
+        // UIDs of exported events; a change here duplicates events in
+        // calendars that subscribe to exports
+        let uid = |country, (y, m, d)| {
+            let holiday = crate::get_holidays(country, Date::from_ymd(y, m, d))
+                .next()
+                .unwrap();
+            holiday.uid().to_string()
+        };
+        #[cfg(not(feature = "no-names"))]
+        {
+            assert_eq!(
+                uid(Country::US, (2025, 7, 4)),
+                "US-20250704-independence-day"
+            );
+            assert_eq!(uid(Country::GB, (2025, 12, 26)), "GB-20251226-boxing-day");
+            assert_eq!(
+                uid(Country::IT, (2025, 12, 26)),
+                "IT-20251226-santo-stefano"
+            );
+            assert_eq!(
+                uid(Country::MX, (2025, 3, 17)),
+                "MX-20250317-benito-juarezs-birthday"
+            );
+            assert_eq!(
+                uid(Country::SI, (2025, 2, 8)),
+                "SI-20250208-preserens-day-the-slovenian-cultural-holiday"
+            );
+            assert_eq!(
+                uid(Country::DJ, (2025, 1, 27)),
+                "DJ-20250127-isra-and-miraj-estimated"
+            );
+        }
+        #[cfg(feature = "no-names")]
+        assert_eq!(uid(Country::US, (2025, 7, 4)), "US-20250704-");
+
+        // records that only differ in spelling of their names share UIDs
+        let date = Date::from_ymd(2025, 1, 1);
+        #[cfg(not(feature = "no-names"))]
+        {
+            // same-day holidays are separate records with UIDs of their own
+            let uids: Vec<_> = crate::get_holidays(Country::CZ, date)
+                .map(|it| it.uid().to_string())
+                .collect();
+            assert_eq!(
+                uids,
+                [
+                    "CZ-20250101-independent-czech-state-restoration-day",
+                    "CZ-20250101-new-years-day"
+                ]
+            );
+        }
+        let uid = |name| Holiday::new(Country::CZ, date, name).uid().to_string();
+        assert_eq!(uid("New Year's Day"), uid("New Years Day"));
+        assert_eq!(uid("St. Stephen's Day"), uid("St Stephen’s Day"));
+        assert_eq!(uid("Día de Reyes"), uid("DIA DE REYES!"));
+        assert_ne!(uid("Boxing Day"), uid("Boxing Day (observed)"));
+    }
+
+    #[test]
+    fn year_chunks_match_range() {
+        let countries = [Country::US, Country::DE, Country::JP];
+        let chunks: Vec<_> = crate::iter_years(countries, 2018..=2027).unwrap().collect();
+        assert_eq!(chunks.len(), 10);
+        assert!(chunks.iter().map(|it| it.0.get()).eq(2018..=2027));
+
+        let concatenated: Vec<_> = chunks.into_iter().flat_map(|it| it.1).collect();
+        let range: Vec<_> = crate::get_holidays(countries, 2018..2028).collect();
+        assert_eq!(concatenated, range);
+
+        let all: Vec<_> = crate::iter_years(Any, crate::CoveredYear::MIN..)
+            .unwrap()
+            .flat_map(|it| it.1)
+            .collect();
+        assert!(all.iter().copied().eq(crate::data::DATA.iter()));
+
+        // years without embedded data are rejected
+        let min = crate::CoveredYear::MIN.get();
+        assert!(crate::iter_years(countries, min - 1..=2027).is_err());
+    }
+
+    #[test]
+    fn year_chunks_are_lazy() {
+        // queries of years are only executed once they're requested
+        let planned = crate::query::plans_selected();
+        let mut years = crate::iter_years(Country::US, 2025..).unwrap();
+        assert_eq!(crate::query::plans_selected(), planned);
+        let (year, holidays) = years.next().unwrap();
+        assert_eq!(crate::query::plans_selected(), planned + 1);
+        assert_eq!(year.get(), 2025);
+        assert_eq!(
+            holidays.count(),
+            crate::get_holidays(Country::US, 2025..2026).count()
+        );
+        assert_eq!(years.last().map(|it| it.0), Some(crate::CoveredYear::MAX));
+        let (start, end) = (2030, 2025);
+        assert_eq!(crate::iter_years(Any, start..end).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn weekend_relations_match_chrono() {
+        use crate::WeekendRelation;
+        use chrono::{Datelike, Weekday as W};
+
+        let mut relations = Vec::new();
+        let holidays = crate::get_holidays(Country::US, 2025..2026).with_weekend_relation();
+        for (relation, holiday) in holidays {
+            let (y, m, d) = holiday.date.ymd();
+            let date = chrono::NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).unwrap();
+            let expected = match date.weekday() {
+                W::Sat | W::Sun => WeekendRelation::OnWeekend,
+                W::Mon | W::Fri => WeekendRelation::AdjacentToWeekend,
+                W::Tue | W::Wed | W::Thu => WeekendRelation::Weekday,
+            };
+            assert_eq!(relation, expected, "{}", holiday.name);
+            assert_eq!(holiday.weekend_relation(Workweek::MON_FRI), expected);
+            relations.push(relation);
+        }
+        // US holidays are mostly moved to Mondays
+        assert_eq!(relations.len(), 11);
+        assert!(relations.contains(&WeekendRelation::Weekday));
+        assert!(relations.contains(&WeekendRelation::AdjacentToWeekend));
+
+        // 2027 Independence Day and Christmas are on weekends
+        let weekend = crate::get_holidays(Country::US, 2027..2028)
+            .with_weekend_relation()
+            .filter(|(relation, _)| *relation == WeekendRelation::OnWeekend)
+            .count();
+        assert!(weekend >= 2);
+    }
+
+    #[test]
+    fn classified_days_match_naive_loop() {
+        use crate::{DayKind, WindowClip};
+
+        let (start, end) = (Date::from_ymd(2025, 12, 1), Date::from_ymd(2026, 1, 1));
+        for countries in [
+            crate::CountrySet::new(),
+            [Country::US].into_iter().collect(),
+            [Country::GB, Country::DE, Country::IL]
+                .into_iter()
+                .collect(),
+        ] {
+            for workweek in [Workweek::MON_FRI, Workweek::SUN_THU] {
+                let naive: Vec<_> = (start.0..end.0)
+                    .map(Date)
+                    .map(|date| {
+                        if crate::is_holiday(countries, date) {
+                            DayKind::Holiday
+                        } else if workweek.contains(date.weekday()) {
+                            DayKind::Workday
+                        } else {
+                            DayKind::Weekend
+                        }
+                    })
+                    .collect();
+                let kinds =
+                    crate::classify_days(countries, start..end, workweek, WindowClip::Exact);
+                assert_eq!(kinds, naive);
+                let last = Date(end.0 - 1);
+                let dated: Vec<_> =
+                    crate::iter_day_kinds(countries, start..=last, workweek, WindowClip::Exact)
+                        .collect();
+                assert!(dated.iter().map(|it| it.1).eq(naive));
+                assert!(dated.iter().map(|it| it.0).eq((start.0..end.0).map(Date)));
+            }
+        }
+    }
+
+    #[test]
+    fn classified_windows() {
+        use crate::WindowClip;
+
+        let coverage = Country::US.coverage();
+        let covered = Date::from_year(coverage.first_year)..Date::from_year(coverage.last_year + 1);
+        let days = |range: std::ops::Range<Date>| (range.end.0 - range.start.0) as usize;
+        let wide =
+            Date::from_year(coverage.first_year - 10)..Date::from_year(coverage.last_year + 10);
+
+        let classify = |dates: std::ops::Range<Date>, clip| {
+            crate::classify_days(Country::US, dates, Workweek::MON_FRI, clip)
+        };
+        assert_eq!(
+            classify(wide.clone(), WindowClip::Exact).len(),
+            days(wide.clone())
+        );
+        assert_eq!(
+            classify(wide, WindowClip::ToCoverage).len(),
+            days(covered.clone())
+        );
+
+        // unbounded sides end at coverage either way
+        for clip in [WindowClip::Exact, WindowClip::ToCoverage] {
+            let kinds = crate::iter_day_kinds::<_, Date>(Country::US, .., Workweek::MON_FRI, clip);
+            assert_eq!(kinds.len(), days(covered.clone()));
+        }
+        assert!(classify(covered.end..covered.start, WindowClip::Exact).is_empty());
+    }
+
+    #[test]
+    fn occurrence_years() {
+        use crate::Month;
+
+        let coverage = Country::DE.coverage();
+        let covered: Vec<_> = (coverage.first_year..=coverage.last_year)
+            .filter_map(crate::CoveredYear::new)
+            .collect();
+
+        // fixed date holiday
+        assert_eq!(
+            crate::occurrence_years(Country::DE, Month::DECEMBER, 25),
+            covered
+        );
+        assert!(crate::missing_years(Country::DE, Month::DECEMBER, 25).is_empty());
+
+        // Easter Monday is on April 21 only in some years
+        let easter = crate::occurrence_years(Country::DE, Month::APRIL, 21);
+        let year = |it: i64| crate::CoveredYear::try_from(it).unwrap();
+        assert!(
+            easter.contains(&year(2025))
+                && easter.contains(&year(2014))
+                && !easter.contains(&year(2024))
+        );
+        assert!(easter.len() > 1 && easter.len() < covered.len() / 5);
+
+        for (month, day) in [(Month::APRIL, 21), (Month::MAY, 1), (Month::MARCH, 3)] {
+            let mut all = crate::occurrence_years(Country::DE, month, day);
+            let missing = crate::missing_years(Country::DE, month, day);
+            assert!(missing.iter().all(|it| !all.contains(it)));
+            all.extend(missing);
+            all.sort_unstable();
+            assert_eq!(all, covered);
+        }
+
+        // February 29 only exists in leap years
+        let leap: Vec<_> = covered
+            .iter()
+            .copied()
+            .filter(|it| Date::from_ymd_opt(it.get(), 2, 29).is_some())
+            .collect();
+        assert_eq!(crate::missing_years(Country::DE, Month::FEBRUARY, 29), leap);
+        assert!(crate::occurrence_years(Country::DE, Month::FEBRUARY, 30).is_empty());
+    }
 
     #[test]
     fn test_name() {
-        use crate::internal::Date;
         use crate::Any;
 
         let holidays = crate::get_holidays(Any, Date::from_ymd(2025, 1, 1));
@@ -0,0 +1,257 @@
+//! Holiday name normalization used by name search and holiday UIDs.
+//!
+//! This module is also compiled into `build.rs` to normalize keys of the
+//! generated name index, so it must only depend on `std`.
+
+use std::borrow::Cow;
+
+/// Controls whether [`normalize_name_with`] replaces letters with diacritics
+/// by their ASCII base letters.
+///
+/// ```
+/// use holidays::Folding;
+///
+/// let name = "Día de la Independencia";
+/// assert_eq!(holidays::normalize_name_with(name, Folding::None), "día de la independencia");
+/// assert_eq!(holidays::normalize_name_with(name, Folding::Ascii), "dia de la independencia");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Folding {
+    /// Letters are kept as they are (apart from being lowercased).
+    None,
+    /// Common Latin letters with diacritics are replaced by ASCII letters,
+    /// e.g. `ñ` by `n` and `ß` by `ss`.
+    #[default]
+    Ascii,
+}
+
+/// Normalizes a holiday name for search, folding diacritics to ASCII.
+///
+/// This is the canonical normalization used by name search APIs, such as
+/// [`with_name`](crate::with_name), on both the searched name and embedded
+/// names. It's equivalent to [`normalize_name_with`] with [`Folding::Ascii`];
+/// see that function for exact rules.
+///
+/// ```
+/// assert_eq!(holidays::normalize_name("St. Stephen's Day (Boxing Day)"), "st stephens day boxing day");
+/// assert_eq!(holidays::normalize_name("Año Nuevo"), "ano nuevo");
+/// ```
+pub fn normalize_name(name: &str) -> Cow<'_, str> {
+    normalize_name_with(name, Folding::Ascii)
+}
+
+/// Normalizes a holiday name for search.
+///
+/// Normalization rules are part of the stable API, as search results depend
+/// on them. The name is processed character by character:
+///
+/// 1. Apostrophes (`'`, `’`, `ʼ`) are removed, so `Stephen's` becomes
+///    `stephens`.
+/// 2. Any other character that isn't alphanumeric (punctuation, brackets,
+///    whitespace, ...) separates words.
+/// 3. Letters are lowercased, and then with [`Folding::Ascii`] common Latin
+///    letters with diacritics are replaced by their ASCII base letters.
+/// 4. Words are joined by a single space, without leading or trailing
+///    spaces.
+///
+/// The name is returned borrowed if it's already normalized.
+///
+/// ```
+/// use holidays::Folding;
+///
+/// assert_eq!(holidays::normalize_name_with("  Año   Nuevo! ", Folding::None), "año nuevo");
+/// assert_eq!(holidays::normalize_name_with("  Año   Nuevo! ", Folding::Ascii), "ano nuevo");
+/// ```
+pub fn normalize_name_with(name: &str, folding: Folding) -> Cow<'_, str> {
+    if normalized_chars(name, folding).eq(name.chars()) {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(normalized_chars(name, folding).collect())
+    }
+}
+
+/// Returns `true` if `name` normalizes to `normalized`, without allocating.
+///
+/// `normalized` must already be normalized with the same `folding`.
+pub(crate) fn matches_normalized(name: &str, normalized: &str, folding: Folding) -> bool {
+    normalized_chars(name, folding).eq(normalized.chars())
+}
+
+/// Returns an iterator over characters of the slug of `name`, used in
+/// [`Holiday::uid`](crate::Holiday::uid).
+///
+/// The slug is `name` normalized with [`Folding::Ascii`], with words joined
+/// by `-` instead of a space. Slugs end up in UIDs of exported calendar
+/// events, so changing these rules duplicates events in calendars that
+/// subscribe to them.
+pub(crate) fn slug_chars(name: &str) -> impl Iterator<Item = char> + '_ {
+    normalized_chars(name, Folding::Ascii).map(|it| if it == ' ' { '-' } else { it })
+}
+
+/// Returns an iterator over characters of normalized `name`.
+pub(crate) fn normalized_chars(name: &str, folding: Folding) -> NormalizedChars<'_> {
+    NormalizedChars {
+        source: name.chars(),
+        folding,
+        buffer: ['\0'; 8],
+        pos: 0,
+        len: 0,
+        started: false,
+        separated: false,
+    }
+}
+
+pub(crate) struct NormalizedChars<'a> {
+    source: std::str::Chars<'a>,
+    folding: Folding,
+    /// Pending output of a single source character; a separator and up to
+    /// three lowercase characters, each folded into up to two.
+    buffer: [char; 8],
+    pos: usize,
+    len: usize,
+    /// Whether any word has been produced.
+    started: bool,
+    /// Whether a word separator was seen since the last word character.
+    separated: bool,
+}
+
+impl NormalizedChars<'_> {
+    fn push(&mut self, c: char) {
+        self.buffer[self.len] = c;
+        self.len += 1;
+    }
+}
+
+impl Iterator for NormalizedChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.pos == self.len {
+            let c = self.source.next()?;
+            if matches!(c, '\'' | '\u{2019}' | '\u{02BC}') {
+                continue;
+            }
+            if !c.is_alphanumeric() {
+                self.separated = true;
+                continue;
+            }
+
+            self.pos = 0;
+            self.len = 0;
+            if self.started && self.separated {
+                self.push(' ');
+            }
+            self.started = true;
+            self.separated = false;
+
+            for lower in c.to_lowercase() {
+                match (self.folding, fold(lower)) {
+                    (Folding::Ascii, "") | (Folding::None, _) => self.push(lower),
+                    (Folding::Ascii, folded) => {
+                        for c in folded.chars() {
+                            self.push(c);
+                        }
+                    }
+                }
+            }
+        }
+
+        let result = self.buffer[self.pos];
+        self.pos += 1;
+        Some(result)
+    }
+}
+
+/// Returns ASCII replacement of a lowercase letter, or an empty string if it
+/// should be kept.
+fn fold(c: char) -> &'static str {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        'þ' => "th",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn punctuation() {
+        assert_eq!(
+            normalize_name("St. Stephen's Day (Boxing Day)"),
+            "st stephens day boxing day"
+        );
+        assert_eq!(normalize_name("Boxing Day"), "boxing day");
+        assert_eq!(normalize_name("New Year’s Day"), "new years day");
+        assert_eq!(
+            normalize_name("Commemoration of Atatürk, Youth and Sports Day; Eid al-Adha"),
+            "commemoration of ataturk youth and sports day eid al adha"
+        );
+        assert_eq!(normalize_name("  --  "), "");
+        assert_eq!(normalize_name(""), "");
+    }
+
+    #[test]
+    fn diacritics() {
+        assert_eq!(normalize_name("Año Nuevo"), "ano nuevo");
+        assert_eq!(normalize_name("Año Nuevo"), normalize_name("ano nuevo"));
+        assert_eq!(normalize_name_with("Año Nuevo", Folding::None), "año nuevo");
+        assert_ne!(
+            normalize_name_with("Año Nuevo", Folding::None),
+            normalize_name_with("ano nuevo", Folding::None)
+        );
+        assert_eq!(normalize_name("Prešeren's Day"), "preserens day");
+        assert_eq!(normalize_name("Straße"), "strasse");
+        assert_eq!(normalize_name("ÉPIPHANIE"), "epiphanie");
+        // non-latin scripts are only lowercased
+        assert_eq!(normalize_name("天皇誕生日"), "天皇誕生日");
+    }
+
+    #[test]
+    fn slugs() {
+        let slug = |name: &str| slug_chars(name).collect::<String>();
+        assert_eq!(slug("Independence Day"), "independence-day");
+        assert_eq!(
+            slug("Isra' and Mi'raj (estimated)"),
+            "isra-and-miraj-estimated"
+        );
+        assert_eq!(slug("Benito Juárez's birthday"), "benito-juarezs-birthday");
+        assert_eq!(slug("Christmas Day (Orthodox)"), "christmas-day-orthodox");
+        assert_eq!(slug("天皇誕生日"), "天皇誕生日");
+        assert_eq!(slug(""), "");
+    }
+
+    #[test]
+    fn borrows_normalized() {
+        assert!(matches!(normalize_name("boxing day"), Cow::Borrowed(_)));
+        assert!(matches!(normalize_name("Boxing Day"), Cow::Owned(_)));
+        assert!(matches!(normalize_name("boxing  day"), Cow::Owned(_)));
+        assert!(matches_normalized(
+            "Boxing-Day!",
+            "boxing day",
+            Folding::Ascii
+        ));
+    }
+}
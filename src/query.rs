@@ -1,30 +1,40 @@
 use crate::country::{Country, CountrySet, CountrySetHolidayIter};
-use crate::{date::Date, Holiday};
+use crate::date::{Date, DateBounds, DateConversionError, IsoDateBuf, Month, OnConversionError};
+use crate::{DayHolidays, Holiday, HolidayFilter, HolidayRef, HolidaySnapshot, SharedHoliday};
+use selection::{CountrySelection, DateSelection};
+use std::ops::Bound;
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct Query {
     countries: CountrySet,
-    date_filter: Option<DateQuery>,
+    date_filter: DateQuery,
 }
 
 impl Query {
-    pub const EMPTY: Query = Query {
+    pub(crate) const EMPTY: Query = Query {
         countries: CountrySet::all(),
-        date_filter: None,
+        date_filter: DateQuery::Any,
     };
 
-    pub const fn country(value: Country) -> Self {
+    /// Query that doesn't select any countries nor constrain dates; used for
+    /// the date axis when it's not filtered.
+    pub(crate) const NO_DATE: Query = Query {
+        countries: CountrySet::new(),
+        date_filter: DateQuery::Any,
+    };
+
+    pub(crate) const fn country(value: Country) -> Self {
         Query {
             countries: {
                 let mut countries = CountrySet::new();
                 countries.insert(value);
                 countries
             },
-            date_filter: None,
+            date_filter: DateQuery::Any,
         }
     }
 
-    pub fn countries<I>(value: I) -> Self
+    pub(crate) fn countries<I>(value: I) -> Self
     where
         I: IntoIterator,
         I::Item: Into<Country>,
@@ -35,52 +45,45 @@ impl Query {
                 countries.extend(value);
                 countries
             },
-            date_filter: None,
+            date_filter: DateQuery::Any,
         }
     }
 
     #[allow(dead_code)]
-    pub const fn year(value: i64) -> Self {
+    pub(crate) const fn year(value: i64) -> Self {
         Query {
             countries: CountrySet::new(),
-            date_filter: Some(DateQuery::year(value)),
+            date_filter: DateQuery::year(value),
         }
     }
 
     #[allow(dead_code)]
-    pub fn year_range<R: std::ops::RangeBounds<i64>>(value: R) -> Self {
+    pub(crate) fn year_range<R: std::ops::RangeBounds<i64>>(value: R) -> Self {
         Query {
             countries: CountrySet::new(),
             date_filter: DateQuery::year_range(value),
         }
     }
 
-    pub fn date(value: impl Into<Date>) -> Self {
+    pub(crate) const fn date(value: Date) -> Self {
         Query {
             countries: CountrySet::new(),
-            date_filter: Some(DateQuery::date(value)),
+            date_filter: DateQuery::Exact(value),
         }
     }
 
-    pub fn date_range<D, R>(value: R) -> Self
-    where
-        D: Into<Date> + Clone,
-        R: std::ops::RangeBounds<D>,
-    {
+    pub(crate) fn date_bounds(start: Bound<Date>, end: Bound<Date>) -> Self {
         Query {
             countries: CountrySet::new(),
-            date_filter: DateQuery::date_range(value),
+            date_filter: DateQuery::from_bounds(start, end),
         }
     }
 
-    pub fn and(mut self, other: Self) -> Self {
+    /// Combines a query of countries with a query of dates, selecting
+    /// countries of both and dates they have in common.
+    pub(crate) fn and(mut self, other: Self) -> Self {
         self.countries |= other.countries;
-        self.date_filter = match (self.date_filter, other.date_filter) {
-            (None, Some(it)) => Some(it),
-            (Some(it), None) => Some(it),
-            (Some(a), Some(b)) => Some(a & b),
-            (None, None) => None,
-        };
+        self.date_filter = self.date_filter & other.date_filter;
         self
     }
 }
@@ -96,37 +99,318 @@ impl std::ops::BitAnd for Query {
 impl std::ops::BitAndAssign for Query {
     fn bitand_assign(&mut self, rhs: Self) {
         self.countries &= rhs.countries;
-        self.date_filter = match (self.date_filter, rhs.date_filter) {
-            (None, Some(it)) => Some(it),
-            (Some(it), None) => Some(it),
-            (Some(a), Some(b)) => Some(a & b),
-            (None, None) => None,
-        };
+        self.date_filter = self.date_filter & rhs.date_filter;
     }
 }
 
-impl IntoIterator for Query {
-    type Item = <Iter as Iterator>::Item;
+impl Query {
+    /// Returns the inclusive start and the exclusive end of selected dates,
+    /// where `None` leaves the side unbounded.
+    pub(crate) fn date_range(&self) -> (Option<Date>, Option<Date>) {
+        self.date_filter.bounds()
+    }
+
+    /// Returns indices of records of `country` on selected dates, whether or
+    /// not the country itself is selected.
+    pub(crate) fn dates_of(&self, country: Country) -> &'static [usize] {
+        self.date_filter
+            .slice_within(crate::data::country_indices(country))
+    }
+
+    /// Returns selected countries and half-open bounds of selected dates,
+    /// which are equal for all spellings of queries that select the same
+    /// records.
+    #[cfg(any(test, feature = "cache"))]
+    pub(crate) fn normalized(&self) -> (CountrySet, Option<Date>, Option<Date>) {
+        if self.countries.is_empty() || self.date_filter.is_empty() {
+            return (CountrySet::new(), Some(Date::MIN), Some(Date::MIN));
+        }
+        let (start, end) = self.date_filter.bounds();
+        (self.countries, start, end)
+    }
+
+    /// Executes the query with `options`.
+    pub(crate) fn iter_with(self, options: QueryOptions) -> Iter {
+        plan(&self, options).into_iter()
+    }
+}
+
+/// Description of how a query is executed, chosen by [`plan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Plan {
+    /// Query can't match any records, so nothing is looked up.
+    Empty,
+    /// Looks up the record of each country on `date`.
+    Lookup { countries: CountrySet, date: Date },
+    /// Scans `DATA` indices in `range` and keeps records of `countries`.
+    Scan {
+        range: std::ops::Range<usize>,
+        countries: CountrySet,
+    },
+    /// Merges per-country tables of `countries`, limited to `dates`.
+    Merge {
+        countries: CountrySet,
+        dates: DateQuery,
+    },
+    /// Walks per-country tables of `countries` one after another, limited to
+    /// `dates`.
+    Chain {
+        countries: CountrySet,
+        dates: DateQuery,
+    },
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of plans selected on this thread, so tests can check when
+    /// queries are executed.
+    static PLANS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Returns the number of plans selected on this thread.
+#[cfg(test)]
+pub(crate) fn plans_selected() -> usize {
+    PLANS.with(|it| it.get())
+}
+
+/// Selects the execution plan for the `query`.
+///
+/// Plans never affect results, so this is the only place strategy
+/// heuristics live and the only thing their tests need to check.
+pub(crate) fn plan(query: &Query, options: QueryOptions) -> Plan {
+    #[cfg(test)]
+    PLANS.with(|it| it.set(it.get() + 1));
+    let countries = query.countries;
+    match (query.date_filter, options.strategy) {
+        (dates, _) if countries.is_empty() || dates.is_empty() => Plan::Empty,
+        // records of a single date are in the same order either way
+        (dates, _)
+            if options.order == Order::CountryThenDate && !matches!(dates, DateQuery::Exact(_)) =>
+        {
+            Plan::Chain { countries, dates }
+        }
+        (DateQuery::Exact(date), Strategy::Auto | Strategy::ForcePerCountry) => {
+            Plan::Lookup { countries, date }
+        }
+        (DateQuery::Any, Strategy::Auto) | (_, Strategy::ForcePerCountry) => Plan::Merge {
+            countries,
+            dates: query.date_filter,
+        },
+        (dates, Strategy::Auto | Strategy::ForceRangeScan) => Plan::Scan {
+            range: dates.as_data_range(),
+            countries,
+        },
+    }
+}
+
+impl IntoIterator for Plan {
+    type Item = &'static Holiday;
     type IntoIter = Iter;
-    
+
     fn into_iter(self) -> Self::IntoIter {
-        Iter(match self.date_filter {
-            Some(empty) if empty.is_empty() => IterImpl::Empty,
-            Some(DateQuery::Exact(date)) => IterImpl::Exact {
-                inner: self.countries.iter(),
+        let inner = match self {
+            Plan::Empty => IterImpl::Empty,
+            Plan::Lookup { countries, date } => IterImpl::Exact {
+                inner: countries.iter(),
                 date,
+                current: 0..0,
             },
-            Some(date_query) => IterImpl::DateRange {
-                range: date_query.as_data_range(),
-                countries: self.countries,
+            Plan::Scan { range, countries } => IterImpl::DateRange { range, countries },
+            Plan::Merge {
+                countries,
+                dates: DateQuery::Any,
+            } => IterImpl::NoDate(countries.holidays()),
+            Plan::Merge { countries, dates } => {
+                IterImpl::NoDate(CountrySetHolidayIter::from_slices(
+                    countries
+                        .iter()
+                        .map(|it| dates.slice_within(crate::data::country_indices(it))),
+                ))
+            }
+            Plan::Chain { countries, dates } => IterImpl::ByCountry {
+                countries: countries.iter(),
+                current: [].iter(),
+                dates,
             },
-            None => IterImpl::NoDate(self.countries.holidays()),
-        })
+        };
+        Iter {
+            inner,
+            months: ALL_MONTHS,
+        }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = <Iter as Iterator>::Item;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_with(QueryOptions::DEFAULT)
+    }
+}
+
+/// Strategy used to find records matching a query.
+///
+/// ```
+/// use holidays::{Country, PreparedQuery, QueryOptions, Strategy};
+///
+/// // few countries over many years are cheaper to look up country by country
+/// let options = QueryOptions::DEFAULT.with_strategy(Strategy::ForcePerCountry);
+/// let query = PreparedQuery::new([Country::US, Country::JP], 2000..2030).with_options(options);
+/// assert!(query.iter().eq(holidays::get_holidays([Country::US, Country::JP], 2000..2030)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    /// Picks the strategy based on the shape of the query.
+    #[default]
+    Auto,
+    /// Scans all records in the queried date range and filters them by
+    /// country.
+    ForceRangeScan,
+    /// Looks up records of each queried country separately and merges them.
+    ForcePerCountry,
+}
+
+/// Determines whether a holiday on the pivot date is a neighbor of the
+/// pivot, in [`neighbors_with`](crate::neighbors_with).
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::{Country, Pivot};
+///
+/// let christmas = Date::from_ymd(2025, 12, 25);
+/// let (_, next) = holidays::neighbors_with(Country::US, christmas, Pivot::Next);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(next.unwrap().name, "Christmas Day");
+///
+/// let (previous, next) = holidays::neighbors_with(Country::US, christmas, Pivot::Excluded);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(previous.unwrap().name, "Thanksgiving Day");
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(next.unwrap().name, "New Year's Day");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Pivot {
+    /// Holidays on the pivot date are next holidays.
+    #[default]
+    Next,
+    /// Holidays on the pivot date are excluded, so both neighbors are
+    /// strictly before or after it.
+    Excluded,
+}
+
+/// Order in which query results are yielded.
+///
+/// ```
+/// use holidays::{Country, Order};
+///
+/// let countries = [Country::US, Country::JP];
+/// let codes: Vec<_> = holidays::get_holidays_ordered(countries, 2025..2026, Order::CountryThenDate)
+///     .countries()
+///     .collect();
+///
+/// // all holidays of JP come before any of US
+/// let first_us = codes.iter().position(|it| *it == Country::US).unwrap();
+/// assert!(codes[..first_us].iter().all(|it| *it == Country::JP));
+/// assert!(codes[first_us..].iter().all(|it| *it == Country::US));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Results are ordered by date and then by country code, so holidays of
+    /// different countries are interleaved.
+    #[default]
+    DateThenCountry,
+    /// Results are ordered by country code and then by date, so all holidays
+    /// of a country are yielded before any of the next one.
+    ///
+    /// Per-country tables are walked one after another, without merging
+    /// them, which is also the cheapest way to build per-country calendars.
+    CountryThenDate,
+}
+
+/// Options that control how a query is executed.
+///
+/// Apart from the [`Order`] of results, options never affect which records
+/// are returned, or the order in which they are returned; only how fast
+/// they're found. Forced strategies are best-effort: queries that match no
+/// records, for instance, aren't executed at all, and neither strategy
+/// applies to queries ordered by country.
+///
+/// ```
+/// use holidays::{Country, PreparedQuery, QueryOptions, Strategy};
+///
+/// let query = PreparedQuery::new([Country::US, Country::GB], 2025..2026);
+/// let forced = query.with_options(QueryOptions::DEFAULT.with_strategy(Strategy::ForceRangeScan));
+///
+/// assert!(query.iter().eq(forced.iter()));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct QueryOptions {
+    /// Strategy used to find matching records.
+    pub strategy: Strategy,
+    /// Order in which matching records are yielded.
+    pub order: Order,
+}
+
+impl QueryOptions {
+    /// Default options, which let the query pick the best strategy and order
+    /// results by date.
+    ///
+    /// ```
+    /// use holidays::{Order, QueryOptions, Strategy};
+    ///
+    /// assert_eq!(QueryOptions::DEFAULT, QueryOptions::default());
+    /// assert_eq!(QueryOptions::DEFAULT.strategy, Strategy::Auto);
+    /// assert_eq!(QueryOptions::DEFAULT.order, Order::DateThenCountry);
+    /// ```
+    pub const DEFAULT: QueryOptions = QueryOptions {
+        strategy: Strategy::Auto,
+        order: Order::DateThenCountry,
+    };
+
+    /// Returns options with `strategy` replaced.
+    ///
+    /// ```
+    /// use holidays::{Country, PreparedQuery, QueryOptions, Strategy};
+    ///
+    /// let options = QueryOptions::DEFAULT.with_strategy(Strategy::ForcePerCountry);
+    /// let query = PreparedQuery::new([Country::FR, Country::DE], 2025..2026);
+    /// assert!(query.iter().eq(query.with_options(options).iter()));
+    /// ```
+    pub const fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Returns options with `order` of results replaced.
+    ///
+    /// ```
+    /// use holidays::{Country, Order, PreparedQuery, QueryOptions};
+    ///
+    /// let options = QueryOptions::DEFAULT.with_order(Order::CountryThenDate);
+    /// let query = PreparedQuery::new([Country::US, Country::FR], 2025..2026).with_options(options);
+    ///
+    /// let mut countries: Vec<_> = query.iter().map(|it| it.code).collect();
+    /// countries.dedup();
+    /// assert_eq!(countries, [Country::FR, Country::US]);
+    /// ```
+    pub const fn with_order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
     }
 }
 
-#[derive(Clone, Copy)]
-enum DateQuery {
+/// Dates selected by a query.
+///
+/// Ranges are half-open, and intersections of queries that can't match any
+/// date are [`DateQuery::Empty`], so only ranges built directly can be empty
+/// without being `Empty`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateQuery {
+    /// All dates.
+    Any,
+    /// No dates.
+    Empty,
     Exact(Date),
     FromDate(Date),
     ToDate(Date),
@@ -134,257 +418,398 @@ enum DateQuery {
 }
 
 impl DateQuery {
-    const EMPTY: DateQuery = DateQuery::DateRange(Date(0), Date(0));
-
     #[allow(dead_code)]
     #[inline(always)]
     const fn year(value: i64) -> Self {
-        DateQuery::DateRange(Date::from_year(value), Date::from_year(value + 1))
+        Self::between(
+            Some(Date::saturating_from_year(value)),
+            Some(Date::saturating_from_year(value.saturating_add(1))),
+        )
     }
 
     #[allow(dead_code)]
-    #[inline(always)]
-    fn year_range<R>(value: R) -> Option<Self>
+    #[inline]
+    fn year_range<R>(value: R) -> Self
     where
         R: std::ops::RangeBounds<i64>,
     {
         let start = match value.start_bound() {
-            std::ops::Bound::Included(it) => Date::from_year(*it),
-            std::ops::Bound::Excluded(it) => Date::from_year(it + 1),
-            std::ops::Bound::Unbounded => match value.end_bound() {
-                std::ops::Bound::Included(it) => {
-                    return Some(DateQuery::ToDate(Date::from_year(it + 1)))
-                }
-                std::ops::Bound::Excluded(it) => {
-                    return Some(DateQuery::ToDate(Date::from_year(*it)))
-                }
-                std::ops::Bound::Unbounded => return None,
-            },
+            Bound::Included(it) => Bound::Included(Date::saturating_from_year(*it)),
+            Bound::Excluded(it) => {
+                Bound::Included(Date::saturating_from_year(it.saturating_add(1)))
+            }
+            Bound::Unbounded => Bound::Unbounded,
         };
-
         let end = match value.end_bound() {
-            std::ops::Bound::Included(it) => Date::from_year(it + 1),
-            std::ops::Bound::Excluded(it) => Date::from_year(*it),
-            std::ops::Bound::Unbounded => match value.start_bound() {
-                std::ops::Bound::Included(it) => {
-                    return Some(DateQuery::FromDate(Date::from_year(*it)))
-                }
-                std::ops::Bound::Excluded(it) => {
-                    return Some(DateQuery::FromDate(Date::from_year(it + 1)))
-                }
-                std::ops::Bound::Unbounded => return None, // unreachable
-            },
+            Bound::Included(it) => {
+                Bound::Excluded(Date::saturating_from_year(it.saturating_add(1)))
+            }
+            Bound::Excluded(it) => Bound::Excluded(Date::saturating_from_year(*it)),
+            Bound::Unbounded => Bound::Unbounded,
         };
-
-        Some(DateQuery::DateRange(start, end))
+        Self::from_bounds(start, end)
     }
 
-    #[inline(always)]
-    fn date(value: impl Into<Date>) -> Self {
-        DateQuery::Exact(value.into())
+    /// Returns the query of dates within bounds.
+    fn from_bounds(start: Bound<Date>, end: Bound<Date>) -> Self {
+        let (start, end) = half_open(start, end);
+        Self::between(start, end)
     }
 
-    #[inline(always)]
-    fn date_range<D, R>(value: R) -> Option<Self>
-    where
-        D: Into<Date> + Clone,
-        R: std::ops::RangeBounds<D>,
-    {
-        let start = match value.start_bound() {
-            std::ops::Bound::Included(it) => it.clone().into(),
-            std::ops::Bound::Excluded(it) => {
-                let mut it = it.clone().into();
-                it.0 += 1;
-                it
-            }
-            std::ops::Bound::Unbounded => match value.end_bound() {
-                std::ops::Bound::Included(it) => {
-                    return Some(DateQuery::ToDate({
-                        let mut it = it.clone().into();
-                        it.0 += 1;
-                        it
-                    }))
-                }
-                std::ops::Bound::Excluded(it) => return Some(DateQuery::ToDate(it.clone().into())),
-                std::ops::Bound::Unbounded => return None,
-            },
-        };
+    /// Returns the query of dates on or after `start` and before `end`,
+    /// where `None` leaves the side unbounded.
+    const fn between(start: Option<Date>, end: Option<Date>) -> Self {
+        match (start, end) {
+            (Some(start), Some(end)) if start.0 >= end.0 => DateQuery::Empty,
+            (Some(start), Some(end)) => DateQuery::DateRange(start, end),
+            (Some(start), None) => DateQuery::FromDate(start),
+            (None, Some(end)) => DateQuery::ToDate(end),
+            (None, None) => DateQuery::Any,
+        }
+    }
 
-        let end = match value.end_bound() {
-            std::ops::Bound::Included(it) => {
-                let mut it = it.clone().into();
-                it.0 += 1;
-                it
-            }
-            std::ops::Bound::Excluded(it) => it.clone().into(),
-            std::ops::Bound::Unbounded => match value.start_bound() {
-                std::ops::Bound::Included(it) => {
-                    return Some(DateQuery::FromDate(it.clone().into()))
-                }
-                std::ops::Bound::Excluded(it) => {
-                    return Some(DateQuery::FromDate({
-                        let mut it = it.clone().into();
-                        it.0 += 1;
-                        it
-                    }))
-                }
-                std::ops::Bound::Unbounded => return None, // unreachable
-            },
-        };
+    /// Returns the inclusive start and exclusive end of selected dates, if
+    /// they're bounded.
+    fn bounds(&self) -> (Option<Date>, Option<Date>) {
+        match *self {
+            DateQuery::Any => (None, None),
+            // any empty range would do
+            DateQuery::Empty => (Some(Date::MIN), Some(Date::MIN)),
+            DateQuery::Exact(date) => (Some(date), Some(date.saturating_add_days(1))),
+            DateQuery::FromDate(from) => (Some(from), None),
+            DateQuery::ToDate(to) => (None, Some(to)),
+            DateQuery::DateRange(from, to) => (Some(from), Some(to)),
+        }
+    }
 
-        Some(DateQuery::DateRange(start, end))
+    fn contains(&self, date: Date) -> bool {
+        match *self {
+            DateQuery::Any => true,
+            DateQuery::Empty => false,
+            DateQuery::Exact(it) => it == date,
+            DateQuery::FromDate(from) => from <= date,
+            DateQuery::ToDate(to) => date < to,
+            DateQuery::DateRange(from, to) => from <= date && date < to,
+        }
     }
 
     fn is_empty(&self) -> bool {
         match self {
+            DateQuery::Empty => true,
             DateQuery::DateRange(a, b) => a >= b,
-            _ => false,
+            DateQuery::Any
+            | DateQuery::Exact(_)
+            | DateQuery::FromDate(_)
+            | DateQuery::ToDate(_) => false,
         }
     }
 
+    /// Returns the sub-slice of sorted `DATA` `indices` whose dates match the
+    /// query.
+    fn slice_within(&self, indices: &'static [usize]) -> &'static [usize] {
+        let index_of =
+            |date: Date| indices.partition_point(|it| crate::data::DATA[*it].date < date);
+        let range = match self {
+            DateQuery::Any => 0..indices.len(),
+            DateQuery::Empty => 0..0,
+            DateQuery::DateRange(from, to) if from >= to => 0..0,
+            DateQuery::Exact(date) => index_of(*date)..index_of(date.saturating_add_days(1)),
+            DateQuery::FromDate(from) => index_of(*from)..indices.len(),
+            DateQuery::ToDate(to) => 0..index_of(*to),
+            DateQuery::DateRange(from, to) => index_of(*from)..index_of(*to),
+        };
+        &indices[range]
+    }
+
     fn as_data_range(&self) -> std::ops::Range<usize> {
+        use crate::data::clamp_to_index;
         const DATA_LEN: usize = crate::data::DATA.len();
-        match self {
-            DateQuery::DateRange(from, to) => {
-                match (
-                    crate::data::date_to_index(*from),
-                    crate::data::date_to_index(*to),
-                ) {
-                    (Some(a), Some(b)) => a..b,
-                    (Some(a), None) => a..DATA_LEN,
-                    (None, Some(b)) => 0..b,
-                    (None, None) => 0..0,
-                }
-            }
-            DateQuery::FromDate(date) => match crate::data::date_to_index(*date) {
-                Some(it) => it..DATA_LEN,
-                None => DATA_LEN..DATA_LEN,
-            },
-            DateQuery::ToDate(date) => match crate::data::date_to_index(*date) {
-                Some(it) => 0..it,
-                None => 0..0,
-            },
-            DateQuery::Exact(date) => {
-                let from = match crate::data::date_to_index(*date) {
-                    Some(it) => it,
-                    None => return DATA_LEN..DATA_LEN,
-                };
-                match crate::data::date_to_index(Date(date.0 + 1)) {
-                    Some(to) => from..to,
-                    None => from..DATA_LEN,
-                }
-            }
+        let (from, to) = match self {
+            DateQuery::Any => (0, DATA_LEN),
+            DateQuery::Empty => (0, 0),
+            DateQuery::DateRange(from, to) => (clamp_to_index(*from), clamp_to_index(*to)),
+            DateQuery::FromDate(date) => (clamp_to_index(*date), DATA_LEN),
+            DateQuery::ToDate(date) => (0, clamp_to_index(*date)),
+            DateQuery::Exact(date) => (
+                clamp_to_index(*date),
+                clamp_to_index(date.saturating_add_days(1)),
+            ),
+        };
+        // reversed ranges are empty
+        from..to.max(from)
+    }
+}
+
+/// Returns the inclusive start and the exclusive end of dates within bounds,
+/// where `None` leaves the side unbounded.
+fn half_open(start: Bound<Date>, end: Bound<Date>) -> (Option<Date>, Option<Date>) {
+    let start = match start {
+        Bound::Included(it) => Some(it),
+        Bound::Excluded(it) => Some(it.saturating_add_days(1)),
+        Bound::Unbounded => None,
+    };
+    let end = match end {
+        Bound::Included(it) => Some(it.saturating_add_days(1)),
+        Bound::Excluded(it) => Some(it),
+        Bound::Unbounded => None,
+    };
+    (start, end)
+}
+
+/// Returns the last record of `countries` before `date` and the first one on
+/// or after it (or after it, if the pivot is excluded).
+fn neighbors_in(
+    countries: CountrySet,
+    date: Date,
+    pivot: Pivot,
+) -> (Option<&'static Holiday>, Option<&'static Holiday>) {
+    let next_from = match pivot {
+        Pivot::Next => date,
+        Pivot::Excluded => date.saturating_add_days(1),
+    };
+
+    // records are sorted in result order, so neighbors are found by
+    // comparing their indices across countries
+    let mut previous = None;
+    let mut next = None;
+    for country in countries {
+        let indices = crate::data::country_indices(country);
+        let before = indices.partition_point(|it| crate::data::DATA[*it].date < date);
+        let after = indices.partition_point(|it| crate::data::DATA[*it].date < next_from);
+        if let Some(i) = before.checked_sub(1) {
+            previous = previous.max(Some(indices[i]));
+        }
+        if let Some(&i) = indices.get(after) {
+            next = Some(next.map_or(i, |it: usize| it.min(i)));
         }
     }
+
+    // the last record of the previous day is the greatest name of its
+    // country, while single-result APIs return the least one
+    let previous = previous.and_then(|it| {
+        let holiday = &crate::data::DATA[it];
+        crate::data::country_date_to_holiday(holiday.code, holiday.date)
+    });
+    (previous, next.map(|it| &crate::data::DATA[it]))
+}
+
+/// Returns the set of countries that observe any holiday on `date`.
+///
+/// Records of a single day are adjacent in data, so this only scans a single
+/// block of records. Countries with multiple records are counted once.
+pub(crate) fn observing_countries(date: Date) -> CountrySet {
+    let range = DateQuery::Exact(date).as_data_range();
+    countries_of(&crate::data::DATA[range])
+}
+
+/// Returns the plan that finds all records on `date`, for lookups that check
+/// them against another query instead of walking its results.
+pub(crate) fn date_plan(date: Date) -> Plan {
+    let query = Query {
+        countries: observing_countries(date),
+        date_filter: DateQuery::Exact(date),
+    };
+    plan(&query, QueryOptions::DEFAULT)
+}
+
+fn countries_of(records: &[Holiday]) -> CountrySet {
+    records.iter().map(|it| it.code).collect()
 }
 
 impl std::ops::BitAnd for DateQuery {
     type Output = Self;
 
+    /// Returns the query of dates selected by both queries.
     fn bitand(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
-            (DateQuery::FromDate(a), DateQuery::FromDate(b)) => DateQuery::FromDate(a.max(b)),
-            (DateQuery::ToDate(a), DateQuery::ToDate(b)) => DateQuery::ToDate(a.min(b)),
-            (DateQuery::Exact(a), DateQuery::Exact(b)) => {
-                if a != b {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::Exact(a)
-                }
-            }
-
-            (DateQuery::FromDate(a), DateQuery::Exact(b))
-            | (DateQuery::Exact(b), DateQuery::FromDate(a)) => {
-                if a > b {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::Exact(b)
-                }
-            }
-            (DateQuery::ToDate(a), DateQuery::Exact(b))
-            | (DateQuery::Exact(b), DateQuery::ToDate(a)) => {
-                if b >= a {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::Exact(b)
-                }
-            }
-            (DateQuery::Exact(a), DateQuery::DateRange(b_from, b_to))
-            | (DateQuery::DateRange(b_from, b_to), DateQuery::Exact(a)) => {
-                if b_from > a || b_to <= a {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::Exact(a)
-                }
-            }
-            (DateQuery::FromDate(a), DateQuery::ToDate(b))
-            | (DateQuery::ToDate(b), DateQuery::FromDate(a)) => {
-                if a >= b {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::DateRange(a, b)
-                }
-            }
-            (DateQuery::FromDate(a), DateQuery::DateRange(b_from, b_to))
-            | (DateQuery::DateRange(b_from, b_to), DateQuery::FromDate(a)) => {
-                let from = a.max(b_from);
-                if from >= b_to {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::DateRange(from, b_to)
-                }
-            }
-            (DateQuery::ToDate(a), DateQuery::DateRange(b_from, b_to))
-            | (DateQuery::DateRange(b_from, b_to), DateQuery::ToDate(a)) => {
-                let to = a.max(b_to);
-                if to >= b_from {
-                    DateQuery::EMPTY
+            (a, b) if a.is_empty() || b.is_empty() => DateQuery::Empty,
+            (DateQuery::Any, it) | (it, DateQuery::Any) => it,
+            // exact dates stay exact, so they're looked up directly
+            (DateQuery::Exact(date), other) | (other, DateQuery::Exact(date)) => {
+                if other.contains(date) {
+                    DateQuery::Exact(date)
                 } else {
-                    DateQuery::DateRange(b_from, to)
+                    DateQuery::Empty
                 }
             }
-            (DateQuery::DateRange(a_from, a_to), DateQuery::DateRange(b_from, b_to)) => {
-                let from = a_from.max(b_from);
-                let to = a_to.min(b_to);
-                if to >= from {
-                    DateQuery::EMPTY
-                } else {
-                    DateQuery::DateRange(from, to)
-                }
+            (a, b) => {
+                let ((a_from, a_to), (b_from, b_to)) = (a.bounds(), b.bounds());
+                let from = match (a_from, b_from) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                };
+                let to = match (a_to, b_to) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                DateQuery::between(from, to)
             }
         }
     }
 }
 
+/// A query with country and date selections resolved ahead of time.
+///
+/// Prepared queries are cheap to copy and can be executed any number of times,
+/// from any thread, without repeating conversion of query inputs.
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use holidays::{Country, PreparedQuery};
+///
+/// static QUERY: OnceLock<PreparedQuery> = OnceLock::new();
+///
+/// let query = QUERY.get_or_init(|| PreparedQuery::new([Country::US, Country::GB], 2025..2026));
+///
+/// assert_eq!(query.iter().count(), holidays::get_holidays([Country::US, Country::GB], 2025..2026).count());
+/// assert_eq!(query.iter().count(), query.iter().count());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreparedQuery(Query, QueryOptions);
+
+impl PreparedQuery {
+    /// Resolves a query from the same inputs as accepted by [`get_holidays`].
+    ///
+    /// [`get_holidays`]: crate::get_holidays
+    ///
+    /// ```
+    /// use holidays::{Any, PreparedQuery};
+    ///
+    /// let query = PreparedQuery::new(Any, 2025);
+    /// assert_eq!(query.iter().count(), holidays::get_holidays(Any, 2025).count());
+    /// ```
+    #[inline]
+    pub fn new<CountryIter, DateLike, DateRange>(
+        countries: impl Into<CountrySelection<CountryIter>>,
+        date: impl Into<DateSelection<DateLike, DateRange>>,
+    ) -> Self
+    where
+        CountryIter: IntoIterator,
+        CountryIter::Item: Into<Country>,
+        DateLike: Into<Date> + Clone,
+        DateRange: std::ops::RangeBounds<DateLike>,
+    {
+        let country_query = countries.into().into_query();
+        let date_query = date.into().into_query();
+        PreparedQuery(country_query.and(date_query), QueryOptions::DEFAULT)
+    }
+
+    /// Returns the query with execution `options` replaced.
+    ///
+    /// ```
+    /// use holidays::{Country, Order, PreparedQuery, QueryOptions};
+    ///
+    /// let by_date = PreparedQuery::new([Country::US, Country::CA], 2025..2026);
+    /// let by_country = by_date.with_options(QueryOptions::DEFAULT.with_order(Order::CountryThenDate));
+    /// assert_eq!(by_date.iter().count(), by_country.iter().count());
+    /// assert!(!by_date.iter().eq(by_country.iter()));
+    /// ```
+    pub const fn with_options(mut self, options: QueryOptions) -> Self {
+        self.1 = options;
+        self
+    }
+
+    /// Returns execution options of the query.
+    ///
+    /// ```
+    /// use holidays::{Country, PreparedQuery, QueryOptions};
+    ///
+    /// let query = PreparedQuery::new(Country::US, 2025);
+    /// assert_eq!(query.options(), QueryOptions::DEFAULT);
+    /// ```
+    pub const fn options(&self) -> QueryOptions {
+        self.1
+    }
+
+    /// Executes the query and returns an iterator over matching holidays.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, PreparedQuery};
+    ///
+    /// let query = PreparedQuery::new(Country::US, 2025..2026);
+    /// let first = query.iter().next().unwrap();
+    /// assert_eq!(first.date::<Date>(), Ok(Date::from_ymd(2025, 1, 1)));
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter {
+        self.0.iter_with(self.1)
+    }
+}
+
+impl IntoIterator for PreparedQuery {
+    type Item = &'static Holiday;
+    type IntoIter = Iter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &PreparedQuery {
+    type Item = &'static Holiday;
+    type IntoIter = Iter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Query execution state; every variant other than `ByCountry` yields
+/// records sorted by date and then by country code, see [`Iter`].
 #[derive(Clone)]
 enum IterImpl {
     Empty,
+    /// Records of a single date, in order of `CountrySetIter`, which is
+    /// alphabetical by code.
     Exact {
+        /// Countries that weren't looked up yet.
         inner: crate::country::CountrySetIter,
         date: Date,
+        /// Remaining indices of records of the country looked up last.
+        current: std::ops::Range<usize>,
     },
+    /// Records within a window of `DATA`, which is sorted in result order.
     DateRange {
         range: std::ops::Range<usize>,
         countries: CountrySet,
     },
+    /// Records of all dates, scanned or merged from per-country tables by
+    /// `DATA` index.
     NoDate(CountrySetHolidayIter),
+    /// Records of selected dates from per-country tables, walked one after
+    /// another, so they're sorted by country code and then by date.
+    ByCountry {
+        /// Countries whose tables weren't walked yet.
+        countries: crate::country::CountrySetIter,
+        /// Remaining indices of the table being walked.
+        current: std::slice::Iter<'static, usize>,
+        dates: DateQuery,
+    },
+    /// Results of the inner iterator that match a user filter.
+    Filtered {
+        inner: Box<IterImpl>,
+        filter: std::sync::Arc<dyn HolidayFilter + Send + Sync>,
+    },
 }
 
-/// Iterator over holiday query results.
-#[derive(Clone)]
-pub struct Iter(IterImpl);
-
-impl Iterator for Iter {
+impl Iterator for IterImpl {
     type Item = &'static Holiday;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match &mut self.0 {
+        match self {
             IterImpl::Empty => None,
-            IterImpl::Exact { inner, date } => loop {
-                let next = inner.next()?;
-                if let Some(it) = crate::data::country_date_to_holiday(next, *date) {
-                    return Some(it);
+            IterImpl::Exact {
+                inner,
+                date,
+                current,
+            } => loop {
+                if let Some(i) = current.next() {
+                    return Some(&crate::data::DATA[i]);
                 }
+                *current = crate::data::country_date_to_indices(inner.next()?, *date);
             },
             IterImpl::DateRange { range, countries } => loop {
                 let i = range.next()?;
@@ -394,88 +819,1272 @@ impl Iterator for Iter {
                 }
             },
             IterImpl::NoDate(inner) => inner.next(),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => loop {
+                if let Some(&i) = current.next() {
+                    return Some(&crate::data::DATA[i]);
+                }
+                *current = dates
+                    .slice_within(crate::data::country_indices(countries.next()?))
+                    .iter();
+            },
+            IterImpl::Filtered { inner, filter } => inner.find(|it| filter.matches(it)),
         }
     }
-}
-
-#[derive(Clone)]
-enum BoundsResultImpl<I>
-where
-    I: Iterator,
-    I::Item: Into<Country>,
-{
-    Empty,
-    One(Country),
-    Many(I),
-}
-#[derive(Clone)]
-pub(crate) struct BoundsResult<I>(BoundsResultImpl<I>)
-where
-    I: Iterator,
-    I::Item: Into<Country>;
 
-impl<I> Iterator for BoundsResult<I>
-where
-    I: Iterator,
-    I::Item: Into<Country>,
-{
-    type Item = (Country, Option<(&'static Holiday, &'static Holiday)>);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IterImpl::Empty => (0, Some(0)),
+            IterImpl::Exact {
+                inner,
+                date,
+                current,
+            } => {
+                let len = current.len() + { *inner }
+                    .map(|it| crate::data::country_date_to_indices(it, *date).len())
+                    .sum::<usize>();
+                (len, Some(len))
+            }
+            IterImpl::DateRange { range, countries } if *countries == CountrySet::all() => {
+                (range.len(), Some(range.len()))
+            }
+            IterImpl::DateRange { range, countries } => {
+                let len = countries
+                    .iter()
+                    .map(|it| crate::data::country_indices_within(it, range).len())
+                    .sum();
+                (len, Some(len))
+            }
+            IterImpl::NoDate(inner) => inner.size_hint(),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => {
+                let len = current.len() + { *countries }
+                    .map(|it| dates.slice_within(crate::data::country_indices(it)).len())
+                    .sum::<usize>();
+                (len, Some(len))
+            }
+            IterImpl::Filtered { inner, .. } => (0, inner.size_hint().1),
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let next = match &mut self.0 {
-            BoundsResultImpl::Empty => return None,
-            BoundsResultImpl::One(country) => {
-                let value = *country;
-                self.0 = BoundsResultImpl::Empty;
-                value
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            IterImpl::DateRange { range, countries }
+                if crate::data::prefer_nth_search(*countries, n) =>
+            {
+                let Some(i) = crate::data::nth_index_within(*countries, range, n) else {
+                    range.start = range.end;
+                    return None;
+                };
+                range.start = i + 1;
+                Some(&crate::data::DATA[i])
             }
-            BoundsResultImpl::Many(inner) => inner.next().map(|it| it.into())?,
-        };
+            IterImpl::NoDate(inner) => inner.nth(n),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => {
+                // whole tables are skipped without visiting their records
+                let mut n = n;
+                while n >= current.len() {
+                    n -= current.len();
+                    let Some(next) = countries.next() else {
+                        *current = [].iter();
+                        return None;
+                    };
+                    *current = dates
+                        .slice_within(crate::data::country_indices(next))
+                        .iter();
+                }
+                current.nth(n).map(|&i| &crate::data::DATA[i])
+            }
+            // exact dates hold few records per country, and filters have to
+            // see every record
+            _ => {
+                for _ in 0..n {
+                    self.next()?;
+                }
+                self.next()
+            }
+        }
+    }
 
-        let indices = crate::data::COUNTRY_JUMP_TABLE[next as usize];
-        let bounds = indices.first().map(|it| {
-            let min = it;
-            // SAFETY: If first value exists, as guaranteed by `map`, then last
-            // value must exist as well and be either the same value or some
-            // later one in the slice.
-            let max = unsafe { indices.last().unwrap_unchecked() };
-
-            // SAFETY: Every index stored in `COUNTRY_JUMP_TABLE` is a valid index into `DATA`
-            let (min, max) = unsafe {
-                (
-                    crate::data::DATA.get_unchecked(*min),
-                    crate::data::DATA.get_unchecked(*max),
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            IterImpl::Empty => init,
+            IterImpl::Exact {
+                inner,
+                date,
+                current,
+            } => current
+                .chain(inner.flat_map(|it| crate::data::country_date_to_indices(it, date)))
+                .map(|i| &crate::data::DATA[i])
+                .fold(init, f),
+            IterImpl::DateRange { range, countries } => crate::data::DATA[range]
+                .iter()
+                .filter(|it| countries.contains(it.code))
+                .fold(init, f),
+            IterImpl::NoDate(inner) => inner.fold(init, f),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => current
+                .chain(
+                    countries.flat_map(|it| dates.slice_within(crate::data::country_indices(it))),
                 )
-            };
-
-            (min, max)
-        });
+                .map(|&i| &crate::data::DATA[i])
+                .fold(init, f),
+            IterImpl::Filtered { inner, filter } => fold_filtered(*inner, &*filter, init, &mut f),
+        }
+    }
 
-        Some((next, bounds))
+    fn last(self) -> Option<Self::Item> {
+        self.last_where(&|_| true)
     }
 }
 
-pub mod selection {
-    use super::*;
+// each variant wraps a fused iterator: `CountrySetIter`, `Range` and
+// `CountrySetHolidayIter` stay exhausted, and so do searches over them and
+// chains of their tables
+impl std::iter::FusedIterator for IterImpl {}
 
-    /// Selection qualifier that makes the query ignore a certain axis.
-    pub struct Any;
+/// Folds records of `inner` that match `filter`.
+///
+/// Takes `f` as a trait object, as nested filters would otherwise instantiate
+/// `IterImpl::fold` with ever deeper closure types.
+fn fold_filtered<B>(
+    inner: IterImpl,
+    filter: &dyn HolidayFilter,
+    init: B,
+    f: &mut dyn FnMut(B, &'static Holiday) -> B,
+) -> B {
+    inner.fold(
+        init,
+        |acc, it| if filter.matches(it) { f(acc, it) } else { acc },
+    )
+}
 
-    pub enum CountrySelection<I>
-    where
-        I: IntoIterator,
+impl IterImpl {
+    /// Returns `true` if `DATA[index]` is a remaining record.
+    fn contains(&self, index: usize) -> bool {
+        let record = &crate::data::DATA[index];
+        match self {
+            IterImpl::Empty => false,
+            IterImpl::Exact {
+                inner,
+                date,
+                current,
+            } => current.contains(&index) || (record.date == *date && inner.contains(record.code)),
+            IterImpl::DateRange { range, countries } => {
+                range.contains(&index) && countries.contains(record.code)
+            }
+            IterImpl::NoDate(inner) => inner.contains(index),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => {
+                current.as_slice().binary_search(&index).is_ok()
+                    || (countries.contains(record.code) && dates.contains(record.date))
+            }
+            IterImpl::Filtered { inner, filter } => filter.matches(record) && inner.contains(index),
+        }
+    }
+
+    /// Returns `true` if there are no remaining records, without yielding
+    /// any where possible.
+    fn is_empty(&self) -> bool {
+        match self {
+            IterImpl::Empty => true,
+            IterImpl::Exact {
+                inner,
+                date,
+                current,
+            } => {
+                current.is_empty() && { *inner }
+                    .all(|it| crate::data::country_date_to_index(it, *date).is_none())
+            }
+            IterImpl::DateRange { range, countries } if *countries == CountrySet::all() => {
+                range.is_empty()
+            }
+            IterImpl::DateRange { range, countries } => countries
+                .iter()
+                .all(|it| crate::data::country_indices_within(it, range).is_empty()),
+            IterImpl::NoDate(inner) => inner.is_empty(),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => {
+                current.len() == 0 && { *countries }.all(|it| {
+                    dates
+                        .slice_within(crate::data::country_indices(it))
+                        .is_empty()
+                })
+            }
+            // filters can only be evaluated on records
+            IterImpl::Filtered { .. } => self.clone().next().is_none(),
+        }
+    }
+
+    /// Returns the last remaining record that satisfies `predicate`, without
+    /// walking from the front where possible.
+    fn last_where(self, predicate: &dyn Fn(&Holiday) -> bool) -> Option<&'static Holiday> {
+        match self {
+            IterImpl::Empty => None,
+            IterImpl::Exact {
+                inner,
+                date,
+                current,
+            } => inner
+                .rev()
+                .flat_map(|it| crate::data::country_date_to_indices(it, date).rev())
+                .chain(current.rev())
+                .map(|i| &crate::data::DATA[i])
+                .find(|it| predicate(it)),
+            IterImpl::DateRange { range, countries } if countries == CountrySet::all() => range
+                .rev()
+                .map(|i| &crate::data::DATA[i])
+                .find(|it| predicate(it)),
+            IterImpl::DateRange { range, countries } => range
+                .rev()
+                .map(|i| &crate::data::DATA[i])
+                .find(|it| countries.contains(it.code) && predicate(it)),
+            IterImpl::NoDate(inner) => inner.filter(|it| predicate(it)).last(),
+            IterImpl::ByCountry {
+                countries,
+                current,
+                dates,
+            } => countries
+                .rev()
+                .flat_map(|it| {
+                    dates
+                        .slice_within(crate::data::country_indices(it))
+                        .iter()
+                        .rev()
+                })
+                .chain(current.rev())
+                .map(|&i| &crate::data::DATA[i])
+                .find(|it| predicate(it)),
+            IterImpl::Filtered { inner, filter } => {
+                inner.last_where(&|it| filter.matches(it) && predicate(it))
+            }
+        }
+    }
+}
+
+/// Bit mask of months that pass the month filter; bit `n` is set for month
+/// `n`.
+const ALL_MONTHS: u16 = 0b1_1111_1111_1110;
+
+/// Iterator over holiday query results.
+///
+/// With the default [`Order::DateThenCountry`], results are ordered by date
+/// and then by country code, so the first item is always the earliest
+/// holiday and [`Iterator::last`] the latest one; this makes `min_by_key` and
+/// `max_by_key` over dates unnecessary.
+///
+/// Queries with [`Order::CountryThenDate`] instead yield all holidays of a
+/// country before moving on to the next one, so the first and the last item
+/// are only the earliest and the latest holiday of their country. Adapters
+/// that group holidays of the same date, like [`Iter::by_day`] and
+/// [`Iter::collapse_shared`], then only group adjacent results, which are
+/// mostly holidays of a single country.
+///
+/// `last` doesn't walk over the whole result for most queries, and neither do
+/// [`nth`](Iterator::nth) and [`skip`](Iterator::skip) of unfiltered queries
+/// of a date range or of all dates, e.g. to paginate results.
+///
+/// [Size hints](Iterator::size_hint) of queries without filters are exact,
+/// so collecting their results allocates only once. Filtered queries can't
+/// know their length upfront, which is why `Iter` isn't an
+/// [`ExactSizeIterator`].
+///
+/// Once exhausted, `Iter` keeps returning `None`, as promised by its
+/// [`FusedIterator`](std::iter::FusedIterator) implementation.
+///
+/// ```
+/// use holidays::Country;
+///
+/// let results = holidays::get_holidays(Country::DE, 2025..2026);
+/// let (len, _) = results.size_hint();
+/// let names: Vec<_> = results.clone().map(|it| it.name).collect();
+/// assert_eq!(names.len(), len);
+/// assert_eq!(results.last().map(|it| it.name), names.last().copied());
+/// ```
+#[derive(Clone)]
+pub struct Iter {
+    inner: IterImpl,
+    months: u16,
+}
+
+impl Iterator for Iter {
+    type Item = &'static Holiday;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.months == ALL_MONTHS {
+            return self.inner.next();
+        }
+        if self.months == 0 {
+            return None;
+        }
+        loop {
+            let next = self.inner.next()?;
+            if (self.months >> next.date.month()) & 1 == 1 {
+                return Some(next);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.months {
+            ALL_MONTHS => self.inner.size_hint(),
+            0 => (0, Some(0)),
+            _ => (0, self.inner.size_hint().1),
+        }
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // internal iteration avoids re-entering the state machine of `next`
+        // for every record
+        match self.months {
+            ALL_MONTHS => self.inner.fold(init, f),
+            0 => init,
+            months => self
+                .inner
+                .filter(|it| (months >> it.date.month()) & 1 == 1)
+                .fold(init, f),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.months {
+            // unfiltered queries skip records without visiting them
+            ALL_MONTHS => self.inner.nth(n),
+            0 => None,
+            _ => {
+                for _ in 0..n {
+                    self.next()?;
+                }
+                self.next()
+            }
+        }
+    }
+
+    fn count(self) -> usize {
+        // unfiltered queries know their length without walking the records
+        match self.size_hint() {
+            (lower, Some(upper)) if lower == upper => lower,
+            _ => self.fold(0, |count, _| count + 1),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self.months {
+            ALL_MONTHS => self.inner.last(),
+            0 => None,
+            months => self
+                .inner
+                .last_where(&|it| (months >> it.date.month()) & 1 == 1),
+        }
+    }
+}
+
+impl std::iter::FusedIterator for Iter {}
+
+impl Iter {
+    /// Restricts results to holidays observed in the given `month` of any
+    /// year.
+    ///
+    /// Combining multiple month constraints yields only holidays that satisfy
+    /// all of them, i.e. selecting different months results in an empty
+    /// iterator.
+    ///
+    /// ```
+    /// use holidays::{Country, Month};
+    ///
+    /// let december = holidays::get_holidays(Country::DE, 2020..2030).in_month(Month::DECEMBER);
+    ///
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert!(december.names().any(|name| name == "Christmas Day"));
+    /// ```
+    pub fn in_month(mut self, month: Month) -> Self {
+        self.months &= 1 << month.number();
+        self
+    }
+
+    /// Restricts results to holidays that match `filter`.
+    ///
+    /// Unlike [`Iterator::filter`], this keeps returning an `Iter`, so the
+    /// filtered results can still use its adapters and report an upper bound
+    /// of their [size](Iterator::size_hint). Filters are combined with
+    /// [`Iter::in_month`] and each other, i.e. results match all of them.
+    ///
+    /// ```
+    /// use holidays::filter::OnWeekday;
+    /// use holidays::{Country, Weekday};
+    ///
+    /// let long_weekends = holidays::get_holidays(Country::GB, 2025..2026)
+    ///     .with_filter(|it: &holidays::Holiday| it.name != "Christmas Day")
+    ///     .with_filter(OnWeekday(Weekday::Monday));
+    ///
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert!(long_weekends.names().any(|name| name == "Spring Bank Holiday"));
+    /// ```
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: HolidayFilter + Send + Sync + 'static,
+    {
+        self.inner = IterImpl::Filtered {
+            inner: Box::new(self.inner),
+            filter: std::sync::Arc::new(filter),
+        };
+        self
+    }
+
+    /// Returns `true` if there are no matched holidays.
+    ///
+    /// Unlike checking the result of [`Iterator::next`], this doesn't consume
+    /// any results, and is answered from the query plan without looking at
+    /// individual records, unless a [month](Iter::in_month) or [custom
+    /// filter](Iter::with_filter) has to be checked.
+    ///
+    /// ```
+    /// use holidays::{Country, PreparedQuery};
+    ///
+    /// let query = PreparedQuery::new(Country::US, 2025..2026);
+    /// assert!(!query.iter().is_empty());
+    /// assert!(PreparedQuery::new(Country::US, 1900..1901).iter().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        match self.months {
+            ALL_MONTHS => self.inner.is_empty(),
+            0 => true,
+            _ => self.clone().next().is_none(),
+        }
+    }
+
+    /// Returns `true` if `index` is the index of a matched record.
+    fn contains(&self, index: usize) -> bool {
+        let month = crate::data::DATA[index].date.month();
+        (self.months >> month) & 1 == 1 && self.inner.contains(index)
+    }
+
+    /// Returns `true` if any matched holiday is on `date`.
+    ///
+    /// Only records on `date` are looked up and checked against the query,
+    /// so this is much cheaper than searching the results, e.g. with
+    /// `iter.any(|it| it.date == date)`.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let holidays = holidays::get_holidays([Country::US, Country::DE], 2025..2026);
+    /// assert!(holidays.clone().any_on(Date::from_ymd(2025, 10, 3)));
+    /// assert!(!holidays.any_on(Date::from_ymd(2025, 10, 4)));
+    /// ```
+    pub fn any_on(self, date: impl Into<Date>) -> bool {
+        date_plan(date.into())
+            .into_iter()
+            .any(|it| self.contains(HolidayRef::of_static(it).index()))
+    }
+
+    /// Returns `true` if any matched holiday has `name`, compared after
+    /// [normalization](crate::normalize_name).
+    ///
+    /// Records with `name` are found like with [`with_name`] and then checked
+    /// against the query, which is cheaper than searching the results if the
+    /// crate is built with `name-index` feature. With `no-names` feature it's
+    /// always `false`.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let holidays = holidays::get_holidays(Country::DE, 2025..2026);
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert!(holidays.clone().any_named("christmas day"));
+    /// assert!(!holidays.any_named("Independence Day"));
+    /// ```
+    ///
+    /// [`with_name`]: crate::with_name
+    pub fn any_named(self, name: &str) -> bool {
+        crate::data::holidays_with_name(name)
+            .any(|it| self.contains(HolidayRef::of_static(it).index()))
+    }
+
+    /// Returns an iterator over matched holidays paired with their
+    /// [position relative to the weekend](Holiday::weekend_relation) of the
+    /// default [`Workweek`](crate::Workweek).
+    ///
+    /// ```
+    /// use holidays::{Country, WeekendRelation};
+    ///
+    /// let long_weekends = holidays::get_holidays(Country::US, 2025..2026)
+    ///     .with_weekend_relation()
+    ///     .filter(|(relation, _)| *relation == WeekendRelation::AdjacentToWeekend)
+    ///     .count();
+    /// assert_eq!(long_weekends, 6);
+    /// ```
+    pub fn with_weekend_relation(
+        self,
+    ) -> impl Iterator<Item = (crate::WeekendRelation, &'static Holiday)> {
+        let workweek = crate::Workweek::default();
+        self.map(move |it| (it.weekend_relation(workweek), it))
+    }
+
+    /// Returns an iterator over names of matched holidays.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let mut names = holidays::get_holidays(Country::US, Date::from_ymd(2025, 7, 4)).names();
+    ///
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(names.next(), Some("Independence Day"));
+    /// ```
+    #[inline]
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        self.map(|it| it.name)
+    }
+
+    /// Returns an iterator over countries of matched holidays.
+    ///
+    /// Countries are yielded once for every matched holiday; use
+    /// [`Iter::into_country_set`] to collect them without repetition.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let countries: Vec<_> = holidays::get_holidays(
+    ///     [Country::JP, Country::US],
+    ///     Date::from_ymd(2025, 1, 1),
+    /// )
+    /// .countries()
+    /// .collect();
+    ///
+    /// assert_eq!(countries, [Country::JP, Country::US]);
+    /// ```
+    #[inline]
+    pub fn countries(self) -> impl Iterator<Item = Country> {
+        self.map(|it| it.code)
+    }
+
+    /// Returns an iterator over [handles](HolidayRef) of matched holidays.
+    ///
+    /// ```
+    /// use holidays::{Country, HolidayRef};
+    ///
+    /// let handles: Vec<HolidayRef> = holidays::get_holidays(Country::US, 2025..2026).handles().collect();
+    /// assert!(handles.into_iter().map(HolidayRef::get).eq(holidays::get_holidays(Country::US, 2025..2026)));
+    /// ```
+    #[inline]
+    pub fn handles(self) -> impl Iterator<Item = HolidayRef> {
+        self.map(HolidayRef::of_static)
+    }
+
+    /// Collects matched holidays into an owned [`HolidaySnapshot`].
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+    /// assert_eq!(snapshot.len(), holidays::get_holidays(Country::US, 2025..2026).count());
+    /// ```
+    pub fn snapshot(self) -> HolidaySnapshot {
+        HolidaySnapshot::new(self.handles())
+    }
+
+    /// Collects countries of matched holidays into a [`CountrySet`].
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Any, Country};
+    ///
+    /// let set = holidays::get_holidays(Any, Date::from_ymd(2025, 12, 25)).into_country_set();
+    ///
+    /// assert!(set.contains(Country::DE));
+    /// ```
+    pub fn into_country_set(self) -> CountrySet {
+        let mut set = CountrySet::new();
+        set.extend(self.countries());
+        set
+    }
+
+    /// Groups holidays observed on the same date, e.g. to render a row per
+    /// day listing all observing countries.
+    ///
+    /// Results are ordered by date, so each date is yielded once, with its
+    /// holidays ordered by country code.
+    ///
+    /// The date of each group converts to any supported date type, like
+    /// [`Holiday::date`].
+    ///
+    /// ```
+    /// use holidays::{Country, Date};
+    ///
+    /// let days = holidays::get_holidays([Country::US, Country::GB, Country::DE], 2025..2026);
+    /// for day in days.by_day() {
+    ///     let date: Date = day.date()?;
+    ///     let countries: Vec<_> = day.holidays.iter().map(|it| it.code.to_string()).collect();
+    ///     println!("{date}: {}", countries.join(", "));
+    /// }
+    /// # Ok::<(), holidays::error::DateConversionError>(())
+    /// ```
+    pub fn by_day(self) -> ByDay {
+        ByDay {
+            inner: self,
+            lookahead: None,
+        }
+    }
+
+    /// Collapses holidays observed on the same date under the same name into a
+    /// single [`SharedHoliday`] that lists all observing countries.
+    ///
+    /// Shared holidays of a single date are yielded in order in which their
+    /// names first appear in the results. With `no-names` feature all
+    /// holidays of a single date are collapsed together.
+    ///
+    /// Only adjacent results are collapsed, so with [`Order::CountryThenDate`]
+    /// holidays of different countries are only merged when they end up next
+    /// to each other, e.g. in queries of a single date.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let mut shared = holidays::get_holidays(
+    ///     [Country::DE, Country::FR],
+    ///     Date::from_ymd(2025, 12, 25),
+    /// )
+    /// .collapse_shared();
+    /// let christmas = shared.next().unwrap();
+    ///
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(christmas.name, "Christmas Day");
+    /// assert_eq!(christmas.countries.len(), 2);
+    /// ```
+    pub fn collapse_shared(self) -> SharedIter {
+        SharedIter {
+            inner: self,
+            pending: std::collections::VecDeque::new(),
+            lookahead: None,
+        }
+    }
+
+    /// Returns an iterator over dates of matched holidays in requested format.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let dates: Vec<Date> = holidays::get_holidays(Country::FR, 2025)
+    ///     .dates()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(dates.first(), Some(&Date::from_ymd(2025, 1, 1)));
+    /// ```
+    #[inline]
+    pub fn dates<D>(self) -> impl Iterator<Item = Result<D, DateConversionError>>
+    where
+        D: TryFrom<Date>,
+    {
+        self.map(|it| it.date())
+    }
+
+    /// Returns an iterator over dates of matched holidays in requested
+    /// format, handling dates that can't be represented by it according to
+    /// `on_error`.
+    ///
+    /// [`Iter::dates`] is equivalent to [`OnConversionError::Fail`].
+    ///
+    /// ```
+    /// use std::time::SystemTime;
+    /// use holidays::{Country, OnConversionError};
+    ///
+    /// let dates: Vec<SystemTime> = holidays::get_holidays(Country::FR, 2025)
+    ///     .dates_with(OnConversionError::Skip)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(dates.len(), 1);
+    /// ```
+    pub fn dates_with<D>(
+        self,
+        on_error: OnConversionError,
+    ) -> impl Iterator<Item = Result<D, DateConversionError>>
+    where
+        D: DateBounds,
+    {
+        self.filter_map(move |it| on_error.convert(it.date))
+    }
+
+    /// Returns an iterator over matched holidays paired with their dates in
+    /// requested format.
+    ///
+    /// Unlike mapping results with [`Holiday::date`], the returned iterator
+    /// skips and counts results as cheaply as `Iter` does.
+    ///
+    /// Dates `D` can't represent are clamped to its [`DateBounds::bounds`],
+    /// like [`OnConversionError::ClampToBounds`] does; use
+    /// [`Iter::try_with_dates`] to detect them instead.
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use holidays::Country;
+    ///
+    /// # #[cfg(not(feature = "no-names"))]
+    /// let (date, holiday) = holidays::get_holidays(Country::US, 2025..2026)
+    ///     .with_dates::<SystemTime>()
+    ///     .find(|(_, it)| it.name == "Independence Day")
+    ///     .unwrap();
+    ///
+    /// # #[cfg(not(feature = "no-names"))]
+    /// let since_epoch = date.duration_since(SystemTime::UNIX_EPOCH).unwrap();
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(since_epoch, Duration::from_secs(20_273 * 86_400));
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(holiday.code, Country::US);
+    /// ```
+    pub fn with_dates<D>(self) -> WithDates<D>
+    where
+        D: DateBounds,
+    {
+        WithDates {
+            inner: self,
+            date: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over matched holidays paired with their dates in
+    /// requested format, or errors for dates that can't be represented by
+    /// it.
+    ///
+    /// This is the fallible variant of [`Iter::with_dates`].
+    ///
+    /// ```
+    /// use holidays::error::DateConversionError;
+    /// use holidays::{Country, Date};
+    ///
+    /// struct Year2025(Date);
+    ///
+    /// impl TryFrom<Date> for Year2025 {
+    ///     type Error = DateConversionError;
+    ///
+    ///     fn try_from(value: Date) -> Result<Self, Self::Error> {
+    ///         match value.year() {
+    ///             2025 => Ok(Year2025(value)),
+    ///             _ => Err(DateConversionError),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut dates = holidays::get_holidays(Country::FR, 2025..2027).try_with_dates::<Year2025>();
+    /// assert!(dates.next().is_some_and(|it| it.is_ok()));
+    /// assert!(dates.last().is_some_and(|it| it.is_err()));
+    /// ```
+    pub fn try_with_dates<D>(self) -> TryWithDates<D>
+    where
+        D: TryFrom<Date>,
+    {
+        TryWithDates {
+            inner: self,
+            date: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over dates of matched holidays formatted in ISO
+    /// 8601 `YYYY-MM-DD` format, without allocating.
+    ///
+    /// This is the cheapest way to serialize dates of results, e.g. as JSON
+    /// strings, as it skips conversions to other date types and
+    /// [`std::fmt`].
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let dates: Vec<_> = holidays::get_holidays(Country::FR, 2025..2026).iso_dates().collect();
+    /// assert_eq!(dates[0].as_str(), "2025-01-01");
+    /// assert_eq!(dates.last().map(|it| it.to_string()), Some("2025-12-25".to_string()));
+    /// ```
+    pub fn iso_dates(self) -> impl Iterator<Item = IsoDateBuf> {
+        self.map(|it| IsoDateBuf::from(it.date))
+    }
+}
+
+/// Iterator over holidays collapsed by date and name.
+///
+/// Returned by [`Iter::collapse_shared`].
+///
+/// ```
+/// use holidays::Country;
+///
+/// let shared = holidays::get_holidays([Country::US, Country::CA], 2025..2026).collapse_shared();
+/// let observed_in_both: Vec<_> =
+///     shared.filter(|it| it.countries.len() == 2).map(|it| it.name).collect();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(observed_in_both.contains(&"Christmas Day"));
+/// ```
+#[derive(Clone)]
+pub struct SharedIter {
+    inner: Iter,
+    /// Groups of the current date; buffer is reused across dates.
+    pending: std::collections::VecDeque<SharedHoliday>,
+    lookahead: Option<&'static Holiday>,
+}
+
+impl Iterator for SharedIter {
+    type Item = SharedHoliday;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(it) = self.pending.pop_front() {
+            return Some(it);
+        }
+
+        let first = self.lookahead.take().or_else(|| self.inner.next())?;
+        let mut current = first;
+        loop {
+            match self.pending.iter_mut().find(|it| it.name == current.name) {
+                Some(group) => group.countries.insert(current.code),
+                None => self.pending.push_back(SharedHoliday {
+                    date: current.date,
+                    name: current.name,
+                    countries: {
+                        let mut countries = CountrySet::new();
+                        countries.insert(current.code);
+                        countries
+                    },
+                }),
+            }
+
+            match self.inner.next() {
+                Some(next) if next.date == first.date => current = next,
+                next => {
+                    self.lookahead = next;
+                    break;
+                }
+            }
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+/// Iterator over holidays grouped by date.
+///
+/// Returned by [`Iter::by_day`].
+///
+/// ```
+/// use holidays::{Any, Country};
+///
+/// let days = holidays::get_holidays(Any, 2025..2026).by_day();
+/// let busiest = days.max_by_key(|it| it.holidays.len()).unwrap();
+/// assert!(busiest.countries().contains(Country::US));
+/// ```
+#[derive(Clone)]
+pub struct ByDay {
+    inner: Iter,
+    lookahead: Option<&'static Holiday>,
+}
+
+impl Iterator for ByDay {
+    type Item = DayHolidays;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.lookahead.take().or_else(|| self.inner.next())?;
+        let mut holidays = vec![first];
+        loop {
+            match self.inner.next() {
+                Some(next) if next.date == first.date => holidays.push(next),
+                next => {
+                    self.lookahead = next;
+                    break;
+                }
+            }
+        }
+        Some(DayHolidays {
+            date: first.date,
+            holidays,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.lookahead.is_some());
+        let (lower, upper) = self.inner.size_hint();
+        (
+            usize::from(pending + lower > 0),
+            upper.and_then(|it| it.checked_add(pending)),
+        )
+    }
+}
+
+impl std::iter::FusedIterator for ByDay {}
+
+/// Iterator over holidays paired with their dates.
+///
+/// Returned by [`Iter::with_dates`].
+///
+/// ```
+/// use std::time::SystemTime;
+/// use holidays::Country;
+///
+/// let mut dates = holidays::get_holidays(Country::DE, 2025..2026).with_dates::<SystemTime>();
+/// let (first, _) = dates.next().unwrap();
+/// let (last, _) = dates.last().unwrap();
+/// assert!(first < last);
+/// ```
+pub struct WithDates<D> {
+    inner: Iter,
+    // doesn't own a `D`, so it's `Send` and `Sync` regardless of it
+    date: std::marker::PhantomData<fn() -> D>,
+}
+
+impl<D> WithDates<D>
+where
+    D: DateBounds,
+{
+    fn pair(holiday: &'static Holiday) -> (D, &'static Holiday) {
+        let (min, max) = D::bounds();
+        let date = D::try_from(holiday.date.clamp(min, max))
+            .ok()
+            .expect("dates within DateBounds::bounds are convertible");
+        (date, holiday)
+    }
+}
+
+impl<D> Clone for WithDates<D> {
+    fn clone(&self) -> Self {
+        WithDates {
+            inner: self.inner.clone(),
+            date: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<D> Iterator for WithDates<D>
+where
+    D: DateBounds,
+{
+    type Item = (D, &'static Holiday);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Self::pair)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.fold(init, |acc, it| f(acc, Self::pair(it)))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(Self::pair)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(Self::pair)
+    }
+}
+
+impl<D> std::iter::FusedIterator for WithDates<D> where D: DateBounds {}
+
+/// Iterator over holidays paired with their dates, or errors for dates that
+/// can't be converted.
+///
+/// Returned by [`Iter::try_with_dates`].
+///
+/// ```
+/// use std::time::SystemTime;
+/// use holidays::Country;
+///
+/// let dates = holidays::get_holidays(Country::DE, 2025..2026).try_with_dates::<SystemTime>();
+/// let dates: Vec<_> = dates.map(|it| it.map(|(date, _)| date)).collect::<Result<_, _>>().unwrap();
+/// assert!(dates.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+pub struct TryWithDates<D> {
+    inner: Iter,
+    // doesn't own a `D`, so it's `Send` and `Sync` regardless of it
+    date: std::marker::PhantomData<fn() -> D>,
+}
+
+impl<D> TryWithDates<D>
+where
+    D: TryFrom<Date>,
+{
+    fn pair(holiday: &'static Holiday) -> Result<(D, &'static Holiday), DateConversionError> {
+        Ok((holiday.date()?, holiday))
+    }
+}
+
+impl<D> Clone for TryWithDates<D> {
+    fn clone(&self) -> Self {
+        TryWithDates {
+            inner: self.inner.clone(),
+            date: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<D> Iterator for TryWithDates<D>
+where
+    D: TryFrom<Date>,
+{
+    type Item = Result<(D, &'static Holiday), DateConversionError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Self::pair)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.fold(init, |acc, it| f(acc, Self::pair(it)))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(Self::pair)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(Self::pair)
+    }
+}
+
+impl<D> std::iter::FusedIterator for TryWithDates<D> where D: TryFrom<Date> {}
+
+#[derive(Clone)]
+enum BoundsResultImpl<I>
+where
+    I: Iterator,
+    I::Item: Into<Country>,
+{
+    Empty,
+    One(Country),
+    All(crate::country::CountrySetIter),
+    Many(I),
+}
+/// Iterator over selected countries paired with indices of their records in
+/// `DATA` that match the date query.
+#[derive(Clone)]
+pub(crate) struct CountrySlices<I>
+where
+    I: Iterator,
+    I::Item: Into<Country>,
+{
+    countries: BoundsResultImpl<I>,
+    dates: DateQuery,
+}
+
+impl<I> Iterator for CountrySlices<I>
+where
+    I: Iterator,
+    I::Item: Into<Country>,
+{
+    type Item = (Country, &'static [usize]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match &mut self.countries {
+            BoundsResultImpl::Empty => return None,
+            BoundsResultImpl::One(country) => {
+                let value = *country;
+                self.countries = BoundsResultImpl::Empty;
+                value
+            }
+            BoundsResultImpl::All(inner) => inner.next()?,
+            BoundsResultImpl::Many(inner) => inner.next().map(|it| it.into())?,
+        };
+
+        let indices = self.dates.slice_within(crate::data::country_indices(next));
+        Some((next, indices))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct BoundsResult<I>(CountrySlices<I>)
+where
+    I: Iterator,
+    I::Item: Into<Country>;
+
+impl<I> Iterator for BoundsResult<I>
+where
+    I: Iterator,
+    I::Item: Into<Country>,
+{
+    type Item = (Country, Option<(&'static Holiday, &'static Holiday)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (next, indices) = self.0.next()?;
+        Some((next, slice_bounds(indices)))
+    }
+}
+
+/// Returns the first and last record of sorted `DATA` `indices`.
+///
+/// A country without records, or without records in the window, has no
+/// bounds; indices are checked against `DATA` by `check_country_jump_table`.
+fn slice_bounds(indices: &[usize]) -> Option<(&'static Holiday, &'static Holiday)> {
+    match (indices.first(), indices.last()) {
+        (Some(min), Some(max)) => Some((&crate::data::DATA[*min], &crate::data::DATA[*max])),
+        _ => None,
+    }
+}
+
+pub mod selection {
+    //! Types describing query selections.
+
+    use super::*;
+    use crate::country::CountryParseError;
+    use std::collections::{btree_set, hash_set, vec_deque};
+
+    /// Selection qualifier that makes the query ignore a certain axis.
+    ///
+    /// ```
+    /// use holidays::{Any, Country};
+    ///
+    /// // every holiday of any country in 2025
+    /// let all_countries = holidays::get_holidays(Any, 2025..2026).count();
+    /// let us = holidays::get_holidays(Country::US, 2025..2026).count();
+    /// assert!(all_countries > us);
+    /// ```
+    pub struct Any;
+
+    /// Countries selected by a query.
+    ///
+    /// Query functions accept any value that can be converted into a
+    /// selection, so this type rarely needs to be named directly.
+    ///
+    /// Collections are consumed by each query, so reused ones are best
+    /// passed by reference, e.g. `&[Country]` or `&Vec<Country>`, or
+    /// converted into a [`CountrySet`] once with [`to_set`](Self::to_set).
+    /// A reference to a selection can be passed to queries as well.
+    ///
+    /// # Optional selections
+    ///
+    /// Countries and [collections](CountryCollection) of them wrapped in an
+    /// `Option`, e.g. an optional field of a parsed configuration, select all
+    /// countries if they're `None`, at any level of nesting. Only an empty
+    /// collection selects no countries:
+    ///
+    /// | Selection                                    | `None` | `Some(None)` | `Some(empty)` | `Some(countries)` |
+    /// |----------------------------------------------|--------|--------------|---------------|-------------------|
+    /// | `Option<Country>`                            | all    | -            | -             | listed            |
+    /// | `Option<Vec<Country>>`, `Option<&[Country]>` | all    | -            | none          | listed            |
+    /// | `Option<Option<Country>>`                    | all    | all          | -             | listed            |
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let all = holidays::get_holidays(holidays::Any, 2025..2026).count();
+    /// let configured: Option<Vec<Country>> = None;
+    /// assert_eq!(holidays::get_holidays(configured, 2025..2026).count(), all);
+    /// assert_eq!(holidays::get_holidays(None::<Country>, 2025..2026).count(), all);
+    /// assert_eq!(holidays::get_holidays(Some(Vec::<Country>::new()), 2025..2026).count(), 0);
+    /// ```
+    #[derive(Clone, Copy)]
+    pub enum CountrySelection<I>
+    where
+        I: IntoIterator,
         I::Item: Into<Country>,
     {
+        /// All countries.
         All,
+        /// A single country.
         One(Country),
+        /// Any number of countries.
         Many(I),
     }
 
+    impl CountrySelection<std::iter::Empty<Country>> {
+        /// Parses an optional country code, as commonly received from user
+        /// input; `None` selects all countries.
+        ///
+        /// Unlike `Option<Country>`, invalid codes (including empty ones)
+        /// are reported as errors instead of being ignored.
+        ///
+        /// ```
+        /// use holidays::selection::CountrySelection;
+        ///
+        /// assert!(CountrySelection::try_from_code(Some("JP")).is_ok());
+        /// assert!(CountrySelection::try_from_code(None).is_ok());
+        /// assert!(CountrySelection::try_from_code(Some("")).is_err());
+        /// ```
+        pub fn try_from_code(code: Option<&str>) -> Result<Self, CountryParseError> {
+            match code {
+                None => Ok(CountrySelection::All),
+                Some(code) => code.parse().map(CountrySelection::One),
+            }
+        }
+
+        /// Selects an optional country; `None` selects all countries.
+        ///
+        /// This is the conversion of `Option<Country>` passed to queries, in
+        /// a `const` context.
+        ///
+        /// ```
+        /// # use holidays::Date;
+        /// use holidays::selection::CountrySelection;
+        /// use holidays::{Any, Country};
+        ///
+        /// let christmas = Date::from_ymd(2025, 12, 25);
+        /// let configured: Option<Country> = None;
+        ///
+        /// let all = holidays::get_holidays(CountrySelection::from_option(configured), christmas).count();
+        /// assert_eq!(all, holidays::get_holidays(Any, christmas).count());
+        /// assert_eq!(holidays::get_holidays(configured, christmas).count(), all);
+        /// ```
+        pub const fn from_option(country: Option<Country>) -> Self {
+            match country {
+                None => CountrySelection::All,
+                Some(country) => CountrySelection::One(country),
+            }
+        }
+    }
+
     impl<I> CountrySelection<I>
     where
         I: IntoIterator,
         I::Item: Into<Country>,
     {
+        #[inline]
         pub(crate) fn into_query(self) -> Query {
             match self {
                 CountrySelection::All => Query::EMPTY,
@@ -484,12 +2093,94 @@ pub mod selection {
             }
         }
 
+        /// Returns the set of selected countries.
+        ///
+        /// The set is `Copy` and cheap to query with, so selections used by
+        /// many queries should be converted only once:
+        ///
+        /// ```
+        /// # use holidays::Date;
+        /// use holidays::selection::CountrySelection;
+        /// use holidays::Country;
+        ///
+        /// let configured = vec![Country::US, Country::GB];
+        /// let countries = CountrySelection::from(&configured).to_set();
+        ///
+        /// let busy = (1..=31)
+        ///     .map(|day| Date::from_ymd(2025, 12, day))
+        ///     .filter(|date| holidays::is_holiday(countries, *date))
+        ///     .count();
+        /// assert_eq!(busy, 2);
+        /// ```
+        pub fn to_set(&self) -> CountrySet
+        where
+            I: Clone,
+        {
+            self.clone().into_set()
+        }
+
+        #[inline]
+        pub(crate) fn into_set(self) -> CountrySet {
+            match self {
+                CountrySelection::All => CountrySet::all(),
+                CountrySelection::One(one) => CountrySet::from_iter([one]),
+                CountrySelection::Many(many) => many.into_iter().map(Into::into).collect(),
+            }
+        }
+
+        /// Returns the last selected record before `date` and the first one
+        /// on or after it (or after it, if the pivot is excluded).
+        #[inline]
+        pub(crate) fn neighbors(
+            self,
+            date: Date,
+            pivot: Pivot,
+        ) -> (Option<&'static Holiday>, Option<&'static Holiday>) {
+            super::neighbors_in(self.into_set(), date, pivot)
+        }
+
         pub(crate) fn bounds(self) -> BoundsResult<I::IntoIter> {
-            BoundsResult(match self {
-                CountrySelection::All => BoundsResultImpl::Empty,
-                CountrySelection::One(country) => BoundsResultImpl::One(country),
-                CountrySelection::Many(countries) => BoundsResultImpl::Many(countries.into_iter()),
-            })
+            // dataset-wide bounds are only reported for explicitly selected
+            // countries
+            if let CountrySelection::All = self {
+                return BoundsResult(CountrySlices {
+                    countries: BoundsResultImpl::Empty,
+                    dates: DateQuery::Any,
+                });
+            }
+            BoundsResult(self.slices(DateQuery::Any))
+        }
+
+        pub(crate) fn bounds_in<D, R>(self, dates: DateSelection<D, R>) -> BoundsResult<I::IntoIter>
+        where
+            D: Into<Date> + Clone,
+            R: std::ops::RangeBounds<D>,
+        {
+            BoundsResult(self.slices(dates.into_query().date_filter))
+        }
+
+        pub(crate) fn slices_in<D, R>(
+            self,
+            dates: DateSelection<D, R>,
+        ) -> CountrySlices<I::IntoIter>
+        where
+            D: Into<Date> + Clone,
+            R: std::ops::RangeBounds<D>,
+        {
+            self.slices(dates.into_query().date_filter)
+        }
+
+        fn slices(self, dates: DateQuery) -> CountrySlices<I::IntoIter> {
+            CountrySlices {
+                countries: match self {
+                    CountrySelection::All => BoundsResultImpl::All(CountrySet::all().iter()),
+                    CountrySelection::One(country) => BoundsResultImpl::One(country),
+                    CountrySelection::Many(countries) => {
+                        BoundsResultImpl::Many(countries.into_iter())
+                    }
+                },
+                dates,
+            }
         }
     }
 
@@ -505,10 +2196,72 @@ pub mod selection {
         }
     }
 
-    // `Option` is an iterator as well
+    /// Collections of countries that convert into a [`CountrySelection`].
+    ///
+    /// Implemented for standard containers of countries, references to them,
+    /// their iterators and common iterator adapters, such as `map` and
+    /// `filter`.
+    ///
+    /// # Breaking change
+    ///
+    /// Selections used to convert from any [`IntoIterator`] of countries.
+    /// `Option` is an iterator as well, but isn't a collection, so an
+    /// `Option<Country>` now converts like any other optional selection, with
+    /// `None` selecting all countries instead of none. Other iterators of
+    /// countries can be selected with [`CountrySelection::Many`], or
+    /// collected into a [`CountrySet`] first.
+    ///
+    /// ```
+    /// use holidays::selection::CountrySelection;
+    /// use holidays::Country;
+    ///
+    /// let codes = ["US", "XX", "GB"];
+    /// let countries = codes.iter().filter_map(|it| it.parse::<Country>().ok());
+    /// let holidays = holidays::get_holidays(countries, 2025..2026);
+    /// assert!(holidays.countries().all(|it| it == Country::US || it == Country::GB));
+    ///
+    /// // `flat_map` isn't a collection
+    /// let countries = codes.iter().flat_map(|it| it.parse::<Country>());
+    /// let holidays = holidays::get_holidays(CountrySelection::Many(countries), 2025..2026);
+    /// assert!(holidays.countries().all(|it| it == Country::US || it == Country::GB));
+    /// ```
+    pub trait CountryCollection: IntoIterator {}
+
+    impl<C: Into<Country>, const N: usize> CountryCollection for [C; N] {}
+    impl<'a, C, const N: usize> CountryCollection for &'a [C; N] where &'a C: Into<Country> {}
+    impl<'a, C> CountryCollection for &'a [C] where &'a C: Into<Country> {}
+    impl<C: Into<Country>> CountryCollection for Vec<C> {}
+    impl<'a, C> CountryCollection for &'a Vec<C> where &'a C: Into<Country> {}
+    impl<C: Into<Country>> CountryCollection for std::collections::VecDeque<C> {}
+    impl<'a, C> CountryCollection for &'a std::collections::VecDeque<C> where &'a C: Into<Country> {}
+    impl<C: Into<Country>, S> CountryCollection for std::collections::HashSet<C, S> {}
+    impl<'a, C, S> CountryCollection for &'a std::collections::HashSet<C, S> where &'a C: Into<Country> {}
+    impl<C: Into<Country>> CountryCollection for std::collections::BTreeSet<C> {}
+    impl<'a, C> CountryCollection for &'a std::collections::BTreeSet<C> where &'a C: Into<Country> {}
+    impl CountryCollection for CountrySet {}
+    impl CountryCollection for &CountrySet {}
+    impl CountryCollection for crate::CountrySetIter {}
+    impl<C: Into<Country>> CountryCollection for std::iter::Empty<C> {}
+    impl<C: Into<Country>> CountryCollection for std::iter::Once<C> {}
+    impl<'a, C> CountryCollection for std::slice::Iter<'a, C> where &'a C: Into<Country> {}
+    impl<C: Into<Country>> CountryCollection for std::vec::IntoIter<C> {}
+    impl<C: Into<Country>, const N: usize> CountryCollection for std::array::IntoIter<C, N> {}
+    impl<'a, C> CountryCollection for vec_deque::Iter<'a, C> where &'a C: Into<Country> {}
+    impl<'a, C> CountryCollection for hash_set::Iter<'a, C> where &'a C: Into<Country> {}
+    impl<'a, C> CountryCollection for btree_set::Iter<'a, C> where &'a C: Into<Country> {}
+    impl<I, F> CountryCollection for std::iter::Map<I, F> where Self: Iterator {}
+    impl<I, P> CountryCollection for std::iter::Filter<I, P> where Self: Iterator {}
+    impl<I, F> CountryCollection for std::iter::FilterMap<I, F> where Self: Iterator {}
+    impl<I> CountryCollection for std::iter::Copied<I> where Self: Iterator {}
+    impl<I> CountryCollection for std::iter::Cloned<I> where Self: Iterator {}
+    impl<A, B> CountryCollection for std::iter::Chain<A, B> where Self: Iterator {}
+    impl<I> CountryCollection for std::iter::Take<I> where Self: Iterator {}
+    impl<I> CountryCollection for std::iter::Skip<I> where Self: Iterator {}
+    impl<I> CountryCollection for std::iter::Rev<I> where Self: Iterator {}
+
     impl<I> From<I> for CountrySelection<I>
     where
-        I: IntoIterator,
+        I: CountryCollection,
         I::Item: Into<Country>,
     {
         fn from(value: I) -> Self {
@@ -516,13 +2269,74 @@ pub mod selection {
         }
     }
 
+    impl<I> From<Option<I>> for CountrySelection<I>
+    where
+        I: CountryCollection,
+        I::Item: Into<Country>,
+    {
+        fn from(value: Option<I>) -> Self {
+            match value {
+                None => CountrySelection::All,
+                Some(it) => CountrySelection::Many(it),
+            }
+        }
+    }
+
+    // `Option` isn't a `CountryCollection`, so this doesn't overlap with the
+    // above
+    impl From<Option<Country>> for CountrySelection<std::iter::Empty<Country>> {
+        fn from(value: Option<Country>) -> Self {
+            CountrySelection::from_option(value)
+        }
+    }
+
+    impl From<Option<Option<Country>>> for CountrySelection<std::iter::Empty<Country>> {
+        fn from(value: Option<Option<Country>>) -> Self {
+            CountrySelection::from_option(value.flatten())
+        }
+    }
+
+    impl<I> From<&CountrySelection<I>> for CountrySelection<CountrySet>
+    where
+        I: IntoIterator + Clone,
+        I::Item: Into<Country>,
+    {
+        fn from(value: &CountrySelection<I>) -> Self {
+            match value {
+                CountrySelection::All => CountrySelection::All,
+                CountrySelection::One(one) => CountrySelection::One(*one),
+                many => CountrySelection::Many(many.to_set()),
+            }
+        }
+    }
+
+    /// Dates selected by a query.
+    ///
+    /// Query functions accept any value that can be converted into a
+    /// selection, so this type rarely needs to be named directly.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    /// use holidays::selection::DateSelection;
+    ///
+    /// fn count(date: impl Into<DateSelection<Date, std::ops::Range<Date>>>) -> usize {
+    ///     holidays::get_holidays(Country::US, date).count()
+    /// }
+    ///
+    /// assert_eq!(count(Date::from_ymd(2025, 12, 25)), 1);
+    /// assert!(count(Date::from_ymd(2025, 1, 1)..Date::from_ymd(2026, 1, 1)) > 1);
+    /// ```
     pub enum DateSelection<D, R>
     where
         D: Into<Date>,
         R: std::ops::RangeBounds<D>,
     {
+        /// All dates.
         None,
+        /// A single date.
         One(D),
+        /// A range of dates.
         Range(R),
     }
 
@@ -531,11 +2345,36 @@ pub mod selection {
         D: Into<Date> + Clone,
         R: std::ops::RangeBounds<D>,
     {
+        /// Lowers the selection into a query without generic parameters, so
+        /// only this conversion is instantiated for each input type.
+        #[inline]
         pub(crate) fn into_query(self) -> Query {
+            let resolve = |bound: Bound<&D>| bound.map(|it| it.clone().into());
+            match self {
+                DateSelection::None => Query::NO_DATE,
+                DateSelection::One(one) => Query::date(one.into()),
+                DateSelection::Range(range) => {
+                    Query::date_bounds(resolve(range.start_bound()), resolve(range.end_bound()))
+                }
+            }
+        }
+
+        /// Returns the inclusive start and the exclusive end of selected
+        /// dates, where `None` leaves the side unbounded.
+        ///
+        /// Unlike bounds of queries, bounds of empty ranges are kept as they
+        /// were selected.
+        pub(crate) fn into_bounds(self) -> (Option<Date>, Option<Date>) {
+            let resolve = |bound: Bound<&D>| bound.map(|it| it.clone().into());
             match self {
-                DateSelection::None => Query::EMPTY,
-                DateSelection::One(one) => Query::date(one),
-                DateSelection::Range(range) => Query::date_range(range),
+                DateSelection::None => (None, None),
+                DateSelection::One(one) => {
+                    let date = one.into();
+                    (Some(date), Some(date.saturating_add_days(1)))
+                }
+                DateSelection::Range(range) => {
+                    half_open(resolve(range.start_bound()), resolve(range.end_bound()))
+                }
             }
         }
     }
@@ -580,3 +2419,1333 @@ pub mod selection {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Any;
+    use std::collections::HashSet;
+
+    #[test]
+    fn new_year_country_set() {
+        let set = crate::get_holidays(Any, Date::from_ymd(2025, 1, 1)).into_country_set();
+
+        assert!(set.contains(Country::US));
+        assert!(set.contains(Country::JP));
+    }
+
+    #[test]
+    fn month_filter_matches_month_ranges() {
+        let december: Vec<_> = crate::get_holidays::<_, Date, _>(Country::GB, Any)
+            .in_month(Month::DECEMBER)
+            .collect();
+
+        assert!(!december.is_empty());
+        assert!(december.iter().all(|it| it.date.month() == 12));
+
+        let expected: Vec<_> = (crate::data::DATA_MIN_YEAR..=crate::data::DATA_MAX_YEAR)
+            .flat_map(|year| {
+                crate::get_holidays(
+                    Country::GB,
+                    Date::from_ymd(year, 12, 1)..Date::from_ymd(year + 1, 1, 1),
+                )
+            })
+            .collect();
+
+        assert_eq!(december, expected);
+    }
+
+    #[test]
+    fn borrowed_selection_type_interface() {
+        // This test pins down type interface requirements of reusable
+        // country selections. It's failing if it doesn't compile.
+
+        fn reuse_iter<C>(countries: C, dates: &[Date]) -> usize
+        where
+            C: selection::CountryCollection + Copy,
+            C::Item: Into<Country>,
+        {
+            dates
+                .iter()
+                .map(|it| crate::get_holidays(countries, *it).count())
+                .sum()
+        }
+        fn reuse_vec(countries: &Vec<Country>, dates: &[Date]) -> usize {
+            dates
+                .iter()
+                .map(|it| crate::get_holidays(countries, *it).count())
+                .sum()
+        }
+        fn reuse_slice(countries: &[Country], dates: &[Date]) -> usize {
+            dates
+                .iter()
+                .map(|it| crate::get_holidays(countries, *it).count())
+                .sum()
+        }
+        fn reuse_selection<I>(countries: &CountrySelection<I>, dates: &[Date]) -> usize
+        where
+            I: IntoIterator + Clone,
+            I::Item: Into<Country>,
+        {
+            dates
+                .iter()
+                .map(|it| crate::get_holidays(countries, *it).count())
+                .sum()
+        }
+
+        let configured = vec![Country::US, Country::GB];
+        let dates = [Date::from_ymd(2025, 12, 25), Date::from_ymd(2025, 12, 26)];
+        let expected = 3;
+
+        assert_eq!(reuse_iter(&configured, &dates), expected);
+        assert_eq!(reuse_iter(configured.as_slice(), &dates), expected);
+        assert_eq!(reuse_iter(&configured[..], &dates), expected);
+        assert_eq!(reuse_vec(&configured, &dates), expected);
+        assert_eq!(reuse_slice(&configured, &dates), expected);
+
+        let selection = CountrySelection::from(configured.clone());
+        let set = selection.to_set();
+        assert_eq!(set, CountrySet::from_iter(configured.iter()));
+        assert_eq!(reuse_iter(set, &dates), expected);
+        assert_eq!(reuse_selection(&selection, &dates), expected);
+        assert_eq!(
+            reuse_selection(&CountrySelection::from(Any), &dates),
+            reuse_iter(CountrySet::all(), &dates)
+        );
+        assert_eq!(
+            reuse_selection(&CountrySelection::from(Country::GB), &dates),
+            2
+        );
+
+        let _ = crate::is_holiday(&configured, 2025..2026);
+        let _ = crate::is_holiday(&selection, 2025..2026);
+        let _ = crate::get_bounding_dates(&configured);
+        let _ = crate::get_bounding_dates(&selection);
+        let _ = crate::bounding_in(&configured[..], 2025..2026);
+        let _ = crate::nearest_holiday(&configured, dates[0], 3);
+        let _ = crate::neighbors(&selection, dates[0]);
+        let _ = crate::within(&configured, dates[0], 3);
+    }
+
+    #[test]
+    fn optional_selection_type_interface() {
+        // This test pins down optional country selections, e.g. from parsed
+        // configuration. It's failing if it doesn't compile.
+
+        let vec: Option<Vec<Country>> = Some(vec![Country::US]);
+        let slice: Option<&[Country]> = Some(&[Country::US]);
+        let nested: Option<Option<Country>> = Some(Some(Country::US));
+        let set: Option<CountrySet> = Some(CountrySet::all());
+        let opt: Option<Country> = Some(Country::US);
+
+        let _ = crate::get_holidays(vec.clone(), 2025..2026);
+        let _ = crate::get_holidays(slice, 2025..2026);
+        let _ = crate::get_holidays(nested, 2025..2026);
+        let _ = crate::get_holidays(set, 2025..2026);
+        let _ = crate::get_holidays(opt, 2025..2026);
+        let _ = crate::is_holiday(vec.as_deref(), Date::from_ymd(2025, 7, 4));
+        let _ = PreparedQuery::new(nested, Date::from_ymd(2025, 7, 4));
+        let _ = crate::get_bounding_dates(slice);
+        let rule = crate::ClosureRule::AnyClosed;
+        let _ = crate::is_business_day(
+            vec,
+            rule,
+            crate::Workweek::MON_FRI,
+            Date::from_ymd(2025, 7, 4),
+        );
+    }
+
+    #[test]
+    fn size_hints_match_counts() {
+        let us = CountrySet::from_iter([Country::US]);
+        let few = CountrySet::from_iter([Country::US, Country::GB, Country::JP]);
+        let christmas = Date::from_ymd(2025, 12, 25);
+        let range = Date::from_ymd(2024, 3, 1)..Date::from_ymd(2026, 9, 1);
+        let scan = QueryOptions::DEFAULT.with_strategy(Strategy::ForceRangeScan);
+        let per_country = QueryOptions::DEFAULT.with_strategy(Strategy::ForcePerCountry);
+
+        let queries = [
+            PreparedQuery::new(Any, christmas),
+            PreparedQuery::new(few, christmas),
+            PreparedQuery::new(CountrySet::new(), christmas),
+            PreparedQuery::new(Any, range.clone()),
+            PreparedQuery::new(us, range.clone()),
+            PreparedQuery::new(few, range.clone()).with_options(scan),
+            PreparedQuery::new(few, range.clone()).with_options(per_country),
+            PreparedQuery::new::<_, Date, _>(Any, Any),
+            PreparedQuery::new::<_, Date, _>(us, Any),
+            PreparedQuery::new::<_, Date, _>(few, Any),
+        ];
+        for query in queries {
+            // hints of unfiltered queries are exact, also after some records
+            // were yielded
+            let mut iter = query.iter();
+            for _ in 0..3 {
+                let count = iter.clone().fold(0, |count, _| count + 1);
+                assert_eq!(iter.size_hint(), (count, Some(count)));
+                iter.next();
+            }
+
+            let filtered = query.iter().with_filter(|it: &Holiday| it.date.day() < 10);
+            let (lower, upper) = filtered.size_hint();
+            let count = filtered.clone().fold(0, |count, _| count + 1);
+            assert!(lower <= count && upper.is_some_and(|it| it >= count));
+            let in_month = query.iter().in_month(crate::Month::DECEMBER);
+            let count = in_month.clone().fold(0, |count, _| count + 1);
+            assert!(in_month.size_hint().1.is_some_and(|it| it >= count));
+        }
+    }
+
+    #[test]
+    fn exhausted_iterators_stay_exhausted() {
+        fn assert_fused<T: std::iter::FusedIterator>(_: &T) {}
+
+        let few = CountrySet::from_iter([Country::US, Country::GB, Country::JP]);
+        let christmas = Date::from_ymd(2025, 12, 25);
+        let range = Date::from_ymd(2025, 3, 1)..Date::from_ymd(2025, 9, 1);
+        for strategy in [
+            Strategy::Auto,
+            Strategy::ForceRangeScan,
+            Strategy::ForcePerCountry,
+        ] {
+            let options = QueryOptions::DEFAULT.with_strategy(strategy);
+            let queries = [
+                PreparedQuery::new(few, christmas),
+                PreparedQuery::new(Any, christmas),
+                PreparedQuery::new(CountrySet::new(), christmas),
+                PreparedQuery::new(few, range.clone()),
+                PreparedQuery::new(Any, range.clone()),
+                PreparedQuery::new(few, Date::from_ymd(2025, 3, 1)..Date::from_ymd(2025, 3, 1)),
+                PreparedQuery::new::<_, Date, _>(Country::US, Any),
+                PreparedQuery::new::<_, Date, _>(few, Any),
+                PreparedQuery::new::<_, Date, _>(Any, Any),
+            ];
+            for query in queries {
+                let query = query.with_options(options);
+                let iters = [
+                    query.iter(),
+                    query.iter().with_filter(|it: &Holiday| it.date.day() < 10),
+                    query.iter().in_month(crate::Month::DECEMBER),
+                ];
+                for mut iter in iters {
+                    assert_fused(&iter);
+                    iter.by_ref().for_each(drop);
+                    for _ in 0..3 {
+                        assert!(
+                            iter.next().is_none(),
+                            "{strategy:?} query yielded past the end"
+                        );
+                    }
+                    assert_eq!(iter.size_hint().1, Some(0));
+                }
+            }
+        }
+
+        // the end is also reached by `next` alone, without `fold`
+        let mut iter = PreparedQuery::new::<_, Date, _>(few, Any).iter();
+        while iter.next().is_some() {}
+        assert!((0..3).all(|_| iter.next().is_none()));
+    }
+
+    #[test]
+    fn nth_matches_naive() {
+        let few = CountrySet::from_iter([Country::US, Country::GB, Country::JP]);
+        let most: CountrySet = CountrySet::all().iter().skip(3).collect();
+        let range = Date::from_ymd(2010, 3, 1)..Date::from_ymd(2026, 9, 1);
+        let mut queries = Vec::new();
+        for strategy in [
+            Strategy::Auto,
+            Strategy::ForceRangeScan,
+            Strategy::ForcePerCountry,
+        ] {
+            let options = QueryOptions::DEFAULT.with_strategy(strategy);
+            for countries in [
+                CountrySet::all(),
+                most,
+                few,
+                CountrySet::from_iter([Country::DE]),
+            ] {
+                queries.push(PreparedQuery::new(countries, range.clone()).with_options(options));
+                let undated = PreparedQuery::new::<_, Date, _>(countries, Any);
+                queries.push(undated.with_options(options));
+            }
+            let christmas = PreparedQuery::new(few, Date::from_ymd(2025, 12, 25));
+            queries.push(christmas.with_options(options));
+        }
+
+        for query in queries {
+            let iters = [
+                query.iter(),
+                query.iter().with_filter(|it: &Holiday| it.date.day() < 10),
+                query.iter().in_month(crate::Month::DECEMBER),
+            ];
+            for iter in iters {
+                let all: Vec<_> = iter.clone().collect();
+                let len = all.len();
+                assert_eq!(iter.clone().last(), all.last().copied());
+                let offsets = [
+                    0,
+                    1,
+                    5,
+                    63,
+                    64,
+                    191,
+                    192,
+                    1000,
+                    len / 2,
+                    len.saturating_sub(1),
+                ];
+                for n in offsets.into_iter().chain([len, len + 5]) {
+                    let mut skipped = iter.clone();
+                    assert_eq!(skipped.nth(n), all.get(n).copied(), "nth({n}) of {len}");
+                    // skipping leaves the iterator right after the record
+                    let rest: Vec<_> = skipped.collect();
+                    assert_eq!(rest[..], all[(n + 1).min(len)..]);
+                    assert!(iter.clone().skip(n).eq(all[n.min(len)..].iter().copied()));
+                }
+                assert!(iter.clone().step_by(97).eq(all.iter().step_by(97).copied()));
+            }
+        }
+    }
+
+    #[test]
+    fn optional_selections() {
+        let count = |selection: CountrySelection<Vec<Country>>| {
+            crate::get_holidays(selection, 2025..2026).count()
+        };
+        let all = crate::get_holidays(Any, 2025..2026).count();
+        let us = crate::get_holidays(Country::US, 2025..2026).count();
+        assert!(0 < us && us < all);
+
+        assert_eq!(count(None.into()), all);
+        assert_eq!(count(Some(vec![]).into()), 0);
+        assert_eq!(count(Some(vec![Country::US]).into()), us);
+
+        let slice = |it: Option<&[Country]>| crate::get_holidays(it, 2025..2026).count();
+        assert_eq!(slice(None), all);
+        assert_eq!(slice(Some(&[])), 0);
+        assert_eq!(slice(Some(&[Country::US])), us);
+
+        // `None` selects all countries at any level of nesting
+        let nested = |it: Option<Option<Country>>| crate::get_holidays(it, 2025..2026).count();
+        assert_eq!(nested(None), all);
+        assert_eq!(nested(Some(None)), all);
+        assert_eq!(nested(Some(Some(Country::US))), us);
+
+        let opt = |it: Option<Country>| crate::get_holidays(it, 2025..2026).count();
+        assert_eq!(opt(None), all);
+        assert_eq!(opt(Some(Country::US)), us);
+        let christmas = Date::from_ymd(2025, 12, 25);
+        assert_eq!(
+            crate::get_holidays(None::<Country>, christmas).count(),
+            crate::get_holidays(Any, christmas).count()
+        );
+        assert!(crate::is_holiday(None::<Country>, christmas));
+
+        let from_option = |it: Option<Country>| {
+            crate::get_holidays(CountrySelection::from_option(it), 2025..2026).count()
+        };
+        assert_eq!(from_option(None), all);
+        assert_eq!(from_option(Some(Country::US)), us);
+    }
+
+    fn plan_of(query: PreparedQuery) -> Plan {
+        plan(&query.0, query.1)
+    }
+
+    #[test]
+    fn golden_plans() {
+        use crate::data::{clamp_to_index, DATA};
+
+        let date = Date::from_ymd(2025, 1, 1);
+        let year = clamp_to_index(date)..clamp_to_index(Date::from_ymd(2026, 1, 1));
+        let us = CountrySet::from_iter([Country::US]);
+        let many = CountrySet::from_iter([Country::US, Country::GB, Country::JP]);
+        let scan = QueryOptions::DEFAULT.with_strategy(Strategy::ForceRangeScan);
+        let per_country = QueryOptions::DEFAULT.with_strategy(Strategy::ForcePerCountry);
+
+        // exact date
+        assert_eq!(
+            plan_of(PreparedQuery::new(Country::US, date)),
+            Plan::Lookup {
+                countries: us,
+                date
+            }
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(Any, date)),
+            Plan::Lookup {
+                countries: CountrySet::all(),
+                date
+            }
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(Country::US, date).with_options(scan)),
+            Plan::Scan {
+                range: clamp_to_index(date)..clamp_to_index(Date::from_ymd(2025, 1, 2)),
+                countries: us
+            }
+        );
+
+        // date range
+        assert_eq!(
+            plan_of(PreparedQuery::new(Country::US, 2025..2026)),
+            Plan::Scan {
+                range: year.clone(),
+                countries: us
+            }
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(many, 2025..2026)),
+            Plan::Scan {
+                range: year.clone(),
+                countries: many
+            }
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(many, 2025..2026).with_options(per_country)),
+            Plan::Merge {
+                countries: many,
+                dates: DateQuery::DateRange(date, Date::from_ymd(2026, 1, 1))
+            }
+        );
+
+        // no date
+        assert_eq!(
+            plan_of(PreparedQuery::new::<_, Date, _>(many, Any)),
+            Plan::Merge {
+                countries: many,
+                dates: DateQuery::Any
+            }
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new::<_, Date, _>(many, Any).with_options(scan)),
+            Plan::Scan {
+                range: 0..DATA.len(),
+                countries: many
+            }
+        );
+
+        // country order
+        let by_country = QueryOptions::DEFAULT.with_order(Order::CountryThenDate);
+        assert_eq!(
+            plan_of(PreparedQuery::new(many, 2025..2026).with_options(by_country)),
+            Plan::Chain {
+                countries: many,
+                dates: DateQuery::DateRange(date, Date::from_ymd(2026, 1, 1))
+            }
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(many, date).with_options(by_country)),
+            Plan::Lookup {
+                countries: many,
+                date
+            }
+        );
+
+        // empty
+        let reversed = Date::from_ymd(2026, 1, 1)..date;
+        assert_eq!(
+            plan_of(PreparedQuery::new(Country::US, reversed.clone())),
+            Plan::Empty
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(Any, reversed).with_options(scan)),
+            Plan::Empty
+        );
+        assert_eq!(
+            plan_of(PreparedQuery::new(Vec::<Country>::new(), 2025..2026)),
+            Plan::Empty
+        );
+    }
+
+    #[test]
+    fn ranges_beyond_coverage_clamp() {
+        use crate::data::{DATA, DATA_MAX_YEAR, DATA_MIN_YEAR};
+        const { assert!(DATA_MIN_YEAR > 1990 && DATA_MAX_YEAR < 2050) };
+
+        let scan = QueryOptions::DEFAULT.with_strategy(Strategy::ForceRangeScan);
+        let count = |query: PreparedQuery| query.with_options(scan).iter().count();
+        let before_2002 = DATA.iter().filter(|it| it.date.year() <= 2001).count();
+        assert!(before_2002 > 0);
+
+        assert_eq!(
+            count(PreparedQuery::new(Any, ..=Date::from_ymd(2001, 12, 31))),
+            before_2002
+        );
+        assert_eq!(
+            count(PreparedQuery::new(Any, Date::from_ymd(1990, 1, 1)..)),
+            DATA.len()
+        );
+        assert_eq!(
+            count(PreparedQuery::new(Any, ..Date::from_ymd(2050, 1, 1))),
+            DATA.len()
+        );
+        assert_eq!(
+            count(PreparedQuery::new(Any, Date::from_ymd(2050, 1, 1)..)),
+            0
+        );
+        assert_eq!(
+            count(PreparedQuery::new(
+                Any,
+                Date::from_ymd(1990, 1, 1)..Date::from_ymd(2050, 1, 1)
+            )),
+            DATA.len()
+        );
+        assert_eq!(
+            count(PreparedQuery::new(
+                Any,
+                Date::from_ymd(1900, 1, 1)..Date::from_ymd(1950, 1, 1)
+            )),
+            0
+        );
+        assert_eq!(
+            count(PreparedQuery::new(Any, Date::from_ymd(1900, 1, 1))),
+            0
+        );
+
+        assert_eq!(crate::get_holidays(Any, ..2002).count(), before_2002);
+        assert_eq!(crate::get_holidays(Any, 1990..).count(), DATA.len());
+        assert_eq!(crate::get_holidays(Any, 1900..1950).count(), 0);
+    }
+
+    #[test]
+    fn bounding_in_matches_queries() {
+        let windows = [
+            (Date::from_ymd(2025, 1, 1), Date::from_ymd(2026, 1, 1)),
+            (Date::from_ymd(2010, 3, 15), Date::from_ymd(2012, 7, 1)),
+            (Date::from_ymd(2025, 12, 25), Date::from_ymd(2025, 12, 26)),
+            (Date::from_ymd(1900, 1, 1), Date::from_ymd(1950, 1, 1)),
+            (Date::from_ymd(2100, 1, 1), Date::from_ymd(2200, 1, 1)),
+        ];
+        let countries = [Country::US, Country::JP, Country::DE, Country::ZW];
+
+        for (from, to) in windows {
+            let bounds: Vec<_> = crate::bounding_in(countries, from..to).collect();
+            assert_eq!(bounds.len(), countries.len());
+
+            for (country, bounds) in bounds {
+                let holidays = crate::get_holidays(country, from..to);
+                let min = holidays.clone().min_by_key(|it| it.date);
+                let max = holidays.max_by_key(|it| it.date);
+                assert_eq!(bounds, min.zip(max), "{country} in {from:?}..{to:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_of_countries_without_records() {
+        // every embedded country has records, so an empty jump table slice
+        // stands in for one without any
+        assert_eq!(slice_bounds(&[]), None);
+
+        let indices = crate::data::country_indices(Country::US);
+        let (first, last) = slice_bounds(indices).unwrap();
+        assert_eq!(
+            first,
+            crate::get_holidays::<_, Date, _>(Country::US, Any)
+                .next()
+                .unwrap()
+        );
+        assert_eq!(
+            last,
+            crate::get_holidays::<_, Date, _>(Country::US, Any)
+                .last()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-names"))]
+    fn collapse_new_year() {
+        let date = Date::from_ymd(2025, 1, 1);
+        let expected = crate::get_holidays(Any, date)
+            .filter(|it| it.name == "New Year's Day")
+            .count();
+
+        let new_year: Vec<_> = crate::get_holidays(Any, date)
+            .collapse_shared()
+            .filter(|it| it.name == "New Year's Day")
+            .collect();
+
+        assert_eq!(new_year.len(), 1);
+        assert_eq!(new_year[0].countries.len(), expected);
+    }
+
+    #[test]
+    fn collapse_preserves_records() {
+        let range = Date::from_ymd(2024, 1, 1)..Date::from_ymd(2026, 1, 1);
+        let holidays = crate::get_holidays(Any, range.clone());
+        let collapsed = crate::get_holidays(Any, range).collapse_shared();
+
+        let total: usize = collapsed.clone().map(|it| it.countries.len()).sum();
+        assert_eq!(total, holidays.count());
+
+        let mut collapsed: Vec<_> = collapsed.map(|it| (it.date, it.name)).collect();
+        let len = collapsed.len();
+        collapsed.dedup();
+        assert_eq!(collapsed.len(), len);
+    }
+
+    #[test]
+    fn by_day_groups_consecutive_dates() {
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let options = QueryOptions::DEFAULT.with_strategy(strategy);
+                let mut expected: Vec<(Date, Vec<&Holiday>)> = Vec::new();
+                for holiday in query.iter_with(options) {
+                    match expected.last_mut() {
+                        Some((date, group)) if *date == holiday.date => group.push(holiday),
+                        _ => expected.push((holiday.date, vec![holiday])),
+                    }
+                }
+
+                let days = query.iter_with(options).by_day();
+                let (lower, upper) = days.size_hint();
+                let days: Vec<_> = days.map(|it| (it.date().unwrap(), it.holidays)).collect();
+                assert!(lower <= days.len() && upper.is_none_or(|it| it >= days.len()));
+                assert_eq!(days, expected, "{strategy:?} days of {query:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn by_day_all_countries() {
+        let mut days = crate::get_holidays(Any, 2025..2026).by_day();
+        let new_year = days.next().unwrap();
+        assert_eq!(new_year.display_date().to_string(), "2025-01-01");
+        assert!(new_year.countries().contains(Country::US));
+        assert!(new_year
+            .holidays
+            .windows(2)
+            .all(|w| (w[0].code as u16, w[0].name) < (w[1].code as u16, w[1].name)));
+        assert!(days.all(|it| it.date::<Date>().unwrap() > Date::from_ymd(2025, 1, 1)));
+        assert!(days.next().is_none());
+    }
+
+    /// Checks that `with_dates` and `try_with_dates` pair results with their
+    /// dates converted into `D`, also when skipped or walked from the back.
+    fn assert_with_dates<D>()
+    where
+        D: DateBounds + PartialEq + std::fmt::Debug,
+    {
+        let query = PreparedQuery::new([Country::US, Country::JP], 2020..2026);
+        let expected: Vec<(D, &Holiday)> =
+            query.iter().map(|it| (it.date().unwrap(), it)).collect();
+
+        assert!(query
+            .iter()
+            .with_dates::<D>()
+            .eq(query.iter().map(|it| (it.date().unwrap(), it))));
+        assert_eq!(
+            query.iter().with_dates::<D>().size_hint(),
+            query.iter().size_hint()
+        );
+        assert_eq!(query.iter().with_dates::<D>().count(), expected.len());
+        assert_eq!(
+            query.iter().with_dates::<D>().last().as_ref(),
+            expected.last()
+        );
+        assert_eq!(
+            query.iter().with_dates::<D>().nth(10).as_ref(),
+            expected.get(10)
+        );
+        let folded = query
+            .iter()
+            .with_dates::<D>()
+            .fold(Vec::new(), |mut acc, it| {
+                acc.push(it);
+                acc
+            });
+        assert_eq!(folded, expected);
+
+        let fallible: Vec<_> = query
+            .iter()
+            .try_with_dates::<D>()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(fallible, expected);
+        let tenth = query
+            .iter()
+            .try_with_dates::<D>()
+            .nth(10)
+            .map(Result::unwrap);
+        assert_eq!(tenth.as_ref(), expected.get(10));
+    }
+
+    #[test]
+    fn with_dates_system_time() {
+        assert_with_dates::<std::time::SystemTime>();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn with_dates_chrono() {
+        assert_with_dates::<chrono::NaiveDate>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn with_dates_time() {
+        assert_with_dates::<time::Date>();
+    }
+
+    #[test]
+    fn with_dates_conversion_errors() {
+        /// Dates before 2025, like a format that ends at a fixed date.
+        #[derive(Debug, PartialEq)]
+        struct Before2025(Date);
+
+        impl TryFrom<Date> for Before2025 {
+            type Error = DateConversionError;
+
+            fn try_from(value: Date) -> Result<Self, Self::Error> {
+                match value.year() {
+                    ..2025 => Ok(Before2025(value)),
+                    _ => Err(DateConversionError),
+                }
+            }
+        }
+
+        impl DateBounds for Before2025 {
+            fn bounds() -> (Date, Date) {
+                (Date::MIN, Date::from_ymd(2024, 12, 31))
+            }
+        }
+
+        let query = PreparedQuery::new(Country::US, 2024..2026);
+        let results: Vec<_> = query.iter().try_with_dates::<Before2025>().collect();
+        assert_eq!(results.len(), query.iter().count());
+        for (result, holiday) in results.iter().zip(query.iter()) {
+            match result {
+                Ok((date, it)) => assert_eq!((date.0, *it), (holiday.date, holiday)),
+                Err(_) => assert_eq!(holiday.date.year(), 2025),
+            }
+        }
+
+        let clamped: Vec<_> = query.iter().with_dates::<Before2025>().collect();
+        assert_eq!(clamped.len(), query.iter().count());
+        for ((date, it), holiday) in clamped.iter().zip(query.iter()) {
+            assert_eq!(*it, holiday);
+            assert_eq!(date.0, holiday.date.min(Date::from_ymd(2024, 12, 31)));
+        }
+    }
+
+    #[test]
+    fn prepared_query_across_threads() {
+        static QUERY: std::sync::OnceLock<PreparedQuery> = std::sync::OnceLock::new();
+
+        fn assert_send_sync<T: Send + Sync + Copy + 'static>() {}
+        assert_send_sync::<PreparedQuery>();
+
+        let countries = [Country::US, Country::JP, Country::DE];
+        let range = Date::from_ymd(2020, 1, 1)..Date::from_ymd(2030, 1, 1);
+        let expected: Vec<_> = crate::get_holidays(countries, range.clone()).collect();
+
+        let query = *QUERY.get_or_init(|| PreparedQuery::new(countries, range));
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(move || query.iter().collect::<Vec<_>>()))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+
+        let shared = QUERY.get().unwrap();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert!(shared.iter().eq(expected.iter().copied())));
+            }
+        });
+    }
+
+    #[test]
+    fn disjoint_month_filters() {
+        let holidays = crate::get_holidays::<_, Date, _>(Country::GB, Any)
+            .in_month(Month::JANUARY)
+            .in_month(Month::DECEMBER);
+
+        assert_eq!(holidays.count(), 0);
+    }
+
+    #[test]
+    fn unfiltered_dates_keep_selected_countries() {
+        let last = *crate::enabled_countries().last().unwrap();
+        for country in [Country::GB, last] {
+            let holidays: Vec<_> = crate::get_holidays::<_, Date, _>(country, Any).collect();
+            assert_eq!(holidays.len(), crate::data::country_indices(country).len());
+            assert!(holidays.iter().all(|it| it.code == country));
+        }
+    }
+
+    #[test]
+    fn date_query_intersections() {
+        let day = |d| Date::from_ymd(2025, 1, d);
+        let queries = [
+            DateQuery::Any,
+            DateQuery::Empty,
+            DateQuery::Exact(day(10)),
+            DateQuery::Exact(day(20)),
+            DateQuery::FromDate(day(10)),
+            DateQuery::FromDate(day(15)),
+            DateQuery::ToDate(day(10)),
+            DateQuery::ToDate(day(15)),
+            DateQuery::DateRange(day(5), day(15)),
+            DateQuery::DateRange(day(12), day(25)),
+            DateQuery::DateRange(day(15), day(15)),
+            DateQuery::DateRange(day(20), day(10)),
+            DateQuery::Exact(Date::MIN),
+            DateQuery::ToDate(Date::MAX),
+        ];
+        let probes: Vec<_> = (1..=31)
+            .map(day)
+            .chain([Date::MIN, Date(Date::MIN.0 + 1), Date::MAX])
+            .collect();
+
+        for a in queries {
+            for b in queries {
+                let both = a & b;
+                assert_eq!(both, b & a, "{a:?} & {b:?} isn't commutative");
+                for date in &probes {
+                    assert_eq!(
+                        both.contains(*date),
+                        a.contains(*date) && b.contains(*date),
+                        "{a:?} & {b:?} = {both:?} at {date:?}"
+                    );
+                }
+                let matches_none = probes.iter().all(|it| !both.contains(*it));
+                if matches_none && a != b {
+                    assert_eq!(both, DateQuery::Empty, "{a:?} & {b:?}");
+                }
+            }
+        }
+        assert_eq!(DateQuery::Any & DateQuery::Any, DateQuery::Any);
+        assert_eq!(
+            DateQuery::Exact(day(10)) & DateQuery::FromDate(day(1)),
+            DateQuery::Exact(day(10))
+        );
+    }
+
+    /// Queries covering all query shapes with various country sets.
+    fn sample_queries() -> Vec<Query> {
+        let country_sets: Vec<CountrySet> = vec![
+            CountrySet::new(),
+            [Country::US].into_iter().collect(),
+            [Country::US, Country::DE, Country::JP]
+                .into_iter()
+                .collect(),
+            CountrySet::all().iter().step_by(7).collect(),
+            CountrySet::all().iter().skip(3).collect(),
+            CountrySet::all(),
+        ];
+        let mut dates: Vec<DateQuery> = vec![
+            DateQuery::Any,
+            DateQuery::Empty,
+            DateQuery::DateRange(Date(0), Date(0)),
+            DateQuery::FromDate(Date::from_ymd(2030, 6, 1)),
+            DateQuery::ToDate(Date::from_ymd(2003, 2, 1)),
+        ];
+        for day in (0..365 * 30).step_by(173) {
+            let from = Date::from_ymd(2001, 1, 1).saturating_add_days(day);
+            dates.push(DateQuery::Exact(from));
+            dates.push(DateQuery::DateRange(
+                from,
+                from.saturating_add_days(day % 400),
+            ));
+        }
+
+        let mut result = Vec::with_capacity(country_sets.len() * dates.len());
+        for countries in &country_sets {
+            for date_filter in &dates {
+                result.push(Query {
+                    countries: *countries,
+                    date_filter: *date_filter,
+                });
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn strategies_are_equivalent() {
+        for query in sample_queries() {
+            let expected: Vec<_> = query.into_iter().collect();
+            for strategy in [Strategy::ForceRangeScan, Strategy::ForcePerCountry] {
+                let options = QueryOptions::DEFAULT.with_strategy(strategy);
+                assert!(
+                    query.iter_with(options).eq(expected.iter().copied()),
+                    "{strategy:?} differs for {query:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn results_are_chronological() {
+        let sort_key = |it: &&Holiday| (it.date, it.code as u16, it.name);
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let options = QueryOptions::DEFAULT.with_strategy(strategy);
+                let results: Vec<_> = query.iter_with(options).collect();
+                assert!(
+                    results
+                        .windows(2)
+                        .all(|w| sort_key(&w[0]) < sort_key(&w[1])),
+                    "{strategy:?} results of {query:?} are out of order"
+                );
+
+                // a single date is ordered like the range of that day, or
+                // like that day of all dates
+                let DateQuery::Exact(date) = query.date_filter else {
+                    continue;
+                };
+                let day = Query {
+                    countries: query.countries,
+                    date_filter: DateQuery::DateRange(date, date.saturating_add_days(1)),
+                };
+                assert!(
+                    day.iter_with(options).eq(results.iter().copied()),
+                    "{query:?}"
+                );
+                let undated = Query {
+                    countries: query.countries,
+                    date_filter: DateQuery::Any,
+                };
+                let on_date = undated.iter_with(options).filter(|it| it.date == date);
+                assert!(on_date.eq(results.iter().copied()), "{query:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn country_order_sorts_by_country() {
+        let by_country = QueryOptions::DEFAULT.with_order(Order::CountryThenDate);
+        for query in sample_queries() {
+            // results by date are sorted by country code second, so a stable
+            // sort by country orders them by country code and then by date
+            let mut expected: Vec<_> = query.into_iter().collect();
+            expected.sort_by_key(|it| it.code as u16);
+
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let options = by_country.with_strategy(strategy);
+                for offset in [0, 1, 7, 100] {
+                    let mut iter = query.iter_with(options);
+                    assert_eq!(iter.nth(offset), expected.get(offset).copied(), "{query:?}");
+                    let rest = &expected[(offset + 1).min(expected.len())..];
+                    assert_eq!(
+                        iter.size_hint(),
+                        (rest.len(), Some(rest.len())),
+                        "{query:?}"
+                    );
+                    assert_eq!(iter.is_empty(), rest.is_empty(), "{query:?}");
+                    assert_eq!(iter.clone().last(), rest.last().copied(), "{query:?}");
+                    let folded = iter.clone().fold(Vec::new(), |mut acc, it| {
+                        acc.push(it);
+                        acc
+                    });
+                    assert_eq!(folded, rest, "{query:?} with {strategy:?}");
+                    assert!(iter.clone().eq(rest.iter().copied()), "{query:?}");
+                    if let Some(first) = rest.first() {
+                        assert!(iter.clone().any_on(first.date), "{query:?}");
+                    }
+                    let in_december = iter.in_month(Month::DECEMBER);
+                    let expected_december = rest.iter().copied().filter(|it| it.date.month() == 12);
+                    assert!(in_december.eq(expected_december), "{query:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn country_order_collapses_within_a_country() {
+        let countries = [Country::DE, Country::FR];
+        let merged: Vec<_> = crate::get_holidays(countries, 2025..2026)
+            .collapse_shared()
+            .collect();
+        assert!(merged.iter().any(|it| it.countries.len() == 2));
+
+        let by_country = crate::get_holidays_ordered(countries, 2025..2026, Order::CountryThenDate);
+        assert!(by_country
+            .collapse_shared()
+            .all(|it| it.countries.len() == 1));
+    }
+
+    #[test]
+    fn last_matches_default() {
+        // walks the whole iterator like default `Iterator::last`
+        fn reference(iter: Iter) -> Option<&'static Holiday> {
+            iter.fold(None, |_, it| Some(it))
+        }
+
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let iter = query.iter_with(QueryOptions::DEFAULT.with_strategy(strategy));
+                for iter in [
+                    iter.clone(),
+                    iter.clone().in_month(Month::DECEMBER),
+                    iter.clone().in_month(Month::MARCH).in_month(Month::APRIL),
+                ] {
+                    assert_eq!(iter.clone().last(), reference(iter.clone()), "{query:?}");
+
+                    // partially consumed iterators
+                    let mut consumed = iter.clone();
+                    consumed.nth(3);
+                    assert_eq!(consumed.clone().last(), reference(consumed), "{query:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_empty_matches_next() {
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let iter = query.iter_with(QueryOptions::DEFAULT.with_strategy(strategy));
+                for mut iter in [
+                    iter.clone(),
+                    iter.clone().in_month(Month::DECEMBER),
+                    iter.clone().with_filter(|it: &Holiday| it.date.day() == 1),
+                ] {
+                    for _ in 0..3 {
+                        assert_eq!(iter.is_empty(), iter.clone().next().is_none(), "{query:?}");
+                        iter.nth(2);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fold_matches_next() {
+        fn walk(iter: Iter) -> Vec<&'static Holiday> {
+            let mut result = Vec::new();
+            for it in iter {
+                result.push(it);
+            }
+            result
+        }
+
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let mut iter = query.iter_with(QueryOptions::DEFAULT.with_strategy(strategy));
+                for _ in 0..2 {
+                    for iter in [
+                        iter.clone(),
+                        iter.clone().in_month(Month::DECEMBER),
+                        iter.clone().with_filter(|it: &Holiday| it.date.day() > 10),
+                    ] {
+                        let folded = iter.clone().fold(Vec::new(), |mut acc, it| {
+                            acc.push(it);
+                            acc
+                        });
+                        assert_eq!(folded, walk(iter), "{query:?} with {strategy:?}");
+                    }
+                    // merge state is kept by partially consumed iterators
+                    iter.nth(5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn count_matches_walk() {
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let mut iter = query.iter_with(QueryOptions::DEFAULT.with_strategy(strategy));
+                iter.nth(3);
+                for iter in [iter.clone(), iter.clone().in_month(Month::MAY)] {
+                    assert_eq!(
+                        iter.clone().count(),
+                        iter.fold(0, |count, _| count + 1),
+                        "{query:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lookups_match_search() {
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let mut iter = query.iter_with(QueryOptions::DEFAULT.with_strategy(strategy));
+                iter.next();
+                for iter in [iter.clone(), iter.clone().in_month(Month::JANUARY)] {
+                    let mut dates: Vec<_> = iter.clone().map(|it| it.date).take(2).collect();
+                    dates.extend([Date::from_ymd(2025, 1, 1), Date::from_ymd(2025, 1, 2)]);
+                    for date in dates {
+                        assert_eq!(
+                            iter.clone().any_on(date),
+                            iter.clone().any(|it| it.date == date),
+                            "{query:?} on {date}"
+                        );
+                    }
+                }
+            }
+        }
+
+        // name lookups scan all records without `name-index` feature, so only
+        // a few queries are checked
+        for query in sample_queries().into_iter().step_by(23) {
+            let iter = query.iter_with(QueryOptions::DEFAULT);
+            for name in ["new year's day", "Christmas Day", "Nonexistent Day"] {
+                let normalized = crate::normalize_name(name);
+                assert_eq!(
+                    iter.clone().any_named(name),
+                    iter.clone()
+                        .any(|it| crate::normalize_name(it.name) == normalized),
+                    "{query:?} named {name}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn date_lookups_dont_scan() {
+        let christmas = Date::from_ymd(2025, 12, 25);
+        // a single-country query over all years is merged from its table, but
+        // `any_on` only looks up records of the date
+        let query = PreparedQuery::new::<_, Date, _>(Country::US, Any);
+        assert!(matches!(plan_of(query), Plan::Merge { .. }));
+        assert_eq!(
+            date_plan(christmas),
+            Plan::Lookup {
+                countries: observing_countries(christmas),
+                date: christmas
+            }
+        );
+        assert!(query.iter().any_on(christmas));
+
+        // dates without records aren't looked up at all
+        assert_eq!(date_plan(Date::MAX), Plan::Empty);
+        assert!(!query.iter().any_on(Date::MAX));
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        for query in sample_queries() {
+            for strategy in [
+                Strategy::Auto,
+                Strategy::ForceRangeScan,
+                Strategy::ForcePerCountry,
+            ] {
+                let mut iter = query.iter_with(QueryOptions::DEFAULT.with_strategy(strategy));
+                let expected: Vec<_> = iter.clone().collect();
+
+                for consumed in [0, 1, 5] {
+                    let remaining = expected.get(consumed..).unwrap_or(&[]);
+                    let mut clone = iter.clone();
+                    // advancing a clone doesn't affect the original
+                    let peeked: Vec<_> = clone.by_ref().take(3).collect();
+                    assert_eq!(
+                        peeked,
+                        remaining.iter().take(3).copied().collect::<Vec<_>>()
+                    );
+
+                    let mut other = iter.clone();
+                    assert!(other.by_ref().eq(remaining.iter().copied()), "{query:?}");
+                    assert_eq!(other.next(), None);
+                    assert!(clone.eq(remaining.iter().skip(3).copied()), "{query:?}");
+
+                    // clones of clones behave the same
+                    assert!(iter.clone().clone().eq(remaining.iter().copied()));
+
+                    iter.nth(if consumed == 0 { 0 } else { 3 });
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn input_forms_are_equivalent() {
+        use std::time::SystemTime;
+
+        fn reference(countries: CountrySet, from: Date, to: Date) -> Vec<&'static Holiday> {
+            crate::data::DATA
+                .iter()
+                .filter(|it| countries.contains(it.code) && from <= it.date && it.date < to)
+                .collect()
+        }
+
+        let (from, to) = (Date::from_ymd(2025, 1, 1), Date::from_ymd(2026, 1, 1));
+        let last = Date::from_ymd(2025, 12, 31);
+        let set: CountrySet = [Country::US, Country::DE].into_iter().collect();
+        let expected = |from, to| reference(set, from, to);
+        let pair = expected(from, to);
+        let new_year = expected(from, from.saturating_add_days(1));
+
+        // country forms
+        let us = reference(CountrySet::from_iter([Country::US]), from, to);
+        assert_eq!(
+            crate::get_holidays(Country::US, from..to).collect::<Vec<_>>(),
+            us
+        );
+        assert_eq!(
+            crate::get_holidays(Some(Country::US), from..to).collect::<Vec<_>>(),
+            us
+        );
+        assert_eq!(
+            crate::get_holidays(None::<Country>, from..to).collect::<Vec<_>>(),
+            reference(CountrySet::all(), from, to)
+        );
+        assert_eq!(
+            crate::get_holidays(Any, from..to).collect::<Vec<_>>(),
+            reference(CountrySet::all(), from, to)
+        );
+        assert_eq!(
+            crate::get_holidays([Country::US, Country::DE], from..to).collect::<Vec<_>>(),
+            pair
+        );
+        assert_eq!(
+            crate::get_holidays(&[Country::DE, Country::US][..], from..to).collect::<Vec<_>>(),
+            pair
+        );
+        assert_eq!(
+            crate::get_holidays(vec![Country::US, Country::DE, Country::US], from..to)
+                .collect::<Vec<_>>(),
+            pair
+        );
+        assert_eq!(
+            crate::get_holidays([Country::US, Country::DE].iter(), from..to).collect::<Vec<_>>(),
+            pair
+        );
+        let codes = ["US", "XX", "DE"]
+            .into_iter()
+            .filter_map(|it| it.parse::<Country>().ok());
+        assert_eq!(
+            crate::get_holidays(codes, from..to).collect::<Vec<_>>(),
+            pair
+        );
+        assert_eq!(crate::get_holidays(set, from..to).collect::<Vec<_>>(), pair);
+        assert_eq!(
+            crate::get_holidays(&set, from..to).collect::<Vec<_>>(),
+            pair
+        );
+
+        // date forms
+        let query = |iter: Iter| iter.collect::<Vec<_>>();
+        assert_eq!(query(crate::get_holidays(&set, from..=last)), pair);
+        assert_eq!(query(crate::get_holidays(&set, 2025..2026)), pair);
+        // years convert to their first day
+        assert_eq!(query(crate::get_holidays(&set, 2025..=2025)), new_year);
+        assert_eq!(query(crate::get_holidays(&set, 2025)), new_year);
+        assert_eq!(query(crate::get_holidays(&set, from)), new_year);
+        assert_eq!(query(crate::get_holidays(&set, Some(from))), new_year);
+        assert_eq!(
+            query(crate::get_holidays(
+                &set,
+                (Bound::Excluded(from), Bound::Included(last))
+            )),
+            expected(from.saturating_add_days(1), to)
+        );
+        assert_eq!(
+            query(crate::get_holidays(&set, from..)),
+            expected(from, Date::MAX)
+        );
+        assert_eq!(
+            query(crate::get_holidays(&set, ..to)),
+            expected(Date::MIN, to)
+        );
+        assert_eq!(
+            query(crate::get_holidays(&set, None::<Date>)),
+            expected(Date::MIN, Date::MAX)
+        );
+        assert_eq!(
+            query(crate::get_holidays::<_, Date, _>(&set, Any)),
+            expected(Date::MIN, Date::MAX)
+        );
+        let system: std::ops::Range<SystemTime> = from.try_into().unwrap()..to.try_into().unwrap();
+        assert_eq!(query(crate::get_holidays(&set, system)), pair);
+        assert_eq!(
+            query(crate::get_holidays(
+                &set,
+                crate::near(Date::from_ymd(2025, 7, 2), 2)
+            )),
+            expected(Date::from_ymd(2025, 6, 30), Date::from_ymd(2025, 7, 5))
+        );
+    }
+
+    #[test]
+    fn observing_matches_reference() {
+        for date in [
+            Date::from_ymd(2025, 1, 1),
+            Date::from_ymd(2025, 12, 25),
+            Date::from_ymd(2025, 3, 4),
+        ] {
+            let reference: HashSet<Country> =
+                crate::get_holidays(Any, date).map(|it| it.code).collect();
+            let set = observing_countries(date);
+
+            assert_eq!(set.len(), reference.len());
+            assert!(set.iter().all(|it| reference.contains(&it)));
+        }
+        assert!(observing_countries(Date::MAX).is_empty());
+    }
+
+    #[test]
+    fn observing_counts_countries_once() {
+        let date = Date::from_ymd(2025, 1, 1);
+        let records = [
+            Holiday::new(Country::US, date, "New Year's Day"),
+            Holiday::new(Country::US, date, "Second Record"),
+            Holiday::new(Country::DE, date, "Neujahr"),
+        ];
+
+        assert_eq!(countries_of(&records).len(), 2);
+    }
+
+    // same-day records of a country are merged with `no-names`
+    #[test]
+    #[cfg(not(feature = "no-names"))]
+    fn observing_counts_double_entries_once() {
+        // embedded double entries: CZ on New Year's Day 2025 and GR on
+        // Labor Day 2000, which was also Easter Monday
+        let selected = CountrySet::from_iter([Country::CZ, Country::GR]);
+        for (date, records) in [
+            (Date::from_ymd(2025, 1, 1), 3),
+            (Date::from_ymd(2000, 5, 1), 3),
+        ] {
+            assert_eq!(
+                crate::get_holidays(selected, date).count(),
+                records,
+                "{date}"
+            );
+            assert_eq!((observing_countries(date) & selected).len(), 2, "{date}");
+            assert!(
+                crate::observing_count(date) < crate::get_holidays(Any, date).count(),
+                "{date}"
+            );
+        }
+    }
+}
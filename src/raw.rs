@@ -0,0 +1,172 @@
+//! Read-only access to the embedded data tables, for downstream crates that
+//! build their own indices over holiday data.
+//!
+//! Requires the `raw-tables` feature.
+//!
+//! # Stability
+//!
+//! The functions in this module and the layout guarantees documented on them
+//! are stable. The layout of the underlying tables, e.g. which records are
+//! embedded or how they're grouped, may change in any minor release; such
+//! changes increment [`LAYOUT_VERSION`], which downstream indices can store to
+//! detect that they must be rebuilt.
+//!
+//! ```
+//! use holidays::{raw, Country};
+//!
+//! // count records of each year without going through queries
+//! for year in raw::years() {
+//!     let records = raw::year_records(year);
+//!     assert!(records.iter().all(|it| it.display_date().to_string().starts_with(&year.to_string())));
+//! }
+//!
+//! let us = raw::country_indices(Country::US);
+//! assert!(us.iter().all(|&i| raw::records()[i].code == Country::US));
+//! ```
+
+use crate::data::{DATA, DATA_MAX_YEAR, DATA_MIN_YEAR, YEAR_JUMP_TABLE};
+use crate::{Country, Holiday};
+
+/// Version of the table layout, incremented whenever the structure or
+/// contents of tables exposed by this module change.
+///
+/// ```
+/// use holidays::raw;
+///
+/// // tables are read assuming the layout they were written for
+/// assert_eq!(raw::LAYOUT_VERSION, 1);
+/// ```
+pub const LAYOUT_VERSION: u32 = 1;
+
+/// Returns all embedded holiday records.
+///
+/// Records are sorted by date, then by country code and then by name, and a
+/// country can observe multiple records on the same date, see
+/// [`Holiday::name`]. Indices into this slice are used by all other tables of this module, and
+/// are stable within a single build of the crate only.
+///
+/// ```
+/// use holidays::{raw, Country};
+///
+/// let us = raw::records().iter().filter(|it| it.code == Country::US).count();
+/// assert_eq!(us, raw::country_indices(Country::US).len());
+/// ```
+pub fn records() -> &'static [Holiday] {
+    DATA
+}
+
+/// Returns indices of [`records`] of the `country`, in ascending order.
+///
+/// ```
+/// use holidays::{raw, Country};
+///
+/// let indices = raw::country_indices(Country::JP);
+/// assert!(indices.windows(2).all(|w| w[0] < w[1]));
+/// assert!(indices.iter().all(|&i| raw::records()[i].code == Country::JP));
+/// ```
+pub fn country_indices(country: Country) -> &'static [usize] {
+    crate::data::country_indices(country)
+}
+
+/// Returns the inclusive range of years between the first and the last
+/// embedded record.
+///
+/// Years within the range aren't guaranteed to have any records.
+///
+/// ```
+/// use holidays::raw;
+///
+/// let total: usize = raw::years().map(|year| raw::year_records(year).len()).sum();
+/// assert_eq!(total, raw::records().len());
+/// ```
+pub fn years() -> std::ops::RangeInclusive<i64> {
+    DATA_MIN_YEAR..=DATA_MAX_YEAR
+}
+
+/// Returns the index of the first record of each year in [`years`], or of
+/// the first record after the year if it has no records.
+///
+/// The slice has one entry per year and is non-decreasing, so records of
+/// year `y` are `records()[offsets[i]..offsets[i + 1]]` for `i = y -
+/// years().start()`, with the last year ending at `records().len()`.
+///
+/// ```
+/// use holidays::raw;
+///
+/// let offsets = raw::year_offsets();
+/// let i = (2025 - raw::years().start()) as usize;
+/// assert_eq!(&raw::records()[offsets[i]..offsets[i + 1]], raw::year_records(2025));
+/// ```
+pub fn year_offsets() -> &'static [usize] {
+    YEAR_JUMP_TABLE
+}
+
+/// Returns records of the `year`, or an empty slice for years outside of
+/// [`years`].
+///
+/// ```
+/// use holidays::{raw, Country};
+///
+/// let records = raw::year_records(2025);
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(records.iter().any(|it| it.code == Country::US && it.name == "Independence Day"));
+/// assert!(raw::year_records(raw::years().end() + 1).is_empty());
+/// ```
+pub fn year_records(year: i64) -> &'static [Holiday] {
+    if !years().contains(&year) {
+        return &[];
+    }
+    let i = (year - DATA_MIN_YEAR) as usize;
+    let start = YEAR_JUMP_TABLE[i];
+    let end = YEAR_JUMP_TABLE.get(i + 1).copied().unwrap_or(DATA.len());
+    &DATA[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CountrySet;
+    use crate::Date;
+
+    #[test]
+    fn records_are_ordered() {
+        assert!(records()
+            .windows(2)
+            .all(|w| (w[0].date, w[0].code as u16, w[0].name)
+                < (w[1].date, w[1].code as u16, w[1].name)));
+        assert!(records().iter().all(|it| years().contains(&it.date.year())));
+    }
+
+    #[test]
+    fn country_indices_partition_records() {
+        let mut seen = vec![false; records().len()];
+        for country in CountrySet::all() {
+            let indices = country_indices(country);
+            assert!(indices.windows(2).all(|w| w[0] < w[1]));
+            for &i in indices {
+                assert_eq!(records()[i].code, country);
+                assert!(!std::mem::replace(&mut seen[i], true));
+            }
+        }
+        assert!(seen.into_iter().all(|it| it));
+    }
+
+    #[test]
+    fn year_offsets_match_records() {
+        let offsets = year_offsets();
+        assert_eq!(offsets.len(), years().count());
+        assert!(offsets.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut total = 0;
+        for year in years() {
+            let records = year_records(year);
+            let (first, last) = (Date::from_ymd(year, 1, 1), Date::from_ymd(year, 12, 31));
+            assert!(records.iter().all(|it| first <= it.date && it.date <= last));
+            total += records.len();
+        }
+        assert_eq!(total, records().len());
+
+        assert!(year_records(*years().start() - 1).is_empty());
+        assert!(year_records(*years().end() + 1).is_empty());
+    }
+}
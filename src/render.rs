@@ -0,0 +1,382 @@
+//! Rendering of holidays into human readable text, e.g. for reports or
+//! calendar views.
+//!
+//! All names are in english; for localized output format fields of
+//! [`Holiday`] with a dedicated library instead.
+
+use std::fmt::Write;
+
+use crate::Holiday;
+
+/// Style of rendered dates.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::render::DateStyle;
+/// use holidays::{Country, Holiday, HolidayFormat};
+///
+/// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+/// let format = HolidayFormat::DEFAULT.with_date_style(DateStyle::DayMonthName);
+/// assert_eq!(holiday.render(&format), "4 July 2025: Independence Day");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+    /// ISO 8601 `YYYY-MM-DD` format, e.g. `2025-07-04`.
+    #[default]
+    Iso,
+    /// Day followed by the month name and the year, e.g. `4 July 2025`.
+    DayMonthName,
+}
+
+/// Order of the date and the name in rendered holidays.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::render::FieldOrder;
+/// use holidays::{Country, Holiday, HolidayFormat};
+///
+/// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+/// let format = HolidayFormat::DEFAULT.with_order(FieldOrder::NameFirst);
+/// assert_eq!(holiday.render(&format), "Independence Day: 2025-07-04");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldOrder {
+    /// Date before the name, e.g. `2025-07-04: Independence Day`.
+    #[default]
+    DateFirst,
+    /// Name before the date, e.g. `Independence Day: 2025-07-04`.
+    NameFirst,
+}
+
+/// Label of the country that follows the name of rendered holidays.
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::render::CountryLabel;
+/// use holidays::{Country, Holiday, HolidayFormat};
+///
+/// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+/// let format = HolidayFormat::DEFAULT.with_country(CountryLabel::Name);
+/// assert_eq!(holiday.render(&format), "2025-07-04: Independence Day (United States)");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CountryLabel {
+    /// Country isn't included.
+    #[default]
+    None,
+    /// ISO 3166-1 alpha-2 code, e.g. `Independence Day (US)`.
+    Code,
+    /// English name, e.g. `Independence Day (United States)`.
+    Name,
+}
+
+/// Function applied to holiday names by [`HolidayFormat::with_name_transform`].
+type NameTransform = Box<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Options of [`Holiday::render`].
+///
+/// ```
+/// # use holidays::Date;
+/// use holidays::render::{CountryLabel, DateStyle, FieldOrder};
+/// use holidays::{Country, Holiday, HolidayFormat};
+///
+/// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+///
+/// assert_eq!(holiday.render(&HolidayFormat::DEFAULT), "2025-07-04: Independence Day");
+///
+/// let format = HolidayFormat::DEFAULT
+///     .with_order(FieldOrder::NameFirst)
+///     .with_date_style(DateStyle::DayMonthName)
+///     .with_weekday(true)
+///     .with_country(CountryLabel::Code)
+///     .with_separator(" on ");
+/// assert_eq!(holiday.render(&format), "Independence Day (US) on Friday, 4 July 2025");
+/// ```
+pub struct HolidayFormat {
+    order: FieldOrder,
+    date_style: DateStyle,
+    weekday: bool,
+    country: CountryLabel,
+    separator: &'static str,
+    name_transform: Option<NameTransform>,
+}
+
+impl HolidayFormat {
+    /// ISO date followed by the name, separated by `": "`.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// assert_eq!(holiday.render(&HolidayFormat::DEFAULT), "2025-07-04: Independence Day");
+    /// ```
+    pub const DEFAULT: HolidayFormat = HolidayFormat {
+        order: FieldOrder::DateFirst,
+        date_style: DateStyle::Iso,
+        weekday: false,
+        country: CountryLabel::None,
+        separator: ": ",
+        name_transform: None,
+    };
+
+    /// Sets the order of the date and the name.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::render::FieldOrder;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// let format = HolidayFormat::DEFAULT.with_order(FieldOrder::NameFirst);
+    /// assert_eq!(holiday.render(&format), "Independence Day: 2025-07-04");
+    /// ```
+    pub const fn with_order(mut self, order: FieldOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the style of the date.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::render::DateStyle;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// let format = HolidayFormat::DEFAULT.with_date_style(DateStyle::DayMonthName);
+    /// assert_eq!(holiday.render(&format), "4 July 2025: Independence Day");
+    /// ```
+    pub const fn with_date_style(mut self, style: DateStyle) -> Self {
+        self.date_style = style;
+        self
+    }
+
+    /// Sets whether the date is preceded by the name of the weekday, e.g.
+    /// `Friday, 2025-07-04`.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// let format = HolidayFormat::DEFAULT.with_weekday(true);
+    /// assert_eq!(holiday.render(&format), "Friday, 2025-07-04: Independence Day");
+    /// ```
+    pub const fn with_weekday(mut self, weekday: bool) -> Self {
+        self.weekday = weekday;
+        self
+    }
+
+    /// Sets the country label that follows the name.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::render::CountryLabel;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// let format = HolidayFormat::DEFAULT.with_country(CountryLabel::Code);
+    /// assert_eq!(holiday.render(&format), "2025-07-04: Independence Day (US)");
+    /// ```
+    pub const fn with_country(mut self, country: CountryLabel) -> Self {
+        self.country = country;
+        self
+    }
+
+    /// Sets the text between the date and the name.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// let format = HolidayFormat::DEFAULT.with_separator(" - ");
+    /// assert_eq!(holiday.render(&format), "2025-07-04 - Independence Day");
+    /// ```
+    pub const fn with_separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets a function that transforms holiday names before they're
+    /// rendered, e.g. to translate or abbreviate them.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use std::collections::HashMap;
+    /// use holidays::{Country, Holiday, HolidayFormat};
+    ///
+    /// let translations = HashMap::from([("Independence Day", "Día de la Independencia")]);
+    /// let format = HolidayFormat::DEFAULT.with_name_transform(move |name| {
+    ///     translations.get(name).copied().unwrap_or(name).to_string()
+    /// });
+    ///
+    /// let holiday = Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day");
+    /// assert_eq!(holiday.render(&format), "2025-07-04: Día de la Independencia");
+    /// ```
+    pub fn with_name_transform(
+        mut self,
+        transform: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.name_transform = Some(Box::new(transform));
+        self
+    }
+
+    fn write_date(&self, holiday: &Holiday, out: &mut String) -> std::fmt::Result {
+        let date = holiday.date;
+        if self.weekday {
+            write!(out, "{}, ", date.weekday().name())?;
+        }
+        match self.date_style {
+            DateStyle::Iso => date.format_iso(out),
+            DateStyle::DayMonthName => {
+                let month = crate::Month::new(date.month())
+                    .expect("month of a Date is always in 1..=12 range");
+                write!(out, "{} {} {}", date.day(), month.name(), date.year())
+            }
+        }
+    }
+
+    fn write_name(&self, holiday: &Holiday, out: &mut String) -> std::fmt::Result {
+        match &self.name_transform {
+            Some(transform) => out.push_str(&transform(holiday.name)),
+            None => out.push_str(holiday.name),
+        }
+        match self.country {
+            CountryLabel::None => Ok(()),
+            CountryLabel::Code => write!(out, " ({})", holiday.code),
+            CountryLabel::Name => write!(out, " ({})", holiday.code.name()),
+        }
+    }
+}
+
+impl std::fmt::Debug for HolidayFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HolidayFormat")
+            .field("order", &self.order)
+            .field("date_style", &self.date_style)
+            .field("weekday", &self.weekday)
+            .field("country", &self.country)
+            .field("separator", &self.separator)
+            .field("name_transform", &self.name_transform.is_some())
+            .finish()
+    }
+}
+
+impl Default for HolidayFormat {
+    fn default() -> Self {
+        HolidayFormat::DEFAULT
+    }
+}
+
+impl Holiday {
+    /// Renders the holiday as text in the `format`.
+    ///
+    /// See [`HolidayFormat`] for available options.
+    ///
+    /// ```
+    /// use holidays::{Country, HolidayFormat};
+    ///
+    /// for holiday in holidays::get_holidays(Country::US, 2025..2026) {
+    ///     println!("{}", holiday.render(&HolidayFormat::DEFAULT));
+    /// }
+    /// ```
+    pub fn render(&self, format: &HolidayFormat) -> String {
+        let mut out = String::new();
+        // writing into a string never fails
+        let _ = match format.order {
+            FieldOrder::DateFirst => format.write_date(self, &mut out).and_then(|_| {
+                out.push_str(format.separator);
+                format.write_name(self, &mut out)
+            }),
+            FieldOrder::NameFirst => format.write_name(self, &mut out).and_then(|_| {
+                out.push_str(format.separator);
+                format.write_date(self, &mut out)
+            }),
+        };
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Country;
+    use crate::Date;
+
+    fn independence_day() -> Holiday {
+        Holiday::new(Country::US, Date::from_ymd(2025, 7, 4), "Independence Day")
+    }
+
+    #[test]
+    fn options() {
+        let holiday = independence_day();
+        let render = |format: HolidayFormat| holiday.render(&format);
+
+        assert_eq!(
+            render(HolidayFormat::default()),
+            "2025-07-04: Independence Day"
+        );
+        assert_eq!(
+            render(HolidayFormat::DEFAULT.with_order(FieldOrder::NameFirst)),
+            "Independence Day: 2025-07-04"
+        );
+        assert_eq!(
+            render(HolidayFormat::DEFAULT.with_date_style(DateStyle::DayMonthName)),
+            "4 July 2025: Independence Day"
+        );
+        assert_eq!(
+            render(HolidayFormat::DEFAULT.with_weekday(true)),
+            "Friday, 2025-07-04: Independence Day"
+        );
+        assert_eq!(
+            render(HolidayFormat::DEFAULT.with_country(CountryLabel::Code)),
+            "2025-07-04: Independence Day (US)"
+        );
+        assert_eq!(
+            render(HolidayFormat::DEFAULT.with_country(CountryLabel::Name)),
+            "2025-07-04: Independence Day (United States)"
+        );
+        assert_eq!(
+            render(HolidayFormat::DEFAULT.with_separator(" - ")),
+            "2025-07-04 - Independence Day"
+        );
+    }
+
+    #[test]
+    fn name_transform() {
+        let format = HolidayFormat::DEFAULT
+            .with_order(FieldOrder::NameFirst)
+            .with_country(CountryLabel::Code);
+        assert_eq!(
+            independence_day().render(&format),
+            "Independence Day (US): 2025-07-04"
+        );
+        let format = format.with_name_transform(|name| name.to_uppercase());
+        assert_eq!(
+            independence_day().render(&format),
+            "INDEPENDENCE DAY (US): 2025-07-04"
+        );
+
+        // transforms can capture state, e.g. a translation table
+        let prefix = String::from("Día: ");
+        let format =
+            HolidayFormat::DEFAULT.with_name_transform(move |name| format!("{prefix}{name}"));
+        assert_eq!(
+            independence_day().render(&format),
+            "2025-07-04: Día: Independence Day"
+        );
+    }
+
+    #[test]
+    fn english_names() {
+        let names: Vec<_> = (1..=12)
+            .filter_map(crate::Month::new)
+            .map(crate::Month::name)
+            .collect();
+        assert_eq!(names.first(), Some(&"January"));
+        assert_eq!(names.last(), Some(&"December"));
+        assert_eq!(crate::Weekday::Sunday.name(), "Sunday");
+    }
+}
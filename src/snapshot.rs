@@ -0,0 +1,260 @@
+//! Owned query results.
+
+use crate::{Holiday, HolidayRef};
+
+/// Owned results of a query, created with [`Iter::snapshot`].
+///
+/// A snapshot only stores [handles](HolidayRef) of matched records, so it's
+/// cheap to create and clone, and being `Send + 'static` it can be moved into
+/// threads and tasks that outlive the query.
+///
+/// ```
+/// use holidays::Country;
+///
+/// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+///
+/// let names = std::thread::spawn(move || snapshot.into_iter().map(|it| it.name).collect::<Vec<_>>())
+///     .join()
+///     .unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(names.first(), Some(&"New Year's Day"));
+/// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, snapshots serialize as a sequence of records
+/// with `code`, `date` (in ISO 8601 format), `name` and `flags` fields, as
+/// handles aren't stable across builds. Deserialization looks records up by
+/// country, date and name, and fails if any isn't embedded.
+///
+/// [`Iter::snapshot`]: crate::Iter::snapshot
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct HolidaySnapshot {
+    handles: Vec<HolidayRef>,
+}
+
+impl HolidaySnapshot {
+    pub(crate) fn new(handles: impl Iterator<Item = HolidayRef>) -> Self {
+        HolidaySnapshot {
+            handles: handles.collect(),
+        }
+    }
+
+    /// Returns the number of records.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+    /// assert_eq!(snapshot.len(), holidays::get_holidays(Country::US, 2025..2026).count());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if there are no records.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+    /// assert!(!snapshot.is_empty());
+    /// assert!(holidays::get_holidays(Country::US, 1900).snapshot().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Returns the record at `index`, or `None` if it's out of bounds.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+    /// # #[cfg(not(feature = "no-names"))]
+    /// assert_eq!(snapshot.get(0).map(|it| it.name), Some("New Year's Day"));
+    /// assert_eq!(snapshot.get(snapshot.len()), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'static Holiday> {
+        self.handles.get(index).map(|it| it.get())
+    }
+
+    /// Returns handles of the records.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+    /// let first = snapshot.handles()[0];
+    /// assert_eq!(first.get(), snapshot.get(0).unwrap());
+    /// ```
+    pub fn handles(&self) -> &[HolidayRef] {
+        &self.handles
+    }
+
+    /// Returns an iterator over the records.
+    ///
+    /// ```
+    /// use holidays::Country;
+    ///
+    /// let snapshot = holidays::get_holidays(Country::US, 2025..2026).snapshot();
+    /// assert!(snapshot.iter().eq(holidays::get_holidays(Country::US, 2025..2026)));
+    /// // iterating doesn't consume the snapshot
+    /// assert_eq!(snapshot.iter().count(), snapshot.len());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &'static Holiday> + '_ {
+        self.into_iter()
+    }
+}
+
+type Resolve = fn(HolidayRef) -> &'static Holiday;
+
+impl IntoIterator for HolidaySnapshot {
+    type Item = &'static Holiday;
+    type IntoIter = std::iter::Map<std::vec::IntoIter<HolidayRef>, Resolve>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.handles.into_iter().map(HolidayRef::get as Resolve)
+    }
+}
+
+impl<'a> IntoIterator for &'a HolidaySnapshot {
+    type Item = &'static Holiday;
+    type IntoIter = std::iter::Map<std::iter::Copied<std::slice::Iter<'a, HolidayRef>>, Resolve>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.handles.iter().copied().map(HolidayRef::get as Resolve)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use crate::Country;
+    use crate::Date;
+    use serde::ser::SerializeStruct;
+
+    struct Record(&'static Holiday);
+
+    impl serde::Serialize for Record {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut record = serializer.serialize_struct("Holiday", 4)?;
+            record.serialize_field("code", self.0.code.as_ref())?;
+            record.serialize_field("date", &format_args!("{}", self.0.display_date()))?;
+            record.serialize_field("name", self.0.name)?;
+            record.serialize_field("flags", &self.0.flags().bits())?;
+            record.end()
+        }
+    }
+
+    impl serde::Serialize for HolidaySnapshot {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter().map(Record))
+        }
+    }
+
+    /// Fields that identify a record; the others are ignored.
+    #[derive(serde::Deserialize)]
+    struct Key {
+        code: String,
+        date: String,
+        name: String,
+    }
+
+    impl<'de> serde::Deserialize<'de> for HolidaySnapshot {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            use serde::de::Error;
+
+            let handles = Vec::<Key>::deserialize(deserializer)?
+                .into_iter()
+                .map(|key| {
+                    let code: Country = key.code.parse().map_err(D::Error::custom)?;
+                    let date: Date = key.date.parse().map_err(D::Error::custom)?;
+                    crate::data::country_date_to_indices(code, date)
+                        .find(|it| crate::data::DATA[*it].name == key.name)
+                        .map(HolidayRef::from_index)
+                        .ok_or_else(|| {
+                            D::Error::custom(format!(
+                                "no {code} holiday {:?} on {}",
+                                key.name, key.date
+                            ))
+                        })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(HolidaySnapshot { handles })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Any, Country};
+
+    #[test]
+    fn snapshot_matches_query() {
+        let snapshot = crate::get_holidays([Country::US, Country::JP], 2025..2026).snapshot();
+        let live: Vec<_> = crate::get_holidays([Country::US, Country::JP], 2025..2026).collect();
+
+        assert_eq!(snapshot.len(), live.len());
+        assert!(!snapshot.is_empty());
+        assert!(snapshot.iter().eq(live.iter().copied()));
+        assert_eq!(snapshot.get(0), live.first().copied());
+        assert_eq!(snapshot.get(live.len()), None);
+        assert!(crate::get_holidays(Any, 1900..1901).snapshot().is_empty());
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let snapshot = crate::get_holidays(Country::DE, 2025..2026).snapshot();
+        let clone = snapshot.clone();
+        let count = snapshot.into_iter().count();
+
+        assert_eq!(clone.len(), count);
+        assert!((&clone)
+            .into_iter()
+            .eq(crate::get_holidays(Country::DE, 2025..2026)));
+    }
+
+    #[test]
+    fn static_type_interface() {
+        // This test pins down that snapshots can be moved into spawned tasks.
+        // It's failing if it doesn't compile.
+        fn spawn<F, R>(task: F) -> R
+        where
+            F: FnOnce() -> R + Send + 'static,
+            R: Send + 'static,
+        {
+            std::thread::spawn(task).join().unwrap()
+        }
+
+        let snapshot = crate::get_holidays(Country::GB, 2025..2026).snapshot();
+        let expected = snapshot.len();
+        assert_eq!(spawn(move || snapshot.iter().count()), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use crate::Date;
+        use crate::HolidaySnapshot;
+
+        let snapshot = crate::get_holidays(Country::US, Date::from_ymd(2025, 7, 4)).snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let name = if cfg!(feature = "no-names") {
+            ""
+        } else {
+            "Independence Day"
+        };
+        assert_eq!(
+            json,
+            format!(r#"[{{"code":"US","date":"2025-07-04","name":"{name}","flags":0}}]"#)
+        );
+        assert_eq!(
+            serde_json::from_str::<HolidaySnapshot>(&json).unwrap(),
+            snapshot
+        );
+
+        let missing = r#"[{"code":"US","date":"2025-07-05","name":"","flags":0}]"#;
+        assert!(serde_json::from_str::<HolidaySnapshot>(missing).is_err());
+    }
+}
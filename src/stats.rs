@@ -0,0 +1,493 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::country::Country;
+use crate::date::{Date, DateConversionError};
+use crate::query::Iter;
+use crate::Holiday;
+
+/// Axis along which holidays are bucketed by [`histogram`].
+///
+/// ```
+/// use holidays::{Axis, Country};
+///
+/// let by_weekday = holidays::histogram(Country::US, 2025..2026, Axis::Weekday);
+/// let mondays = by_weekday.iter().find(|(day, _)| *day == 1).map_or(0, |(_, count)| *count);
+/// assert!(mondays >= 5);
+/// let total: usize = by_weekday.iter().map(|(_, count)| count).sum();
+/// assert_eq!(total, holidays::get_holidays(Country::US, 2025..2026).count());
+/// ```
+///
+/// [`histogram`]: crate::histogram
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Buckets labeled by month number in `1..=12`.
+    Month,
+    /// Buckets labeled by ISO 8601 weekday number, `1` for Monday through `7`
+    /// for Sunday.
+    Weekday,
+    /// Buckets labeled by year.
+    Year,
+}
+
+/// Statistics of holidays observed in a single country within a date
+/// selection.
+///
+/// Returned by [`summary`].
+///
+/// ```
+/// use holidays::{Country, Date};
+///
+/// let summaries = holidays::summary([Country::US, Country::GB], 2025..2026);
+/// for it in &summaries {
+///     if let Some(first) = it.first::<Date>() {
+///         println!("{}: {} holidays, the first on {}", it.country, it.count, first?);
+///     }
+/// }
+/// let total: usize = summaries.iter().map(|it| it.count).sum();
+/// assert_eq!(total, holidays::get_holidays([Country::US, Country::GB], 2025..2026).count());
+/// # Ok::<(), holidays::error::DateConversionError>(())
+/// ```
+///
+/// [`summary`]: crate::summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CountrySummary {
+    /// The `Country` these statistics describe.
+    pub country: Country,
+    /// Number of observed holidays.
+    pub count: usize,
+    /// Date of the first observed holiday.
+    first: Option<Date>,
+    /// Date of the last observed holiday.
+    last: Option<Date>,
+    /// Number of distinct holiday names.
+    pub unique_names: usize,
+}
+
+impl CountrySummary {
+    pub(crate) fn new(country: Country, indices: &'static [usize]) -> Self {
+        let mut names: Vec<&'static str> = indices
+            .iter()
+            .map(|it| crate::data::DATA[*it].name)
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+
+        CountrySummary {
+            country,
+            count: indices.len(),
+            first: indices.first().map(|it| crate::data::DATA[*it].date),
+            last: indices.last().map(|it| crate::data::DATA[*it].date),
+            unique_names: names.len(),
+        }
+    }
+
+    /// Returns the date of the first observed holiday in specified format, or
+    /// `None` if no holidays were observed.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let summary = holidays::summary(Country::FR, 2025..2026);
+    /// assert_eq!(summary[0].first::<Date>(), Some(Ok(Date::from_ymd(2025, 1, 1))));
+    /// ```
+    pub fn first<D>(&self) -> Option<Result<D, DateConversionError>>
+    where
+        D: TryFrom<Date>,
+    {
+        self.first
+            .map(|it| <D as TryFrom<Date>>::try_from(it).map_err(|_| DateConversionError))
+    }
+
+    /// Returns the date of the last observed holiday in specified format, or
+    /// `None` if no holidays were observed.
+    ///
+    /// ```
+    /// # use holidays::Date;
+    /// use holidays::Country;
+    ///
+    /// let summary = holidays::summary(Country::US, 2025..2026);
+    /// assert_eq!(summary[0].last::<Date>(), Some(Ok(Date::from_ymd(2025, 12, 25))));
+    /// ```
+    pub fn last<D>(&self) -> Option<Result<D, DateConversionError>>
+    where
+        D: TryFrom<Date>,
+    {
+        self.last
+            .map(|it| <D as TryFrom<Date>>::try_from(it).map_err(|_| DateConversionError))
+    }
+}
+
+pub(crate) fn histogram(holidays: Iter, axis: Axis) -> Vec<(u32, usize)> {
+    match axis {
+        Axis::Month => {
+            let mut counts = [0; 12];
+            for holiday in holidays {
+                counts[holiday.date.month() as usize - 1] += 1;
+            }
+            (1..).zip(counts).collect()
+        }
+        Axis::Weekday => {
+            let mut counts = [0; 7];
+            for holiday in holidays {
+                counts[holiday.date.weekday().number_from_monday() as usize - 1] += 1;
+            }
+            (1..).zip(counts).collect()
+        }
+        Axis::Year => {
+            // results are sorted by date, so equal years are always adjacent
+            let mut counts: Vec<(u32, usize)> = Vec::new();
+            for holiday in holidays {
+                let year = holiday.date.year() as u32;
+                match counts.last_mut() {
+                    Some((last, count)) if *last == year => *count += 1,
+                    _ => counts.push((year, 1)),
+                }
+            }
+            counts
+        }
+    }
+}
+
+/// Differences between holidays of a country in a year and in the year
+/// before it.
+///
+/// Returned by [`year_over_year`]. Holidays are matched by their
+/// [normalized](crate::normalize_name) names, and a name observed multiple
+/// times a year is matched in order of dates. All lists are sorted by date.
+///
+/// [`year_over_year`]: crate::year_over_year
+///
+/// ```
+/// use holidays::Country;
+///
+/// let delta = holidays::year_over_year(Country::DE, 2026)?;
+/// for moved in &delta.moved {
+///     let (from, to) = (moved.previous.display_date(), moved.current.display_date());
+///     println!("{}: {from} -> {to}", moved.name);
+/// }
+/// # #[cfg(not(feature = "no-names"))]
+/// assert!(delta.moved.iter().any(|it| it.name == "Good Friday"));
+/// # Ok::<(), holidays::error::YearOutOfRange>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct YearDelta {
+    /// The `Country` this delta describes.
+    pub country: Country,
+    /// The year compared with the year before it.
+    pub year: i64,
+    /// Holidays of `year` with a name that isn't observed in the previous
+    /// year.
+    pub added: Vec<&'static Holiday>,
+    /// Holidays of the previous year with a name that isn't observed in
+    /// `year`.
+    pub removed: Vec<&'static Holiday>,
+    /// Holidays observed in both years on a different month or day.
+    pub moved: Vec<MovedHoliday>,
+}
+
+/// Holiday observed in consecutive years on different days, see
+/// [`YearDelta::moved`].
+///
+/// ```
+/// use holidays::Country;
+///
+/// let delta = holidays::year_over_year(Country::US, 2026)?;
+/// # #[cfg(not(feature = "no-names"))]
+/// let thanksgiving = delta.moved.iter().find(|it| it.name == "Thanksgiving Day").unwrap();
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(thanksgiving.previous.display_date().to_string(), "2025-11-27");
+/// # #[cfg(not(feature = "no-names"))]
+/// assert_eq!(thanksgiving.current.display_date().to_string(), "2026-11-26");
+/// # Ok::<(), holidays::error::YearOutOfRange>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MovedHoliday {
+    /// Name of the holiday, as it appears in `current`.
+    pub name: &'static str,
+    /// The record of the previous year.
+    pub previous: &'static Holiday,
+    /// The record of the compared year.
+    pub current: &'static Holiday,
+}
+
+/// Returns holidays of `country` in `year`, keyed by their normalized
+/// names, ordered by date.
+fn names_in_year(country: Country, year: i64) -> HashMap<Cow<'static, str>, Vec<&'static Holiday>> {
+    let mut names: HashMap<_, Vec<_>> = HashMap::new();
+    for holiday in crate::get_holidays(country, year..year + 1) {
+        names
+            .entry(crate::normalize_name(holiday.name))
+            .or_default()
+            .push(holiday);
+    }
+    names
+}
+
+pub(crate) fn year_over_year(country: Country, year: i64) -> YearDelta {
+    let mut current = names_in_year(country, year);
+    let previous = names_in_year(country, year - 1);
+    let mut delta = YearDelta {
+        country,
+        year,
+        added: Vec::new(),
+        removed: Vec::new(),
+        moved: Vec::new(),
+    };
+
+    for (key, before) in previous {
+        let after = current.remove(&key).unwrap_or_default();
+        for (&previous, &current) in before.iter().zip(&after) {
+            let day = |it: &Holiday| (it.date.month(), it.date.day());
+            if day(previous) != day(current) {
+                delta.moved.push(MovedHoliday {
+                    name: current.name,
+                    previous,
+                    current,
+                });
+            }
+        }
+        delta.removed.extend(before.iter().skip(after.len()));
+        delta.added.extend(after.iter().skip(before.len()));
+    }
+    delta.added.extend(current.into_values().flatten());
+
+    for records in [&mut delta.added, &mut delta.removed] {
+        records.sort_by_key(|it| (it.date, it.name));
+    }
+    delta.moved.sort_by_key(|it| (it.current.date, it.name));
+    delta
+}
+
+/// Returns the Jaccard index of dates of two sorted slices of `DATA`
+/// indices, each holding records of a single country.
+pub(crate) fn jaccard(a: &[usize], b: &[usize]) -> f64 {
+    // a country can observe multiple records a day, so each date is only
+    // counted once
+    fn dates(indices: &[usize]) -> std::iter::Peekable<impl Iterator<Item = crate::Date> + '_> {
+        let mut last = None;
+        let dates = indices.iter().map(|it| crate::data::DATA[*it].date);
+        dates
+            .filter(move |it| last.replace(*it) != Some(*it))
+            .peekable()
+    }
+    let (mut a, mut b) = (dates(a), dates(b));
+    let (mut shared, mut union) = (0, 0);
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+        match x.cmp(y) {
+            std::cmp::Ordering::Less => {
+                a.next();
+            }
+            std::cmp::Ordering::Greater => {
+                b.next();
+            }
+            std::cmp::Ordering::Equal => {
+                shared += 1;
+                a.next();
+                b.next();
+            }
+        }
+        union += 1;
+    }
+    union += a.count() + b.count();
+    if union == 0 {
+        // calendars without any holidays are the same
+        return 1.0;
+    }
+    shared as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Country;
+    use crate::Date;
+
+    #[test]
+    fn month_histogram() {
+        let histogram = crate::histogram(Country::US, 2025..2026, Axis::Month);
+
+        assert_eq!(
+            histogram,
+            [
+                (1, 2),
+                (2, 1),
+                (3, 0),
+                (4, 0),
+                (5, 1),
+                (6, 1),
+                (7, 1),
+                (8, 0),
+                (9, 1),
+                (10, 1),
+                (11, 2),
+                (12, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn buckets_sum_to_count() {
+        let countries = [Country::DE, Country::JP, Country::BR];
+        let range = Date::from_ymd(2010, 6, 1)..Date::from_ymd(2020, 6, 1);
+        let count = crate::get_holidays(countries, range.clone()).count();
+
+        for axis in [Axis::Month, Axis::Weekday, Axis::Year] {
+            let histogram = crate::histogram(countries, range.clone(), axis);
+            let total: usize = histogram.iter().map(|(_, count)| count).sum();
+            assert_eq!(total, count, "{axis:?}");
+        }
+
+        let years = crate::histogram(countries, range, Axis::Year);
+        let labels: Vec<_> = years.iter().map(|(year, _)| *year).collect();
+        assert_eq!(labels, (2010..=2020).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn summary_matches_queries() {
+        let countries = [Country::US, Country::DE, Country::JP, Country::ZW];
+        let windows = [
+            Date::from_ymd(2025, 1, 1)..Date::from_ymd(2026, 1, 1),
+            Date::from_ymd(2003, 5, 17)..Date::from_ymd(2011, 2, 2),
+            Date::from_ymd(1950, 1, 1)..Date::from_ymd(1960, 1, 1),
+        ];
+
+        for window in windows {
+            let summary = crate::summary(countries, window.clone());
+            assert_eq!(summary.len(), countries.len());
+
+            for (summary, country) in summary.into_iter().zip(countries) {
+                let holidays: Vec<_> = crate::get_holidays(country, window.clone()).collect();
+                let mut names: Vec<_> = holidays.iter().map(|it| it.name).collect();
+                names.sort();
+                names.dedup();
+
+                assert_eq!(summary.country, country);
+                assert_eq!(summary.count, holidays.len());
+                assert_eq!(summary.unique_names, names.len());
+                assert_eq!(
+                    summary.first::<Date>().transpose(),
+                    holidays.first().map(|it| it.date()).transpose()
+                );
+                assert_eq!(
+                    summary.last::<Date>().transpose(),
+                    holidays.last().map(|it| it.date()).transpose()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn similarity_scores() {
+        let window = Date::from_ymd(2015, 1, 1)..Date::from_ymd(2025, 1, 1);
+        let countries = [
+            Country::US,
+            Country::CA,
+            Country::GB,
+            Country::DE,
+            Country::JP,
+        ];
+        for a in countries {
+            assert_eq!(crate::similarity(a, a, window.clone()), 1.0);
+            for b in countries {
+                let score = crate::similarity(a, b, window.clone());
+                assert_eq!(score, crate::similarity(b, a, window.clone()), "{a} {b}");
+
+                let dates = |country| -> std::collections::HashSet<Date> {
+                    crate::get_holidays(country, window.clone())
+                        .map(|it| it.date)
+                        .collect()
+                };
+                let (a, b) = (dates(a), dates(b));
+                let expected = a.intersection(&b).count() as f64 / a.union(&b).count() as f64;
+                assert_eq!(score, expected);
+            }
+        }
+
+        // Independence Day and Marine Day
+        let july = Date::from_ymd(2025, 7, 1)..Date::from_ymd(2025, 8, 1);
+        assert_eq!(
+            crate::similarity(Country::US, Country::JP, july.clone()),
+            0.0
+        );
+        let empty = Date::from_ymd(2025, 7, 1)..Date::from_ymd(2025, 7, 1);
+        assert_eq!(crate::similarity(Country::US, Country::JP, empty), 1.0);
+    }
+
+    #[test]
+    fn most_similar_ranking() {
+        let candidates = [Country::JP, Country::CA, Country::GB, Country::US];
+        let ranked = crate::most_similar(Country::US, candidates, 2020..2025, 10);
+        assert_eq!(ranked.len(), 3);
+        assert!(ranked.iter().all(|(it, _)| *it != Country::US));
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        for (country, score) in &ranked {
+            assert_eq!(*score, crate::similarity(Country::US, *country, 2020..2025));
+        }
+
+        let top = crate::most_similar(Country::US, candidates, 2020..2025, 1);
+        assert_eq!(top[..], ranked[..1]);
+        assert!(crate::most_similar(Country::US, candidates, 2020..2025, 0).is_empty());
+
+        // equal scores are ordered by country code
+        let ties = crate::most_similar(Country::US, [Country::JP, Country::GB], 2025..2025, 2);
+        assert_eq!(ties, [(Country::GB, 1.0), (Country::JP, 1.0)]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-names"))]
+    fn year_over_year_deltas() {
+        let delta = crate::year_over_year(Country::DE, 2026).unwrap();
+        assert_eq!((delta.country, delta.year), (Country::DE, 2026));
+        let easter = delta
+            .moved
+            .iter()
+            .find(|it| it.name == "Easter Monday")
+            .unwrap();
+        assert_eq!(easter.previous.date, Date::from_ymd(2025, 4, 21));
+        assert_eq!(easter.current.date, Date::from_ymd(2026, 4, 6));
+
+        // fixed dates are neither moved, added nor removed
+        let christmas = |it: &&Holiday| it.name.contains("Christmas Day");
+        assert!(!delta.moved.iter().any(|it| christmas(&it.current)));
+        assert!(!delta.added.iter().any(christmas) && !delta.removed.iter().any(christmas));
+
+        for country in [Country::US, Country::GB, Country::JP, Country::BR] {
+            for year in 2001..2035 {
+                let delta = crate::year_over_year(country, year).unwrap();
+                assert!(delta
+                    .added
+                    .iter()
+                    .all(|it| it.date.year() == year && it.code == country));
+                assert!(delta.removed.iter().all(|it| it.date.year() == year - 1));
+                for moved in &delta.moved {
+                    assert_eq!(moved.previous.date.year(), year - 1);
+                    assert_eq!(moved.current.date.year(), year);
+                    assert_eq!(moved.current.name, moved.name);
+                    let day = |it: &Holiday| (it.date.month(), it.date.day());
+                    assert_ne!(day(moved.previous), day(moved.current));
+                }
+                assert!(delta
+                    .moved
+                    .windows(2)
+                    .all(|w| w[0].current.date <= w[1].current.date));
+            }
+        }
+    }
+
+    #[test]
+    fn year_over_year_bounds() {
+        let coverage = Country::DE.coverage();
+        assert_eq!(
+            crate::year_over_year(Country::DE, coverage.first_year),
+            Err(crate::error::YearOutOfRange)
+        );
+        assert!(crate::year_over_year(Country::DE, coverage.first_year + 1).is_ok());
+        assert!(crate::year_over_year(Country::DE, coverage.last_year).is_ok());
+        assert!(crate::year_over_year(Country::DE, coverage.last_year + 1).is_err());
+        assert!(crate::year_over_year(Country::DE, crate::CoveredYear::MIN.get() - 1).is_err());
+        let last = crate::CoveredYear::try_from(coverage.last_year).unwrap();
+        assert!(crate::year_over_year(Country::DE, last).is_ok());
+    }
+}
@@ -0,0 +1,237 @@
+//! Utilities for testing allocation behavior of code that queries holidays.
+//!
+//! Requires the `test-support` feature.
+//!
+//! [`CountingAllocator`] counts allocations made by each thread, so it can
+//! be installed as the global allocator of a test binary whose tests run in
+//! parallel:
+//!
+//! ```
+//! use holidays::test_support::{count_allocations, CountingAllocator};
+//! use holidays::Country;
+//! # use holidays::Date;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//!
+//! let (holiday, allocations) =
+//!     count_allocations(|| holidays::is_holiday(Country::US, Date::from_ymd(2025, 7, 4)));
+//! assert!(holiday);
+//! assert_eq!(allocations, 0);
+//! ```
+//!
+//! # Guarantees
+//!
+//! The following queries don't allocate at all, from constructing the query
+//! through exhausting its results:
+//!
+//! - queries of an exact date, for any countries, including [`is_holiday`];
+//! - queries of a single country, with or without a date range;
+//! - queries of a date range with the default [`Strategy`], for any
+//!   countries;
+//! - [`Iter::is_empty`] and [`Iter::any_on`] of any query without month or
+//!   custom filters, as they don't advance it.
+//!
+//! The remaining allocating paths, with counts measured on the current data,
+//! are:
+//!
+//! - queries of multiple countries without a date range, or with
+//!   [`Strategy::ForcePerCountry`], when fewer than half of all countries are
+//!   selected: 2 allocations when the first result is requested, for the merge
+//!   heap and per-country cursors;
+//! - [`Iter::collapse_shared`]: grows a queue of records sharing the date,
+//!   3 allocations for all holidays on New Year's Day 2025;
+//! - [`Iter::with_filter`]: 2 allocations per filter when it's applied, for
+//!   the filter and the filtered iterator.
+//!
+//! [`is_holiday`]: crate::is_holiday
+//! [`Strategy`]: crate::Strategy
+//! [`Strategy::ForcePerCountry`]: crate::Strategy::ForcePerCountry
+//! [`Iter::collapse_shared`]: crate::Iter::collapse_shared
+//! [`Iter::with_filter`]: crate::Iter::with_filter
+//! [`Iter::is_empty`]: crate::Iter::is_empty
+//! [`Iter::any_on`]: crate::Iter::any_on
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Global allocator that counts allocations of each thread and delegates to
+/// [`System`].
+///
+/// Reallocations count as allocations, while deallocations aren't counted.
+///
+/// ```
+/// use holidays::test_support::CountingAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+///
+/// let results: Vec<_> = holidays::get_holidays(holidays::Country::US, 2025..2026).collect();
+/// assert!(!results.is_empty());
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record() {
+        // the counter is unavailable while the thread is being torn down
+        let _ = ALLOCATIONS.try_with(|it| it.set(it.get() + 1));
+    }
+}
+
+// SAFETY: all calls are forwarded to `System` unchanged
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record();
+        // SAFETY: caller upholds `GlobalAlloc::alloc` contract
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::record();
+        // SAFETY: caller upholds `GlobalAlloc::alloc_zeroed` contract
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::record();
+        // SAFETY: caller upholds `GlobalAlloc::realloc` contract
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: caller upholds `GlobalAlloc::dealloc` contract
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+/// Returns the result of `f` and the number of allocations it made on the
+/// current thread.
+///
+/// Always counts `0` unless [`CountingAllocator`] is the global allocator.
+///
+/// ```
+/// use holidays::test_support::{count_allocations, CountingAllocator};
+/// use holidays::Country;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+///
+/// // size hints of single country queries are exact, so the vector is
+/// // allocated only once
+/// let (results, allocations) =
+///     count_allocations(|| holidays::get_holidays(Country::US, 2025..2026).collect::<Vec<_>>());
+/// assert!(!results.is_empty());
+/// assert_eq!(allocations, 1);
+/// ```
+pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+    use crate::{Any, Country, CountrySet, PreparedQuery, QueryOptions, Strategy};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocations<R>(f: impl FnOnce() -> R) -> usize {
+        count_allocations(f).1
+    }
+
+    #[test]
+    fn counts_allocations() {
+        assert_eq!(allocations(|| Vec::<u8>::with_capacity(8)), 1);
+        assert_eq!(allocations(|| ()), 0);
+    }
+
+    #[test]
+    fn exact_dates_dont_allocate() {
+        let date = Date::from_ymd(2025, 1, 1);
+        assert_eq!(allocations(|| crate::is_holiday(Country::US, date)), 0);
+        assert_eq!(
+            allocations(|| crate::get_holidays(Country::US, date).count()),
+            0
+        );
+        assert_eq!(allocations(|| crate::get_holidays(Any, date).count()), 0);
+        assert_eq!(
+            allocations(|| crate::get_holidays([Country::US, Country::DE], date).count()),
+            0
+        );
+    }
+
+    #[test]
+    fn single_countries_dont_allocate() {
+        let range = Date::from_ymd(2025, 1, 1)..Date::from_ymd(2027, 1, 1);
+        for strategy in [
+            Strategy::Auto,
+            Strategy::ForcePerCountry,
+            Strategy::ForceRangeScan,
+        ] {
+            let options = QueryOptions::DEFAULT.with_strategy(strategy);
+            for query in [
+                PreparedQuery::new(Country::US, range.clone()),
+                PreparedQuery::new::<_, Date, _>(Country::US, Any),
+            ] {
+                let query = query.with_options(options);
+                assert_eq!(allocations(|| query.iter().count()), 0, "{strategy:?}");
+                assert_eq!(allocations(|| query.iter().last()), 0, "{strategy:?}");
+            }
+        }
+        assert_eq!(
+            allocations(|| crate::is_holiday(Country::US, range.clone())),
+            0
+        );
+    }
+
+    #[test]
+    fn date_ranges_dont_allocate() {
+        let countries: CountrySet = [Country::US, Country::DE, Country::JP]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            allocations(|| crate::get_holidays(&countries, 2025..2027).count()),
+            0
+        );
+        assert_eq!(
+            allocations(|| crate::get_holidays(Any, 2025..2027).count()),
+            0
+        );
+    }
+
+    #[test]
+    fn documented_allocations() {
+        let countries: CountrySet = [Country::US, Country::DE, Country::JP]
+            .into_iter()
+            .collect();
+        let query = PreparedQuery::new::<_, Date, _>(&countries, Any);
+        assert_eq!(allocations(|| query.iter()), 0);
+        assert_eq!(allocations(|| query.iter().for_each(drop)), 2);
+        // exact lengths are counted without the merge state
+        assert_eq!(allocations(|| query.iter().count()), 0);
+
+        // groups depend on names of the dataset, so only their bound is
+        // documented
+        let new_year = Date::from_ymd(2025, 1, 1);
+        assert!(allocations(|| crate::get_holidays(Any, new_year).collapse_shared().count()) <= 3);
+
+        // lookups don't need the merge state; that `any_on` doesn't walk
+        // results is checked by its plan in `query` tests
+        let christmas = Date::from_ymd(2025, 12, 25);
+        assert_eq!(allocations(|| query.iter().is_empty()), 0);
+        assert_eq!(allocations(|| query.iter().any_on(christmas)), 0);
+
+        let filter = crate::filter::OnWeekday(crate::Weekday::Monday);
+        let iter = crate::get_holidays(Country::US, 2025..2026);
+        let filtered = allocations(|| iter.clone().with_filter(filter));
+        assert_eq!(filtered, 2);
+        assert_eq!(allocations(|| iter.with_filter(filter).count()), 2);
+    }
+}
@@ -0,0 +1,321 @@
+//! Working days of the week used by business day helpers.
+
+use crate::date::Weekday;
+
+/// Set of working days of the week.
+///
+/// The crate has no per-country weekend data yet, so the default workweek,
+/// used where `None` is given instead of a workweek, is
+/// [`Workweek::MON_FRI`] for all countries.
+///
+/// ```
+/// use holidays::workweek::Workweek;
+/// use holidays::Weekday;
+///
+/// const FOUR_DAYS: Workweek = match Workweek::from_days(&[
+///     Weekday::Monday,
+///     Weekday::Tuesday,
+///     Weekday::Wednesday,
+///     Weekday::Thursday,
+/// ]) {
+///     Ok(it) => it,
+///     Err(_) => panic!("empty workweek"),
+/// };
+///
+/// assert!(FOUR_DAYS.contains(Weekday::Thursday));
+/// assert!(!FOUR_DAYS.contains(Weekday::Friday));
+/// assert_eq!(FOUR_DAYS.working_days(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "u8", try_from = "u8")
+)]
+pub struct Workweek(u8);
+
+impl Workweek {
+    /// Monday through Friday.
+    ///
+    /// ```
+    /// use holidays::{Weekday, Workweek};
+    ///
+    /// assert!(Workweek::MON_FRI.contains(Weekday::Friday));
+    /// assert!(!Workweek::MON_FRI.contains(Weekday::Saturday));
+    /// assert_eq!(Workweek::MON_FRI, Workweek::default());
+    /// ```
+    pub const MON_FRI: Workweek = Workweek(0b0011_1110);
+    /// Sunday through Thursday.
+    ///
+    /// ```
+    /// use holidays::{Weekday, Workweek};
+    ///
+    /// assert!(Workweek::SUN_THU.contains(Weekday::Sunday));
+    /// assert!(!Workweek::SUN_THU.contains(Weekday::Friday));
+    /// ```
+    pub const SUN_THU: Workweek = Workweek(0b1001_1110);
+
+    /// Bits of all weekdays; bit `n` is set for ISO 8601 weekday `n`.
+    const ALL_DAYS: u8 = 0b1111_1110;
+
+    /// Constructs a workweek from its working `days`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyWorkweek`] if there are no working `days`, as business
+    /// days could never be found.
+    ///
+    /// ```
+    /// use holidays::{Weekday, Workweek};
+    ///
+    /// let workweek = Workweek::from_days(&[Weekday::Monday, Weekday::Wednesday, Weekday::Friday])?;
+    /// assert_eq!(workweek.working_days(), 3);
+    /// assert!(Workweek::from_days(&[]).is_err());
+    /// # Ok::<(), holidays::error::EmptyWorkweek>(())
+    /// ```
+    pub const fn from_days(days: &[Weekday]) -> Result<Self, EmptyWorkweek> {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < days.len() {
+            bits |= 1 << days[i].number_from_monday();
+            i += 1;
+        }
+        Self::from_bits(bits)
+    }
+
+    /// Constructs a workweek from a bit mask with bit `n` set for each
+    /// working ISO 8601 weekday `n`, i.e. bit `1` for Monday through bit `7`
+    /// for Sunday.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyWorkweek`] if no weekday bits are set. Other bits are
+    /// ignored.
+    ///
+    /// ```
+    /// use holidays::{Weekday, Workweek};
+    ///
+    /// assert_eq!(Workweek::from_bits(0b0011_1110), Ok(Workweek::MON_FRI));
+    /// // bit `0` isn't a weekday
+    /// assert!(Workweek::from_bits(0b0000_0001).is_err());
+    ///
+    /// let weekends = Workweek::from_bits(0b1100_0000)?;
+    /// assert!(weekends.contains(Weekday::Saturday));
+    /// # Ok::<(), holidays::error::EmptyWorkweek>(())
+    /// ```
+    pub const fn from_bits(bits: u8) -> Result<Self, EmptyWorkweek> {
+        match bits & Self::ALL_DAYS {
+            0 => Err(EmptyWorkweek),
+            bits => Ok(Workweek(bits)),
+        }
+    }
+
+    /// Returns the bit mask of working days, as accepted by
+    /// [`Workweek::from_bits`].
+    ///
+    /// ```
+    /// use holidays::Workweek;
+    ///
+    /// let bits = Workweek::SUN_THU.bits();
+    /// assert_eq!(Workweek::from_bits(bits), Ok(Workweek::SUN_THU));
+    /// ```
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if `day` is a working day.
+    ///
+    /// ```
+    /// use holidays::{Weekday, Workweek};
+    ///
+    /// assert!(Workweek::MON_FRI.contains(Weekday::Monday));
+    /// assert!(!Workweek::MON_FRI.contains(Weekday::Sunday));
+    /// ```
+    pub const fn contains(self, day: Weekday) -> bool {
+        (self.0 >> day.number_from_monday()) & 1 == 1
+    }
+
+    /// Returns the number of working days in a week; at least `1`.
+    ///
+    /// ```
+    /// use holidays::Workweek;
+    ///
+    /// assert_eq!(Workweek::MON_FRI.working_days(), 5);
+    /// ```
+    pub const fn working_days(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the position of `day` relative to the weekend, i.e. days that
+    /// aren't working.
+    ///
+    /// ```
+    /// use holidays::{Weekday, WeekendRelation, Workweek};
+    ///
+    /// let workweek = Workweek::SUN_THU;
+    /// assert_eq!(workweek.weekend_relation(Weekday::Thursday), WeekendRelation::AdjacentToWeekend);
+    /// assert_eq!(workweek.weekend_relation(Weekday::Tuesday), WeekendRelation::Weekday);
+    /// assert_eq!(workweek.weekend_relation(Weekday::Friday), WeekendRelation::OnWeekend);
+    /// ```
+    pub const fn weekend_relation(self, day: Weekday) -> WeekendRelation {
+        let number = day.number_from_monday();
+        let previous = (number + 5) % 7 + 1;
+        let next = number % 7 + 1;
+        if (self.0 >> number) & 1 == 0 {
+            WeekendRelation::OnWeekend
+        } else if (self.0 >> previous) & 1 == 0 || (self.0 >> next) & 1 == 0 {
+            WeekendRelation::AdjacentToWeekend
+        } else {
+            WeekendRelation::Weekday
+        }
+    }
+}
+
+/// Position of a day relative to the weekend of a [`Workweek`].
+///
+/// Returned by [`Holiday::weekend_relation`](crate::Holiday::weekend_relation),
+/// e.g. to tell holidays that make long weekends apart from the rest.
+///
+/// ```
+/// use holidays::{Country, WeekendRelation, Workweek};
+///
+/// // Monday holidays and Independence Day on Friday make long weekends
+/// let long_weekends = holidays::get_holidays(Country::US, 2025..2026)
+///     .filter(|it| it.weekend_relation(Workweek::MON_FRI) == WeekendRelation::AdjacentToWeekend)
+///     .count();
+/// assert!(long_weekends >= 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WeekendRelation {
+    /// Working day that isn't next to the weekend, e.g. Wednesday.
+    Weekday,
+    /// Working day right before or after the weekend, e.g. Friday or
+    /// Monday.
+    AdjacentToWeekend,
+    /// Day of the weekend, e.g. Saturday or Sunday.
+    OnWeekend,
+}
+
+impl Default for Workweek {
+    fn default() -> Self {
+        Workweek::MON_FRI
+    }
+}
+
+// `None` stands for the default workweek in business day helpers
+impl From<Option<Workweek>> for Workweek {
+    fn from(value: Option<Workweek>) -> Self {
+        value.unwrap_or_default()
+    }
+}
+
+impl From<Workweek> for u8 {
+    fn from(value: Workweek) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u8> for Workweek {
+    type Error = EmptyWorkweek;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Workweek::from_bits(value)
+    }
+}
+
+impl TryFrom<&[Weekday]> for Workweek {
+    type Error = EmptyWorkweek;
+
+    fn try_from(value: &[Weekday]) -> Result<Self, Self::Error> {
+        Workweek::from_days(value)
+    }
+}
+
+/// Error returned when constructing a [`Workweek`] without any working days.
+///
+/// ```
+/// use holidays::error::EmptyWorkweek;
+/// use holidays::Workweek;
+///
+/// assert_eq!(Workweek::from_days(&[]), Err(EmptyWorkweek));
+/// assert_eq!(Workweek::try_from(0u8), Err(EmptyWorkweek));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyWorkweek;
+crate::error::error_msg!(EmptyWorkweek, "Workweek has no working days");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        use Weekday::*;
+
+        assert_eq!(
+            Workweek::from_days(&[Monday, Tuesday, Wednesday, Thursday, Friday]),
+            Ok(Workweek::MON_FRI)
+        );
+        assert_eq!(
+            Workweek::from_days(&[Thursday, Sunday, Monday, Tuesday, Wednesday]),
+            Ok(Workweek::SUN_THU)
+        );
+        assert_eq!(
+            Workweek::from_days(&[Monday, Monday]).map(Workweek::working_days),
+            Ok(1)
+        );
+        assert_eq!(
+            Workweek::try_from(Workweek::SUN_THU.bits()),
+            Ok(Workweek::SUN_THU)
+        );
+
+        assert!(Workweek::MON_FRI.contains(Friday) && !Workweek::MON_FRI.contains(Sunday));
+        assert!(Workweek::SUN_THU.contains(Sunday) && !Workweek::SUN_THU.contains(Friday));
+    }
+
+    #[test]
+    fn weekend_relations() {
+        use Weekday::*;
+        use WeekendRelation::{AdjacentToWeekend as Adjacent, OnWeekend as Off, Weekday as Mid};
+
+        let days = [
+            Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+        ];
+        let relations = |workweek: Workweek| days.map(|it| workweek.weekend_relation(it));
+        assert_eq!(
+            relations(Workweek::MON_FRI),
+            [Adjacent, Mid, Mid, Mid, Adjacent, Off, Off]
+        );
+        assert_eq!(
+            relations(Workweek::SUN_THU),
+            [Mid, Mid, Mid, Adjacent, Off, Off, Adjacent]
+        );
+        // weekends wrap around the end of ISO weeks
+        let sunday_off = Workweek::from_days(&days[..6]).unwrap();
+        assert_eq!(
+            relations(sunday_off),
+            [Adjacent, Mid, Mid, Mid, Mid, Adjacent, Off]
+        );
+        assert_eq!(relations(Workweek::from_days(&days).unwrap()), [Mid; 7]);
+    }
+
+    #[test]
+    fn empty_is_rejected() {
+        assert_eq!(Workweek::from_days(&[]), Err(EmptyWorkweek));
+        assert_eq!(Workweek::from_bits(0), Err(EmptyWorkweek));
+        // bit 0 doesn't correspond to any weekday
+        assert_eq!(Workweek::from_bits(1), Err(EmptyWorkweek));
+        assert_eq!(Workweek::try_from(&[][..]), Err(EmptyWorkweek));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&Workweek::MON_FRI).unwrap(), "62");
+        assert_eq!(
+            serde_json::from_str::<Workweek>("158").unwrap(),
+            Workweek::SUN_THU
+        );
+        assert!(serde_json::from_str::<Workweek>("0").is_err());
+    }
+}
@@ -0,0 +1,117 @@
+//! Time zones used to determine the current date of a country.
+
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+
+use crate::Date;
+
+/// Time zone in which the current date is determined.
+///
+/// Without the `chrono-tz` feature only fixed UTC offsets are supported, so
+/// daylight saving time has to be accounted for by the caller.
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use holidays::TimeZoneRef;
+///
+/// let tz: TimeZoneRef = FixedOffset::east_opt(13 * 3600).unwrap().into();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeZoneRef {
+    /// Fixed offset from UTC.
+    Fixed(FixedOffset),
+    /// Time zone from the IANA database.
+    ///
+    /// Requires the `chrono-tz` feature.
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl TimeZoneRef {
+    /// Returns the date of the `instant` in this time zone.
+    pub(crate) fn date_at(self, instant: DateTime<Utc>) -> Date {
+        match self {
+            TimeZoneRef::Fixed(offset) => instant.with_timezone(&offset).date_naive().into(),
+            #[cfg(feature = "chrono-tz")]
+            TimeZoneRef::Named(tz) => instant.with_timezone(&tz).date_naive().into(),
+        }
+    }
+}
+
+impl From<FixedOffset> for TimeZoneRef {
+    fn from(value: FixedOffset) -> Self {
+        TimeZoneRef::Fixed(value)
+    }
+}
+
+impl From<Utc> for TimeZoneRef {
+    fn from(value: Utc) -> Self {
+        TimeZoneRef::Fixed(value.fix())
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+impl From<chrono_tz::Tz> for TimeZoneRef {
+    fn from(value: chrono_tz::Tz) -> Self {
+        TimeZoneRef::Named(value)
+    }
+}
+
+/// Default time zone of the country, see [`Country::time_zone_name`].
+///
+/// [`Country::time_zone_name`]: crate::Country::time_zone_name
+#[cfg(feature = "chrono-tz")]
+impl From<crate::Country> for TimeZoneRef {
+    fn from(value: crate::Country) -> Self {
+        let tz = value
+            .time_zone_name()
+            .parse()
+            .expect("countries.csv only contains IANA time zones known to chrono-tz");
+        TimeZoneRef::Named(tz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Country;
+
+    #[test]
+    fn fixed_offset_crosses_midnight() {
+        // 2025-12-31T11:30Z is already 2026-01-01 in UTC+13
+        let instant = "2025-12-31T11:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let nzdt = TimeZoneRef::from(FixedOffset::east_opt(13 * 3600).unwrap());
+
+        assert_eq!(nzdt.date_at(instant), Date::from_ymd(2026, 1, 1));
+        assert_eq!(
+            TimeZoneRef::from(Utc).date_at(instant),
+            Date::from_ymd(2025, 12, 31)
+        );
+        assert!(crate::is_holiday_at(Country::NZ, nzdt, instant));
+        assert!(!crate::is_holiday_at(Country::NZ, Utc, instant));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn named_zones() {
+        let instant = "2025-12-31T11:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert!(crate::is_holiday_at(
+            Country::NZ,
+            chrono_tz::Pacific::Auckland,
+            instant
+        ));
+        assert!(crate::is_holiday_at(Country::NZ, Country::NZ, instant));
+        assert!(!crate::is_holiday_at(
+            Country::NZ,
+            chrono_tz::America::New_York,
+            instant
+        ));
+
+        for &country in crate::enabled_countries() {
+            assert!(
+                country.time_zone_name().parse::<chrono_tz::Tz>().is_ok(),
+                "{country} has invalid time zone"
+            );
+        }
+    }
+}
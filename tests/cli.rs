@@ -0,0 +1,82 @@
+//! Runs `examples/cli.rs` and checks its output for known queries.
+
+use std::process::{Command, Output};
+
+fn cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", "--quiet", "--example", "cli", "--"])
+        .args(args)
+        .output()
+        .expect("unable to run cli example")
+}
+
+#[test]
+fn christmas_csv() {
+    let output = cli(&[
+        "--country",
+        "US,GB",
+        "--from",
+        "2025-01-01",
+        "--to",
+        "2025-12-31",
+        "--name",
+        "christmas",
+    ]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "date,country,name\n2025-12-25,GB,Christmas Day\n2025-12-25,US,Christmas Day\n"
+    );
+}
+
+#[test]
+fn json_output() {
+    let output = cli(&[
+        "--country",
+        "US",
+        "--from",
+        "2025-07-04",
+        "--to",
+        "2025-07-04",
+        "--format",
+        "json",
+    ]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "[\n  {\"date\": \"2025-07-04\", \"country\": \"US\", \"name\": \"Independence Day\"}\n]\n"
+    );
+}
+
+#[test]
+fn no_matches() {
+    let output = cli(&[
+        "--country",
+        "US",
+        "--from",
+        "2025-07-05",
+        "--to",
+        "2025-07-05",
+    ]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn bad_input() {
+    for args in [
+        &["--country", "XX"][..],
+        &["--from", "2025-13-01"],
+        &["--format", "xml"],
+        &["--unknown"],
+        &["--country"],
+    ] {
+        let output = cli(args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(!output.stderr.is_empty(), "{args:?}");
+    }
+}
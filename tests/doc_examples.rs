@@ -0,0 +1,152 @@
+//! Checks that every public function and type has an example in its docs.
+//!
+//! Examples are doctests, so they double as tests of how the API reads in
+//! user code; an item without one is easy to get wrong without noticing.
+
+use std::path::Path;
+
+/// Returns `true` if `line` can be part of the doc comments and attributes
+/// of the item that follows.
+fn is_item_prelude(line: &str) -> bool {
+    let line = line.trim();
+    if line.starts_with("///") || line.starts_with("//") {
+        return true;
+    }
+    // attributes, possibly spanning multiple lines
+    !line.is_empty() && !line.ends_with('}') && !line.ends_with(';') && !line.ends_with('{')
+}
+
+/// Declarations of public items, with `pub const fn` ahead of `pub const`
+/// so that functions aren't mistaken for constants.
+const ITEM_PREFIXES: &[&str] = &[
+    "pub fn ",
+    "pub const fn ",
+    "pub struct ",
+    "pub enum ",
+    "pub trait ",
+    "pub type ",
+    "pub const ",
+    "pub static ",
+];
+
+/// Returns names of public items declared in `source`, including associated
+/// items of impl blocks, without an example in their docs.
+///
+/// Test modules, which are only ever the last item of a file, aren't
+/// checked.
+fn items_without_examples(source: &str) -> Vec<String> {
+    let lines: Vec<_> = source.lines().collect();
+    let end = lines
+        .iter()
+        .position(|it| *it == "#[cfg(test)]")
+        .unwrap_or(lines.len());
+    let mut missing = Vec::new();
+    for (i, line) in lines[..end].iter().enumerate() {
+        let Some(item) = ITEM_PREFIXES
+            .iter()
+            .find_map(|it| line.trim_start().strip_prefix(it))
+        else {
+            continue;
+        };
+        let name: String = item
+            .chars()
+            .take_while(|it| it.is_alphanumeric() || *it == '_')
+            .collect();
+        let prelude = lines[..i].iter().rev().take_while(|it| is_item_prelude(it));
+        let mut docs = prelude
+            .filter(|it| it.trim_start().starts_with("///"))
+            .peekable();
+        // items without any docs are only allowed with an explicit
+        // `#[allow(missing_docs)]`, e.g. self-explanatory constants
+        let documented =
+            docs.peek().is_none() || docs.any(|it| it.trim_start().starts_with("/// ```"));
+        if !documented {
+            missing.push(name);
+        }
+    }
+    missing
+}
+
+#[test]
+fn public_items_have_examples() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut files: Vec<_> = std::fs::read_dir(&src)
+        .expect("missing src directory")
+        .map(|it| it.expect("unreadable src directory").path())
+        .filter(|it| it.extension().is_some_and(|it| it == "rs"))
+        .collect();
+    files.sort();
+
+    let mut missing = Vec::new();
+    for file in files {
+        let source = std::fs::read_to_string(&file).expect("unreadable source file");
+        let name = file
+            .file_name()
+            .expect("source file without name")
+            .to_string_lossy();
+        missing.extend(
+            items_without_examples(&source)
+                .into_iter()
+                .map(|it| format!("{name}: {it}")),
+        );
+    }
+    assert!(
+        missing.is_empty(),
+        "public items without examples:\n{}",
+        missing.join("\n")
+    );
+}
+
+#[test]
+fn examples_are_detected() {
+    let source = "\
+/// Documented.
+///
+/// ```
+/// assert!(true);
+/// ```
+#[derive(Debug)]
+#[cfg_attr(
+    feature = \"serde\",
+    derive(serde::Serialize),
+)]
+pub struct Documented;
+
+/// Not documented.
+pub fn undocumented() {}
+
+pub(crate) fn private() {}
+
+impl Documented {
+    /// Documented.
+    ///
+    /// ```
+    /// assert!(true);
+    /// ```
+    pub const fn method(&self) {}
+
+    /// Not documented.
+    pub const ASSOCIATED: u8 = 0;
+}
+
+#[allow(missing_docs)]
+impl Documented {
+    pub const SELF_EXPLANATORY: u8 = 0;
+}
+
+/// Not documented.
+pub type Alias = Documented;
+
+/// Not documented.
+pub static STATIC: u8 = 0;
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+}
+";
+    assert_eq!(
+        items_without_examples(source),
+        ["undocumented", "ASSOCIATED", "Alias", "STATIC"]
+    );
+}
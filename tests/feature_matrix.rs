@@ -0,0 +1,104 @@
+//! Compiles and runs `feature_smoke` test with representative feature
+//! combinations, documentation examples without names, and date tests
+//! without debug assertions, none of which `cargo test` builds on its own.
+//!
+//! It's slow, so it's ignored by default:
+//!
+//! ```sh
+//! cargo test --test feature_matrix -- --ignored
+//! ```
+
+use std::process::Command;
+
+/// Names of configurations and their features; all are built without
+/// default features.
+const CONFIGURATIONS: &[(&str, &str)] = &[
+    ("single-country", "JP"),
+    ("single-country without names", "JP,no-names"),
+    ("chrono only", "JP,chrono"),
+    ("time only", "JP,time"),
+    ("chrono and time", "JP,chrono,time"),
+    (
+        "optional modules",
+        "JP,US,serde,raw-tables,name-index,country-docs,test-support",
+    ),
+    ("all-countries", "all-countries,chrono,time"),
+];
+
+#[test]
+#[ignore = "builds the crate once per configuration"]
+fn feature_combinations() {
+    let mut failures = Vec::new();
+    for (name, features) in CONFIGURATIONS {
+        let output = Command::new(env!("CARGO"))
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            // separate target directory avoids invalidating artifacts of the
+            // default configuration
+            .env(
+                "CARGO_TARGET_DIR",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/target/feature-matrix"),
+            )
+            .args([
+                "test",
+                "--quiet",
+                "--test",
+                "feature_smoke",
+                "--no-default-features",
+            ])
+            .args(["--features", features])
+            .output()
+            .expect("unable to run cargo");
+
+        if !output.status.success() {
+            failures.push(format!(
+                "{name} (--features {features}):\n{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} configuration(s) failed:\n\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+#[ignore = "builds the crate with a separate profile"]
+fn without_debug_assertions() {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["test", "--quiet", "--lib", "--profile", "ndebug", "date::"])
+        .output()
+        .expect("unable to run cargo");
+
+    assert!(
+        output.status.success(),
+        "date tests failed without debug assertions:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+#[ignore = "builds the crate and its examples with no-names feature"]
+fn doc_examples_without_names() {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env(
+            "CARGO_TARGET_DIR",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/target/feature-matrix"),
+        )
+        .args(["test", "--quiet", "--doc", "--features", "no-names"])
+        .output()
+        .expect("unable to run cargo");
+
+    assert!(
+        output.status.success(),
+        "documentation examples failed with no-names feature:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
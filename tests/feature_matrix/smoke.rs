@@ -0,0 +1,69 @@
+//! Smoke test shared by all configurations of the feature matrix.
+//!
+//! Only `JP` is assumed to be enabled, as every configuration includes it.
+
+use holidays::Date;
+use holidays::{error::CountryParseError, Country};
+
+fn japan() -> Country {
+    "jp".parse()
+        .expect("JP must be enabled in every configuration")
+}
+
+#[test]
+fn parses_codes() {
+    assert_eq!(japan().as_ref(), "JP");
+    assert!(matches!(
+        "XX".parse::<Country>(),
+        Err(CountryParseError::Unknown)
+    ));
+    assert_eq!(Country::is_enabled("US"), cfg!(feature = "US"));
+}
+
+#[test]
+fn queries_known_holiday() {
+    let new_year = Date::from_ymd(2025, 1, 1);
+    assert!(holidays::is_holiday(japan(), new_year));
+
+    let holiday = holidays::get_holidays(japan(), new_year).next().unwrap();
+    if cfg!(feature = "no-names") {
+        assert_eq!(holiday.name, "");
+    } else {
+        assert_eq!(holiday.name, "New Year's Day");
+    }
+    assert_eq!(holiday.display_date().to_string(), "2025-01-01");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn converts_chrono_dates() {
+    let date = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    let holiday = holidays::get_holidays(japan(), date).next().unwrap();
+    assert_eq!(holiday.date::<chrono::NaiveDate>(), Ok(date));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn converts_time_dates() {
+    let date = time::macros::date!(2025 - 01 - 01);
+    let holiday = holidays::get_holidays(japan(), date).next().unwrap();
+    assert_eq!(holiday.date::<time::Date>(), Ok(date));
+}
+
+#[test]
+fn infers_default_date() {
+    let holiday = holidays::get_holidays(japan(), Date::from_ymd(2025, 1, 1))
+        .next()
+        .unwrap();
+    let date = holiday.date_default().unwrap();
+
+    #[cfg(feature = "time")]
+    let expected: time::Date = time::macros::date!(2025 - 01 - 01);
+    #[cfg(all(feature = "chrono", not(feature = "time")))]
+    let expected: chrono::NaiveDate = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    let expected: std::time::SystemTime =
+        std::time::UNIX_EPOCH + std::time::Duration::from_secs(20089 * 86400);
+
+    assert_eq!(date, expected);
+}
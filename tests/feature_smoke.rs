@@ -0,0 +1,5 @@
+//! Smoke test of the current feature set; see `feature_matrix.rs` for
+//! running it across configurations.
+
+#[path = "feature_matrix/smoke.rs"]
+mod smoke;
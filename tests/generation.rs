@@ -0,0 +1,413 @@
+//! Runs dataset processing of `build.rs` on fixture CSV files.
+
+#[allow(dead_code)]
+#[path = "../build/dataset.rs"]
+mod dataset;
+
+use dataset::{Country, Gap};
+use std::collections::HashMap;
+use std::fs::File;
+
+fn fixture(name: &str) -> File {
+    File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/").to_string() + name)
+        .expect("missing fixture")
+}
+
+fn countries() -> HashMap<String, Country> {
+    dataset::read_countries(fixture("countries.csv"))
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut country)| {
+            country.index = index as u16;
+            (country.code.clone(), country)
+        })
+        .collect()
+}
+
+fn gaps(countries: &HashMap<String, Country>, year_range: (i64, i64)) -> Vec<Gap> {
+    let holidays =
+        dataset::read_holidays(fixture("holidays_gap.csv"), countries, year_range, false).unwrap();
+    let mut ordered: Vec<&Country> = countries.values().collect();
+    ordered.sort_by_key(|it| it.index);
+    dataset::coverage_gaps(&holidays, ordered.into_iter())
+}
+
+fn gap(code: &str, first_year: i64, last_year: i64) -> Gap {
+    Gap {
+        code: code.to_string(),
+        first_year,
+        last_year,
+    }
+}
+
+#[test]
+fn reports_missing_years() {
+    let countries = countries();
+    assert_eq!(
+        gaps(&countries, (2000, 2035)),
+        [
+            gap("BB", 2021, 2021),
+            gap("BB", 2023, 2023),
+            gap("CC", 2019, 2023)
+        ]
+    );
+}
+
+#[test]
+fn window_follows_requested_years() {
+    let countries = countries();
+    assert_eq!(
+        gaps(&countries, (2022, 2035)),
+        [gap("BB", 2023, 2023), gap("CC", 2022, 2023)]
+    );
+    assert_eq!(gaps(&countries, (2022, 2022)), [gap("CC", 2022, 2022)]);
+    assert_eq!(gaps(&countries, (2030, 2035)), []);
+}
+
+#[test]
+fn disabled_countries_are_ignored() {
+    let mut countries = countries();
+    countries.remove("CC");
+    assert_eq!(
+        gaps(&countries, (2000, 2035)),
+        [gap("BB", 2021, 2021), gap("BB", 2023, 2023)]
+    );
+}
+
+#[test]
+fn flags_default_to_empty() {
+    let countries = countries();
+    let holidays =
+        dataset::read_holidays(fixture("holidays_gap.csv"), &countries, (2000, 2035), false)
+            .unwrap();
+    assert!(holidays.iter().all(|it| it.flags == 0));
+
+    let holidays = dataset::read_holidays(
+        fixture("holidays_flags.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+    let flags: Vec<u16> = holidays.iter().map(|it| it.flags).collect();
+    assert_eq!(flags, [0, 1, 0x0302]);
+}
+
+#[test]
+fn unknown_columns_are_rejected() {
+    let countries = countries();
+    assert_eq!(
+        dataset::read_holidays(
+            fixture("holidays_extra.csv"),
+            &countries,
+            (2000, 2035),
+            false
+        )
+        .err(),
+        Some("unknown column 'subdivisions'".to_string())
+    );
+    let holidays = dataset::read_holidays(
+        fixture("holidays_extra.csv"),
+        &countries,
+        (2000, 2035),
+        true,
+    )
+    .unwrap();
+    assert_eq!(holidays.len(), 2);
+}
+
+#[test]
+fn required_columns_are_checked() {
+    let header =
+        |columns: &[&str]| dataset::Schema::from_header(&csv::StringRecord::from(columns), false);
+
+    assert!(header(&["country_code", "date", "holiday_name"]).is_ok());
+    // column order isn't significant
+    assert!(header(&["flags", "holiday_name", "date", "country_code"]).is_ok());
+    assert_eq!(
+        header(&["country_code", "date"]),
+        Err("missing column 'holiday_name'".to_string())
+    );
+    assert_eq!(
+        header(&["country_code", "date", "date", "holiday_name"]),
+        Err("duplicate column 'date'".to_string())
+    );
+}
+
+/// Splits a Markdown table row into cells, honoring escaped pipes.
+fn table_cells(row: &str) -> Option<Vec<String>> {
+    let inner = row.strip_prefix('|')?.strip_suffix('|')?;
+    let mut cells = vec![String::new()];
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => cells.last_mut()?.push(chars.next()?),
+            '|' => cells.push(String::new()),
+            c => cells.last_mut()?.push(c),
+        }
+    }
+    Some(cells.into_iter().map(|it| it.trim().to_string()).collect())
+}
+
+#[test]
+fn country_docs_are_valid_markdown() {
+    let countries = countries();
+    let holidays = dataset::read_holidays(
+        fixture("holidays_markdown.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+
+    for (code, expected) in [
+        (
+            "AA",
+            vec!["New Year's Day", "Back\\slash_Day [1]", "New Year's Day"],
+        ),
+        ("BB", vec!["*Star* | Pipe Day"]),
+    ] {
+        let doc = dataset::country_doc(&countries[code], &holidays, (2025, 2027), 10);
+        assert!(std::str::from_utf8(doc.as_bytes()).is_ok());
+
+        let rows: Vec<_> = doc.lines().filter(|it| it.starts_with('|')).collect();
+        assert_eq!(rows[..2], ["| Date | Holiday |", "|------|---------|"]);
+        let names: Vec<_> = rows[2..]
+            .iter()
+            .map(|row| {
+                let cells = table_cells(row).unwrap_or_else(|| panic!("broken row: {row}"));
+                assert_eq!(cells.len(), 2, "broken row: {row}");
+                cells[1].clone()
+            })
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    let doc = dataset::country_doc(&countries["CC"], &holidays, (2025, 2027), 10);
+    assert!(!doc.contains('|'));
+}
+
+#[test]
+fn country_docs_are_capped() {
+    let countries = countries();
+    let holidays = dataset::read_holidays(
+        fixture("holidays_markdown.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+
+    let doc = dataset::country_doc(&countries["AA"], &holidays, (2025, 2027), 1);
+    assert_eq!(doc.lines().filter(|it| it.starts_with('|')).count(), 3);
+    assert!(doc.contains("Only the first 1 of 3 holidays are listed."));
+    assert_eq!(
+        doc,
+        dataset::country_doc(&countries["AA"], &holidays, (2025, 2027), 1)
+    );
+}
+
+#[test]
+fn same_day_rows_are_separate() {
+    let countries = countries();
+    let holidays = dataset::read_holidays(
+        fixture("holidays_same_day.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+
+    let records: Vec<_> = holidays
+        .iter()
+        .map(|it| (it.country.code.as_str(), it.name.as_str(), it.flags))
+        .collect();
+    assert_eq!(
+        records,
+        [
+            ("AA", "Bank Holiday", 2),
+            // duplicate rows are merged
+            ("AA", "New Year's Day", 1),
+            ("AA", "Zeta Day", 2),
+            ("BB", "New Year's Day", 0),
+        ]
+    );
+}
+
+#[test]
+fn stripped_same_day_records_are_merged() {
+    let countries = countries();
+    let holidays = dataset::read_holidays(
+        fixture("holidays_same_day.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+    let holidays = dataset::strip_names(holidays);
+
+    let records: Vec<_> = holidays
+        .iter()
+        .map(|it| (it.country.code.as_str(), it.name.as_str(), it.flags))
+        .collect();
+    assert_eq!(records, [("AA", "", 3), ("BB", "", 0)]);
+}
+
+#[test]
+fn name_languages_default_per_country() {
+    let countries: HashMap<String, Country> =
+        dataset::read_countries(fixture("countries_name_language.csv"))
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut country)| {
+                country.index = index as u16;
+                (country.code.clone(), country)
+            })
+            .collect();
+    assert_eq!(countries["AA"].name_language, "de");
+    assert_eq!(countries["BB"].name_language, "en");
+    // the column is optional
+    assert!(self::countries()
+        .values()
+        .all(|it| it.name_language == "en"));
+
+    let holidays = dataset::read_holidays(
+        fixture("holidays_name_language.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+    let languages: Vec<_> = holidays
+        .iter()
+        .map(|it| {
+            (
+                it.country.code.as_str(),
+                it.name.as_str(),
+                it.name_language(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        languages,
+        [
+            ("AA", "Neujahr", "de"),
+            ("BB", "New Year's Day", "en"),
+            ("AA", "Bank Holiday", "en"),
+            ("BB", "Berchtoldstag", "de-CH"),
+        ]
+    );
+}
+
+#[test]
+fn portions_are_packed_into_flags() {
+    let countries = countries();
+    let holidays = dataset::read_holidays(
+        fixture("holidays_portion.csv"),
+        &countries,
+        (2000, 2035),
+        false,
+    )
+    .unwrap();
+
+    let portions: Vec<_> = holidays
+        .iter()
+        .map(|it| {
+            (
+                it.country.code.as_str(),
+                it.date.day,
+                it.flags >> dataset::PORTION_SHIFT,
+            )
+        })
+        .collect();
+    assert_eq!(
+        portions,
+        [
+            ("AA", 24, 2),
+            ("BB", 24, 0),
+            ("AA", 25, 0),
+            // same-day holidays keep their own halves
+            ("AA", 31, 2),
+            ("AA", 31, 2),
+            ("BB", 31, 1),
+            ("BB", 31, 2),
+            ("CC", 31, 1),
+        ]
+    );
+}
+
+#[test]
+fn extra_records_are_labeled() {
+    let countries = countries();
+    let read =
+        |name| dataset::read_holidays(fixture(name), &countries, (2000, 2035), false).unwrap();
+    let holidays =
+        dataset::merge_extra(read("holidays_flags.csv"), read("holidays_supplement.csv"));
+
+    let records: Vec<_> = holidays
+        .iter()
+        .map(|it| {
+            let extra = it.flags & dataset::SOURCE_EXTRA != 0;
+            (
+                it.country.code.as_str(),
+                it.date.day,
+                it.name.as_str(),
+                extra,
+            )
+        })
+        .collect();
+    assert_eq!(
+        records,
+        [
+            ("AA", 1, "New Year's Day", false),
+            ("AA", 2, "Bank Holiday", true),
+            ("AA", 2, "New Year's Day (observed)", false),
+            ("BB", 2, "Substitute Holiday", false),
+            ("CC", 3, "Gamma Day", true),
+        ]
+    );
+    // other flags are kept
+    assert_eq!(holidays[2].flags, 1);
+}
+
+#[test]
+fn attribution_is_validated() {
+    let read = |content: &str| dataset::read_attribution(content.as_bytes());
+    let attribution = read(
+        "# comment\n\n[source]\nname = \"Upstream \\\"Data\\\"\" # trailing\nlicense = \"MIT\"\nurl = \"https://example.com\"\n",
+    )
+    .unwrap();
+    assert_eq!(attribution.source, "Upstream \"Data\"");
+    assert_eq!(
+        (attribution.license.as_str(), attribution.url.as_str()),
+        ("MIT", "https://example.com")
+    );
+
+    for (content, error) in [
+        ("", "missing key 'name'"),
+        ("name = \"a\"", "line 1: key outside of [source] table"),
+        (
+            "[source]\nname = \"a\"\nlicense = \"MIT\"",
+            "missing key 'url'",
+        ),
+        (
+            "[source]\nname = \" \"\nlicense = \"MIT\"\nurl = \"b\"",
+            "empty key 'name'",
+        ),
+        (
+            "[source]\nname = \"a\"\nname = \"b\"",
+            "line 3: duplicate key 'name'",
+        ),
+        ("[source]\nauthor = \"a\"", "line 2: unknown key 'author'"),
+        ("[source]\nname = a", "line 2: invalid string value"),
+        ("[source]\nname = \"a\\nb\"", "line 2: invalid string value"),
+        ("[source]\nname = \"a\" b", "line 2: invalid string value"),
+        ("[data]", "line 1: unexpected table [data]"),
+    ] {
+        assert_eq!(read(content), Err(error.to_string()), "{content:?}");
+    }
+
+    // the file the crate is built with
+    let root = File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/ATTRIBUTION.toml")).unwrap();
+    assert!(dataset::read_attribution(root).is_ok());
+}
@@ -0,0 +1,73 @@
+//! Checks well-known holidays listed in `golden.csv` against embedded data.
+//!
+//! The list is curated by hand and holds up to three holidays of each
+//! country: New Year's Day, a national day and Christmas, where they're
+//! observed. Regenerated data that drops or moves any of them fails here,
+//! instead of reaching users unnoticed.
+
+use holidays::{Country, Date};
+
+struct Fact {
+    country: Country,
+    date: Date,
+    name: String,
+}
+
+/// Reads facts of enabled countries.
+fn facts() -> Vec<Fact> {
+    let mut reader = csv::Reader::from_path(concat!(env!("CARGO_MANIFEST_DIR"), "/golden.csv"))
+        .expect("missing golden.csv");
+    reader
+        .records()
+        .map(|record| record.expect("malformed golden.csv"))
+        .filter_map(|record| {
+            let country: Country = record[0].parse().ok()?;
+            Some(Fact {
+                country,
+                date: record[1].parse().expect("invalid golden date"),
+                name: record[2].to_string(),
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn golden_facts() {
+    let facts = facts();
+    let mut failures = Vec::new();
+    for fact in &facts {
+        let coverage = fact.country.coverage();
+        if fact.date.year() < coverage.first_year || fact.date.year() > coverage.last_year {
+            // embedded years were narrowed down by the build configuration
+            continue;
+        }
+        let actual: Vec<_> = holidays::get_holidays(fact.country, fact.date).collect();
+        if !holidays::is_holiday(fact.country, fact.date) || actual.is_empty() {
+            failures.push(format!(
+                "{} {}: expected \"{}\", found no holidays",
+                fact.country, fact.date, fact.name
+            ));
+            continue;
+        }
+        let names: Vec<_> = actual.iter().map(|it| it.name).collect();
+        if !cfg!(feature = "no-names") && !names.contains(&fact.name.as_str()) {
+            failures.push(format!(
+                "{} {}: expected \"{}\", found {names:?}",
+                fact.country, fact.date, fact.name
+            ));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "golden facts don't match:\n{}",
+        failures.join("\n")
+    );
+
+    // countries added to the dataset need curated facts as well
+    for country in holidays::enabled_countries() {
+        assert!(
+            facts.iter().any(|it| it.country == *country),
+            "no golden facts of {country}"
+        );
+    }
+}